@@ -1,10 +1,4 @@
---
--- File generated with SQLiteStudio v3.4.4 on Mon Aug 21 01:09:19 2023
---
--- Text encoding used: System
---
-PRAGMA foreign_keys = off;
-BEGIN TRANSACTION;
+-- The schema as it was before versioned migrations, when every guild shared one emoji economy.
 
 -- Table: emoji_inventory
 CREATE TABLE IF NOT EXISTS emoji_inventory (user INTEGER NOT NULL, emoji TEXT NOT NULL, group_id REFERENCES emoji_inventory_groups (id) ON DELETE SET NULL ON UPDATE CASCADE);
@@ -32,6 +26,3 @@ CREATE TABLE IF NOT EXISTS user_settings (user INTEGER PRIMARY KEY, private INTE
 
 -- Index: 
 CREATE UNIQUE INDEX IF NOT EXISTS "" ON trade_offers (user, target_user);
-
-COMMIT TRANSACTION;
-PRAGMA foreign_keys = on;
@@ -0,0 +1,42 @@
+-- Moves from the single global economy to per-guild economies.
+--
+-- All existing inventories, groups, drop dates, trade offers and trade logs are assigned to the home guild, which the
+-- migration runner puts in temp.migration_parameters. If there is existing data and no home guild was given, the
+-- NOT NULL constraints on the new guild columns make this migration fail.
+
+-- Table: emoji_inventory_groups
+CREATE TABLE new_emoji_inventory_groups (id INTEGER PRIMARY KEY NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL, name TEXT NOT NULL COLLATE NOCASE, sort_order INTEGER NOT NULL CHECK (sort_order != 0), CONSTRAINT unique_name_per_user UNIQUE (guild, user, name COLLATE NOCASE), UNIQUE (guild, user, sort_order));
+INSERT INTO new_emoji_inventory_groups (id, guild, user, name, sort_order)
+	SELECT id, (SELECT home_guild FROM temp.migration_parameters), user, name, sort_order FROM emoji_inventory_groups;
+DROP TABLE emoji_inventory_groups;
+ALTER TABLE new_emoji_inventory_groups RENAME TO emoji_inventory_groups;
+
+-- Table: emoji_inventory
+CREATE TABLE new_emoji_inventory (guild INTEGER NOT NULL, user INTEGER NOT NULL, emoji TEXT NOT NULL, group_id REFERENCES emoji_inventory_groups (id) ON DELETE SET NULL ON UPDATE CASCADE);
+INSERT INTO new_emoji_inventory (guild, user, emoji, group_id)
+	SELECT (SELECT home_guild FROM temp.migration_parameters), user, emoji, group_id FROM emoji_inventory;
+DROP TABLE emoji_inventory;
+ALTER TABLE new_emoji_inventory RENAME TO emoji_inventory;
+
+-- Table: last_seen
+CREATE TABLE new_last_seen (guild INTEGER NOT NULL, user INTEGER NOT NULL, date DATE NOT NULL DEFAULT (date()), PRIMARY KEY (guild, user) ON CONFLICT REPLACE);
+INSERT INTO new_last_seen (guild, user, date)
+	SELECT (SELECT home_guild FROM temp.migration_parameters), user, date FROM last_seen;
+DROP TABLE last_seen;
+ALTER TABLE new_last_seen RENAME TO last_seen;
+
+-- Table: trade_log
+CREATE TABLE new_trade_log (id INTEGER PRIMARY KEY, guild INTEGER NOT NULL, initiating_user INTEGER NOT NULL, recipient_user INTEGER NOT NULL, time DATETIME DEFAULT (datetime()) NOT NULL);
+INSERT INTO new_trade_log (id, guild, initiating_user, recipient_user, time)
+	SELECT id, (SELECT home_guild FROM temp.migration_parameters), initiating_user, recipient_user, time FROM trade_log;
+DROP TABLE trade_log;
+ALTER TABLE new_trade_log RENAME TO trade_log;
+
+-- Table: trade_offers
+DROP INDEX IF EXISTS "";
+CREATE TABLE new_trade_offers (id INTEGER PRIMARY KEY NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL, target_user INTEGER NOT NULL, time DATETIME DEFAULT (datetime()) NOT NULL);
+INSERT INTO new_trade_offers (id, guild, user, target_user, time)
+	SELECT id, (SELECT home_guild FROM temp.migration_parameters), user, target_user, time FROM trade_offers;
+DROP TABLE trade_offers;
+ALTER TABLE new_trade_offers RENAME TO trade_offers;
+CREATE UNIQUE INDEX "" ON trade_offers (guild, user, target_user);
@@ -0,0 +1,11 @@
+-- Stores which drop period a user was last seen in, rather than the date, as periods now depend on guild settings.
+--
+-- Existing dates become keys of the default weekly period. In guilds that had daily drops, users who were already seen
+-- today will get one more drop today.
+
+-- Table: last_seen
+CREATE TABLE new_last_seen (guild INTEGER NOT NULL, user INTEGER NOT NULL, period TEXT NOT NULL, PRIMARY KEY (guild, user) ON CONFLICT REPLACE);
+INSERT INTO new_last_seen (guild, user, period)
+	SELECT guild, user, strftime('%G-W%V', date) FROM last_seen;
+DROP TABLE last_seen;
+ALTER TABLE new_last_seen RENAME TO last_seen;
@@ -0,0 +1,11 @@
+-- Keeps track of streaks of drop periods in a row that users were seen in, and of streak rewards still to be picked.
+
+-- Table: last_seen
+ALTER TABLE last_seen ADD COLUMN streak INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE last_seen ADD COLUMN best_streak INTEGER NOT NULL DEFAULT 1;
+
+-- Table: streak_rewards
+CREATE TABLE streak_rewards (id INTEGER PRIMARY KEY NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL);
+
+-- Table: streak_reward_choices
+CREATE TABLE streak_reward_choices (reward INTEGER NOT NULL REFERENCES streak_rewards (id) ON DELETE CASCADE, emoji TEXT NOT NULL);
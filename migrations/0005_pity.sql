@@ -0,0 +1,4 @@
+-- Counts how many duplicate emojis in a row users have been handed, for bad luck protection.
+
+-- Table: pity_counters
+CREATE TABLE pity_counters (guild INTEGER NOT NULL, user INTEGER NOT NULL, duplicates INTEGER NOT NULL, PRIMARY KEY (guild, user));
@@ -0,0 +1,7 @@
+-- Lets users choose how they hear about drops and trade offers, and keeps what they have yet to hear about for a digest.
+
+-- Table: user_settings
+ALTER TABLE user_settings ADD COLUMN notifications TEXT NOT NULL DEFAULT 'reaction' CHECK (notifications IN ('reaction', 'direct_message', 'next_command', 'none'));
+
+-- Table: pending_notifications
+CREATE TABLE pending_notifications (id INTEGER PRIMARY KEY NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL, emoji TEXT, offering_user INTEGER, CHECK ((emoji IS NULL) != (offering_user IS NULL)));
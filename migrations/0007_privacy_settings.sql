@@ -0,0 +1,10 @@
+-- Splits the single private setting into one per thing it used to hide, and lets users limit who can offer them trades.
+
+-- Table: user_settings
+ALTER TABLE user_settings ADD COLUMN show_inventory INTEGER NOT NULL DEFAULT 1 CHECK (show_inventory IN (0, 1));
+ALTER TABLE user_settings ADD COLUMN listed_as_owner INTEGER NOT NULL DEFAULT 1 CHECK (listed_as_owner IN (0, 1));
+ALTER TABLE user_settings ADD COLUMN public_recycling INTEGER NOT NULL DEFAULT 1 CHECK (public_recycling IN (0, 1));
+ALTER TABLE user_settings ADD COLUMN drop_reactions INTEGER NOT NULL DEFAULT 1 CHECK (drop_reactions IN (0, 1));
+ALTER TABLE user_settings ADD COLUMN trade_offers_from TEXT NOT NULL DEFAULT 'everyone' CHECK (trade_offers_from IN ('everyone', 'trading_role', 'nobody'));
+UPDATE user_settings SET show_inventory = 0, listed_as_owner = 0, public_recycling = 0, drop_reactions = 0 WHERE private = 1;
+ALTER TABLE user_settings DROP COLUMN private;
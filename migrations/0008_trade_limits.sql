@@ -0,0 +1,7 @@
+-- Lets users block others from offering them trades, and remembers rejected offers for the cooldown on offering again.
+
+-- Table: blocked_users
+CREATE TABLE blocked_users (user INTEGER NOT NULL, blocked_user INTEGER NOT NULL, PRIMARY KEY (user, blocked_user));
+
+-- Table: trade_rejections
+CREATE TABLE trade_rejections (guild INTEGER NOT NULL, user INTEGER NOT NULL, target_user INTEGER NOT NULL, time DATETIME DEFAULT (datetime()) NOT NULL, PRIMARY KEY (guild, user, target_user));
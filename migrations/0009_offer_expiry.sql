@@ -0,0 +1,8 @@
+-- Gives notices a kind, so offerers can be told about their trade offers expiring.
+
+-- Table: pending_notifications
+CREATE TABLE new_pending_notifications (id INTEGER PRIMARY KEY NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL, kind TEXT NOT NULL CHECK (kind IN ('emoji', 'trade_offer', 'expired_offer')), emoji TEXT, other_user INTEGER, CHECK ((kind = 'emoji') = (emoji IS NOT NULL) AND (kind = 'emoji') = (other_user IS NULL)));
+INSERT INTO new_pending_notifications (id, guild, user, kind, emoji, other_user)
+	SELECT id, guild, user, CASE WHEN emoji IS NULL THEN 'trade_offer' ELSE 'emoji' END, emoji, offering_user FROM pending_notifications;
+DROP TABLE pending_notifications;
+ALTER TABLE new_pending_notifications RENAME TO pending_notifications;
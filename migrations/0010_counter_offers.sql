@@ -0,0 +1,12 @@
+-- Links counter-offers to the offers they replaced.
+--
+-- Offer IDs become AUTOINCREMENT, so the ID of a replaced offer is never reused by a later one and the link keeps
+-- pointing at nothing rather than at an unrelated offer.
+
+-- Table: trade_offers
+CREATE TABLE new_trade_offers (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL, target_user INTEGER NOT NULL, time DATETIME DEFAULT (datetime()) NOT NULL, counter_to INTEGER);
+INSERT INTO new_trade_offers (id, guild, user, target_user, time)
+	SELECT id, guild, user, target_user, time FROM trade_offers;
+DROP TABLE trade_offers;
+ALTER TABLE new_trade_offers RENAME TO trade_offers;
+CREATE UNIQUE INDEX "" ON trade_offers (guild, user, target_user);
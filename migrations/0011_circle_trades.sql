@@ -0,0 +1,7 @@
+-- Logs who gave what to whom in trades between more than two users.
+--
+-- Such a trade still gets a trade_log row, with the user who proposed it as the initiating user and whom they gave to
+-- as the recipient, and its contents are what the proposing user gave and got, as for any other trade.
+
+-- Table: trade_log_participants
+CREATE TABLE trade_log_participants (trade INTEGER NOT NULL REFERENCES trade_log (id) ON DELETE CASCADE ON UPDATE CASCADE, user INTEGER NOT NULL, recipient_user INTEGER NOT NULL, emoji TEXT NOT NULL, count INTEGER NOT NULL CHECK (count > 0));
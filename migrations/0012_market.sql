@@ -0,0 +1,9 @@
+-- Open trade listings, which any user can fill.
+--
+-- Listing IDs are shown to users to fill listings by, so they are AUTOINCREMENT and never reused.
+
+-- Table: market_listings
+CREATE TABLE market_listings (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL, any_one INTEGER NOT NULL DEFAULT 0 CHECK (any_one IN (0, 1)), time DATETIME DEFAULT (datetime()) NOT NULL);
+
+-- Table: market_listing_contents
+CREATE TABLE market_listing_contents (listing INTEGER NOT NULL REFERENCES market_listings (id) ON DELETE CASCADE ON UPDATE CASCADE, emoji TEXT NOT NULL, count INTEGER NOT NULL CHECK (count != 0));
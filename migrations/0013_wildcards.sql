@@ -0,0 +1,8 @@
+-- Lets the request of a trade offer hold a wildcard, which the target user fills in with emojis of their choosing when
+-- they accept it.
+
+-- Table: trade_offer_wildcards
+CREATE TABLE trade_offer_wildcards (trade INTEGER PRIMARY KEY NOT NULL REFERENCES trade_offers (id) ON DELETE CASCADE ON UPDATE CASCADE, kind TEXT NOT NULL CHECK (kind IN ('any_of', 'category', 'duplicates')), category TEXT, count INTEGER NOT NULL CHECK (count > 0), CHECK ((kind = 'category') = (category IS NOT NULL)));
+
+-- Table: trade_offer_wildcard_emojis
+CREATE TABLE trade_offer_wildcard_emojis (trade INTEGER NOT NULL REFERENCES trade_offer_wildcards (trade) ON DELETE CASCADE ON UPDATE CASCADE, emoji TEXT NOT NULL, PRIMARY KEY (trade, emoji));
@@ -0,0 +1,16 @@
+-- Timed auctions, where users bid emojis for emojis another user puts up.
+--
+-- Auction IDs are shown to users to bid by, so they are AUTOINCREMENT and never reused.
+-- What is put up and what is bid is held in escrow: the inventory rows point to a lock, and can't be traded or recycled until the lock is gone.
+
+-- Table: auctions
+CREATE TABLE auctions (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL, ends DATETIME NOT NULL, time DATETIME DEFAULT (datetime()) NOT NULL);
+
+-- Table: auction_bids
+CREATE TABLE auction_bids (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, auction INTEGER NOT NULL REFERENCES auctions (id) ON DELETE CASCADE, user INTEGER NOT NULL, time DATETIME DEFAULT (datetime()) NOT NULL, UNIQUE (auction, user));
+
+-- Table: emoji_locks
+CREATE TABLE emoji_locks (id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL, auction INTEGER UNIQUE REFERENCES auctions (id) ON DELETE CASCADE, bid INTEGER UNIQUE REFERENCES auction_bids (id) ON DELETE CASCADE);
+
+-- Table: emoji_inventory
+ALTER TABLE emoji_inventory ADD COLUMN lock INTEGER REFERENCES emoji_locks (id) ON DELETE SET NULL;
@@ -0,0 +1,7 @@
+-- Locks for trade offers, for guilds that reserve the emojis offered in them.
+
+-- Table: emoji_locks
+ALTER TABLE emoji_locks ADD COLUMN trade INTEGER REFERENCES trade_offers (id) ON DELETE CASCADE;
+
+-- Index: emoji_locks_trade
+CREATE UNIQUE INDEX emoji_locks_trade ON emoji_locks (trade);
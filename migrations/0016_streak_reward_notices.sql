@@ -0,0 +1,8 @@
+-- Lets users be told about streak rewards waiting to be picked, which have neither an emoji nor another user.
+
+-- Table: pending_notifications
+CREATE TABLE new_pending_notifications (id INTEGER PRIMARY KEY NOT NULL, guild INTEGER NOT NULL, user INTEGER NOT NULL, kind TEXT NOT NULL CHECK (kind IN ('emoji', 'trade_offer', 'expired_offer', 'streak_reward')), emoji TEXT, other_user INTEGER, CHECK ((kind = 'emoji') = (emoji IS NOT NULL) AND (kind IN ('trade_offer', 'expired_offer')) = (other_user IS NOT NULL)));
+INSERT INTO new_pending_notifications (id, guild, user, kind, emoji, other_user)
+	SELECT id, guild, user, kind, emoji, other_user FROM pending_notifications;
+DROP TABLE pending_notifications;
+ALTER TABLE new_pending_notifications RENAME TO pending_notifications;
@@ -0,0 +1,185 @@
+//! Suggestions for emoji and group name options while they are being typed.
+
+use serenity::all::UserId;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+	context::Context,
+	discord::Choice,
+	emoji::{Emoji, EmojiMap},
+	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
+	inventory::queries::get_group_names,
+	util::parse_emoji_input,
+};
+
+/// Discord won't take more choices than this.
+const MAX_CHOICES: usize = 25;
+/// Discord's limit, in characters, for both the name and the value of a choice.
+const MAX_CHOICE_LENGTH: usize = 100;
+
+/// Suggests the user's groups whose names contain the input.
+pub async fn group_names(
+	context: Context<'_>,
+	user: UserId,
+	input: &str,
+) -> Result<Vec<Choice>, BotError> {
+	let input = input.trim().to_lowercase();
+	Ok(get_group_names(context.database, context.guild, user)
+		.await?
+		.into_iter()
+		.filter(|name| name.to_lowercase().contains(&input))
+		.take(MAX_CHOICES)
+		.map(|name| Choice {
+			name: name.clone(),
+			value: name,
+		})
+		.collect())
+}
+
+/// Suggests emojis owned by `owner` to finish a list of emojis with.
+pub async fn owned_emojis(
+	context: Context<'_>,
+	owner: UserId,
+	input: &str,
+) -> Result<Vec<Choice>, BotError> {
+	let (finished, unfinished) = split_unfinished(context.emoji_map, input);
+	let owned = owned(context, owner).await?;
+	Ok(complete_emoji(finished, unfinished, owned))
+}
+
+/// Suggests a single emoji owned by `owner`.
+pub async fn owned_emoji(
+	context: Context<'_>,
+	owner: UserId,
+	input: &str,
+) -> Result<Vec<Choice>, BotError> {
+	let owned = owned(context, owner).await?;
+	Ok(complete_emoji("", input, owned))
+}
+
+/// Suggests a single emoji out of all of them.
+pub fn any_emoji(input: &str) -> Vec<Choice> {
+	let emojis = Emoji::all().map(|emoji| (emoji, None));
+	complete_emoji("", input, emojis)
+}
+
+async fn owned(
+	context: Context<'_>,
+	owner: UserId,
+) -> Result<impl Iterator<Item = (Emoji, Option<u32>)>, BotError> {
+	Ok(EmojisWithCounts::from_database_for_user(
+		context.database,
+		context.emoji_map,
+		context.guild,
+		owner,
+	)
+	.await?
+	.into_iter()
+	.map(|(emoji, count)| (emoji, Some(count))))
+}
+
+/// Splits the input into the longest start that is all emojis, and the rest, which is presumably an emoji being typed.
+fn split_unfinished<'i>(emoji_map: &EmojiMap, input: &'i str) -> (&'i str, &'i str) {
+	// Nothing longer could be filled in anyway, and this keeps the repeated parsing cheap.
+	if input.chars().count() > MAX_CHOICE_LENGTH {
+		return (input, "");
+	}
+	let ends = input
+		.grapheme_indices(true)
+		.map(|(index, _)| index)
+		.chain([input.len()]);
+	for end in ends.rev() {
+		if parse_emoji_input(emoji_map, &input[..end]).is_ok() {
+			return input.split_at(end);
+		}
+	}
+	("", input)
+}
+
+/// Appends each candidate emoji that matches the unfinished input by name or shortcode to the finished input. Candidates that start with it come first. Counts, if given, are shown alongside.
+fn complete_emoji(
+	finished: &str,
+	unfinished: &str,
+	candidates: impl IntoIterator<Item = (Emoji, Option<u32>)>,
+) -> Vec<Choice> {
+	let query = unfinished
+		.trim()
+		.trim_matches(|char| matches!(char, ':' | '"' | '“' | '”'))
+		.to_lowercase();
+	let mut matches = candidates
+		.into_iter()
+		.filter_map(|(emoji, count)| {
+			let name = emoji.name().to_lowercase();
+			let starts_with = emoji.as_str() == query
+				|| name.starts_with(&query)
+				|| emoji
+					.shortcodes()
+					.iter()
+					.any(|shortcode| shortcode.starts_with(&query));
+			let contains = starts_with
+				|| name.contains(&query)
+				|| emoji
+					.shortcodes()
+					.iter()
+					.any(|shortcode| shortcode.contains(&query));
+			contains.then_some((!starts_with, emoji, count))
+		})
+		.collect::<Vec<_>>();
+	// Stable, so the candidates otherwise keep their order.
+	matches.sort_by_key(|(later, _, _)| *later);
+	matches
+		.into_iter()
+		.map(|(_, emoji, count)| {
+			let value = format!("{finished}{emoji}");
+			let name = match count {
+				Some(count) => format!("{value} {} ×{count}", emoji.name()),
+				None => format!("{value} {}", emoji.name()),
+			};
+			Choice {
+				name: name.chars().take(MAX_CHOICE_LENGTH).collect(),
+				value,
+			}
+		})
+		.filter(|choice| choice.value.chars().count() <= MAX_CHOICE_LENGTH)
+		.take(MAX_CHOICES)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn completes_the_last_emoji() {
+		let emoji_map = EmojiMap::without_images();
+		let candidates = ["🙂", "🙃", "😀"].map(|emoji| (emoji_map.get(emoji).unwrap(), Some(2)));
+
+		let (finished, unfinished) = split_unfinished(&emoji_map, "🙃 :grin");
+		assert_eq!((finished, unfinished), ("🙃 ", ":grin"));
+		let choices = complete_emoji(finished, unfinished, candidates);
+		assert_eq!(
+			choices,
+			[Choice {
+				name: String::from("🙃 😀 grinning face ×2"),
+				value: String::from("🙃 😀"),
+			}]
+		);
+
+		let (finished, unfinished) = split_unfinished(&emoji_map, "🙃\"upside");
+		assert_eq!((finished, unfinished), ("🙃", "\"upside"));
+		let values: Vec<_> = complete_emoji(finished, unfinished, candidates)
+			.into_iter()
+			.map(|choice| choice.value)
+			.collect();
+		assert_eq!(values, ["🙃🙃"]);
+
+		// Starting with the input goes before merely containing it, even though plenty of faces shed tears before it in the list.
+		let values: Vec<_> = any_emoji("tear")
+			.into_iter()
+			.map(|choice| choice.value)
+			.take(1)
+			.collect();
+		assert_eq!(values, ["📆"]);
+	}
+}
@@ -0,0 +1,115 @@
+//! The slash commands, and the registry that both registers them with Discord and dispatches interactions to them.
+
+use serenity::{all::CommandInteraction, async_trait, builder::CreateCommand};
+
+use crate::{
+	context::Context, discord::Choice, error::BotError, events, find_emoji, images, inventory,
+	pity, streaks, trading, user_settings,
+};
+
+#[async_trait]
+pub trait SlashCommand: Send + Sync {
+	/// The name the command is registered under, which must match the name in `register`.
+	fn name(&self) -> &'static str;
+	fn register(&self) -> CreateCommand;
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError>;
+	/// Suggests values for the option that is being typed. Only called for options registered with autocomplete.
+	async fn autocomplete(
+		&self,
+		_context: Context<'_>,
+		_interaction: &CommandInteraction,
+	) -> Result<Vec<Choice>, BotError> {
+		Ok(Vec::new())
+	}
+}
+
+pub struct CommandRegistry {
+	commands: Vec<Box<dyn SlashCommand>>,
+}
+
+impl CommandRegistry {
+	/// Every command the bot has. Adding a command means adding it here, and nowhere else.
+	pub fn new() -> Self {
+		Self {
+			commands: vec![
+				Box::new(inventory::view::InventoryCommand),
+				Box::new(inventory::group::GroupCommand),
+				Box::new(find_emoji::WhoCommand),
+				Box::new(trading::trade::TradeCommand),
+				Box::new(trading::market::MarketCommand),
+				Box::new(trading::auction_house::AuctionCommand),
+				Box::new(trading::recycling::RecycleCommand),
+				Box::new(streaks::StreakCommand),
+				Box::new(pity::PityCommand),
+				Box::new(events::EventCommand),
+				Box::new(user_settings::privacy::SettingsCommand),
+				Box::new(user_settings::notifications::NotificationsCommand),
+				Box::new(images::rasterize::ImageCommand),
+				Box::new(images::generate::GenerateCommand),
+				Box::new(images::generate::Generate2Command),
+				Box::new(images::generate::TestImageCommand),
+			],
+		}
+	}
+
+	pub fn get(&self, name: &str) -> Option<&dyn SlashCommand> {
+		self.commands
+			.iter()
+			.find(|command| command.name() == name)
+			.map(|command| command.as_ref())
+	}
+
+	pub fn create_commands(&self) -> Vec<CreateCommand> {
+		self.commands
+			.iter()
+			.map(|command| command.register())
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashSet;
+
+	use super::*;
+
+	#[test]
+	fn names_are_unique_and_match_registration() {
+		let registry = CommandRegistry::new();
+		let mut names = HashSet::new();
+		for command in &registry.commands {
+			assert!(
+				names.insert(command.name()),
+				"{} is there twice",
+				command.name()
+			);
+			let registered = serenity::json::to_value(command.register()).unwrap();
+			assert_eq!(registered["name"], command.name());
+		}
+	}
+
+	#[test]
+	fn descriptions_fit_in_discord() {
+		/// Discord refuses to register a command if it or any of its options has a longer description.
+		const MAX_DESCRIPTION_LENGTH: usize = 100;
+		fn check(path: &str, value: &serenity::json::Value) {
+			let path = format!("{path} {}", value["name"].as_str().unwrap_or_default());
+			let description = value["description"].as_str().unwrap_or_default();
+			assert!(
+				description.chars().count() <= MAX_DESCRIPTION_LENGTH,
+				"The description of{path} is {} characters long",
+				description.chars().count()
+			);
+			for option in value["options"].as_array().into_iter().flatten() {
+				check(&path, option);
+			}
+		}
+		for command in CommandRegistry::new().create_commands() {
+			check("", &serenity::json::to_value(command).unwrap());
+		}
+	}
+}
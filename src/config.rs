@@ -0,0 +1,858 @@
+//! The bot's configuration, read from `./config.toml` at startup.
+//!
+//! Guild tunables have defaults in `[defaults]`, which can be overridden per guild in `[guilds.<guild ID>]`. Every problem in the file is collected and reported at once, rather than failing on the first.
+
+use std::{collections::HashMap, fmt::Display, time::Duration};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Utc};
+use serenity::all::{GuildId, RoleId};
+use toml::{Table, Value};
+
+use crate::{drop_table::RarityTable, emoji::Emoji, emoji_list::Rarity};
+
+const CONFIG_PATH: &str = "./config.toml";
+/// Overrides `discord_token` from the file.
+const TOKEN_VARIABLE: &str = "EMOJIBOT_DISCORD_TOKEN";
+const DEFAULT_PITY_INCREASE: f64 = 0.25;
+const DEFAULT_EVENT_BOOST: u32 = 5;
+/// Boosted drops are picked by rerolling everything else, which gets slow for large boosts.
+const MAX_EVENT_BOOST: i64 = 1_000;
+
+pub struct Config {
+	pub discord_token: String,
+	/// The guild that gets existing data when migrating a database from before guilds had separate economies.
+	pub home_guild: Option<GuildId>,
+	pub commands: CommandScope,
+	pub images: ImageConfig,
+	defaults: GuildConfig,
+	guilds: HashMap<GuildId, GuildConfig>,
+}
+
+/// Where the slash commands get registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandScope {
+	/// Once, for every guild at the same time. Discord can take a while to show changes to global commands.
+	Global,
+	/// Separately in every guild the bot is in.
+	EveryGuild,
+	/// Separately in only these guilds.
+	Guilds(Vec<GuildId>),
+}
+
+impl CommandScope {
+	/// Whether the commands should be registered specifically in this guild.
+	pub fn includes_guild(&self, guild: GuildId) -> bool {
+		match self {
+			Self::Global => false,
+			Self::EveryGuild => true,
+			Self::Guilds(guilds) => guilds.contains(&guild),
+		}
+	}
+}
+
+pub struct ImageConfig {
+	pub canvas_width: u32,
+	pub canvas_height: u32,
+	/// The most emojis `/generate2` will place on one image.
+	pub emoji_limit: usize,
+}
+
+#[derive(Clone)]
+pub struct GuildConfig {
+	pub trading_roles: Vec<RoleId>,
+	pub drop_period: DropPeriod,
+	/// Where drop periods start at midnight. There is no timezone database, so this is a fixed offset from UTC, and daylight saving time has to be accounted for by hand.
+	pub timezone: FixedOffset,
+	/// The streak lengths, in drop periods, that earn a bonus emoji.
+	pub streak_milestones: Vec<u32>,
+	pub streak_reward: StreakReward,
+	/// Weighs the rarities against each other for every emoji that is handed out.
+	pub drop_table: RarityTable,
+	/// Bad luck protection, if it's on.
+	pub pity: Option<PityConfig>,
+	/// Time-boxed events, which may not have started yet or be over already.
+	pub events: Vec<Event>,
+	/// How many emojis go into a single recycle.
+	pub recycle_count: usize,
+	pub trade_confirmation_timeout: Duration,
+	/// How long trade offers last before they expire.
+	pub offer_lifetime: Duration,
+	/// The most trade offers a user can have out at the same time.
+	pub max_outgoing_offers: usize,
+	/// How long a user has to wait to offer a trade again to someone who rejected their last offer.
+	pub rejection_cooldown: Duration,
+	/// How long auctions take bids before they close.
+	pub auction_length: Duration,
+	/// Whether trade offers lock the emojis they offer until they are gone, so the same emojis can't be offered twice or recycled in the meantime.
+	pub reserve_offered_emojis: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropPeriod {
+	Daily,
+	/// Weeks start on Monday.
+	Weekly,
+	/// Periods of this many hours, counted from midnight.
+	Hours(u32),
+}
+
+/// After `after` duplicate emojis in a row, every further duplicate adds `increase` to the chance that the next emoji is one the user lacks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PityConfig {
+	pub after: u32,
+	/// Between 0 and 1.
+	pub increase: f64,
+}
+
+/// A time-boxed event, during which its emojis drop more often.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+	pub name: String,
+	pub start: DateTime<Utc>,
+	/// The first moment the event is over.
+	pub end: DateTime<Utc>,
+	pub emojis: Vec<Emoji>,
+	/// How many times likelier the event's emojis are to be picked than their rarity makes them.
+	pub boost: u32,
+	/// Whether every periodic drop during the event comes with an extra emoji out of the event's own.
+	pub bonus_drop: bool,
+}
+
+impl Event {
+	pub fn is_active(&self, time: DateTime<Utc>) -> bool {
+		self.start <= time && time < self.end
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakReward {
+	/// A random emoji, given right away.
+	Emoji,
+	/// A pick out of three random emojis, made with `/streak`.
+	Choice,
+}
+
+impl Default for ImageConfig {
+	fn default() -> Self {
+		Self {
+			canvas_width: 500,
+			canvas_height: 250,
+			emoji_limit: 1_000,
+		}
+	}
+}
+
+impl Default for GuildConfig {
+	fn default() -> Self {
+		Self {
+			trading_roles: Vec::new(),
+			drop_period: DropPeriod::Weekly,
+			timezone: FixedOffset::east_opt(0).unwrap(),
+			streak_milestones: vec![4, 8, 12],
+			streak_reward: StreakReward::Emoji,
+			drop_table: RarityTable::default(),
+			pity: None,
+			events: Vec::new(),
+			recycle_count: 3,
+			trade_confirmation_timeout: Duration::from_secs(60),
+			offer_lifetime: Duration::from_secs(7 * 24 * 60 * 60),
+			max_outgoing_offers: 10,
+			rejection_cooldown: Duration::from_secs(60 * 60),
+			auction_length: Duration::from_secs(24 * 60 * 60),
+			reserve_offered_emojis: false,
+		}
+	}
+}
+
+/// Every problem found in the configuration file.
+#[derive(Debug)]
+pub struct ConfigError(Vec<String>);
+
+impl Display for ConfigError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "Invalid configuration in {CONFIG_PATH}:")?;
+		for error in &self.0 {
+			writeln!(f, "- {error}")?;
+		}
+		Ok(())
+	}
+}
+
+impl Config {
+	pub fn load() -> Result<Self, ConfigError> {
+		let text = std::fs::read_to_string(CONFIG_PATH)
+			.map_err(|error| ConfigError(vec![format!("Could not read the file: {error}")]))?;
+		Self::parse(&text, std::env::var(TOKEN_VARIABLE).ok())
+	}
+
+	pub(crate) fn parse(text: &str, token_override: Option<String>) -> Result<Self, ConfigError> {
+		let table = text
+			.parse::<Table>()
+			.map_err(|error| ConfigError(vec![error.to_string()]))?;
+		let mut errors = Vec::new();
+
+		check_keys(
+			&table,
+			"",
+			&[
+				"discord_token",
+				"home_guild",
+				"commands",
+				"images",
+				"defaults",
+				"guilds",
+			],
+			&mut errors,
+		);
+
+		let discord_token = token_override
+			.or_else(|| get_string(&table, "", "discord_token", &mut errors).map(String::from));
+		if discord_token.is_none() {
+			errors.push(format!(
+				"`discord_token` is missing, and {TOKEN_VARIABLE} is not set."
+			));
+		}
+		let home_guild = get_id(&table, "", "home_guild", &mut errors).map(GuildId::new);
+
+		let mut commands = CommandScope::EveryGuild;
+		if let Some(table) = get_table(&table, "", "commands", &mut errors) {
+			check_keys(table, "commands", &["global", "guilds"], &mut errors);
+			let global = get_bool(table, "commands", "global", &mut errors).unwrap_or(false);
+			let guilds = get_id_array(table, "commands", "guilds", &mut errors);
+			match (global, guilds) {
+				(true, Some(_)) => errors.push(String::from(
+					"`commands`: global commands cannot be limited to specific guilds.",
+				)),
+				(true, None) => commands = CommandScope::Global,
+				(false, Some(guilds)) => {
+					commands = CommandScope::Guilds(guilds.into_iter().map(GuildId::new).collect())
+				}
+				(false, None) => (),
+			}
+		}
+
+		let mut images = ImageConfig::default();
+		if let Some(table) = get_table(&table, "", "images", &mut errors) {
+			check_keys(
+				table,
+				"images",
+				&["canvas_width", "canvas_height", "emoji_limit"],
+				&mut errors,
+			);
+			if let Some(width) = get_positive(table, "images", "canvas_width", &mut errors) {
+				images.canvas_width = width as u32;
+			}
+			if let Some(height) = get_positive(table, "images", "canvas_height", &mut errors) {
+				images.canvas_height = height as u32;
+			}
+			if let Some(limit) = get_positive(table, "images", "emoji_limit", &mut errors) {
+				images.emoji_limit = limit as usize;
+			}
+		}
+
+		let mut defaults = GuildConfig::default();
+		if let Some(table) = get_table(&table, "", "defaults", &mut errors) {
+			read_guild_config(table, "defaults", false, &mut defaults, &mut errors);
+		}
+
+		let mut guilds = HashMap::new();
+		if let Some(table) = get_table(&table, "", "guilds", &mut errors) {
+			for (key, value) in table {
+				let path = format!("guilds.{key}");
+				let Some(guild) = key.parse::<u64>().ok().filter(|id| *id != 0) else {
+					errors.push(format!("`{path}`: \"{key}\" is not a guild ID."));
+					continue;
+				};
+				let Value::Table(table) = value else {
+					errors.push(format!("`{path}`: expected a table."));
+					continue;
+				};
+				let mut guild_config = defaults.clone();
+				read_guild_config(table, &path, true, &mut guild_config, &mut errors);
+				guilds.insert(GuildId::new(guild), guild_config);
+			}
+		}
+
+		if !errors.is_empty() {
+			return Err(ConfigError(errors));
+		}
+		Ok(Self {
+			discord_token: discord_token.unwrap(),
+			home_guild,
+			commands,
+			images,
+			defaults,
+			guilds,
+		})
+	}
+
+	/// Gets the settings for a guild, which are the defaults if the guild has no section of its own.
+	pub fn guild(&self, guild: GuildId) -> &GuildConfig {
+		self.guilds.get(&guild).unwrap_or(&self.defaults)
+	}
+}
+
+/// Trading roles only make sense for a specific guild, so they are not allowed in the defaults.
+fn read_guild_config(
+	table: &Table,
+	path: &str,
+	allow_roles: bool,
+	config: &mut GuildConfig,
+	errors: &mut Vec<String>,
+) {
+	let mut allowed_keys = vec![
+		"drop_period",
+		"timezone",
+		"streak_milestones",
+		"streak_reward",
+		"rarity_weights",
+		"pity_after",
+		"pity_increase",
+		"events",
+		"recycle_count",
+		"trade_confirmation_timeout",
+		"offer_lifetime",
+		"max_outgoing_offers",
+		"rejection_cooldown",
+		"auction_length",
+		"reserve_offered_emojis",
+	];
+	if allow_roles {
+		allowed_keys.push("trading_roles");
+	}
+	check_keys(table, path, &allowed_keys, errors);
+
+	if allow_roles {
+		if let Some(roles) = get_id_array(table, path, "trading_roles", errors) {
+			config.trading_roles = roles.into_iter().map(RoleId::new).collect();
+		}
+	}
+	match table.get("drop_period") {
+		None => (),
+		Some(Value::String(period)) if period == "daily" => config.drop_period = DropPeriod::Daily,
+		Some(Value::String(period)) if period == "weekly" => {
+			config.drop_period = DropPeriod::Weekly
+		}
+		Some(Value::Integer(hours)) if *hours > 0 && *hours <= u32::MAX as i64 => {
+			config.drop_period = DropPeriod::Hours(*hours as u32)
+		}
+		Some(period) => errors.push(format!(
+			"`{}`: expected \"daily\", \"weekly\" or a number of hours, got {period}.",
+			join(path, "drop_period")
+		)),
+	}
+	if let Some(timezone) = get_string(table, path, "timezone", errors) {
+		match parse_timezone(timezone) {
+			Some(timezone) => config.timezone = timezone,
+			None => errors.push(format!(
+				"`{}`: expected \"UTC\" or an offset from it like \"+02:00\", got \"{timezone}\".",
+				join(path, "timezone")
+			)),
+		}
+	}
+	if let Some(milestones) = get_positive_array(table, path, "streak_milestones", errors) {
+		config.streak_milestones = milestones;
+	}
+	if let Some(reward) = get_string(table, path, "streak_reward", errors) {
+		match reward {
+			"emoji" => config.streak_reward = StreakReward::Emoji,
+			"choice" => config.streak_reward = StreakReward::Choice,
+			_ => errors.push(format!(
+				"`{}`: expected \"emoji\" or \"choice\", got \"{reward}\".",
+				join(path, "streak_reward")
+			)),
+		}
+	}
+	if let Some(weights_table) = get_table(table, path, "rarity_weights", errors) {
+		let weights_path = join(path, "rarity_weights");
+		check_keys(
+			weights_table,
+			&weights_path,
+			&Rarity::ALL.map(Rarity::name),
+			errors,
+		);
+		let mut weights = Rarity::ALL.map(|rarity| (rarity, 0));
+		for (rarity, weight) in &mut weights {
+			match weights_table.get(rarity.name()) {
+				None => (),
+				Some(Value::Integer(number)) if *number >= 0 && *number <= u32::MAX as i64 => {
+					*weight = *number as u32
+				}
+				Some(_) => errors.push(format!(
+					"`{}`: expected a whole number that is not negative.",
+					join(&weights_path, rarity.name())
+				)),
+			}
+		}
+		match RarityTable::new(|rarity| {
+			weights
+				.iter()
+				.find(|(other, _)| *other == rarity)
+				.map_or(0, |(_, weight)| *weight)
+		}) {
+			Some(drop_table) => config.drop_table = drop_table,
+			None => errors.push(format!(
+				"`{weights_path}`: at least one rarity needs a positive weight."
+			)),
+		}
+	}
+	if let Some(after) = get_positive(table, path, "pity_after", errors) {
+		config.pity = Some(PityConfig {
+			after: after as u32,
+			increase: config
+				.pity
+				.map_or(DEFAULT_PITY_INCREASE, |pity| pity.increase),
+		});
+	}
+	if let Some(increase) = get_positive(table, path, "pity_increase", errors) {
+		if increase > 100 {
+			errors.push(format!(
+				"`{}`: expected a percentage, got {increase}.",
+				join(path, "pity_increase")
+			));
+		} else if let Some(pity) = &mut config.pity {
+			pity.increase = increase as f64 / 100.0;
+		} else {
+			errors.push(format!(
+				"`{}`: bad luck protection is off, as `pity_after` is not set.",
+				join(path, "pity_increase")
+			));
+		}
+	}
+	if let Some(events) = read_events(table, path, config.timezone, errors) {
+		config.events = events;
+	}
+	if let Some(count) = get_positive(table, path, "recycle_count", errors) {
+		config.recycle_count = count as usize;
+	}
+	if let Some(seconds) = get_positive(table, path, "trade_confirmation_timeout", errors) {
+		config.trade_confirmation_timeout = Duration::from_secs(seconds as u64);
+	}
+	if let Some(seconds) = get_positive(table, path, "offer_lifetime", errors) {
+		config.offer_lifetime = Duration::from_secs(seconds as u64);
+	}
+	if let Some(count) = get_positive(table, path, "max_outgoing_offers", errors) {
+		config.max_outgoing_offers = count as usize;
+	}
+	if let Some(seconds) = get_positive(table, path, "rejection_cooldown", errors) {
+		config.rejection_cooldown = Duration::from_secs(seconds as u64);
+	}
+	if let Some(seconds) = get_positive(table, path, "auction_length", errors) {
+		config.auction_length = Duration::from_secs(seconds as u64);
+	}
+	if let Some(reserve) = get_bool(table, path, "reserve_offered_emojis", errors) {
+		config.reserve_offered_emojis = reserve;
+	}
+}
+
+/// Times without an offset are in the guild's timezone.
+fn read_events(
+	table: &Table,
+	path: &str,
+	timezone: FixedOffset,
+	errors: &mut Vec<String>,
+) -> Option<Vec<Event>> {
+	let events_path = join(path, "events");
+	let Value::Array(values) = table.get("events")? else {
+		errors.push(format!("`{events_path}`: expected a list of tables."));
+		return None;
+	};
+	let mut events = Vec::new();
+	for (index, value) in values.iter().enumerate() {
+		let path = format!("{events_path}[{index}]");
+		let Value::Table(table) = value else {
+			errors.push(format!("`{path}`: expected a table."));
+			continue;
+		};
+		check_keys(
+			table,
+			&path,
+			&["name", "start", "end", "emojis", "boost", "bonus_drop"],
+			errors,
+		);
+		let name = get_string(table, &path, "name", errors);
+		let start = get_time(table, &path, "start", timezone, errors);
+		let end = get_time(table, &path, "end", timezone, errors);
+		let emojis = get_emoji_array(table, &path, "emojis", errors);
+		let boost = get_positive(table, &path, "boost", errors);
+		let bonus_drop = get_bool(table, &path, "bonus_drop", errors).unwrap_or(false);
+		for key in ["name", "start", "end", "emojis"] {
+			if !table.contains_key(key) {
+				errors.push(format!("`{}`: missing.", join(&path, key)));
+			}
+		}
+		if boost.is_some_and(|boost| boost > MAX_EVENT_BOOST) {
+			errors.push(format!(
+				"`{}`: can be at most {MAX_EVENT_BOOST}.",
+				join(&path, "boost")
+			));
+			continue;
+		}
+		let (Some(name), Some(start), Some(end), Some(emojis)) = (name, start, end, emojis) else {
+			continue;
+		};
+		if end <= start {
+			errors.push(format!("`{path}`: the event ends before it starts."));
+			continue;
+		}
+		events.push(Event {
+			name: String::from(name),
+			start,
+			end,
+			emojis,
+			boost: boost.map_or(DEFAULT_EVENT_BOOST, |boost| boost as u32),
+			bonus_drop,
+		});
+	}
+	Some(events)
+}
+
+fn parse_timezone(timezone: &str) -> Option<FixedOffset> {
+	if timezone.eq_ignore_ascii_case("UTC") {
+		return FixedOffset::east_opt(0);
+	}
+	timezone.parse().ok()
+}
+
+fn join(path: &str, key: &str) -> String {
+	if path.is_empty() {
+		String::from(key)
+	} else {
+		format!("{path}.{key}")
+	}
+}
+
+fn check_keys(table: &Table, path: &str, allowed: &[&str], errors: &mut Vec<String>) {
+	for key in table.keys() {
+		if !allowed.contains(&key.as_str()) {
+			errors.push(format!("`{}`: unknown key.", join(path, key)));
+		}
+	}
+}
+
+fn get_table<'l>(
+	table: &'l Table,
+	path: &str,
+	key: &str,
+	errors: &mut Vec<String>,
+) -> Option<&'l Table> {
+	match table.get(key)? {
+		Value::Table(table) => Some(table),
+		_ => {
+			errors.push(format!("`{}`: expected a table.", join(path, key)));
+			None
+		}
+	}
+}
+
+fn get_string<'l>(
+	table: &'l Table,
+	path: &str,
+	key: &str,
+	errors: &mut Vec<String>,
+) -> Option<&'l str> {
+	match table.get(key)? {
+		Value::String(string) => Some(string),
+		_ => {
+			errors.push(format!("`{}`: expected a string.", join(path, key)));
+			None
+		}
+	}
+}
+
+fn get_bool(table: &Table, path: &str, key: &str, errors: &mut Vec<String>) -> Option<bool> {
+	match table.get(key)? {
+		Value::Boolean(value) => Some(*value),
+		_ => {
+			errors.push(format!("`{}`: expected true or false.", join(path, key)));
+			None
+		}
+	}
+}
+
+fn get_positive(table: &Table, path: &str, key: &str, errors: &mut Vec<String>) -> Option<i64> {
+	match table.get(key)? {
+		Value::Integer(number) if *number > 0 && *number <= u32::MAX as i64 => Some(*number),
+		_ => {
+			errors.push(format!(
+				"`{}`: expected a positive whole number.",
+				join(path, key)
+			));
+			None
+		}
+	}
+}
+
+fn get_positive_array(
+	table: &Table,
+	path: &str,
+	key: &str,
+	errors: &mut Vec<String>,
+) -> Option<Vec<u32>> {
+	let numbers = match table.get(key)? {
+		Value::Array(values) => values
+			.iter()
+			.map(|value| match value {
+				Value::Integer(number) if *number > 0 && *number <= u32::MAX as i64 => {
+					Some(*number as u32)
+				}
+				_ => None,
+			})
+			.collect::<Option<Vec<_>>>(),
+		_ => None,
+	};
+	if numbers.is_none() {
+		errors.push(format!(
+			"`{}`: expected a list of positive whole numbers.",
+			join(path, key)
+		));
+	}
+	numbers
+}
+
+/// A TOML date or date-time, or a string in the same format.
+fn get_time(
+	table: &Table,
+	path: &str,
+	key: &str,
+	timezone: FixedOffset,
+	errors: &mut Vec<String>,
+) -> Option<DateTime<Utc>> {
+	let text = match table.get(key)? {
+		Value::Datetime(time) => time.to_string(),
+		Value::String(text) => text.clone(),
+		_ => String::new(),
+	};
+	let time = DateTime::parse_from_rfc3339(&text)
+		.map(|time| time.to_utc())
+		.ok()
+		.or_else(|| {
+			let local = NaiveDateTime::parse_from_str(&text, "%Y-%m-%dT%H:%M:%S")
+				.or_else(|_| NaiveDate::parse_from_str(&text, "%Y-%m-%d").map(|date| date.into()))
+				.ok()?;
+			Some(local.and_local_timezone(timezone).single()?.to_utc())
+		});
+	if time.is_none() {
+		errors.push(format!(
+			"`{}`: expected a date like 2024-10-25 or a time like 2024-10-25T18:00:00+02:00.",
+			join(path, key)
+		));
+	}
+	time
+}
+
+/// The emojis have to be written exactly as in the bot's emoji list, including any variation selectors.
+fn get_emoji_array(
+	table: &Table,
+	path: &str,
+	key: &str,
+	errors: &mut Vec<String>,
+) -> Option<Vec<Emoji>> {
+	let Value::Array(values) = table.get(key)? else {
+		errors.push(format!("`{}`: expected a list of emojis.", join(path, key)));
+		return None;
+	};
+	let mut emojis = Vec::new();
+	for value in values {
+		let emoji = match value {
+			Value::String(text) => Emoji::all().find(|emoji| emoji.as_str() == text),
+			_ => None,
+		};
+		match emoji {
+			Some(emoji) => emojis.push(emoji),
+			None => {
+				errors.push(format!(
+					"`{}`: {value} is not an emoji the bot knows.",
+					join(path, key)
+				));
+				return None;
+			}
+		}
+	}
+	if emojis.is_empty() {
+		errors.push(format!(
+			"`{}`: expected at least one emoji.",
+			join(path, key)
+		));
+		return None;
+	}
+	Some(emojis)
+}
+
+fn get_id(table: &Table, path: &str, key: &str, errors: &mut Vec<String>) -> Option<u64> {
+	match table.get(key)? {
+		Value::Integer(id) if *id > 0 => Some(*id as u64),
+		_ => {
+			errors.push(format!("`{}`: expected an ID.", join(path, key)));
+			None
+		}
+	}
+}
+
+fn get_id_array(
+	table: &Table,
+	path: &str,
+	key: &str,
+	errors: &mut Vec<String>,
+) -> Option<Vec<u64>> {
+	let ids = match table.get(key)? {
+		Value::Array(values) => values
+			.iter()
+			.map(|value| match value {
+				Value::Integer(id) if *id > 0 => Some(*id as u64),
+				_ => None,
+			})
+			.collect::<Option<Vec<_>>>(),
+		_ => None,
+	};
+	if ids.is_none() {
+		errors.push(format!("`{}`: expected a list of IDs.", join(path, key)));
+	}
+	ids
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn guild_sections_override_defaults() {
+		let config = Config::parse(
+			r#"
+			discord_token = "abc"
+
+			[defaults]
+			recycle_count = 4
+
+			[guilds.123]
+			trading_roles = [456, 789]
+			drop_period = "daily"
+			timezone = "-05:30"
+
+			[guilds.321]
+			drop_period = 6
+			streak_milestones = [2]
+			streak_reward = "choice"
+			rarity_weights = { common = 3, legendary = 1 }
+			pity_after = 10
+			pity_increase = 5
+
+			[[guilds.321.events]]
+			name = "Halloween"
+			start = 2024-10-25
+			end = 2024-11-01T00:00:00+01:00
+			emojis = ["🎃", "👻"]
+			bonus_drop = true
+			"#,
+			None,
+		)
+		.unwrap();
+		let guild = config.guild(GuildId::new(123));
+		assert_eq!(guild.trading_roles, [RoleId::new(456), RoleId::new(789)]);
+		assert_eq!(guild.drop_period, DropPeriod::Daily);
+		assert_eq!(
+			guild.timezone,
+			FixedOffset::west_opt(5 * 3600 + 1800).unwrap()
+		);
+		assert_eq!(guild.recycle_count, 4);
+		let fast = config.guild(GuildId::new(321));
+		assert_eq!(fast.drop_period, DropPeriod::Hours(6));
+		assert_eq!(fast.streak_milestones, [2]);
+		assert_eq!(fast.streak_reward, StreakReward::Choice);
+		assert_eq!(fast.drop_table.chance(Rarity::Legendary), 0.25);
+		assert_eq!(fast.drop_table.chance(Rarity::Rare), 0.0);
+		assert_eq!(
+			fast.pity,
+			Some(PityConfig {
+				after: 10,
+				increase: 0.05
+			})
+		);
+		let [halloween] = &fast.events[..] else {
+			panic!("{:?}", fast.events);
+		};
+		assert_eq!(halloween.start.to_rfc3339(), "2024-10-25T00:00:00+00:00");
+		assert_eq!(halloween.end.to_rfc3339(), "2024-10-31T23:00:00+00:00");
+		assert_eq!(halloween.emojis.len(), 2);
+		assert_eq!(halloween.boost, DEFAULT_EVENT_BOOST);
+		assert!(halloween.bonus_drop);
+		let other = config.guild(GuildId::new(1));
+		assert!(other.trading_roles.is_empty());
+		assert_eq!(other.drop_period, DropPeriod::Weekly);
+		assert_eq!(other.timezone.local_minus_utc(), 0);
+		assert_eq!(other.streak_milestones, [4, 8, 12]);
+		assert_eq!(other.pity, None);
+		assert_eq!(other.trade_confirmation_timeout, Duration::from_secs(60));
+	}
+
+	#[test]
+	fn every_invalid_key_is_reported() {
+		let Err(ConfigError(errors)) = Config::parse(
+			r#"
+			colour = "blue"
+
+			[images]
+			canvas_width = -5
+
+			[defaults]
+			trading_roles = [1]
+			drop_period = "fortnightly"
+			timezone = "Europe/Amsterdam"
+
+			[defaults.rarity_weights]
+			common = 0
+
+			[[defaults.events]]
+			name = "Backwards"
+			start = 2024-12-31
+			end = 2024-12-01
+			emojis = ["🎄"]
+
+			[guilds.general]
+			recycle_count = 3
+			"#,
+			None,
+		) else {
+			panic!("Invalid configuration was accepted.");
+		};
+		let expected = [
+			"colour",
+			"discord_token",
+			"images.canvas_width",
+			"defaults.trading_roles",
+			"defaults.drop_period",
+			"defaults.timezone",
+			"defaults.rarity_weights",
+			"defaults.events[0]",
+			"guilds.general",
+		];
+		assert_eq!(errors.len(), expected.len(), "{errors:?}");
+		for key in expected {
+			assert!(
+				errors.iter().any(|error| error.contains(key)),
+				"No error for {key}: {errors:?}"
+			);
+		}
+	}
+
+	#[test]
+	fn command_scope() {
+		let parse = |text| Config::parse(text, Some(String::new())).map(|config| config.commands);
+		assert_eq!(parse("").unwrap(), CommandScope::EveryGuild);
+		assert_eq!(
+			parse("[commands]\nglobal = true").unwrap(),
+			CommandScope::Global
+		);
+		assert_eq!(
+			parse("[commands]\nguilds = [5]").unwrap(),
+			CommandScope::Guilds(vec![GuildId::new(5)])
+		);
+		assert!(parse("[commands]\nglobal = true\nguilds = [5]").is_err());
+	}
+
+	#[test]
+	fn token_can_come_from_environment() {
+		let config = Config::parse("", Some(String::from("from environment"))).unwrap();
+		assert_eq!(config.discord_token, "from environment");
+	}
+}
@@ -1,67 +1,46 @@
-use std::sync::Arc;
-
-use serenity::{
-	all::{CacheHttp, RoleId},
-	client::Cache,
-	http::Http,
-	model::prelude::{GuildId, UserId},
-};
+use serenity::model::prelude::{GuildId, UserId};
 use sqlx::{Pool, Sqlite};
 
-use crate::emoji::EmojiMap;
+use crate::{
+	config::{Config, GuildConfig},
+	discord::Discord,
+	emoji::EmojiMap,
+};
 
 #[derive(Copy, Clone)]
 pub struct Context<'l> {
+	/// The guild the interaction happened in. Inventories, drops and trades are all specific to a guild.
+	pub guild: GuildId,
 	pub database: &'l Pool<Sqlite>,
 	pub emoji_map: &'l EmojiMap,
-	pub trading_roles: &'l Vec<RoleId>,
-	pub http: &'l Arc<Http>,
-	pub cache: &'l Arc<Cache>,
+	pub config: &'l Config,
+	pub discord: &'l dyn Discord,
 }
 
 impl<'l> Context<'l> {
 	pub fn new(
+		guild: GuildId,
 		database: &'l Pool<Sqlite>,
 		emoji_map: &'l EmojiMap,
-		trading_roles: &'l Vec<RoleId>,
-		http: &'l Arc<Http>,
-		cache: &'l Arc<Cache>,
+		config: &'l Config,
+		discord: &'l dyn Discord,
 	) -> Self {
 		Self {
+			guild,
 			database,
 			emoji_map,
-			trading_roles,
-			http,
-			cache,
+			config,
+			discord,
 		}
 	}
 
-	/// Gives nickname if possible, otherwise display name, otherwise ID as a string.
-	pub async fn get_user_name(&self, guild: GuildId, user: UserId) -> String {
-		let member = if let Some(member) = self
-			.cache
-			.guild(guild)
-			.and_then(|guild| guild.members.get(&user).cloned())
-		{
-			member
-		} else if let Ok(member) = self.http.get_member(guild, user).await {
-			member
-		} else {
-			return format!("{}", user);
-		};
-		if let Some(nick) = member.nick {
-			nick
-		} else {
-			member.display_name().to_owned()
-		}
+	/// The settings for the guild the interaction happened in.
+	pub fn guild_config(&self) -> &'l GuildConfig {
+		self.config.guild(self.guild)
 	}
-}
 
-impl CacheHttp for Context<'_> {
-	fn http(&self) -> &Http {
-		self.http
-	}
-	fn cache(&self) -> Option<&Arc<Cache>> {
-		Some(self.cache)
+	/// Gives nickname if possible, otherwise display name, otherwise ID as a string.
+	pub async fn get_user_name(&self, user: UserId) -> String {
+		self.discord.user_name(self.guild, user).await
 	}
 }
@@ -0,0 +1,354 @@
+//! A stand-in for Discord that records everything sent to it, and answers questions from a script.
+
+use std::{
+	collections::{HashMap, VecDeque},
+	sync::Mutex,
+	time::Duration,
+};
+
+use serenity::{
+	all::{CommandInteraction, GuildId, RoleId, UserId},
+	async_trait,
+};
+
+use crate::error::BotError;
+
+use super::{Button, ButtonPress, Choice, Discord, Modal, ModalSubmission, SelectMenu};
+
+/// Something that was sent to the fake Discord.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sent {
+	Reply {
+		content: String,
+		ephemeral: bool,
+	},
+	Image {
+		file_name: String,
+		ephemeral: bool,
+	},
+	Deferred,
+	FollowUp {
+		content: String,
+		ephemeral: bool,
+	},
+	FollowUpImage {
+		file_name: String,
+	},
+	DeletedReply,
+	Buttons {
+		content: String,
+		buttons: Vec<&'static str>,
+	},
+	PublicButtons {
+		content: String,
+		buttons: Vec<&'static str>,
+	},
+	ButtonsUpdate {
+		content: String,
+		buttons: Vec<&'static str>,
+	},
+	SelectMenus {
+		content: String,
+		menus: Vec<&'static str>,
+	},
+	SelectMenusUpdate {
+		content: String,
+		menus: Vec<&'static str>,
+	},
+	ButtonReply {
+		content: String,
+		ephemeral: bool,
+	},
+	ButtonUpdate {
+		content: String,
+	},
+	/// The inputs are their IDs with what they were filled in with.
+	Modal {
+		title: String,
+		inputs: Vec<(&'static str, String)>,
+	},
+	ModalReply {
+		content: String,
+		ephemeral: bool,
+	},
+	Autocomplete {
+		choices: Vec<Choice>,
+	},
+	DirectMessage {
+		user: UserId,
+		content: String,
+	},
+}
+
+/// Who presses, unless it's whoever was asked, and a button or select menu ID, with the values chosen in the menu.
+type ScriptedPress = (Option<UserId>, &'static str, Vec<String>);
+
+/// What is entered in each input of a modal, by input ID.
+type ScriptedSubmission = Vec<(String, String)>;
+
+#[derive(Default)]
+pub struct FakeDiscord {
+	members: HashMap<UserId, (String, Vec<RoleId>)>,
+	/// The buttons that will be pressed and the choices that will be made, in order. None stands for letting it time out.
+	button_presses: Mutex<VecDeque<Option<ScriptedPress>>>,
+	/// What will be entered in the modals that are opened, in order. None stands for letting it time out.
+	modal_submissions: Mutex<VecDeque<Option<ScriptedSubmission>>>,
+	sent: Mutex<Vec<Sent>>,
+}
+
+impl FakeDiscord {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a guild member. Other users are treated as not being in the guild.
+	pub fn with_member(mut self, user: UserId, name: &str, roles: &[RoleId]) -> Self {
+		self.members
+			.insert(user, (String::from(name), roles.to_vec()));
+		self
+	}
+
+	/// Queues up the next button press, by ID, from whoever was asked. None times out instead.
+	pub fn press_button(&self, id: Option<&'static str>) {
+		self.button_presses
+			.lock()
+			.unwrap()
+			.push_back(id.map(|id| (None, id, Vec::new())));
+	}
+
+	/// Queues up the next button press, by ID, from a specific user.
+	pub fn press_button_as(&self, user: UserId, id: &'static str) {
+		self.button_presses
+			.lock()
+			.unwrap()
+			.push_back(Some((Some(user), id, Vec::new())));
+	}
+
+	/// Queues up the next choice in a select menu, by the menu's ID and the chosen value.
+	pub fn choose(&self, menu: &'static str, value: &str) {
+		self.button_presses.lock().unwrap().push_back(Some((
+			None,
+			menu,
+			vec![String::from(value)],
+		)));
+	}
+
+	/// Queues up what will be entered in the next modal, by input ID. None times out instead.
+	pub fn submit_modal(&self, values: Option<&[(&str, &str)]>) {
+		self.modal_submissions
+			.lock()
+			.unwrap()
+			.push_back(values.map(|values| {
+				values
+					.iter()
+					.map(|(id, value)| (String::from(*id), String::from(*value)))
+					.collect()
+			}));
+	}
+
+	/// The asked user is who presses, unless the press was queued up for someone else.
+	fn next_press(&self, asked: UserId) -> Option<ButtonPress> {
+		let press = self
+			.button_presses
+			.lock()
+			.unwrap()
+			.pop_front()
+			.expect("A button press was asked for, but none was queued.");
+		press.map(|(user, id, values)| ButtonPress {
+			custom_id: String::from(id),
+			values,
+			user: user.unwrap_or(asked),
+			interaction: None,
+		})
+	}
+
+	/// Takes everything sent so far.
+	pub fn take_sent(&self) -> Vec<Sent> {
+		std::mem::take(&mut self.sent.lock().unwrap())
+	}
+
+	fn send(&self, sent: Sent) -> Result<(), BotError> {
+		self.sent.lock().unwrap().push(sent);
+		Ok(())
+	}
+}
+
+#[async_trait]
+impl Discord for FakeDiscord {
+	async fn reply(
+		&self,
+		_interaction: &CommandInteraction,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		self.send(Sent::Reply { content, ephemeral })
+	}
+	async fn reply_image(
+		&self,
+		_interaction: &CommandInteraction,
+		_image: &[u8],
+		file_name: &str,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		self.send(Sent::Image {
+			file_name: String::from(file_name),
+			ephemeral,
+		})
+	}
+	async fn defer(&self, _interaction: &CommandInteraction) -> Result<(), BotError> {
+		self.send(Sent::Deferred)
+	}
+	async fn follow_up(
+		&self,
+		_interaction: &CommandInteraction,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		self.send(Sent::FollowUp { content, ephemeral })
+	}
+	async fn follow_up_image(
+		&self,
+		_interaction: &CommandInteraction,
+		_image: &[u8],
+		file_name: &str,
+	) -> Result<(), BotError> {
+		self.send(Sent::FollowUpImage {
+			file_name: String::from(file_name),
+		})
+	}
+	async fn delete_reply(&self, _interaction: &CommandInteraction) -> Result<(), BotError> {
+		self.send(Sent::DeletedReply)
+	}
+	async fn ask_with_buttons(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		buttons: &[Button],
+		_timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		self.send(Sent::Buttons {
+			content,
+			buttons: buttons.iter().map(|button| button.id).collect(),
+		})?;
+		Ok(self.next_press(interaction.user.id))
+	}
+	async fn ask_everyone_with_buttons(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		buttons: &[Button],
+		_timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		self.send(Sent::PublicButtons {
+			content,
+			buttons: buttons.iter().map(|button| button.id).collect(),
+		})?;
+		Ok(self.next_press(interaction.user.id))
+	}
+	async fn update_buttons(
+		&self,
+		press: &ButtonPress,
+		content: String,
+		buttons: &[Button],
+		_timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		self.send(Sent::ButtonsUpdate {
+			content,
+			buttons: buttons.iter().map(|button| button.id).collect(),
+		})?;
+		Ok(self.next_press(press.user))
+	}
+	async fn ask_with_select_menus(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		menus: &[SelectMenu],
+		_timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		self.send(Sent::SelectMenus {
+			content,
+			menus: menus.iter().map(|menu| menu.id).collect(),
+		})?;
+		Ok(self.next_press(interaction.user.id))
+	}
+	async fn update_select_menus(
+		&self,
+		press: &ButtonPress,
+		content: String,
+		menus: &[SelectMenu],
+		_timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		self.send(Sent::SelectMenusUpdate {
+			content,
+			menus: menus.iter().map(|menu| menu.id).collect(),
+		})?;
+		Ok(self.next_press(press.user))
+	}
+	async fn reply_to_button(
+		&self,
+		_press: &ButtonPress,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		self.send(Sent::ButtonReply { content, ephemeral })
+	}
+	async fn update_button_message(
+		&self,
+		_press: &ButtonPress,
+		content: String,
+	) -> Result<(), BotError> {
+		self.send(Sent::ButtonUpdate { content })
+	}
+	async fn ask_with_modal(
+		&self,
+		_press: &ButtonPress,
+		modal: &Modal,
+		_timeout: Duration,
+	) -> Result<Option<ModalSubmission>, BotError> {
+		self.send(Sent::Modal {
+			title: modal.title.clone(),
+			inputs: modal
+				.inputs
+				.iter()
+				.map(|input| (input.id, input.value.clone()))
+				.collect(),
+		})?;
+		let values = self
+			.modal_submissions
+			.lock()
+			.unwrap()
+			.pop_front()
+			.expect("A modal was opened, but no submission was queued.");
+		Ok(values.map(|values| ModalSubmission {
+			values,
+			interaction: None,
+		}))
+	}
+	async fn reply_to_modal(
+		&self,
+		_submission: &ModalSubmission,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		self.send(Sent::ModalReply { content, ephemeral })
+	}
+	async fn autocomplete(
+		&self,
+		_interaction: &CommandInteraction,
+		choices: Vec<Choice>,
+	) -> Result<(), BotError> {
+		self.send(Sent::Autocomplete { choices })
+	}
+	async fn direct_message(&self, user: UserId, content: String) -> Result<(), BotError> {
+		self.send(Sent::DirectMessage { user, content })
+	}
+	async fn user_name(&self, _guild: GuildId, user: UserId) -> String {
+		self.members
+			.get(&user)
+			.map(|(name, _)| name.clone())
+			.unwrap_or_else(|| user.to_string())
+	}
+	async fn member_roles(&self, _guild: GuildId, user: UserId) -> Option<Vec<RoleId>> {
+		self.members.get(&user).map(|(_, roles)| roles.clone())
+	}
+}
@@ -0,0 +1,625 @@
+//! Everything commands say to and ask of Discord goes through the `Discord` trait, so command logic can run against a fake in tests.
+
+#[cfg(test)]
+pub mod fake;
+
+use std::{sync::Arc, time::Duration};
+
+use serenity::{
+	all::{
+		ActionRowComponent, ButtonStyle, CommandInteraction, ComponentInteraction,
+		ComponentInteractionDataKind, GuildId, InputTextStyle, ModalInteraction, RoleId, UserId,
+	},
+	async_trait,
+	builder::{
+		AutocompleteChoice, CreateActionRow, CreateAttachment, CreateAutocompleteResponse,
+		CreateButton, CreateInputText, CreateInteractionResponse,
+		CreateInteractionResponseFollowup, CreateInteractionResponseMessage, CreateMessage,
+		CreateModal, CreateSelectMenu, CreateSelectMenuKind, CreateSelectMenuOption,
+	},
+	client::Cache,
+	gateway::ShardMessenger,
+	http::Http,
+};
+
+use crate::error::BotError;
+
+pub struct Button {
+	pub id: &'static str,
+	pub label: &'static str,
+	pub style: ButtonStyle,
+}
+
+/// A menu to choose one of the options from.
+pub struct SelectMenu {
+	pub id: &'static str,
+	pub options: Vec<SelectOption>,
+}
+
+/// Discord won't take more options than this in one select menu.
+pub const MAX_SELECT_OPTIONS: usize = 25;
+
+pub struct SelectOption {
+	pub value: &'static str,
+	pub label: String,
+	/// Shown as chosen before anything is picked.
+	pub default: bool,
+}
+
+/// A pressed button, or a choice made in a select menu.
+pub struct ButtonPress {
+	pub custom_id: String,
+	/// What was chosen in a select menu. Empty for buttons.
+	pub values: Vec<String>,
+	/// Who pressed it.
+	pub user: UserId,
+	/// Only missing for presses made up by the fake.
+	interaction: Option<ComponentInteraction>,
+}
+
+impl ButtonPress {
+	fn interaction(&self) -> Result<&ComponentInteraction, BotError> {
+		self.interaction
+			.as_ref()
+			.ok_or(BotError::InvalidInteraction(
+				"button press has no interaction",
+			))
+	}
+}
+
+/// A form that pops up for the user to fill in.
+pub struct Modal {
+	pub id: &'static str,
+	pub title: String,
+	pub inputs: Vec<TextInput>,
+}
+
+/// A one-line text field in a modal, which has to be filled in.
+pub struct TextInput {
+	pub id: &'static str,
+	pub label: &'static str,
+	/// Already filled in when the modal opens.
+	pub value: String,
+}
+
+/// A submitted modal.
+pub struct ModalSubmission {
+	/// The text entered in each input, by input ID.
+	pub values: Vec<(String, String)>,
+	/// Only missing for submissions made up by the fake.
+	interaction: Option<ModalInteraction>,
+}
+
+impl ModalSubmission {
+	/// Empty if there is no input with the ID.
+	pub fn value(&self, id: &str) -> &str {
+		self.values
+			.iter()
+			.find(|(input, _)| input == id)
+			.map_or("", |(_, value)| value)
+	}
+	fn interaction(&self) -> Result<&ModalInteraction, BotError> {
+		self.interaction
+			.as_ref()
+			.ok_or(BotError::InvalidInteraction(
+				"modal submission has no interaction",
+			))
+	}
+}
+
+/// A suggestion for an option that is being typed. The name is shown, and the value is what gets filled in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Choice {
+	pub name: String,
+	pub value: String,
+}
+
+#[async_trait]
+pub trait Discord: Send + Sync {
+	async fn reply(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError>;
+	async fn reply_image(
+		&self,
+		interaction: &CommandInteraction,
+		image: &[u8],
+		file_name: &str,
+		ephemeral: bool,
+	) -> Result<(), BotError>;
+	/// Acknowledges the interaction, for commands that take a while to reply.
+	async fn defer(&self, interaction: &CommandInteraction) -> Result<(), BotError>;
+	async fn follow_up(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError>;
+	async fn follow_up_image(
+		&self,
+		interaction: &CommandInteraction,
+		image: &[u8],
+		file_name: &str,
+	) -> Result<(), BotError>;
+	async fn delete_reply(&self, interaction: &CommandInteraction) -> Result<(), BotError>;
+	/// Replies ephemerally with buttons, and waits for one of them to be pressed. Returns None if none were pressed before the timeout.
+	async fn ask_with_buttons(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		buttons: &[Button],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError>;
+	/// Replies with buttons that everyone can see and press, and waits for one of them to be pressed by anyone. Returns None if none were pressed before the timeout.
+	async fn ask_everyone_with_buttons(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		buttons: &[Button],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError>;
+	/// Replaces the message with the buttons, keeping buttons on it, and waits for the next press on it.
+	async fn update_buttons(
+		&self,
+		press: &ButtonPress,
+		content: String,
+		buttons: &[Button],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError>;
+	/// Replies ephemerally with select menus, and waits for a choice in one of them. Returns None if nothing was chosen before the timeout.
+	async fn ask_with_select_menus(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		menus: &[SelectMenu],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError>;
+	/// Replaces the message with the select menus, and waits for the next choice in it.
+	async fn update_select_menus(
+		&self,
+		press: &ButtonPress,
+		content: String,
+		menus: &[SelectMenu],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError>;
+	async fn reply_to_button(
+		&self,
+		press: &ButtonPress,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError>;
+	/// Replaces the message with the buttons, removing the buttons.
+	async fn update_button_message(
+		&self,
+		press: &ButtonPress,
+		content: String,
+	) -> Result<(), BotError>;
+	/// Opens the modal in answer to the button press, and waits for it to be submitted. Returns None if it wasn't submitted before the timeout.
+	async fn ask_with_modal(
+		&self,
+		press: &ButtonPress,
+		modal: &Modal,
+		timeout: Duration,
+	) -> Result<Option<ModalSubmission>, BotError>;
+	async fn reply_to_modal(
+		&self,
+		submission: &ModalSubmission,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError>;
+	/// Answers an autocomplete interaction.
+	async fn autocomplete(
+		&self,
+		interaction: &CommandInteraction,
+		choices: Vec<Choice>,
+	) -> Result<(), BotError>;
+	/// Fails if the user doesn't accept direct messages from the bot.
+	async fn direct_message(&self, user: UserId, content: String) -> Result<(), BotError>;
+	/// Gives nickname if possible, otherwise display name, otherwise ID as a string.
+	async fn user_name(&self, guild: GuildId, user: UserId) -> String;
+	/// Returns None if the user is not a member of the guild.
+	async fn member_roles(&self, guild: GuildId, user: UserId) -> Option<Vec<RoleId>>;
+}
+
+/// The real thing.
+pub struct SerenityDiscord<'l> {
+	pub http: &'l Arc<Http>,
+	pub cache: &'l Arc<Cache>,
+	pub shard: &'l ShardMessenger,
+}
+
+fn button_row(buttons: &[Button]) -> CreateActionRow {
+	CreateActionRow::Buttons(
+		buttons
+			.iter()
+			.map(|button| {
+				CreateButton::new(button.id)
+					.label(button.label)
+					.style(button.style)
+			})
+			.collect(),
+	)
+}
+
+fn select_menu_rows(menus: &[SelectMenu]) -> Vec<CreateActionRow> {
+	menus
+		.iter()
+		.map(|menu| {
+			let options = menu
+				.options
+				.iter()
+				.map(|option| {
+					CreateSelectMenuOption::new(option.label.clone(), option.value)
+						.default_selection(option.default)
+				})
+				.collect();
+			CreateActionRow::SelectMenu(CreateSelectMenu::new(
+				menu.id,
+				CreateSelectMenuKind::String { options },
+			))
+		})
+		.collect()
+}
+
+fn to_press(press: ComponentInteraction) -> ButtonPress {
+	let values = match &press.data.kind {
+		ComponentInteractionDataKind::StringSelect { values } => values.clone(),
+		_ => Vec::new(),
+	};
+	ButtonPress {
+		custom_id: press.data.custom_id.clone(),
+		values,
+		user: press.user.id,
+		interaction: Some(press),
+	}
+}
+
+fn to_submission(submission: ModalInteraction) -> ModalSubmission {
+	let values = submission
+		.data
+		.components
+		.iter()
+		.flat_map(|row| &row.components)
+		.filter_map(|component| match component {
+			ActionRowComponent::InputText(input) => Some((
+				input.custom_id.clone(),
+				input.value.clone().unwrap_or_default(),
+			)),
+			_ => None,
+		})
+		.collect();
+	ModalSubmission {
+		values,
+		interaction: Some(submission),
+	}
+}
+
+fn message(content: String, ephemeral: bool) -> CreateInteractionResponse {
+	CreateInteractionResponse::Message(
+		CreateInteractionResponseMessage::new()
+			.content(content)
+			.ephemeral(ephemeral),
+	)
+}
+
+#[async_trait]
+impl Discord for SerenityDiscord<'_> {
+	async fn reply(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		interaction
+			.create_response(self.http, message(content, ephemeral))
+			.await?;
+		Ok(())
+	}
+	async fn reply_image(
+		&self,
+		interaction: &CommandInteraction,
+		image: &[u8],
+		file_name: &str,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		interaction
+			.create_response(
+				self.http,
+				CreateInteractionResponse::Message(
+					CreateInteractionResponseMessage::new()
+						.add_file(CreateAttachment::bytes(image, file_name))
+						.ephemeral(ephemeral),
+				),
+			)
+			.await?;
+		Ok(())
+	}
+	async fn defer(&self, interaction: &CommandInteraction) -> Result<(), BotError> {
+		interaction.defer(self.http).await?;
+		Ok(())
+	}
+	async fn follow_up(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		interaction
+			.create_followup(
+				self.http,
+				CreateInteractionResponseFollowup::new()
+					.content(content)
+					.ephemeral(ephemeral),
+			)
+			.await?;
+		Ok(())
+	}
+	async fn follow_up_image(
+		&self,
+		interaction: &CommandInteraction,
+		image: &[u8],
+		file_name: &str,
+	) -> Result<(), BotError> {
+		interaction
+			.create_followup(
+				self.http,
+				CreateInteractionResponseFollowup::new()
+					.add_file(CreateAttachment::bytes(image, file_name)),
+			)
+			.await?;
+		Ok(())
+	}
+	async fn delete_reply(&self, interaction: &CommandInteraction) -> Result<(), BotError> {
+		interaction.delete_response(self.http).await?;
+		Ok(())
+	}
+	async fn ask_with_buttons(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		buttons: &[Button],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		interaction
+			.create_response(
+				self.http,
+				CreateInteractionResponse::Message(
+					CreateInteractionResponseMessage::new()
+						.content(content)
+						.ephemeral(true)
+						.components(vec![button_row(buttons)]),
+				),
+			)
+			.await?;
+		let message = interaction.get_response(self.http).await?;
+		let press = message
+			.await_component_interaction(self.shard)
+			.timeout(timeout)
+			.await;
+		Ok(press.map(to_press))
+	}
+	async fn ask_everyone_with_buttons(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		buttons: &[Button],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		interaction
+			.create_response(
+				self.http,
+				CreateInteractionResponse::Message(
+					CreateInteractionResponseMessage::new()
+						.content(content)
+						.components(vec![button_row(buttons)]),
+				),
+			)
+			.await?;
+		let message = interaction.get_response(self.http).await?;
+		let press = message
+			.await_component_interaction(self.shard)
+			.timeout(timeout)
+			.await;
+		Ok(press.map(to_press))
+	}
+	async fn update_buttons(
+		&self,
+		press: &ButtonPress,
+		content: String,
+		buttons: &[Button],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		let interaction = press.interaction()?;
+		interaction
+			.create_response(
+				self.http,
+				CreateInteractionResponse::UpdateMessage(
+					CreateInteractionResponseMessage::new()
+						.content(content)
+						.components(vec![button_row(buttons)]),
+				),
+			)
+			.await?;
+		let press = interaction
+			.message
+			.await_component_interaction(self.shard)
+			.timeout(timeout)
+			.await;
+		Ok(press.map(to_press))
+	}
+	async fn ask_with_select_menus(
+		&self,
+		interaction: &CommandInteraction,
+		content: String,
+		menus: &[SelectMenu],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		interaction
+			.create_response(
+				self.http,
+				CreateInteractionResponse::Message(
+					CreateInteractionResponseMessage::new()
+						.content(content)
+						.ephemeral(true)
+						.components(select_menu_rows(menus)),
+				),
+			)
+			.await?;
+		let message = interaction.get_response(self.http).await?;
+		let press = message
+			.await_component_interaction(self.shard)
+			.timeout(timeout)
+			.await;
+		Ok(press.map(to_press))
+	}
+	async fn update_select_menus(
+		&self,
+		press: &ButtonPress,
+		content: String,
+		menus: &[SelectMenu],
+		timeout: Duration,
+	) -> Result<Option<ButtonPress>, BotError> {
+		let interaction = press.interaction()?;
+		interaction
+			.create_response(
+				self.http,
+				CreateInteractionResponse::UpdateMessage(
+					CreateInteractionResponseMessage::new()
+						.content(content)
+						.components(select_menu_rows(menus)),
+				),
+			)
+			.await?;
+		let press = interaction
+			.message
+			.await_component_interaction(self.shard)
+			.timeout(timeout)
+			.await;
+		Ok(press.map(to_press))
+	}
+	async fn reply_to_button(
+		&self,
+		press: &ButtonPress,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		press
+			.interaction()?
+			.create_response(self.http, message(content, ephemeral))
+			.await?;
+		Ok(())
+	}
+	async fn update_button_message(
+		&self,
+		press: &ButtonPress,
+		content: String,
+	) -> Result<(), BotError> {
+		press
+			.interaction()?
+			.create_response(
+				self.http,
+				CreateInteractionResponse::UpdateMessage(
+					CreateInteractionResponseMessage::new()
+						.content(content)
+						.components(vec![]),
+				),
+			)
+			.await?;
+		Ok(())
+	}
+	async fn ask_with_modal(
+		&self,
+		press: &ButtonPress,
+		modal: &Modal,
+		timeout: Duration,
+	) -> Result<Option<ModalSubmission>, BotError> {
+		let interaction = press.interaction()?;
+		let rows = modal
+			.inputs
+			.iter()
+			.map(|input| {
+				CreateActionRow::InputText(
+					CreateInputText::new(InputTextStyle::Short, input.label, input.id)
+						.value(input.value.clone()),
+				)
+			})
+			.collect();
+		interaction
+			.create_response(
+				self.http,
+				CreateInteractionResponse::Modal(
+					CreateModal::new(modal.id, modal.title.clone()).components(rows),
+				),
+			)
+			.await?;
+		let submission = interaction
+			.message
+			.await_modal_interaction(self.shard)
+			.timeout(timeout)
+			.await;
+		Ok(submission.map(to_submission))
+	}
+	async fn reply_to_modal(
+		&self,
+		submission: &ModalSubmission,
+		content: String,
+		ephemeral: bool,
+	) -> Result<(), BotError> {
+		submission
+			.interaction()?
+			.create_response(self.http, message(content, ephemeral))
+			.await?;
+		Ok(())
+	}
+	async fn autocomplete(
+		&self,
+		interaction: &CommandInteraction,
+		choices: Vec<Choice>,
+	) -> Result<(), BotError> {
+		let choices = choices
+			.into_iter()
+			.map(|choice| AutocompleteChoice::new(choice.name, choice.value))
+			.collect();
+		interaction
+			.create_response(
+				self.http,
+				CreateInteractionResponse::Autocomplete(
+					CreateAutocompleteResponse::new().set_choices(choices),
+				),
+			)
+			.await?;
+		Ok(())
+	}
+	async fn direct_message(&self, user: UserId, content: String) -> Result<(), BotError> {
+		user.direct_message(self.http.as_ref(), CreateMessage::new().content(content))
+			.await?;
+		Ok(())
+	}
+	async fn user_name(&self, guild: GuildId, user: UserId) -> String {
+		let member = if let Some(member) = self
+			.cache
+			.guild(guild)
+			.and_then(|guild| guild.members.get(&user).cloned())
+		{
+			member
+		} else if let Ok(member) = self.http.get_member(guild, user).await {
+			member
+		} else {
+			return format!("{}", user);
+		};
+		if let Some(nick) = member.nick {
+			nick
+		} else {
+			member.display_name().to_owned()
+		}
+	}
+	async fn member_roles(&self, guild: GuildId, user: UserId) -> Option<Vec<RoleId>> {
+		guild
+			.member((self.cache, self.http.as_ref()), user)
+			.await
+			.ok()
+			.map(|member| member.roles)
+	}
+}
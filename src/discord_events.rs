@@ -1,24 +1,42 @@
-use itertools::Itertools;
+use std::sync::{
+	atomic::{AtomicBool, Ordering},
+	Arc,
+};
+
 use serenity::{async_trait, model::prelude::*, prelude::*};
 use sqlx::{Pool, Sqlite};
 
 use crate::{
-	emoji::EmojiMap, find_emoji, images, inventory, periodic_emoji::maybe_give_periodic_emoji,
-	trading, user_settings,
+	commands::CommandRegistry,
+	config::Config,
+	discord::{Discord, SerenityDiscord},
+	emoji::EmojiMap,
+	error::report_error,
+	periodic_emoji::maybe_give_periodic_emoji,
+	registration::{sync_global_commands, sync_guild_commands},
+	trading::{auction_house::run_auction_closing, expiry::run_offer_expiry},
+	user_settings::notifications::send_pending_digest,
 };
 
 pub struct DiscordEventHandler {
 	database: Pool<Sqlite>,
-	emoji_map: EmojiMap,
-	trading_roles: Vec<RoleId>,
+	/// Shared with the background task that closes auctions.
+	emoji_map: Arc<EmojiMap>,
+	/// Shared with the background task that expires trade offers.
+	config: Arc<Config>,
+	commands: CommandRegistry,
+	/// Ready happens again after reconnecting, but the background tasks should only start once.
+	background_tasks_started: AtomicBool,
 }
 
 impl DiscordEventHandler {
-	pub fn new(database: Pool<Sqlite>, emoji_map: EmojiMap, trading_roles: Vec<RoleId>) -> Self {
+	pub fn new(database: Pool<Sqlite>, emoji_map: EmojiMap, config: Config) -> Self {
 		Self {
 			database,
-			emoji_map,
-			trading_roles,
+			emoji_map: Arc::new(emoji_map),
+			config: Arc::new(config),
+			commands: CommandRegistry::new(),
+			background_tasks_started: AtomicBool::new(false),
 		}
 	}
 }
@@ -27,65 +45,103 @@ impl DiscordEventHandler {
 impl EventHandler for DiscordEventHandler {
 	async fn message(&self, context: Context, message: Message) {
 		if message.author.id != context.cache.current_user().id && !message.author.bot {
-			maybe_give_periodic_emoji(&self.database, context, message).await;
+			maybe_give_periodic_emoji(&self.database, &self.config, context, message).await;
 		}
 	}
 
 	async fn interaction_create(&self, context: Context, interaction: Interaction) {
-		let shard_manager = context.shard;
-		if let Interaction::Command(interaction) = interaction {
-			let context = crate::context::Context::new(
-				&self.database,
-				&self.emoji_map,
-				&self.trading_roles,
-				&context.http,
-				&context.cache,
+		let (interaction, is_autocomplete) = match interaction {
+			Interaction::Command(interaction) => (interaction, false),
+			Interaction::Autocomplete(interaction) => (interaction, true),
+			_ => return,
+		};
+		let Some(guild) = interaction.guild_id else {
+			return;
+		};
+		let Some(command) = self.commands.get(&interaction.data.name) else {
+			eprintln!(
+				"Received unknown command /{} from user {} in guild {guild}.",
+				interaction.data.name, interaction.user.id
 			);
+			return;
+		};
+		let discord = SerenityDiscord {
+			http: &context.http,
+			cache: &context.cache,
+			shard: &context.shard,
+		};
+		let context = crate::context::Context::new(
+			guild,
+			&self.database,
+			&self.emoji_map,
+			&self.config,
+			&discord,
+		);
 
-			match interaction.data.name.as_str() {
-				"inventory" => inventory::view::execute(context, interaction).await,
-				"group" => inventory::group::execute(context, interaction).await,
-				"who" => find_emoji::execute(context, interaction).await,
-				"trade" => trading::trade::execute(context, shard_manager, interaction).await,
-				"recycle" => trading::recycling::execute(context, interaction).await,
-				"private" => user_settings::private::execute(context, interaction).await,
-				"image" => images::rasterize::execute(context, interaction).await,
-				"generate" => images::generate::execute(context, interaction).await,
-				"generate2" => images::generate::execute_v2(context, interaction).await,
-				"testimage" => images::generate::execute_test(context, interaction).await,
-				_ => (),
+		if is_autocomplete {
+			// There is no one to report an error to, as autocomplete can only answer with choices.
+			let result = match command.autocomplete(context, &interaction).await {
+				Ok(choices) => discord.autocomplete(&interaction, choices).await,
+				Err(error) => Err(error),
 			};
+			if let Err(error) = result {
+				eprintln!(
+					"Could not autocomplete /{} for user {}: {error}",
+					interaction.data.name, interaction.user.id
+				);
+			}
+		} else {
+			if let Err(error) = command.execute(context, &interaction).await {
+				report_error(&discord, &interaction, error).await;
+			}
+			if let Err(error) = send_pending_digest(context, &interaction).await {
+				eprintln!(
+					"Could not send pending notifications to user {}: {error}",
+					interaction.user.id
+				);
+			}
 		}
 	}
 
 	async fn ready(&self, context: Context, _ready: Ready) {
 		println!("Ready");
-		let arg = std::env::args().nth(1);
-		if let Some(arg) = arg {
-			if &arg == "register" {
-				for guild in context.cache.guilds() {
-					let commands = vec![
-						inventory::view::register(),
-						inventory::group::register(),
-						find_emoji::register(),
-						trading::trade::register(),
-						trading::recycling::register(),
-						user_settings::private::register(),
-						images::rasterize::register(),
-						images::generate::register(),
-						images::generate::register_v2(),
-						images::generate::register_test(),
-					];
-					let commands = guild.set_commands(&context.http, commands).await.unwrap();
+		if !self.background_tasks_started.swap(true, Ordering::Relaxed) {
+			tokio::spawn(run_offer_expiry(
+				self.database.clone(),
+				self.config.clone(),
+				context.http.clone(),
+				context.cache.clone(),
+				context.shard.clone(),
+			));
+			tokio::spawn(run_auction_closing(
+				self.database.clone(),
+				self.emoji_map.clone(),
+				context.http.clone(),
+				context.cache.clone(),
+				context.shard.clone(),
+			));
+		}
+		if let Err(error) =
+			sync_global_commands(&context.http, &self.commands, &self.config.commands).await
+		{
+			eprintln!("Could not update global slash commands: {error}");
+		}
+	}
 
-					let command_names = commands.into_iter().map(|command| command.name).join(", ");
-					println!(
-						"I now have the following guild slash commands in guild {}: {}",
-						guild.get(),
-						command_names
-					);
-				}
-			}
+	/// Also happens for every guild the bot is already in when it connects, not only for newly joined guilds.
+	async fn guild_create(&self, context: Context, guild: Guild, _is_new: Option<bool>) {
+		if let Err(error) = sync_guild_commands(
+			&context.http,
+			&self.commands,
+			&self.config.commands,
+			guild.id,
+		)
+		.await
+		{
+			eprintln!(
+				"Could not update slash commands in guild {}: {error}",
+				guild.id
+			);
 		}
 	}
 }
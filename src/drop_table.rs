@@ -0,0 +1,109 @@
+//! How the emojis that are handed out get picked.
+
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng, RngCore};
+
+use crate::{emoji::Emoji, emoji_list::Rarity};
+
+/// Picks emojis for drops, streak rewards and recycling.
+pub trait DropTable: Send + Sync {
+	fn pick(&self, rng: &mut dyn RngCore) -> Emoji;
+}
+
+/// Picks a rarity by its weight, and then any emoji of that rarity.
+#[derive(Debug, Clone)]
+pub struct RarityTable {
+	weights: [u32; Rarity::ALL.len()],
+	rarities: WeightedIndex<u32>,
+	/// Every emoji, by the index of its rarity in `Rarity::ALL`.
+	emojis: [Vec<Emoji>; Rarity::ALL.len()],
+}
+
+impl RarityTable {
+	/// Returns None if no rarity with emojis has any weight.
+	pub fn new(weights: impl Fn(Rarity) -> u32) -> Option<Self> {
+		let mut emojis = Rarity::ALL.map(|_| Vec::new());
+		for emoji in Emoji::all() {
+			emojis[rarity_index(emoji.rarity())].push(emoji);
+		}
+		// A rarity without emojis can't be picked, whatever its weight.
+		let weights = Rarity::ALL.map(|rarity| {
+			if emojis[rarity_index(rarity)].is_empty() {
+				0
+			} else {
+				weights(rarity)
+			}
+		});
+		let rarities = WeightedIndex::new(weights).ok()?;
+		Some(Self {
+			weights,
+			rarities,
+			emojis,
+		})
+	}
+
+	/// The chance that a dropped emoji has this rarity.
+	pub fn chance(&self, rarity: Rarity) -> f64 {
+		self.weights[rarity_index(rarity)] as f64 / self.weights.iter().sum::<u32>() as f64
+	}
+}
+
+impl Default for RarityTable {
+	fn default() -> Self {
+		Self::new(|rarity| match rarity {
+			Rarity::Common => 70,
+			Rarity::Uncommon => 20,
+			Rarity::Rare => 8,
+			Rarity::Legendary => 2,
+		})
+		.unwrap()
+	}
+}
+
+impl DropTable for RarityTable {
+	fn pick(&self, rng: &mut dyn RngCore) -> Emoji {
+		let emojis = &self.emojis[self.rarities.sample(rng)];
+		emojis[rng.gen_range(0..emojis.len())]
+	}
+}
+
+/// Where the rarity is in `Rarity::ALL`, for arrays sized by it.
+pub fn rarity_index(rarity: Rarity) -> usize {
+	Rarity::ALL
+		.iter()
+		.position(|other| *other == rarity)
+		.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn rarities_drop_as_often_as_weighted() {
+		let table = RarityTable::new(|rarity| match rarity {
+			Rarity::Common => 50,
+			Rarity::Uncommon => 30,
+			Rarity::Rare => 15,
+			Rarity::Legendary => 5,
+		})
+		.unwrap();
+		let mut rng = StdRng::seed_from_u64(1);
+		let mut counts = [0; Rarity::ALL.len()];
+		const DRAWS: u32 = 100_000;
+		for _ in 0..DRAWS {
+			counts[rarity_index(table.pick(&mut rng).rarity())] += 1;
+		}
+		for rarity in Rarity::ALL {
+			let share = counts[rarity_index(rarity)] as f64 / DRAWS as f64;
+			assert!(
+				(share - table.chance(rarity)).abs() < 0.01,
+				"{rarity} dropped {share} of the time instead of {}",
+				table.chance(rarity)
+			);
+		}
+
+		assert!(RarityTable::new(|_| 0).is_none());
+	}
+}
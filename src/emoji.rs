@@ -1,59 +1,19 @@
 use std::{
-	cmp::Ordering,
 	collections::HashMap,
 	fmt::{Display, Write},
 	hash::Hash,
-	ops::Range,
 };
 
-use crate::emoji_list::EMOJI_LIST;
-use rand::{thread_rng, Rng};
+use crate::{
+	drop_table::DropTable,
+	emoji_list::{Category, EmojiData, Rarity, UnicodeVersion, EMOJI_LIST},
+	error::BotError,
+};
+use rand::thread_rng;
 use serenity::model::prelude::ReactionType;
 
 const VS16: char = '\u{fe0f}';
 
-const NON_MIRROR_EMOJIS: [Range<usize>; 29] = [
-	53..54,
-	100..101,
-	134..136,
-	146..148,
-	157..159,
-	638..649,
-	650..653,
-	894..897,
-	910..911,
-	913..914,
-	919..920,
-	928..929,
-	936..937,
-	1033..1034,
-	1046..1047,
-	1072..1074,
-	1077..1082,
-	1085..1087,
-	1109..1114,
-	1115..1118,
-	1163..1164,
-	1173..1174,
-	1214..1215,
-	1226..1227,
-	1233..1235,
-	1237..1239,
-	1277..1279,
-	1304..1538,
-	1535..1875,
-];
-
-const NON_ROTATE_EMOJIS: [Range<usize>; 7] = [
-	130..132,
-	134..136,
-	146..151,
-	641..649,
-	1443..1444,
-	1448..1471,
-	1552..1576,
-];
-
 #[derive(Debug, Clone, Copy)]
 pub struct Emoji {
 	emoji: &'static str,
@@ -62,12 +22,15 @@ pub struct Emoji {
 }
 
 impl Emoji {
-	pub fn random() -> Self {
-		let index = thread_rng().gen_range(0..EMOJI_LIST.len());
-		Self {
-			emoji: EMOJI_LIST[index],
+	pub fn random(drop_table: &dyn DropTable) -> Self {
+		drop_table.pick(&mut thread_rng())
+	}
+	/// Every emoji, in order.
+	pub fn all() -> impl Iterator<Item = Self> {
+		EMOJI_LIST.iter().enumerate().map(|(index, data)| Self {
+			emoji: data.emoji,
 			index,
-		}
+		})
 	}
 	pub fn as_str(&self) -> &'static str {
 		self.emoji
@@ -75,6 +38,38 @@ impl Emoji {
 	pub fn index(&self) -> usize {
 		self.index
 	}
+	fn data(&self) -> &'static EmojiData {
+		&EMOJI_LIST[self.index]
+	}
+	/// The CLDR short name, like "slightly smiling face".
+	pub fn name(&self) -> &'static str {
+		self.data().name
+	}
+	/// Without the colons.
+	pub fn shortcodes(&self) -> &'static [&'static str] {
+		self.data().shortcodes
+	}
+	pub fn category(&self) -> Category {
+		self.data().category
+	}
+	pub fn subcategory(&self) -> &'static str {
+		self.data().subcategory
+	}
+	#[allow(dead_code)]
+	pub fn unicode_version(&self) -> UnicodeVersion {
+		self.data().unicode_version
+	}
+	pub fn rarity(&self) -> Rarity {
+		self.data().rarity
+	}
+	/// Whether it's OK to mirror the emoji. Emojis with text should not be mirrored, for example.
+	pub fn may_mirror(&self) -> bool {
+		self.data().transforms.mirror
+	}
+	/// Whether it's OK to rotate the emoji. Mainly it's just emojis with meaningful directions that should not be rotated.
+	pub fn may_rotate(&self) -> bool {
+		self.data().transforms.rotate
+	}
 	fn file_name(self) -> String {
 		// 5 characters per byte plus one for each dividing "-" or the "." at the end, plus 3 for "svg".
 		let mut string = String::with_capacity(self.emoji.len() * 6 + 3);
@@ -144,31 +139,11 @@ pub struct EmojiWithImage<'t> {
 	emoji: Emoji,
 	/// An SVG render tree.
 	image: &'t resvg::usvg::Tree,
-	/// Whether it's OK to mirror the emoji. Emojis with text should not be mirrored, for example.
-	may_mirror: bool,
-	/// Whether it's OK to rotate the emoji. Mainly it's just emojis with meaningful directions that should not be rotated.
-	may_rotate: bool,
 }
 
 impl<'t> EmojiWithImage<'t> {
 	fn new(emoji: Emoji, image: &'t resvg::usvg::Tree) -> Self {
-		let search_range = |range: &Range<usize>| {
-			if range.start > emoji.index() {
-				Ordering::Greater
-			} else if range.end <= emoji.index() {
-				Ordering::Less
-			} else {
-				Ordering::Equal
-			}
-		};
-		let should_mirror = NON_MIRROR_EMOJIS.binary_search_by(search_range).is_err();
-		let should_rotate = NON_ROTATE_EMOJIS.binary_search_by(search_range).is_err();
-		Self {
-			emoji,
-			image,
-			may_mirror: should_mirror,
-			may_rotate: should_rotate,
-		}
+		Self { emoji, image }
 	}
 	pub fn emoji(&self) -> Emoji {
 		self.emoji
@@ -190,10 +165,10 @@ impl<'t> EmojiWithImage<'t> {
 		resvg::render(self.image, transform, pixmap);
 	}
 	pub fn may_mirror(&self) -> bool {
-		self.may_mirror
+		self.emoji.may_mirror()
 	}
 	pub fn may_rotate(&self) -> bool {
-		self.may_rotate
+		self.emoji.may_rotate()
 	}
 }
 
@@ -233,10 +208,13 @@ pub fn load_emojis() -> Vec<resvg::usvg::Tree> {
 	let options = resvg::usvg::Options::default();
 	let fonts = resvg::usvg::fontdb::Database::default();
 	EMOJI_LIST
-		.into_iter()
+		.iter()
 		.enumerate()
-		.map(|(index, emoji)| {
-			let emoji = Emoji { emoji, index };
+		.map(|(index, data)| {
+			let emoji = Emoji {
+				emoji: data.emoji,
+				index,
+			};
 			let image = emoji.read_svg().unwrap();
 			resvg::usvg::Tree::from_data(&image, &options, &fonts).unwrap()
 		})
@@ -245,6 +223,9 @@ pub fn load_emojis() -> Vec<resvg::usvg::Tree> {
 
 pub struct EmojiMap {
 	map: HashMap<&'static str, Emoji>,
+	/// Keyed by lowercase name.
+	names: HashMap<String, Emoji>,
+	shortcodes: HashMap<&'static str, Emoji>,
 	images: Vec<resvg::usvg::Tree>,
 }
 
@@ -252,17 +233,72 @@ impl EmojiMap {
 	pub fn load() -> Self {
 		println!("Loading images.");
 		let images = load_emojis();
-		let map = EMOJI_LIST
-			.into_iter()
-			.enumerate()
-			.map(|(index, emoji)| (emoji, Emoji { emoji, index }))
-			.collect();
 		println!("Images loaded.");
-		Self { map, images }
+		Self::with_images(images)
+	}
+	/// Loading the images takes a while, and most tests do not need them.
+	#[cfg(test)]
+	pub fn without_images() -> Self {
+		Self::with_images(Vec::new())
+	}
+	fn with_images(images: Vec<resvg::usvg::Tree>) -> Self {
+		let mut map = HashMap::new();
+		let mut names = HashMap::new();
+		let mut shortcodes = HashMap::new();
+		for (index, data) in EMOJI_LIST.iter().enumerate() {
+			let emoji = Emoji {
+				emoji: data.emoji,
+				index,
+			};
+			map.insert(data.emoji, emoji);
+			names.insert(normalize_name(data.name), emoji);
+			for shortcode in data.shortcodes {
+				shortcodes.insert(*shortcode, emoji);
+			}
+		}
+		Self {
+			map,
+			names,
+			shortcodes,
+			images,
+		}
 	}
 	pub fn get(&self, emoji: &str) -> Option<Emoji> {
 		self.map.get(emoji).copied()
 	}
+	/// For emojis that came out of the database, which should always be in the map.
+	pub fn get_from_database(&self, emoji: &str) -> Result<Emoji, BotError> {
+		self.get(emoji)
+			.ok_or_else(|| BotError::UnknownEmoji(String::from(emoji)))
+	}
+	/// Looks up an emoji by its CLDR name, ignoring case and quotes.
+	pub fn get_by_name(&self, name: &str) -> Option<Emoji> {
+		self.names.get(&normalize_name(name)).copied()
+	}
+	/// Takes shortcodes with or without the colons.
+	pub fn get_by_shortcode(&self, shortcode: &str) -> Option<Emoji> {
+		self.shortcodes
+			.get(strip_colons(shortcode).to_lowercase().as_str())
+			.copied()
+	}
+	/// Names resembling one that was not found, best match first.
+	pub fn similar_names(&self, name: &str) -> Vec<&'static str> {
+		closest_matches(
+			&normalize_name(name),
+			EMOJI_LIST.iter().map(|data| data.name),
+			normalize_name,
+		)
+	}
+	/// Shortcodes resembling one that was not found, best match first. Without the colons.
+	pub fn similar_shortcodes(&self, shortcode: &str) -> Vec<&'static str> {
+		closest_matches(
+			&strip_colons(shortcode).to_lowercase(),
+			EMOJI_LIST
+				.iter()
+				.flat_map(|data| data.shortcodes.iter().copied()),
+			str::to_owned,
+		)
+	}
 	pub fn get_with_image(&'_ self, emoji: &str) -> Option<EmojiWithImage<'_>> {
 		self.map.get(emoji).map(|emoji| self.get_image(*emoji))
 	}
@@ -273,6 +309,70 @@ impl EmojiMap {
 	}
 }
 
+fn strip_colons(shortcode: &str) -> &str {
+	shortcode
+		.strip_prefix(':')
+		.and_then(|shortcode| shortcode.strip_suffix(':'))
+		.unwrap_or(shortcode)
+}
+
+/// Names are matched without regard to case or quotes, since some names contain quotes, and phones like to curl apostrophes.
+fn normalize_name(name: &str) -> String {
+	name.trim()
+		.to_lowercase()
+		.chars()
+		.filter(|char| !matches!(char, '"' | '“' | '”'))
+		.map(|char| if char == '’' { '\'' } else { char })
+		.collect()
+}
+
+/// The candidates that are a small number of edits away from the query, followed by ones that contain it.
+fn closest_matches(
+	query: &str,
+	candidates: impl Iterator<Item = &'static str>,
+	normalize: impl Fn(&str) -> String,
+) -> Vec<&'static str> {
+	const SUGGESTION_COUNT: usize = 3;
+	let query_length = query.chars().count();
+	let max_distance = query_length / 3 + 1;
+	let mut matches: Vec<(usize, &'static str)> = candidates
+		.filter_map(|candidate| {
+			let normalized = normalize(candidate);
+			let distance = edit_distance(query, &normalized);
+			if distance <= max_distance {
+				Some((distance, candidate))
+			} else if query_length >= 3 && normalized.contains(query) {
+				Some((max_distance + distance, candidate))
+			} else {
+				None
+			}
+		})
+		.collect();
+	matches.sort();
+	matches.dedup_by_key(|(_, candidate)| *candidate);
+	matches
+		.into_iter()
+		.take(SUGGESTION_COUNT)
+		.map(|(_, candidate)| candidate)
+		.collect()
+}
+
+/// The Levenshtein distance, in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+	let b: Vec<char> = b.chars().collect();
+	let mut previous: Vec<usize> = (0..=b.len()).collect();
+	let mut current = vec![0; b.len() + 1];
+	for (i, a_char) in a.chars().enumerate() {
+		current[0] = i + 1;
+		for (j, b_char) in b.iter().enumerate() {
+			let substitution = previous[j] + usize::from(a_char != *b_char);
+			current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+		}
+		std::mem::swap(&mut previous, &mut current);
+	}
+	previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -305,7 +405,47 @@ mod tests {
 	}
 	#[test]
 	fn find_a_and_z() {
-		assert_eq!(EMOJI_LIST[1605], "🇦");
-		assert_eq!(EMOJI_LIST[1580], "🇿");
+		assert_eq!(EMOJI_LIST[1605].emoji, "🇦");
+		assert_eq!(EMOJI_LIST[1580].emoji, "🇿");
+	}
+	#[test]
+	fn names_and_shortcodes_are_unique() {
+		let emoji_map = EmojiMap::without_images();
+		assert_eq!(emoji_map.names.len(), EMOJI_LIST.len());
+		let shortcode_count: usize = EMOJI_LIST.iter().map(|data| data.shortcodes.len()).sum();
+		assert_eq!(emoji_map.shortcodes.len(), shortcode_count);
+	}
+	#[test]
+	fn look_up_by_metadata() {
+		let emoji_map = EmojiMap::without_images();
+		let smile = emoji_map.get("🙂").unwrap();
+		assert_eq!(smile.name(), "slightly smiling face");
+		assert_eq!(emoji_map.get_by_name("Slightly Smiling Face"), Some(smile));
+		assert_eq!(emoji_map.get_by_shortcode(":slight_smile:"), Some(smile));
+		assert_eq!(
+			emoji_map.get_by_shortcode("slightly_smiling_face"),
+			Some(smile)
+		);
+		assert_eq!(smile.category(), Category::People);
+		assert_eq!(smile.subcategory(), "face-smiling");
+		assert_eq!(smile.unicode_version().to_string(), "1.0");
+
+		let flag = emoji_map.get_by_shortcode("flag_nl").unwrap();
+		assert_eq!(flag.name(), "flag: Netherlands");
+		assert!(!flag.may_mirror());
+		let thumbs_up = emoji_map.get_by_name("thumbs up").unwrap();
+		assert!(thumbs_up.may_mirror() && !thumbs_up.may_rotate());
+		assert!(emoji_map
+			.get_by_name("Japanese “here” button")
+			.is_some_and(|emoji| emoji.as_str() == "🈁"));
+	}
+	#[test]
+	fn suggestions() {
+		let emoji_map = EmojiMap::without_images();
+		assert_eq!(edit_distance("kitten", "sitting"), 3);
+		assert_eq!(emoji_map.similar_names("thumb up")[0], "thumbs up");
+		assert_eq!(emoji_map.similar_shortcodes(":thumbsupp:")[0], "thumbsup");
+		assert!(emoji_map.similar_names("pizza").contains(&"pizza"));
+		assert!(emoji_map.similar_names("zzzzzzzzzzzzzz").is_empty());
 	}
 }
@@ -1,1885 +1,2037 @@
-pub static EMOJI_LIST: [&str; 1875] = [
+//! Every emoji the bot hands out, with what is known about each.
+//!
+//! The order is the display order, and an emoji's position here is its index. Names are CLDR's English short names, with plain apostrophes and quotes.
+
+use std::fmt::Display;
+
+use Category::*;
+use Rarity::*;
+
+/// The categories Discord's emoji picker sorts emojis into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+	People,
+	Nature,
+	Food,
+	Activity,
+	Travel,
+	Objects,
+	Symbols,
+	Flags,
+}
+
+impl Category {
+	pub const ALL: [Category; 8] = [
+		People, Nature, Food, Activity, Travel, Objects, Symbols, Flags,
+	];
+
+	pub fn name(self) -> &'static str {
+		match self {
+			People => "people",
+			Nature => "nature",
+			Food => "food",
+			Activity => "activity",
+			Travel => "travel",
+			Objects => "objects",
+			Symbols => "symbols",
+			Flags => "flags",
+		}
+	}
+}
+
+impl Display for Category {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+/// How rarely an emoji drops. Newer emojis and flags are less common, and a handful of showpieces are legendary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rarity {
+	Common,
+	Uncommon,
+	Rare,
+	Legendary,
+}
+
+impl Rarity {
+	pub const ALL: [Rarity; 4] = [Common, Uncommon, Rare, Legendary];
+
+	pub fn name(self) -> &'static str {
+		match self {
+			Common => "common",
+			Uncommon => "uncommon",
+			Rare => "rare",
+			Legendary => "legendary",
+		}
+	}
+}
+
+impl Display for Rarity {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct UnicodeVersion {
+	pub major: u8,
+	pub minor: u8,
+}
+
+impl Display for UnicodeVersion {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}.{}", self.major, self.minor)
+	}
+}
+
+/// What may be done to an emoji when it is scattered on a generated image. Emojis with text should not be mirrored, and emojis with meaningful directions should not be rotated, for example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transforms {
+	pub mirror: bool,
+	pub rotate: bool,
+}
+
+const ANY: Transforms = Transforms {
+	mirror: true,
+	rotate: true,
+};
+const NO_MIRROR: Transforms = Transforms {
+	mirror: false,
+	rotate: true,
+};
+const NO_ROTATE: Transforms = Transforms {
+	mirror: true,
+	rotate: false,
+};
+const FIXED: Transforms = Transforms {
+	mirror: false,
+	rotate: false,
+};
+
+#[derive(Debug)]
+pub struct EmojiData {
+	pub emoji: &'static str,
+	/// Like "grinning face".
+	pub name: &'static str,
+	/// Without the colons. The first one is the name in snake case, the rest are common aliases.
+	pub shortcodes: &'static [&'static str],
+	pub category: Category,
+	/// The CLDR subgroup, like "face-smiling".
+	pub subcategory: &'static str,
+	/// The Emoji version that introduced the emoji. Since 11.0, Emoji versions match Unicode versions.
+	pub unicode_version: UnicodeVersion,
+	pub transforms: Transforms,
+	pub rarity: Rarity,
+}
+
+/// Keeps the table below to one line per emoji.
+#[allow(clippy::too_many_arguments)]
+const fn emoji(
+	emoji: &'static str,
+	name: &'static str,
+	shortcodes: &'static [&'static str],
+	category: Category,
+	subcategory: &'static str,
+	(major, minor): (u8, u8),
+	transforms: Transforms,
+	rarity: Rarity,
+) -> EmojiData {
+	EmojiData {
+		emoji,
+		name,
+		shortcodes,
+		category,
+		subcategory,
+		unicode_version: UnicodeVersion { major, minor },
+		transforms,
+		rarity,
+	}
+}
+
+#[rustfmt::skip]
+pub static EMOJI_LIST: [EmojiData; 1875] = [
 	// people
-	"😀",
-	"😃",
-	"😄",
-	"😁",
-	"😆",
-	"🥹",
-	"😅",
-	"😂",
-	"🤣",
-	"🥲",
-	"☺️",
-	"😊",
-	"😇",
-	"🙂",
-	"🙃",
-	"😉",
-	"😌",
-	"😍",
-	"🥰",
-	"😘",
-	"😗",
-	"😙",
-	"😚",
-	"😋",
-	"😛",
-	"😝",
-	"😜",
-	"🤪",
-	"🤨",
-	"🧐",
-	"🤓",
-	"😎",
-	"🥸",
-	"🤩",
-	"🥳",
-	"😏",
-	"😒",
-	"😞",
-	"😔",
-	"😟",
-	"😕",
-	"🙁",
-	"☹️",
-	"😣",
-	"😖",
-	"😫",
-	"😩",
-	"🥺",
-	"😢",
-	"😭",
-	"😤",
-	"😠",
-	"😡",
-	"🤬",
-	"🤯",
-	"😳",
-	"🥵",
-	"🥶",
-	"😶‍🌫️",
-	"😱",
-	"😨",
-	"😰",
-	"😥",
-	"😓",
-	"🤗",
-	"🤔",
-	"🫣",
-	"🤭",
-	"🫢",
-	"🫡",
-	"🤫",
-	"🫠",
-	"🤥",
-	"😶",
-	"🫥",
-	"😐",
-	"🫤",
-	"😑",
-	"😬",
-	"🙄",
-	"😯",
-	"😦",
-	"😧",
-	"😮",
-	"😲",
-	"🥱",
-	"😴",
-	"🤤",
-	"😪",
-	"😮‍💨",
-	"😵",
-	"😵‍💫",
-	"🤐",
-	"🥴",
-	"🤢",
-	"🤮",
-	"🤧",
-	"😷",
-	"🤒",
-	"🤕",
-	"🤑",
-	"🤠",
-	"😈",
-	"👿",
-	"👹",
-	"👺",
-	"🤡",
-	"💩",
-	"👻",
-	"💀",
-	"☠️",
-	"👽",
-	"👾",
-	"🤖",
-	"🎃",
-	"😺",
-	"😸",
-	"😹",
-	"😻",
-	"😼",
-	"😽",
-	"🙀",
-	"😿",
-	"😾",
-	"🫶",
-	"🤲",
-	"👐",
-	"🙌",
-	"👏",
-	"🤝",
-	"👍",
-	"👎",
-	"👊",
-	"✊",
-	"🤛",
-	"🤜",
-	"🤞",
-	"✌️",
-	"🫰",
-	"🤟",
-	"🤘",
-	"👌",
-	"🤌",
-	"🤏",
-	"🫳",
-	"🫴",
-	"👈",
-	"👉",
-	"👆",
-	"👇",
-	"☝️",
-	"✋",
-	"🤚",
-	"🖐️",
-	"🖖",
-	"👋",
-	"🤙",
-	"🫲",
-	"🫱",
-	"💪",
-	"🦾",
-	"🖕",
-	"✍️",
-	"🙏",
-	"🫵",
-	"🦶",
-	"🦵",
-	"🦿",
-	"💄",
-	"💋",
-	"👄",
-	"🫦",
-	"🦷",
-	"👅",
-	"👂",
-	"🦻",
-	"👃",
-	"👣",
-	"👁️",
-	"👀",
-	"🫀",
-	"🫁",
-	"🧠",
-	"🗣️",
-	"👤",
-	"👥",
-	"🫂",
-	"👶",
-	"🧒",
-	"👧",
-	"👦",
-	"🧑",
-	"👩",
-	"👨",
-	"🧑‍🦱",
-	"👩‍🦱",
-	"👨‍🦱",
-	"🧑‍🦰",
-	"👩‍🦰",
-	"👨‍🦰",
-	"👱",
-	"👱‍♀️",
-	"👱‍♂️",
-	"🧑‍🦳",
-	"👩‍🦳",
-	"👨‍🦳",
-	"🧑‍🦲",
-	"👩‍🦲",
-	"👨‍🦲",
-	"🧔",
-	"🧔‍♀️",
-	"🧔‍♂️",
-	"🧓",
-	"👵",
-	"👴",
-	"👲",
-	"👳",
-	"👳‍♀️",
-	"👳‍♂️",
-	"🧕",
-	"👮",
-	"👮‍♀️",
-	"👮‍♂️",
-	"👷",
-	"👷‍♀️",
-	"👷‍♂️",
-	"💂",
-	"💂‍♀️",
-	"💂‍♂️",
-	"🕵️",
-	"🕵️‍♀️",
-	"🕵️‍♂️",
-	"🧑‍⚕️",
-	"👩‍⚕️",
-	"👨‍⚕️",
-	"🧑‍🌾",
-	"👩‍🌾",
-	"👨‍🌾",
-	"🧑‍🍳",
-	"👩‍🍳",
-	"👨‍🍳",
-	"🧑‍🎓",
-	"👩‍🎓",
-	"👨‍🎓",
-	"🧑‍🎤",
-	"👩‍🎤",
-	"👨‍🎤",
-	"🧑‍🏫",
-	"👩‍🏫",
-	"👨‍🏫",
-	"🧑‍🏭",
-	"👩‍🏭",
-	"👨‍🏭",
-	"🧑‍💻",
-	"👩‍💻",
-	"👨‍💻",
-	"🧑‍💼",
-	"👩‍💼",
-	"👨‍💼",
-	"🧑‍🔧",
-	"👩‍🔧",
-	"👨‍🔧",
-	"🧑‍🔬",
-	"👩‍🔬",
-	"👨‍🔬",
-	"🧑‍🎨",
-	"👩‍🎨",
-	"👨‍🎨",
-	"🧑‍🚒",
-	"👩‍🚒",
-	"👨‍🚒",
-	"🧑‍✈️",
-	"👩‍✈️",
-	"👨‍✈️",
-	"🧑‍🚀",
-	"👩‍🚀",
-	"👨‍🚀",
-	"🧑‍⚖️",
-	"👩‍⚖️",
-	"👨‍⚖️",
-	"👰",
-	"👰‍♀️",
-	"👰‍♂️",
-	"🤵",
-	"🤵‍♀️",
-	"🤵‍♂️",
-	"🫅",
-	"👸",
-	"🤴",
-	"🦸",
-	"🦸‍♀️",
-	"🦸‍♂️",
-	"🦹",
-	"🦹‍♀️",
-	"🦹‍♂️",
-	"🥷",
-	"🧑‍🎄",
-	"🤶",
-	"🎅",
-	"🧙",
-	"🧙‍♀️",
-	"🧙‍♂️",
-	"🧝",
-	"🧝‍♀️",
-	"🧝‍♂️",
-	"🧌",
-	"🧛",
-	"🧛‍♀️",
-	"🧛‍♂️",
-	"🧟",
-	"🧟‍♀️",
-	"🧟‍♂️",
-	"🧞",
-	"🧞‍♀️",
-	"🧞‍♂️",
-	"🧜",
-	"🧜‍♀️",
-	"🧜‍♂️",
-	"🧚",
-	"🧚‍♀️",
-	"🧚‍♂️",
-	"👼",
-	"🫄",
-	"🤰",
-	"🫃",
-	"🤱",
-	"🧑‍🍼",
-	"👩‍🍼",
-	"👨‍🍼",
-	"🙇",
-	"🙇‍♀️",
-	"🙇‍♂️",
-	"💁",
-	"💁‍♀️",
-	"💁‍♂️",
-	"🙅",
-	"🙅‍♀️",
-	"🙅‍♂️",
-	"🙆",
-	"🙆‍♀️",
-	"🙆‍♂️",
-	"🙋",
-	"🙋‍♀️",
-	"🙋‍♂️",
-	"🧏",
-	"🧏‍♀️",
-	"🧏‍♂️",
-	"🤦",
-	"🤦‍♀️",
-	"🤦‍♂️",
-	"🤷",
-	"🤷‍♀️",
-	"🤷‍♂️",
-	"🙎",
-	"🙎‍♀️",
-	"🙎‍♂️",
-	"🙍",
-	"🙍‍♀️",
-	"🙍‍♂️",
-	"💇",
-	"💇‍♀️",
-	"💇‍♂️",
-	"💆",
-	"💆‍♀️",
-	"💆‍♂️",
-	"🧖",
-	"🧖‍♀️",
-	"🧖‍♂️",
-	"💅",
-	"🤳",
-	"💃",
-	"🕺",
-	"👯",
-	"👯‍♀️",
-	"👯‍♂️",
-	"🕴️",
-	"🧑‍🦽",
-	"👩‍🦽",
-	"👨‍🦽",
-	"🧑‍🦼",
-	"👩‍🦼",
-	"👨‍🦼",
-	"🚶",
-	"🚶‍♀️",
-	"🚶‍♂️",
-	"🧑‍🦯",
-	"👩‍🦯",
-	"👨‍🦯",
-	"🧎",
-	"🧎‍♀️",
-	"🧎‍♂️",
-	"🏃",
-	"🏃‍♀️",
-	"🏃‍♂️",
-	"🧍",
-	"🧍‍♀️",
-	"🧍‍♂️",
-	"🧑‍🤝‍🧑",
-	"👫",
-	"👭",
-	"👬",
-	"💑",
-	"👩‍❤️‍👨",
-	"👩‍❤️‍👩",
-	"👨‍❤️‍👨",
-	"💏",
-	"👩‍❤️‍💋‍👨",
-	"👩‍❤️‍💋‍👩",
-	"👨‍❤️‍💋‍👨",
-	"👪",
-	"👨‍👩‍👦",
-	"👨‍👩‍👧",
-	"👨‍👩‍👧‍👦",
-	"👨‍👩‍👦‍👦",
-	"👨‍👩‍👧‍👧",
-	"👩‍👩‍👦",
-	"👩‍👩‍👧",
-	"👩‍👩‍👧‍👦",
-	"👩‍👩‍👦‍👦",
-	"👩‍👩‍👧‍👧",
-	"👨‍👨‍👦",
-	"👨‍👨‍👧",
-	"👨‍👨‍👧‍👦",
-	"👨‍👨‍👦‍👦",
-	"👨‍👨‍👧‍👧",
-	"👩‍👦",
-	"👩‍👧",
-	"👩‍👧‍👦",
-	"👩‍👦‍👦",
-	"👩‍👧‍👧",
-	"👨‍👦",
-	"👨‍👧",
-	"👨‍👧‍👦",
-	"👨‍👦‍👦",
-	"👨‍👧‍👧",
-	"🪢",
-	"🧶",
-	"🧵",
-	"🪡",
-	"🧥",
-	"🥼",
-	"🦺",
-	"👚",
-	"👕",
-	"👖",
-	"🩲",
-	"🩳",
-	"👔",
-	"👗",
-	"👙",
-	"🩱",
-	"👘",
-	"🥻",
-	"🩴",
-	"🥿",
-	"👠",
-	"👡",
-	"👢",
-	"👞",
-	"👟",
-	"🥾",
-	"🧦",
-	"🧤",
-	"🧣",
-	"🎩",
-	"🧢",
-	"👒",
-	"🎓",
-	"⛑️",
-	"🪖",
-	"👑",
-	"💍",
-	"👝",
-	"👛",
-	"👜",
-	"💼",
-	"🎒",
-	"🧳",
-	"👓",
-	"🕶️",
-	"🥽",
-	"🌂",
+	emoji("😀", "grinning face", &["grinning_face", "grinning"], People, "face-smiling", (1, 0), ANY, Common),
+	emoji("😃", "grinning face with big eyes", &["grinning_face_with_big_eyes", "smiley"], People, "face-smiling", (0, 6), ANY, Common),
+	emoji("😄", "grinning face with smiling eyes", &["grinning_face_with_smiling_eyes", "smile"], People, "face-smiling", (0, 6), ANY, Common),
+	emoji("😁", "beaming face with smiling eyes", &["beaming_face_with_smiling_eyes", "grin"], People, "face-smiling", (0, 6), ANY, Common),
+	emoji("😆", "grinning squinting face", &["grinning_squinting_face", "laughing", "satisfied"], People, "face-smiling", (0, 6), ANY, Common),
+	emoji("🥹", "face holding back tears", &["face_holding_back_tears"], People, "face-concerned", (14, 0), ANY, Rare),
+	emoji("😅", "grinning face with sweat", &["grinning_face_with_sweat", "sweat_smile"], People, "face-smiling", (0, 6), ANY, Common),
+	emoji("😂", "face with tears of joy", &["face_with_tears_of_joy", "joy"], People, "face-smiling", (0, 6), ANY, Common),
+	emoji("🤣", "rolling on the floor laughing", &["rolling_on_the_floor_laughing", "rofl"], People, "face-smiling", (3, 0), ANY, Common),
+	emoji("🥲", "smiling face with tear", &["smiling_face_with_tear"], People, "face-affection", (13, 0), ANY, Rare),
+	emoji("☺️", "smiling face", &["smiling_face", "relaxed"], People, "face-affection", (0, 6), ANY, Common),
+	emoji("😊", "smiling face with smiling eyes", &["smiling_face_with_smiling_eyes", "blush"], People, "face-smiling", (0, 6), ANY, Common),
+	emoji("😇", "smiling face with halo", &["smiling_face_with_halo", "innocent"], People, "face-smiling", (1, 0), ANY, Common),
+	emoji("🙂", "slightly smiling face", &["slightly_smiling_face", "slight_smile"], People, "face-smiling", (1, 0), ANY, Common),
+	emoji("🙃", "upside-down face", &["upside_down_face", "upside_down"], People, "face-smiling", (1, 0), ANY, Common),
+	emoji("😉", "winking face", &["winking_face", "wink"], People, "face-smiling", (0, 6), ANY, Common),
+	emoji("😌", "relieved face", &["relieved_face", "relieved"], People, "face-sleepy", (0, 6), ANY, Common),
+	emoji("😍", "smiling face with heart-eyes", &["smiling_face_with_heart_eyes", "heart_eyes"], People, "face-affection", (0, 6), ANY, Common),
+	emoji("🥰", "smiling face with hearts", &["smiling_face_with_hearts", "smiling_face_with_3_hearts"], People, "face-affection", (11, 0), ANY, Uncommon),
+	emoji("😘", "face blowing a kiss", &["face_blowing_a_kiss", "kissing_heart"], People, "face-affection", (0, 6), ANY, Common),
+	emoji("😗", "kissing face", &["kissing_face", "kissing"], People, "face-affection", (1, 0), ANY, Common),
+	emoji("😙", "kissing face with smiling eyes", &["kissing_face_with_smiling_eyes", "kissing_smiling_eyes"], People, "face-affection", (1, 0), ANY, Common),
+	emoji("😚", "kissing face with closed eyes", &["kissing_face_with_closed_eyes", "kissing_closed_eyes"], People, "face-affection", (0, 6), ANY, Common),
+	emoji("😋", "face savoring food", &["face_savoring_food", "yum"], People, "face-tongue", (0, 6), ANY, Common),
+	emoji("😛", "face with tongue", &["face_with_tongue", "stuck_out_tongue"], People, "face-tongue", (1, 0), ANY, Common),
+	emoji("😝", "squinting face with tongue", &["squinting_face_with_tongue", "stuck_out_tongue_closed_eyes"], People, "face-tongue", (0, 6), ANY, Common),
+	emoji("😜", "winking face with tongue", &["winking_face_with_tongue", "stuck_out_tongue_winking_eye"], People, "face-tongue", (0, 6), ANY, Common),
+	emoji("🤪", "zany face", &["zany_face", "crazy_face"], People, "face-tongue", (5, 0), ANY, Common),
+	emoji("🤨", "face with raised eyebrow", &["face_with_raised_eyebrow", "raised_eyebrow"], People, "face-neutral-skeptical", (5, 0), ANY, Common),
+	emoji("🧐", "face with monocle", &["face_with_monocle", "monocle_face"], People, "face-glasses", (5, 0), ANY, Common),
+	emoji("🤓", "nerd face", &["nerd_face", "nerd"], People, "face-glasses", (1, 0), ANY, Common),
+	emoji("😎", "smiling face with sunglasses", &["smiling_face_with_sunglasses", "sunglasses_face"], People, "face-glasses", (1, 0), ANY, Common),
+	emoji("🥸", "disguised face", &["disguised_face"], People, "face-hat", (13, 0), ANY, Rare),
+	emoji("🤩", "star-struck", &["star_struck"], People, "face-affection", (5, 0), ANY, Common),
+	emoji("🥳", "partying face", &["partying_face", "partying"], People, "face-hat", (11, 0), ANY, Uncommon),
+	emoji("😏", "smirking face", &["smirking_face", "smirk"], People, "face-neutral-skeptical", (0, 6), ANY, Common),
+	emoji("😒", "unamused face", &["unamused_face", "unamused"], People, "face-neutral-skeptical", (0, 6), ANY, Common),
+	emoji("😞", "disappointed face", &["disappointed_face", "disappointed"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😔", "pensive face", &["pensive_face", "pensive"], People, "face-sleepy", (0, 6), ANY, Common),
+	emoji("😟", "worried face", &["worried_face", "worried"], People, "face-concerned", (1, 0), ANY, Common),
+	emoji("😕", "confused face", &["confused_face", "confused"], People, "face-concerned", (1, 0), ANY, Common),
+	emoji("🙁", "slightly frowning face", &["slightly_frowning_face", "slight_frown"], People, "face-concerned", (1, 0), ANY, Common),
+	emoji("☹️", "frowning face", &["frowning_face", "frowning2"], People, "face-concerned", (0, 7), ANY, Common),
+	emoji("😣", "persevering face", &["persevering_face", "persevere"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😖", "confounded face", &["confounded_face", "confounded"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😫", "tired face", &["tired_face"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😩", "weary face", &["weary_face", "weary"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("🥺", "pleading face", &["pleading_face", "pleading"], People, "face-concerned", (11, 0), ANY, Uncommon),
+	emoji("😢", "crying face", &["crying_face", "cry"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😭", "loudly crying face", &["loudly_crying_face", "sob"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😤", "face with steam from nose", &["face_with_steam_from_nose", "triumph"], People, "face-negative", (0, 6), ANY, Common),
+	emoji("😠", "angry face", &["angry_face", "angry"], People, "face-negative", (0, 6), ANY, Common),
+	emoji("😡", "enraged face", &["enraged_face", "rage", "pout"], People, "face-negative", (0, 6), ANY, Common),
+	emoji("🤬", "face with symbols on mouth", &["face_with_symbols_on_mouth", "cursing_face"], People, "face-negative", (5, 0), NO_MIRROR, Common),
+	emoji("🤯", "exploding head", &["exploding_head"], People, "face-unwell", (5, 0), ANY, Common),
+	emoji("😳", "flushed face", &["flushed_face", "flushed"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("🥵", "hot face", &["hot_face"], People, "face-unwell", (11, 0), ANY, Uncommon),
+	emoji("🥶", "cold face", &["cold_face"], People, "face-unwell", (11, 0), ANY, Uncommon),
+	emoji("😶‍🌫️", "face in clouds", &["face_in_clouds"], People, "face-neutral-skeptical", (13, 1), ANY, Rare),
+	emoji("😱", "face screaming in fear", &["face_screaming_in_fear", "scream"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😨", "fearful face", &["fearful_face", "fearful"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😰", "anxious face with sweat", &["anxious_face_with_sweat", "cold_sweat"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😥", "sad but relieved face", &["sad_but_relieved_face", "disappointed_relieved"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("😓", "downcast face with sweat", &["downcast_face_with_sweat", "sweat"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("🤗", "smiling face with open hands", &["smiling_face_with_open_hands", "hugging", "hugs"], People, "face-hand", (1, 0), ANY, Common),
+	emoji("🤔", "thinking face", &["thinking_face", "thinking"], People, "face-hand", (1, 0), ANY, Common),
+	emoji("🫣", "face with peeking eye", &["face_with_peeking_eye"], People, "face-hand", (14, 0), ANY, Rare),
+	emoji("🤭", "face with hand over mouth", &["face_with_hand_over_mouth", "hand_over_mouth"], People, "face-hand", (5, 0), ANY, Common),
+	emoji("🫢", "face with open eyes and hand over mouth", &["face_with_open_eyes_and_hand_over_mouth"], People, "face-hand", (14, 0), ANY, Rare),
+	emoji("🫡", "saluting face", &["saluting_face"], People, "face-hand", (14, 0), ANY, Rare),
+	emoji("🤫", "shushing face", &["shushing_face", "shushing"], People, "face-hand", (5, 0), ANY, Common),
+	emoji("🫠", "melting face", &["melting_face"], People, "face-smiling", (14, 0), ANY, Rare),
+	emoji("🤥", "lying face", &["lying_face"], People, "face-neutral-skeptical", (3, 0), ANY, Common),
+	emoji("😶", "face without mouth", &["face_without_mouth", "no_mouth"], People, "face-neutral-skeptical", (1, 0), ANY, Common),
+	emoji("🫥", "dotted line face", &["dotted_line_face"], People, "face-neutral-skeptical", (14, 0), ANY, Rare),
+	emoji("😐", "neutral face", &["neutral_face"], People, "face-neutral-skeptical", (1, 0), ANY, Common),
+	emoji("🫤", "face with diagonal mouth", &["face_with_diagonal_mouth"], People, "face-concerned", (14, 0), ANY, Rare),
+	emoji("😑", "expressionless face", &["expressionless_face"], People, "face-neutral-skeptical", (1, 0), ANY, Common),
+	emoji("😬", "grimacing face", &["grimacing_face", "grimacing"], People, "face-neutral-skeptical", (1, 0), ANY, Common),
+	emoji("🙄", "face with rolling eyes", &["face_with_rolling_eyes", "rolling_eyes"], People, "face-neutral-skeptical", (1, 0), ANY, Common),
+	emoji("😯", "hushed face", &["hushed_face", "hushed"], People, "face-concerned", (1, 0), ANY, Common),
+	emoji("😦", "frowning face with open mouth", &["frowning_face_with_open_mouth", "frowning"], People, "face-concerned", (1, 0), ANY, Common),
+	emoji("😧", "anguished face", &["anguished_face", "anguished"], People, "face-concerned", (1, 0), ANY, Common),
+	emoji("😮", "face with open mouth", &["face_with_open_mouth", "open_mouth"], People, "face-concerned", (1, 0), ANY, Common),
+	emoji("😲", "astonished face", &["astonished_face", "astonished"], People, "face-concerned", (0, 6), ANY, Common),
+	emoji("🥱", "yawning face", &["yawning_face", "yawning"], People, "face-concerned", (12, 0), ANY, Uncommon),
+	emoji("😴", "sleeping face", &["sleeping_face", "sleeping"], People, "face-sleepy", (1, 0), ANY, Common),
+	emoji("🤤", "drooling face", &["drooling_face"], People, "face-sleepy", (3, 0), ANY, Common),
+	emoji("😪", "sleepy face", &["sleepy_face", "sleepy"], People, "face-sleepy", (0, 6), ANY, Common),
+	emoji("😮‍💨", "face exhaling", &["face_exhaling"], People, "face-neutral-skeptical", (13, 1), ANY, Rare),
+	emoji("😵", "face with crossed-out eyes", &["face_with_crossed_out_eyes", "dizzy_face"], People, "face-unwell", (0, 6), ANY, Common),
+	emoji("😵‍💫", "face with spiral eyes", &["face_with_spiral_eyes"], People, "face-unwell", (13, 1), ANY, Rare),
+	emoji("🤐", "zipper-mouth face", &["zipper_mouth_face", "zipper_mouth"], People, "face-neutral-skeptical", (1, 0), ANY, Common),
+	emoji("🥴", "woozy face", &["woozy_face", "woozy"], People, "face-unwell", (11, 0), ANY, Uncommon),
+	emoji("🤢", "nauseated face", &["nauseated_face"], People, "face-unwell", (3, 0), ANY, Common),
+	emoji("🤮", "face vomiting", &["face_vomiting", "vomiting_face"], People, "face-unwell", (5, 0), ANY, Common),
+	emoji("🤧", "sneezing face", &["sneezing_face"], People, "face-unwell", (3, 0), ANY, Common),
+	emoji("😷", "face with medical mask", &["face_with_medical_mask", "mask"], People, "face-unwell", (0, 6), ANY, Common),
+	emoji("🤒", "face with thermometer", &["face_with_thermometer", "thermometer_face"], People, "face-unwell", (1, 0), ANY, Common),
+	emoji("🤕", "face with head-bandage", &["face_with_head_bandage", "head_bandage"], People, "face-unwell", (1, 0), ANY, Common),
+	emoji("🤑", "money-mouth face", &["money_mouth_face", "money_mouth"], People, "face-tongue", (1, 0), NO_MIRROR, Common),
+	emoji("🤠", "cowboy hat face", &["cowboy_hat_face", "cowboy"], People, "face-hat", (3, 0), ANY, Common),
+	emoji("😈", "smiling face with horns", &["smiling_face_with_horns", "smiling_imp"], People, "face-negative", (1, 0), ANY, Common),
+	emoji("👿", "angry face with horns", &["angry_face_with_horns", "imp"], People, "face-negative", (0, 6), ANY, Common),
+	emoji("👹", "ogre", &["ogre", "japanese_ogre"], People, "face-costume", (0, 6), ANY, Common),
+	emoji("👺", "goblin", &["goblin", "japanese_goblin"], People, "face-costume", (0, 6), ANY, Common),
+	emoji("🤡", "clown face", &["clown_face", "clown"], People, "face-costume", (3, 0), ANY, Common),
+	emoji("💩", "pile of poo", &["pile_of_poo", "poop", "hankey"], People, "face-costume", (0, 6), ANY, Common),
+	emoji("👻", "ghost", &["ghost"], People, "face-costume", (0, 6), ANY, Common),
+	emoji("💀", "skull", &["skull"], People, "face-negative", (0, 6), ANY, Common),
+	emoji("☠️", "skull and crossbones", &["skull_and_crossbones", "skull_crossbones"], People, "face-negative", (1, 0), ANY, Common),
+	emoji("👽", "alien", &["alien"], People, "face-costume", (0, 6), ANY, Legendary),
+	emoji("👾", "alien monster", &["alien_monster", "space_invader"], People, "face-costume", (0, 6), ANY, Common),
+	emoji("🤖", "robot", &["robot"], People, "face-costume", (1, 0), ANY, Common),
+	emoji("🎃", "jack-o-lantern", &["jack_o_lantern"], People, "event", (0, 6), ANY, Common),
+	emoji("😺", "grinning cat", &["grinning_cat", "smiley_cat"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("😸", "grinning cat with smiling eyes", &["grinning_cat_with_smiling_eyes", "smile_cat"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("😹", "cat with tears of joy", &["cat_with_tears_of_joy", "joy_cat"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("😻", "smiling cat with heart-eyes", &["smiling_cat_with_heart_eyes", "heart_eyes_cat"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("😼", "cat with wry smile", &["cat_with_wry_smile", "smirk_cat"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("😽", "kissing cat", &["kissing_cat"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("🙀", "weary cat", &["weary_cat", "scream_cat"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("😿", "crying cat", &["crying_cat", "crying_cat_face"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("😾", "pouting cat", &["pouting_cat"], People, "cat-face", (0, 6), ANY, Common),
+	emoji("🫶", "heart hands", &["heart_hands"], People, "hands", (14, 0), ANY, Rare),
+	emoji("🤲", "palms up together", &["palms_up_together"], People, "hands", (5, 0), ANY, Common),
+	emoji("👐", "open hands", &["open_hands"], People, "hands", (0, 6), ANY, Common),
+	emoji("🙌", "raising hands", &["raising_hands", "raised_hands"], People, "hands", (0, 6), ANY, Common),
+	emoji("👏", "clapping hands", &["clapping_hands", "clap"], People, "hands", (0, 6), ANY, Common),
+	emoji("🤝", "handshake", &["handshake"], People, "hands", (3, 0), ANY, Common),
+	emoji("👍", "thumbs up", &["thumbs_up", "thumbsup", "+1"], People, "hand-fingers-closed", (0, 6), NO_ROTATE, Common),
+	emoji("👎", "thumbs down", &["thumbs_down", "thumbsdown", "-1"], People, "hand-fingers-closed", (0, 6), NO_ROTATE, Common),
+	emoji("👊", "oncoming fist", &["oncoming_fist", "punch"], People, "hand-fingers-closed", (0, 6), ANY, Common),
+	emoji("✊", "raised fist", &["raised_fist", "fist"], People, "hand-fingers-closed", (0, 6), ANY, Common),
+	emoji("🤛", "left-facing fist", &["left_facing_fist"], People, "hand-fingers-closed", (3, 0), FIXED, Common),
+	emoji("🤜", "right-facing fist", &["right_facing_fist"], People, "hand-fingers-closed", (3, 0), FIXED, Common),
+	emoji("🤞", "crossed fingers", &["crossed_fingers", "fingers_crossed"], People, "hand-fingers-partial", (3, 0), ANY, Common),
+	emoji("✌️", "victory hand", &["victory_hand", "v"], People, "hand-fingers-partial", (0, 6), ANY, Common),
+	emoji("🫰", "hand with index finger and thumb crossed", &["hand_with_index_finger_and_thumb_crossed"], People, "hand-fingers-partial", (14, 0), ANY, Rare),
+	emoji("🤟", "love-you gesture", &["love_you_gesture", "love_you"], People, "hand-fingers-partial", (5, 0), ANY, Common),
+	emoji("🤘", "sign of the horns", &["sign_of_the_horns", "metal"], People, "hand-fingers-partial", (1, 0), ANY, Common),
+	emoji("👌", "OK hand", &["ok_hand"], People, "hand-fingers-partial", (0, 6), ANY, Common),
+	emoji("🤌", "pinched fingers", &["pinched_fingers"], People, "hand-fingers-partial", (13, 0), ANY, Rare),
+	emoji("🤏", "pinching hand", &["pinching_hand"], People, "hand-fingers-partial", (12, 0), ANY, Uncommon),
+	emoji("🫳", "palm down hand", &["palm_down_hand"], People, "hand-fingers-open", (14, 0), ANY, Rare),
+	emoji("🫴", "palm up hand", &["palm_up_hand"], People, "hand-fingers-open", (14, 0), ANY, Rare),
+	emoji("👈", "backhand index pointing left", &["backhand_index_pointing_left", "point_left"], People, "hand-single-finger", (0, 6), FIXED, Common),
+	emoji("👉", "backhand index pointing right", &["backhand_index_pointing_right", "point_right"], People, "hand-single-finger", (0, 6), FIXED, Common),
+	emoji("👆", "backhand index pointing up", &["backhand_index_pointing_up", "point_up_2"], People, "hand-single-finger", (0, 6), NO_ROTATE, Common),
+	emoji("👇", "backhand index pointing down", &["backhand_index_pointing_down", "point_down"], People, "hand-single-finger", (0, 6), NO_ROTATE, Common),
+	emoji("☝️", "index pointing up", &["index_pointing_up", "point_up"], People, "hand-single-finger", (0, 6), NO_ROTATE, Common),
+	emoji("✋", "raised hand", &["raised_hand", "hand"], People, "hand-fingers-open", (0, 6), ANY, Common),
+	emoji("🤚", "raised back of hand", &["raised_back_of_hand"], People, "hand-fingers-open", (3, 0), ANY, Common),
+	emoji("🖐️", "hand with fingers splayed", &["hand_with_fingers_splayed"], People, "hand-fingers-open", (0, 7), ANY, Common),
+	emoji("🖖", "vulcan salute", &["vulcan_salute", "vulcan"], People, "hand-fingers-open", (1, 0), ANY, Common),
+	emoji("👋", "waving hand", &["waving_hand", "wave"], People, "hand-fingers-open", (0, 6), ANY, Common),
+	emoji("🤙", "call me hand", &["call_me_hand", "call_me"], People, "hand-fingers-partial", (3, 0), ANY, Common),
+	emoji("🫲", "leftwards hand", &["leftwards_hand"], People, "hand-fingers-open", (14, 0), NO_MIRROR, Rare),
+	emoji("🫱", "rightwards hand", &["rightwards_hand"], People, "hand-fingers-open", (14, 0), NO_MIRROR, Rare),
+	emoji("💪", "flexed biceps", &["flexed_biceps", "muscle"], People, "body-parts", (0, 6), ANY, Common),
+	emoji("🦾", "mechanical arm", &["mechanical_arm"], People, "body-parts", (12, 0), ANY, Uncommon),
+	emoji("🖕", "middle finger", &["middle_finger", "fu"], People, "hand-single-finger", (1, 0), ANY, Common),
+	emoji("✍️", "writing hand", &["writing_hand"], People, "hand-prop", (0, 7), ANY, Common),
+	emoji("🙏", "folded hands", &["folded_hands", "pray"], People, "hands", (0, 6), ANY, Common),
+	emoji("🫵", "index pointing at the viewer", &["index_pointing_at_the_viewer"], People, "hand-single-finger", (14, 0), ANY, Rare),
+	emoji("🦶", "foot", &["foot"], People, "body-parts", (11, 0), ANY, Uncommon),
+	emoji("🦵", "leg", &["leg"], People, "body-parts", (11, 0), ANY, Uncommon),
+	emoji("🦿", "mechanical leg", &["mechanical_leg"], People, "body-parts", (12, 0), ANY, Uncommon),
+	emoji("💄", "lipstick", &["lipstick"], People, "clothing", (0, 6), ANY, Common),
+	emoji("💋", "kiss mark", &["kiss_mark"], People, "emotion", (0, 6), ANY, Common),
+	emoji("👄", "mouth", &["mouth", "lips"], People, "body-parts", (0, 6), ANY, Common),
+	emoji("🫦", "biting lip", &["biting_lip"], People, "body-parts", (14, 0), ANY, Rare),
+	emoji("🦷", "tooth", &["tooth"], People, "body-parts", (11, 0), ANY, Uncommon),
+	emoji("👅", "tongue", &["tongue"], People, "body-parts", (0, 6), ANY, Common),
+	emoji("👂", "ear", &["ear"], People, "body-parts", (0, 6), ANY, Common),
+	emoji("🦻", "ear with hearing aid", &["ear_with_hearing_aid"], People, "body-parts", (12, 0), ANY, Uncommon),
+	emoji("👃", "nose", &["nose"], People, "body-parts", (0, 6), ANY, Common),
+	emoji("👣", "footprints", &["footprints"], People, "person-symbol", (0, 6), ANY, Common),
+	emoji("👁️", "eye", &["eye"], People, "body-parts", (0, 7), ANY, Common),
+	emoji("👀", "eyes", &["eyes"], People, "body-parts", (0, 6), ANY, Common),
+	emoji("🫀", "anatomical heart", &["anatomical_heart"], People, "body-parts", (13, 0), ANY, Rare),
+	emoji("🫁", "lungs", &["lungs"], People, "body-parts", (13, 0), ANY, Rare),
+	emoji("🧠", "brain", &["brain"], People, "body-parts", (5, 0), ANY, Common),
+	emoji("🗣️", "speaking head", &["speaking_head"], People, "person-symbol", (0, 7), ANY, Common),
+	emoji("👤", "bust in silhouette", &["bust_in_silhouette"], People, "person-symbol", (0, 6), ANY, Common),
+	emoji("👥", "busts in silhouette", &["busts_in_silhouette"], People, "person-symbol", (1, 0), ANY, Common),
+	emoji("🫂", "people hugging", &["people_hugging"], People, "person-symbol", (13, 0), ANY, Rare),
+	emoji("👶", "baby", &["baby"], People, "person", (0, 6), ANY, Common),
+	emoji("🧒", "child", &["child"], People, "person", (5, 0), ANY, Common),
+	emoji("👧", "girl", &["girl"], People, "person", (0, 6), ANY, Common),
+	emoji("👦", "boy", &["boy"], People, "person", (0, 6), ANY, Common),
+	emoji("🧑", "person", &["person", "adult"], People, "person", (5, 0), ANY, Common),
+	emoji("👩", "woman", &["woman"], People, "person", (0, 6), ANY, Common),
+	emoji("👨", "man", &["man"], People, "person", (0, 6), ANY, Common),
+	emoji("🧑‍🦱", "person: curly hair", &["person_curly_hair"], People, "person", (12, 1), ANY, Uncommon),
+	emoji("👩‍🦱", "woman: curly hair", &["woman_curly_hair"], People, "person", (11, 0), ANY, Uncommon),
+	emoji("👨‍🦱", "man: curly hair", &["man_curly_hair"], People, "person", (11, 0), ANY, Uncommon),
+	emoji("🧑‍🦰", "person: red hair", &["person_red_hair"], People, "person", (12, 1), ANY, Uncommon),
+	emoji("👩‍🦰", "woman: red hair", &["woman_red_hair"], People, "person", (11, 0), ANY, Uncommon),
+	emoji("👨‍🦰", "man: red hair", &["man_red_hair"], People, "person", (11, 0), ANY, Uncommon),
+	emoji("👱", "person: blond hair", &["person_blond_hair"], People, "person", (0, 6), ANY, Common),
+	emoji("👱‍♀️", "woman: blond hair", &["woman_blond_hair"], People, "person", (4, 0), ANY, Common),
+	emoji("👱‍♂️", "man: blond hair", &["man_blond_hair"], People, "person", (4, 0), ANY, Common),
+	emoji("🧑‍🦳", "person: white hair", &["person_white_hair"], People, "person", (12, 1), ANY, Uncommon),
+	emoji("👩‍🦳", "woman: white hair", &["woman_white_hair"], People, "person", (11, 0), ANY, Uncommon),
+	emoji("👨‍🦳", "man: white hair", &["man_white_hair"], People, "person", (11, 0), ANY, Uncommon),
+	emoji("🧑‍🦲", "person: bald", &["person_bald"], People, "person", (12, 1), ANY, Uncommon),
+	emoji("👩‍🦲", "woman: bald", &["woman_bald"], People, "person", (11, 0), ANY, Uncommon),
+	emoji("👨‍🦲", "man: bald", &["man_bald"], People, "person", (11, 0), ANY, Uncommon),
+	emoji("🧔", "person: beard", &["person_beard"], People, "person", (5, 0), ANY, Common),
+	emoji("🧔‍♀️", "woman: beard", &["woman_beard"], People, "person", (13, 1), ANY, Rare),
+	emoji("🧔‍♂️", "man: beard", &["man_beard"], People, "person", (13, 1), ANY, Rare),
+	emoji("🧓", "older person", &["older_person", "older_adult"], People, "person", (5, 0), ANY, Common),
+	emoji("👵", "older woman", &["older_woman"], People, "person", (0, 6), ANY, Common),
+	emoji("👴", "older man", &["older_man"], People, "person", (0, 6), ANY, Common),
+	emoji("👲", "person with skullcap", &["person_with_skullcap"], People, "person-role", (0, 6), ANY, Common),
+	emoji("👳", "person wearing turban", &["person_wearing_turban"], People, "person-role", (0, 6), ANY, Common),
+	emoji("👳‍♀️", "woman wearing turban", &["woman_wearing_turban"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👳‍♂️", "man wearing turban", &["man_wearing_turban"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧕", "woman with headscarf", &["woman_with_headscarf"], People, "person-role", (5, 0), ANY, Common),
+	emoji("👮", "police officer", &["police_officer"], People, "person-role", (0, 6), ANY, Common),
+	emoji("👮‍♀️", "woman police officer", &["woman_police_officer"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👮‍♂️", "man police officer", &["man_police_officer"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👷", "construction worker", &["construction_worker"], People, "person-role", (0, 6), ANY, Common),
+	emoji("👷‍♀️", "woman construction worker", &["woman_construction_worker"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👷‍♂️", "man construction worker", &["man_construction_worker"], People, "person-role", (4, 0), ANY, Common),
+	emoji("💂", "guard", &["guard"], People, "person-role", (0, 6), ANY, Common),
+	emoji("💂‍♀️", "woman guard", &["woman_guard"], People, "person-role", (4, 0), ANY, Common),
+	emoji("💂‍♂️", "man guard", &["man_guard"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🕵️", "detective", &["detective"], People, "person-role", (0, 7), ANY, Common),
+	emoji("🕵️‍♀️", "woman detective", &["woman_detective"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🕵️‍♂️", "man detective", &["man_detective"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍⚕️", "health worker", &["health_worker"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍⚕️", "woman health worker", &["woman_health_worker"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍⚕️", "man health worker", &["man_health_worker"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🌾", "farmer", &["farmer"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🌾", "woman farmer", &["woman_farmer"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🌾", "man farmer", &["man_farmer"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🍳", "cook", &["cook"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🍳", "woman cook", &["woman_cook"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🍳", "man cook", &["man_cook"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🎓", "student", &["student"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🎓", "woman student", &["woman_student"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🎓", "man student", &["man_student"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🎤", "singer", &["singer"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🎤", "woman singer", &["woman_singer"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🎤", "man singer", &["man_singer"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🏫", "teacher", &["teacher"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🏫", "woman teacher", &["woman_teacher"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🏫", "man teacher", &["man_teacher"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🏭", "factory worker", &["factory_worker"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🏭", "woman factory worker", &["woman_factory_worker"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🏭", "man factory worker", &["man_factory_worker"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍💻", "technologist", &["technologist"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍💻", "woman technologist", &["woman_technologist"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍💻", "man technologist", &["man_technologist"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍💼", "office worker", &["office_worker"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍💼", "woman office worker", &["woman_office_worker"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍💼", "man office worker", &["man_office_worker"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🔧", "mechanic", &["mechanic"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🔧", "woman mechanic", &["woman_mechanic"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🔧", "man mechanic", &["man_mechanic"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🔬", "scientist", &["scientist"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🔬", "woman scientist", &["woman_scientist"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🔬", "man scientist", &["man_scientist"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🎨", "artist", &["artist"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🎨", "woman artist", &["woman_artist"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🎨", "man artist", &["man_artist"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🚒", "firefighter", &["firefighter"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🚒", "woman firefighter", &["woman_firefighter"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🚒", "man firefighter", &["man_firefighter"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍✈️", "pilot", &["pilot"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍✈️", "woman pilot", &["woman_pilot"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍✈️", "man pilot", &["man_pilot"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍🚀", "astronaut", &["astronaut"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍🚀", "woman astronaut", &["woman_astronaut"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍🚀", "man astronaut", &["man_astronaut"], People, "person-role", (4, 0), ANY, Common),
+	emoji("🧑‍⚖️", "judge", &["judge"], People, "person-role", (12, 1), ANY, Uncommon),
+	emoji("👩‍⚖️", "woman judge", &["woman_judge"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👨‍⚖️", "man judge", &["man_judge"], People, "person-role", (4, 0), ANY, Common),
+	emoji("👰", "person with veil", &["person_with_veil"], People, "person-role", (0, 6), ANY, Common),
+	emoji("👰‍♀️", "woman with veil", &["woman_with_veil"], People, "person-role", (13, 0), ANY, Rare),
+	emoji("👰‍♂️", "man with veil", &["man_with_veil"], People, "person-role", (13, 0), ANY, Rare),
+	emoji("🤵", "person in tuxedo", &["person_in_tuxedo"], People, "person-role", (3, 0), ANY, Common),
+	emoji("🤵‍♀️", "woman in tuxedo", &["woman_in_tuxedo"], People, "person-role", (13, 0), ANY, Rare),
+	emoji("🤵‍♂️", "man in tuxedo", &["man_in_tuxedo"], People, "person-role", (13, 0), ANY, Rare),
+	emoji("🫅", "person with crown", &["person_with_crown"], People, "person-role", (14, 0), ANY, Rare),
+	emoji("👸", "princess", &["princess"], People, "person-role", (0, 6), ANY, Common),
+	emoji("🤴", "prince", &["prince"], People, "person-role", (3, 0), ANY, Common),
+	emoji("🦸", "superhero", &["superhero"], People, "person-fantasy", (11, 0), ANY, Uncommon),
+	emoji("🦸‍♀️", "woman superhero", &["woman_superhero"], People, "person-fantasy", (11, 0), ANY, Uncommon),
+	emoji("🦸‍♂️", "man superhero", &["man_superhero"], People, "person-fantasy", (11, 0), ANY, Uncommon),
+	emoji("🦹", "supervillain", &["supervillain"], People, "person-fantasy", (11, 0), ANY, Uncommon),
+	emoji("🦹‍♀️", "woman supervillain", &["woman_supervillain"], People, "person-fantasy", (11, 0), ANY, Uncommon),
+	emoji("🦹‍♂️", "man supervillain", &["man_supervillain"], People, "person-fantasy", (11, 0), ANY, Uncommon),
+	emoji("🥷", "ninja", &["ninja"], People, "person-role", (13, 0), ANY, Rare),
+	emoji("🧑‍🎄", "Mx Claus", &["mx_claus"], People, "person-fantasy", (13, 0), ANY, Rare),
+	emoji("🤶", "Mrs. Claus", &["mrs_claus"], People, "person-fantasy", (3, 0), ANY, Common),
+	emoji("🎅", "Santa Claus", &["santa_claus", "santa"], People, "person-fantasy", (0, 6), ANY, Common),
+	emoji("🧙", "mage", &["mage"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧙‍♀️", "woman mage", &["woman_mage"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧙‍♂️", "man mage", &["man_mage"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧝", "elf", &["elf"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧝‍♀️", "woman elf", &["woman_elf"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧝‍♂️", "man elf", &["man_elf"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧌", "troll", &["troll"], People, "person-fantasy", (14, 0), ANY, Rare),
+	emoji("🧛", "vampire", &["vampire"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧛‍♀️", "woman vampire", &["woman_vampire"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧛‍♂️", "man vampire", &["man_vampire"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧟", "zombie", &["zombie"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧟‍♀️", "woman zombie", &["woman_zombie"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧟‍♂️", "man zombie", &["man_zombie"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧞", "genie", &["genie"], People, "person-fantasy", (5, 0), ANY, Legendary),
+	emoji("🧞‍♀️", "woman genie", &["woman_genie"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧞‍♂️", "man genie", &["man_genie"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧜", "merperson", &["merperson"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧜‍♀️", "mermaid", &["mermaid"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧜‍♂️", "merman", &["merman"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧚", "fairy", &["fairy"], People, "person-fantasy", (5, 0), ANY, Legendary),
+	emoji("🧚‍♀️", "woman fairy", &["woman_fairy"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("🧚‍♂️", "man fairy", &["man_fairy"], People, "person-fantasy", (5, 0), ANY, Common),
+	emoji("👼", "baby angel", &["baby_angel"], People, "person-fantasy", (0, 6), ANY, Common),
+	emoji("🫄", "pregnant person", &["pregnant_person"], People, "person-role", (14, 0), ANY, Rare),
+	emoji("🤰", "pregnant woman", &["pregnant_woman"], People, "person-role", (3, 0), ANY, Common),
+	emoji("🫃", "pregnant man", &["pregnant_man"], People, "person-role", (14, 0), ANY, Rare),
+	emoji("🤱", "breast-feeding", &["breast_feeding"], People, "person-role", (5, 0), ANY, Common),
+	emoji("🧑‍🍼", "person feeding baby", &["person_feeding_baby"], People, "person-role", (13, 0), ANY, Rare),
+	emoji("👩‍🍼", "woman feeding baby", &["woman_feeding_baby"], People, "person-role", (13, 0), ANY, Rare),
+	emoji("👨‍🍼", "man feeding baby", &["man_feeding_baby"], People, "person-role", (13, 0), ANY, Rare),
+	emoji("🙇", "person bowing", &["person_bowing", "bow"], People, "person-gesture", (0, 6), ANY, Common),
+	emoji("🙇‍♀️", "woman bowing", &["woman_bowing"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙇‍♂️", "man bowing", &["man_bowing"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("💁", "person tipping hand", &["person_tipping_hand"], People, "person-gesture", (0, 6), ANY, Common),
+	emoji("💁‍♀️", "woman tipping hand", &["woman_tipping_hand"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("💁‍♂️", "man tipping hand", &["man_tipping_hand"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙅", "person gesturing NO", &["person_gesturing_no"], People, "person-gesture", (0, 6), ANY, Common),
+	emoji("🙅‍♀️", "woman gesturing NO", &["woman_gesturing_no"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙅‍♂️", "man gesturing NO", &["man_gesturing_no"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙆", "person gesturing OK", &["person_gesturing_ok"], People, "person-gesture", (0, 6), ANY, Common),
+	emoji("🙆‍♀️", "woman gesturing OK", &["woman_gesturing_ok"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙆‍♂️", "man gesturing OK", &["man_gesturing_ok"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙋", "person raising hand", &["person_raising_hand"], People, "person-gesture", (0, 6), ANY, Common),
+	emoji("🙋‍♀️", "woman raising hand", &["woman_raising_hand"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙋‍♂️", "man raising hand", &["man_raising_hand"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🧏", "deaf person", &["deaf_person"], People, "person-gesture", (12, 0), ANY, Uncommon),
+	emoji("🧏‍♀️", "deaf woman", &["deaf_woman"], People, "person-gesture", (12, 0), ANY, Uncommon),
+	emoji("🧏‍♂️", "deaf man", &["deaf_man"], People, "person-gesture", (12, 0), ANY, Uncommon),
+	emoji("🤦", "person facepalming", &["person_facepalming", "facepalm"], People, "person-gesture", (3, 0), ANY, Common),
+	emoji("🤦‍♀️", "woman facepalming", &["woman_facepalming"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🤦‍♂️", "man facepalming", &["man_facepalming"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🤷", "person shrugging", &["person_shrugging", "shrug"], People, "person-gesture", (3, 0), ANY, Common),
+	emoji("🤷‍♀️", "woman shrugging", &["woman_shrugging"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🤷‍♂️", "man shrugging", &["man_shrugging"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙎", "person pouting", &["person_pouting"], People, "person-gesture", (0, 6), ANY, Common),
+	emoji("🙎‍♀️", "woman pouting", &["woman_pouting"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙎‍♂️", "man pouting", &["man_pouting"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙍", "person frowning", &["person_frowning"], People, "person-gesture", (0, 6), ANY, Common),
+	emoji("🙍‍♀️", "woman frowning", &["woman_frowning"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("🙍‍♂️", "man frowning", &["man_frowning"], People, "person-gesture", (4, 0), ANY, Common),
+	emoji("💇", "person getting haircut", &["person_getting_haircut"], People, "person-activity", (0, 6), ANY, Common),
+	emoji("💇‍♀️", "woman getting haircut", &["woman_getting_haircut"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("💇‍♂️", "man getting haircut", &["man_getting_haircut"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("💆", "person getting massage", &["person_getting_massage"], People, "person-activity", (0, 6), ANY, Common),
+	emoji("💆‍♀️", "woman getting massage", &["woman_getting_massage"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("💆‍♂️", "man getting massage", &["man_getting_massage"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("🧖", "person in steamy room", &["person_in_steamy_room"], People, "person-activity", (5, 0), ANY, Common),
+	emoji("🧖‍♀️", "woman in steamy room", &["woman_in_steamy_room"], People, "person-activity", (5, 0), ANY, Common),
+	emoji("🧖‍♂️", "man in steamy room", &["man_in_steamy_room"], People, "person-activity", (5, 0), ANY, Common),
+	emoji("💅", "nail polish", &["nail_polish", "nail_care"], People, "hand-prop", (0, 6), ANY, Common),
+	emoji("🤳", "selfie", &["selfie"], People, "hand-prop", (3, 0), ANY, Common),
+	emoji("💃", "woman dancing", &["woman_dancing", "dancer"], People, "person-activity", (0, 6), ANY, Common),
+	emoji("🕺", "man dancing", &["man_dancing"], People, "person-activity", (3, 0), ANY, Common),
+	emoji("👯", "people with bunny ears", &["people_with_bunny_ears"], People, "person-activity", (0, 6), ANY, Common),
+	emoji("👯‍♀️", "women with bunny ears", &["women_with_bunny_ears"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("👯‍♂️", "men with bunny ears", &["men_with_bunny_ears"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("🕴️", "person in suit levitating", &["person_in_suit_levitating"], People, "person-activity", (0, 7), ANY, Common),
+	emoji("🧑‍🦽", "person in manual wheelchair", &["person_in_manual_wheelchair"], People, "person-activity", (12, 1), ANY, Uncommon),
+	emoji("👩‍🦽", "woman in manual wheelchair", &["woman_in_manual_wheelchair"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("👨‍🦽", "man in manual wheelchair", &["man_in_manual_wheelchair"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🧑‍🦼", "person in motorized wheelchair", &["person_in_motorized_wheelchair"], People, "person-activity", (12, 1), ANY, Uncommon),
+	emoji("👩‍🦼", "woman in motorized wheelchair", &["woman_in_motorized_wheelchair"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("👨‍🦼", "man in motorized wheelchair", &["man_in_motorized_wheelchair"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🚶", "person walking", &["person_walking"], People, "person-activity", (0, 6), ANY, Common),
+	emoji("🚶‍♀️", "woman walking", &["woman_walking"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("🚶‍♂️", "man walking", &["man_walking"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("🧑‍🦯", "person with white cane", &["person_with_white_cane"], People, "person-activity", (12, 1), ANY, Uncommon),
+	emoji("👩‍🦯", "woman with white cane", &["woman_with_white_cane"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("👨‍🦯", "man with white cane", &["man_with_white_cane"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🧎", "person kneeling", &["person_kneeling"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🧎‍♀️", "woman kneeling", &["woman_kneeling"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🧎‍♂️", "man kneeling", &["man_kneeling"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🏃", "person running", &["person_running"], People, "person-activity", (0, 6), ANY, Common),
+	emoji("🏃‍♀️", "woman running", &["woman_running"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("🏃‍♂️", "man running", &["man_running"], People, "person-activity", (4, 0), ANY, Common),
+	emoji("🧍", "person standing", &["person_standing"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🧍‍♀️", "woman standing", &["woman_standing"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🧍‍♂️", "man standing", &["man_standing"], People, "person-activity", (12, 0), ANY, Uncommon),
+	emoji("🧑‍🤝‍🧑", "people holding hands", &["people_holding_hands"], People, "family", (12, 0), ANY, Uncommon),
+	emoji("👫", "woman and man holding hands", &["woman_and_man_holding_hands"], People, "family", (0, 6), ANY, Common),
+	emoji("👭", "women holding hands", &["women_holding_hands"], People, "family", (1, 0), ANY, Common),
+	emoji("👬", "men holding hands", &["men_holding_hands"], People, "family", (1, 0), ANY, Common),
+	emoji("💑", "couple with heart", &["couple_with_heart"], People, "family", (0, 6), ANY, Common),
+	emoji("👩‍❤️‍👨", "couple with heart: woman, man", &["couple_with_heart_woman_man"], People, "family", (2, 0), ANY, Common),
+	emoji("👩‍❤️‍👩", "couple with heart: woman, woman", &["couple_with_heart_woman_woman"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍❤️‍👨", "couple with heart: man, man", &["couple_with_heart_man_man"], People, "family", (2, 0), ANY, Common),
+	emoji("💏", "kiss", &["kiss"], People, "family", (0, 6), ANY, Common),
+	emoji("👩‍❤️‍💋‍👨", "kiss: woman, man", &["kiss_woman_man"], People, "family", (2, 0), ANY, Common),
+	emoji("👩‍❤️‍💋‍👩", "kiss: woman, woman", &["kiss_woman_woman"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍❤️‍💋‍👨", "kiss: man, man", &["kiss_man_man"], People, "family", (2, 0), ANY, Common),
+	emoji("👪", "family", &["family"], People, "family", (0, 6), ANY, Common),
+	emoji("👨‍👩‍👦", "family: man, woman, boy", &["family_man_woman_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👩‍👧", "family: man, woman, girl", &["family_man_woman_girl"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👩‍👧‍👦", "family: man, woman, girl, boy", &["family_man_woman_girl_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👩‍👦‍👦", "family: man, woman, boy, boy", &["family_man_woman_boy_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👩‍👧‍👧", "family: man, woman, girl, girl", &["family_man_woman_girl_girl"], People, "family", (2, 0), ANY, Common),
+	emoji("👩‍👩‍👦", "family: woman, woman, boy", &["family_woman_woman_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👩‍👩‍👧", "family: woman, woman, girl", &["family_woman_woman_girl"], People, "family", (2, 0), ANY, Common),
+	emoji("👩‍👩‍👧‍👦", "family: woman, woman, girl, boy", &["family_woman_woman_girl_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👩‍👩‍👦‍👦", "family: woman, woman, boy, boy", &["family_woman_woman_boy_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👩‍👩‍👧‍👧", "family: woman, woman, girl, girl", &["family_woman_woman_girl_girl"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👨‍👦", "family: man, man, boy", &["family_man_man_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👨‍👧", "family: man, man, girl", &["family_man_man_girl"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👨‍👧‍👦", "family: man, man, girl, boy", &["family_man_man_girl_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👨‍👦‍👦", "family: man, man, boy, boy", &["family_man_man_boy_boy"], People, "family", (2, 0), ANY, Common),
+	emoji("👨‍👨‍👧‍👧", "family: man, man, girl, girl", &["family_man_man_girl_girl"], People, "family", (2, 0), ANY, Common),
+	emoji("👩‍👦", "family: woman, boy", &["family_woman_boy"], People, "family", (4, 0), ANY, Common),
+	emoji("👩‍👧", "family: woman, girl", &["family_woman_girl"], People, "family", (4, 0), ANY, Common),
+	emoji("👩‍👧‍👦", "family: woman, girl, boy", &["family_woman_girl_boy"], People, "family", (4, 0), ANY, Common),
+	emoji("👩‍👦‍👦", "family: woman, boy, boy", &["family_woman_boy_boy"], People, "family", (4, 0), ANY, Common),
+	emoji("👩‍👧‍👧", "family: woman, girl, girl", &["family_woman_girl_girl"], People, "family", (4, 0), ANY, Common),
+	emoji("👨‍👦", "family: man, boy", &["family_man_boy"], People, "family", (4, 0), ANY, Common),
+	emoji("👨‍👧", "family: man, girl", &["family_man_girl"], People, "family", (4, 0), ANY, Common),
+	emoji("👨‍👧‍👦", "family: man, girl, boy", &["family_man_girl_boy"], People, "family", (4, 0), ANY, Common),
+	emoji("👨‍👦‍👦", "family: man, boy, boy", &["family_man_boy_boy"], People, "family", (4, 0), ANY, Common),
+	emoji("👨‍👧‍👧", "family: man, girl, girl", &["family_man_girl_girl"], People, "family", (4, 0), ANY, Common),
+	emoji("🪢", "knot", &["knot"], People, "arts & crafts", (13, 0), ANY, Rare),
+	emoji("🧶", "yarn", &["yarn"], People, "arts & crafts", (11, 0), ANY, Uncommon),
+	emoji("🧵", "thread", &["thread"], People, "arts & crafts", (11, 0), ANY, Uncommon),
+	emoji("🪡", "sewing needle", &["sewing_needle"], People, "arts & crafts", (13, 0), ANY, Rare),
+	emoji("🧥", "coat", &["coat"], People, "clothing", (5, 0), ANY, Common),
+	emoji("🥼", "lab coat", &["lab_coat"], People, "clothing", (11, 0), ANY, Uncommon),
+	emoji("🦺", "safety vest", &["safety_vest"], People, "clothing", (12, 0), ANY, Uncommon),
+	emoji("👚", "woman's clothes", &["womans_clothes"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👕", "t-shirt", &["t_shirt"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👖", "jeans", &["jeans"], People, "clothing", (0, 6), ANY, Common),
+	emoji("🩲", "briefs", &["briefs"], People, "clothing", (12, 0), ANY, Uncommon),
+	emoji("🩳", "shorts", &["shorts"], People, "clothing", (12, 0), ANY, Uncommon),
+	emoji("👔", "necktie", &["necktie"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👗", "dress", &["dress"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👙", "bikini", &["bikini"], People, "clothing", (0, 6), ANY, Common),
+	emoji("🩱", "one-piece swimsuit", &["one_piece_swimsuit"], People, "clothing", (12, 0), ANY, Uncommon),
+	emoji("👘", "kimono", &["kimono"], People, "clothing", (0, 6), ANY, Common),
+	emoji("🥻", "sari", &["sari"], People, "clothing", (12, 0), ANY, Uncommon),
+	emoji("🩴", "thong sandal", &["thong_sandal"], People, "clothing", (13, 0), ANY, Rare),
+	emoji("🥿", "flat shoe", &["flat_shoe"], People, "clothing", (11, 0), ANY, Uncommon),
+	emoji("👠", "high-heeled shoe", &["high_heeled_shoe"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👡", "woman's sandal", &["womans_sandal"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👢", "woman's boot", &["womans_boot"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👞", "man's shoe", &["mans_shoe"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👟", "running shoe", &["running_shoe"], People, "clothing", (0, 6), ANY, Common),
+	emoji("🥾", "hiking boot", &["hiking_boot"], People, "clothing", (11, 0), ANY, Uncommon),
+	emoji("🧦", "socks", &["socks"], People, "clothing", (5, 0), ANY, Common),
+	emoji("🧤", "gloves", &["gloves"], People, "clothing", (5, 0), ANY, Common),
+	emoji("🧣", "scarf", &["scarf"], People, "clothing", (5, 0), ANY, Common),
+	emoji("🎩", "top hat", &["top_hat"], People, "clothing", (0, 6), ANY, Common),
+	emoji("🧢", "billed cap", &["billed_cap"], People, "clothing", (5, 0), ANY, Common),
+	emoji("👒", "woman's hat", &["womans_hat"], People, "clothing", (0, 6), ANY, Common),
+	emoji("🎓", "graduation cap", &["graduation_cap"], People, "clothing", (0, 6), ANY, Common),
+	emoji("⛑️", "rescue worker's helmet", &["rescue_workers_helmet"], People, "clothing", (0, 7), ANY, Common),
+	emoji("🪖", "military helmet", &["military_helmet"], People, "clothing", (13, 0), ANY, Rare),
+	emoji("👑", "crown", &["crown"], People, "clothing", (0, 6), ANY, Legendary),
+	emoji("💍", "ring", &["ring"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👝", "clutch bag", &["clutch_bag"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👛", "purse", &["purse"], People, "clothing", (0, 6), ANY, Common),
+	emoji("👜", "handbag", &["handbag"], People, "clothing", (0, 6), ANY, Common),
+	emoji("💼", "briefcase", &["briefcase"], People, "office", (0, 6), ANY, Common),
+	emoji("🎒", "backpack", &["backpack"], People, "clothing", (0, 6), ANY, Common),
+	emoji("🧳", "luggage", &["luggage"], People, "hotel", (11, 0), ANY, Uncommon),
+	emoji("👓", "glasses", &["glasses"], People, "clothing", (0, 6), ANY, Common),
+	emoji("🕶️", "sunglasses", &["sunglasses"], People, "clothing", (0, 7), ANY, Common),
+	emoji("🥽", "goggles", &["goggles"], People, "clothing", (11, 0), ANY, Uncommon),
+	emoji("🌂", "closed umbrella", &["closed_umbrella"], People, "sky & weather", (0, 6), ANY, Common),
 	// nature
-	"🐶",
-	"🐱",
-	"🐭",
-	"🐹",
-	"🐰",
-	"🦊",
-	"🐻",
-	"🐼",
-	"🐻‍❄️",
-	"🐨",
-	"🐯",
-	"🦁",
-	"🐮",
-	"🐷",
-	"🐽",
-	"🐸",
-	"🐵",
-	"🙈",
-	"🙉",
-	"🙊",
-	"🐒",
-	"🐔",
-	"🐧",
-	"🐦",
-	"🐤",
-	"🐣",
-	"🐥",
-	"🦆",
-	"🦅",
-	"🦉",
-	"🦇",
-	"🐺",
-	"🐗",
-	"🐴",
-	"🦄",
-	"🐝",
-	"🐛",
-	"🦋",
-	"🐌",
-	"🪱",
-	"🐞",
-	"🐜",
-	"🪰",
-	"🦟",
-	"🪳",
-	"🪲",
-	"🦗",
-	"🕷️",
-	"🕸️",
-	"🦂",
-	"🐢",
-	"🐍",
-	"🦎",
-	"🦖",
-	"🦕",
-	"🐙",
-	"🦑",
-	"🦐",
-	"🦞",
-	"🦀",
-	"🐡",
-	"🐠",
-	"🐟",
-	"🦭",
-	"🐬",
-	"🐳",
-	"🐋",
-	"🦈",
-	"🐊",
-	"🐅",
-	"🐆",
-	"🦓",
-	"🦍",
-	"🦧",
-	"🐘",
-	"🦣",
-	"🦬",
-	"🦛",
-	"🦏",
-	"🐪",
-	"🐫",
-	"🦒",
-	"🦘",
-	"🐃",
-	"🐂",
-	"🐄",
-	"🐎",
-	"🐖",
-	"🐏",
-	"🐑",
-	"🦙",
-	"🐐",
-	"🦌",
-	"🐕",
-	"🐩",
-	"🦮",
-	"🐕‍🦺",
-	"🐈",
-	"🐈‍⬛",
-	"🪶",
-	"🐓",
-	"🦃",
-	"🦤",
-	"🦚",
-	"🦜",
-	"🦢",
-	"🦩",
-	"🕊️",
-	"🐇",
-	"🦝",
-	"🦨",
-	"🦡",
-	"🦫",
-	"🦦",
-	"🦥",
-	"🐁",
-	"🐀",
-	"🐿️",
-	"🦔",
-	"🐾",
-	"🐉",
-	"🐲",
-	"🌵",
-	"🎄",
-	"🌲",
-	"🌳",
-	"🌴",
-	"🌱",
-	"🌿",
-	"☘️",
-	"🍀",
-	"🎍",
-	"🎋",
-	"🍃",
-	"🍂",
-	"🍁",
-	"🪹",
-	"🪺",
-	"🍄",
-	"🐚",
-	"🪸",
-	"🪨",
-	"🪵",
-	"🌾",
-	"🪴",
-	"💐",
-	"🌷",
-	"🌹",
-	"🥀",
-	"🪷",
-	"🌺",
-	"🌸",
-	"🌼",
-	"🌻",
-	"🌞",
-	"🌝",
-	"🌛",
-	"🌜",
-	"🌚",
-	"🌕",
-	"🌖",
-	"🌗",
-	"🌘",
-	"🌑",
-	"🌒",
-	"🌓",
-	"🌔",
-	"🌙",
-	"🌎",
-	"🌍",
-	"🌏",
-	"🪐",
-	"💫",
-	"⭐",
-	"🌟",
-	"✨",
-	"⚡",
-	"☄️",
-	"💥",
-	"🔥",
-	"🌪️",
-	"🌈",
-	"☀️",
-	"🌤️",
-	"⛅",
-	"🌥️",
-	"☁️",
-	"🌦️",
-	"🌧️",
-	"⛈️",
-	"🌩️",
-	"🌨️",
-	"❄️",
-	"☃️",
-	"⛄",
-	"🌬️",
-	"💨",
-	"💧",
-	"💦",
-	"🫧",
-	"☔",
-	"☂️",
-	"🌊",
-	"🌫️",
+	emoji("🐶", "dog face", &["dog_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐱", "cat face", &["cat_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐭", "mouse face", &["mouse_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐹", "hamster face", &["hamster_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐰", "rabbit face", &["rabbit_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🦊", "fox face", &["fox_face", "fox"], Nature, "animal-mammal", (3, 0), ANY, Common),
+	emoji("🐻", "bear", &["bear"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐼", "panda", &["panda"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐻‍❄️", "polar bear", &["polar_bear"], Nature, "animal-mammal", (13, 0), ANY, Rare),
+	emoji("🐨", "koala", &["koala"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐯", "tiger face", &["tiger_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🦁", "lion", &["lion", "lion_face"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐮", "cow face", &["cow_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐷", "pig face", &["pig_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐽", "pig nose", &["pig_nose"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐸", "frog", &["frog"], Nature, "animal-amphibian", (0, 6), ANY, Common),
+	emoji("🐵", "monkey face", &["monkey_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🙈", "see-no-evil monkey", &["see_no_evil_monkey", "see_no_evil"], Nature, "monkey-face", (0, 6), ANY, Common),
+	emoji("🙉", "hear-no-evil monkey", &["hear_no_evil_monkey", "hear_no_evil"], Nature, "monkey-face", (0, 6), ANY, Common),
+	emoji("🙊", "speak-no-evil monkey", &["speak_no_evil_monkey", "speak_no_evil"], Nature, "monkey-face", (0, 6), ANY, Common),
+	emoji("🐒", "monkey", &["monkey"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐔", "chicken", &["chicken"], Nature, "animal-bird", (0, 6), ANY, Common),
+	emoji("🐧", "penguin", &["penguin"], Nature, "animal-bird", (0, 6), ANY, Common),
+	emoji("🐦", "bird", &["bird"], Nature, "animal-bird", (0, 6), ANY, Common),
+	emoji("🐤", "baby chick", &["baby_chick"], Nature, "animal-bird", (0, 6), ANY, Common),
+	emoji("🐣", "hatching chick", &["hatching_chick"], Nature, "animal-bird", (0, 6), ANY, Common),
+	emoji("🐥", "front-facing baby chick", &["front_facing_baby_chick"], Nature, "animal-bird", (0, 6), ANY, Common),
+	emoji("🦆", "duck", &["duck"], Nature, "animal-bird", (3, 0), ANY, Common),
+	emoji("🦅", "eagle", &["eagle"], Nature, "animal-bird", (3, 0), ANY, Common),
+	emoji("🦉", "owl", &["owl"], Nature, "animal-bird", (3, 0), ANY, Common),
+	emoji("🦇", "bat", &["bat"], Nature, "animal-mammal", (3, 0), ANY, Common),
+	emoji("🐺", "wolf", &["wolf"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐗", "boar", &["boar"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐴", "horse face", &["horse_face"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🦄", "unicorn", &["unicorn"], Nature, "animal-mammal", (1, 0), ANY, Legendary),
+	emoji("🐝", "honeybee", &["honeybee", "bee"], Nature, "animal-bug", (0, 6), ANY, Common),
+	emoji("🐛", "bug", &["bug"], Nature, "animal-bug", (0, 6), ANY, Common),
+	emoji("🦋", "butterfly", &["butterfly"], Nature, "animal-bug", (3, 0), ANY, Common),
+	emoji("🐌", "snail", &["snail"], Nature, "animal-bug", (0, 6), ANY, Common),
+	emoji("🪱", "worm", &["worm"], Nature, "animal-bug", (13, 0), ANY, Rare),
+	emoji("🐞", "lady beetle", &["lady_beetle", "ladybug"], Nature, "animal-bug", (0, 6), ANY, Common),
+	emoji("🐜", "ant", &["ant"], Nature, "animal-bug", (0, 6), ANY, Common),
+	emoji("🪰", "fly", &["fly"], Nature, "animal-bug", (13, 0), ANY, Rare),
+	emoji("🦟", "mosquito", &["mosquito"], Nature, "animal-bug", (11, 0), ANY, Uncommon),
+	emoji("🪳", "cockroach", &["cockroach"], Nature, "animal-bug", (13, 0), ANY, Rare),
+	emoji("🪲", "beetle", &["beetle"], Nature, "animal-bug", (13, 0), ANY, Rare),
+	emoji("🦗", "cricket", &["cricket"], Nature, "animal-bug", (5, 0), ANY, Common),
+	emoji("🕷️", "spider", &["spider"], Nature, "animal-bug", (0, 7), ANY, Common),
+	emoji("🕸️", "spider web", &["spider_web"], Nature, "animal-bug", (0, 7), ANY, Common),
+	emoji("🦂", "scorpion", &["scorpion"], Nature, "animal-bug", (1, 0), ANY, Common),
+	emoji("🐢", "turtle", &["turtle"], Nature, "animal-reptile", (0, 6), ANY, Common),
+	emoji("🐍", "snake", &["snake"], Nature, "animal-reptile", (0, 6), ANY, Common),
+	emoji("🦎", "lizard", &["lizard"], Nature, "animal-reptile", (3, 0), ANY, Common),
+	emoji("🦖", "T-Rex", &["t_rex"], Nature, "animal-reptile", (5, 0), ANY, Legendary),
+	emoji("🦕", "sauropod", &["sauropod"], Nature, "animal-reptile", (5, 0), ANY, Common),
+	emoji("🐙", "octopus", &["octopus"], Nature, "animal-marine", (0, 6), ANY, Legendary),
+	emoji("🦑", "squid", &["squid"], Nature, "food-marine", (3, 0), ANY, Common),
+	emoji("🦐", "shrimp", &["shrimp"], Nature, "food-marine", (3, 0), ANY, Common),
+	emoji("🦞", "lobster", &["lobster"], Nature, "food-marine", (11, 0), ANY, Uncommon),
+	emoji("🦀", "crab", &["crab"], Nature, "food-marine", (1, 0), ANY, Common),
+	emoji("🐡", "blowfish", &["blowfish"], Nature, "animal-marine", (0, 6), ANY, Common),
+	emoji("🐠", "tropical fish", &["tropical_fish"], Nature, "animal-marine", (0, 6), ANY, Common),
+	emoji("🐟", "fish", &["fish"], Nature, "animal-marine", (0, 6), ANY, Common),
+	emoji("🦭", "seal", &["seal"], Nature, "animal-marine", (13, 0), ANY, Rare),
+	emoji("🐬", "dolphin", &["dolphin"], Nature, "animal-marine", (0, 6), ANY, Common),
+	emoji("🐳", "spouting whale", &["spouting_whale", "whale_face"], Nature, "animal-marine", (0, 6), ANY, Common),
+	emoji("🐋", "whale", &["whale", "whale2"], Nature, "animal-marine", (1, 0), ANY, Common),
+	emoji("🦈", "shark", &["shark"], Nature, "animal-marine", (3, 0), ANY, Common),
+	emoji("🐊", "crocodile", &["crocodile"], Nature, "animal-reptile", (1, 0), ANY, Common),
+	emoji("🐅", "tiger", &["tiger"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐆", "leopard", &["leopard"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🦓", "zebra", &["zebra"], Nature, "animal-mammal", (5, 0), ANY, Common),
+	emoji("🦍", "gorilla", &["gorilla"], Nature, "animal-mammal", (3, 0), ANY, Common),
+	emoji("🦧", "orangutan", &["orangutan"], Nature, "animal-mammal", (12, 0), ANY, Uncommon),
+	emoji("🐘", "elephant", &["elephant"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🦣", "mammoth", &["mammoth"], Nature, "animal-mammal", (13, 0), ANY, Rare),
+	emoji("🦬", "bison", &["bison"], Nature, "animal-mammal", (13, 0), ANY, Rare),
+	emoji("🦛", "hippopotamus", &["hippopotamus"], Nature, "animal-mammal", (11, 0), ANY, Uncommon),
+	emoji("🦏", "rhinoceros", &["rhinoceros"], Nature, "animal-mammal", (3, 0), ANY, Common),
+	emoji("🐪", "camel", &["camel"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐫", "two-hump camel", &["two_hump_camel"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🦒", "giraffe", &["giraffe"], Nature, "animal-mammal", (5, 0), ANY, Common),
+	emoji("🦘", "kangaroo", &["kangaroo"], Nature, "animal-mammal", (11, 0), ANY, Uncommon),
+	emoji("🐃", "water buffalo", &["water_buffalo"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐂", "ox", &["ox"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐄", "cow", &["cow"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐎", "horse", &["horse"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐖", "pig", &["pig"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐏", "ram", &["ram"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐑", "sheep", &["sheep"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🦙", "llama", &["llama"], Nature, "animal-mammal", (11, 0), ANY, Uncommon),
+	emoji("🐐", "goat", &["goat"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🦌", "deer", &["deer"], Nature, "animal-mammal", (3, 0), ANY, Common),
+	emoji("🐕", "dog", &["dog"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐩", "poodle", &["poodle"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🦮", "guide dog", &["guide_dog"], Nature, "animal-mammal", (12, 0), ANY, Uncommon),
+	emoji("🐕‍🦺", "service dog", &["service_dog"], Nature, "animal-mammal", (12, 0), ANY, Uncommon),
+	emoji("🐈", "cat", &["cat"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐈‍⬛", "black cat", &["black_cat"], Nature, "animal-mammal", (13, 0), ANY, Rare),
+	emoji("🪶", "feather", &["feather"], Nature, "animal-bird", (13, 0), ANY, Rare),
+	emoji("🐓", "rooster", &["rooster"], Nature, "animal-bird", (1, 0), ANY, Common),
+	emoji("🦃", "turkey", &["turkey"], Nature, "animal-bird", (1, 0), ANY, Common),
+	emoji("🦤", "dodo", &["dodo"], Nature, "animal-bird", (13, 0), ANY, Rare),
+	emoji("🦚", "peacock", &["peacock"], Nature, "animal-bird", (11, 0), ANY, Legendary),
+	emoji("🦜", "parrot", &["parrot"], Nature, "animal-bird", (11, 0), ANY, Uncommon),
+	emoji("🦢", "swan", &["swan"], Nature, "animal-bird", (11, 0), ANY, Uncommon),
+	emoji("🦩", "flamingo", &["flamingo"], Nature, "animal-bird", (12, 0), ANY, Legendary),
+	emoji("🕊️", "dove", &["dove"], Nature, "animal-bird", (0, 7), ANY, Common),
+	emoji("🐇", "rabbit", &["rabbit"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🦝", "raccoon", &["raccoon"], Nature, "animal-mammal", (11, 0), ANY, Uncommon),
+	emoji("🦨", "skunk", &["skunk"], Nature, "animal-mammal", (12, 0), ANY, Uncommon),
+	emoji("🦡", "badger", &["badger"], Nature, "animal-mammal", (11, 0), ANY, Uncommon),
+	emoji("🦫", "beaver", &["beaver"], Nature, "animal-mammal", (13, 0), ANY, Rare),
+	emoji("🦦", "otter", &["otter"], Nature, "animal-mammal", (12, 0), ANY, Uncommon),
+	emoji("🦥", "sloth", &["sloth"], Nature, "animal-mammal", (12, 0), ANY, Uncommon),
+	emoji("🐁", "mouse", &["mouse"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐀", "rat", &["rat"], Nature, "animal-mammal", (1, 0), ANY, Common),
+	emoji("🐿️", "chipmunk", &["chipmunk"], Nature, "animal-mammal", (0, 7), ANY, Common),
+	emoji("🦔", "hedgehog", &["hedgehog"], Nature, "animal-mammal", (5, 0), ANY, Common),
+	emoji("🐾", "paw prints", &["paw_prints"], Nature, "animal-mammal", (0, 6), ANY, Common),
+	emoji("🐉", "dragon", &["dragon"], Nature, "animal-reptile", (1, 0), ANY, Legendary),
+	emoji("🐲", "dragon face", &["dragon_face"], Nature, "animal-reptile", (0, 6), ANY, Common),
+	emoji("🌵", "cactus", &["cactus"], Nature, "plant-other", (0, 6), ANY, Common),
+	emoji("🎄", "Christmas tree", &["christmas_tree", "xmas_tree"], Nature, "event", (0, 6), ANY, Common),
+	emoji("🌲", "evergreen tree", &["evergreen_tree"], Nature, "plant-other", (1, 0), ANY, Common),
+	emoji("🌳", "deciduous tree", &["deciduous_tree"], Nature, "plant-other", (1, 0), ANY, Common),
+	emoji("🌴", "palm tree", &["palm_tree"], Nature, "plant-other", (0, 6), ANY, Common),
+	emoji("🌱", "seedling", &["seedling"], Nature, "plant-other", (0, 6), ANY, Common),
+	emoji("🌿", "herb", &["herb"], Nature, "plant-other", (0, 6), ANY, Common),
+	emoji("☘️", "shamrock", &["shamrock"], Nature, "plant-other", (1, 0), ANY, Common),
+	emoji("🍀", "four leaf clover", &["four_leaf_clover"], Nature, "plant-other", (0, 6), ANY, Legendary),
+	emoji("🎍", "pine decoration", &["pine_decoration"], Nature, "event", (0, 6), ANY, Common),
+	emoji("🎋", "tanabata tree", &["tanabata_tree"], Nature, "event", (0, 6), ANY, Common),
+	emoji("🍃", "leaf fluttering in wind", &["leaf_fluttering_in_wind"], Nature, "plant-other", (0, 6), ANY, Common),
+	emoji("🍂", "fallen leaf", &["fallen_leaf"], Nature, "plant-other", (0, 6), ANY, Common),
+	emoji("🍁", "maple leaf", &["maple_leaf"], Nature, "plant-other", (0, 6), ANY, Common),
+	emoji("🪹", "empty nest", &["empty_nest"], Nature, "plant-other", (14, 0), ANY, Rare),
+	emoji("🪺", "nest with eggs", &["nest_with_eggs"], Nature, "plant-other", (14, 0), ANY, Rare),
+	emoji("🍄", "mushroom", &["mushroom"], Nature, "food-vegetable", (0, 6), ANY, Common),
+	emoji("🐚", "spiral shell", &["spiral_shell"], Nature, "animal-marine", (0, 6), ANY, Common),
+	emoji("🪸", "coral", &["coral"], Nature, "animal-marine", (14, 0), ANY, Rare),
+	emoji("🪨", "rock", &["rock"], Nature, "place-building", (0, 6), ANY, Common),
+	emoji("🪵", "wood", &["wood"], Nature, "place-building", (0, 6), ANY, Common),
+	emoji("🌾", "sheaf of rice", &["sheaf_of_rice"], Nature, "plant-other", (0, 6), ANY, Common),
+	emoji("🪴", "potted plant", &["potted_plant"], Nature, "plant-other", (13, 0), ANY, Rare),
+	emoji("💐", "bouquet", &["bouquet"], Nature, "plant-flower", (0, 6), ANY, Common),
+	emoji("🌷", "tulip", &["tulip"], Nature, "plant-flower", (0, 6), ANY, Common),
+	emoji("🌹", "rose", &["rose"], Nature, "plant-flower", (0, 6), ANY, Common),
+	emoji("🥀", "wilted flower", &["wilted_flower"], Nature, "plant-flower", (3, 0), ANY, Common),
+	emoji("🪷", "lotus", &["lotus"], Nature, "plant-flower", (14, 0), ANY, Rare),
+	emoji("🌺", "hibiscus", &["hibiscus"], Nature, "plant-flower", (0, 6), ANY, Common),
+	emoji("🌸", "cherry blossom", &["cherry_blossom"], Nature, "plant-flower", (0, 6), ANY, Common),
+	emoji("🌼", "blossom", &["blossom"], Nature, "plant-flower", (0, 6), ANY, Common),
+	emoji("🌻", "sunflower", &["sunflower"], Nature, "plant-flower", (0, 6), ANY, Common),
+	emoji("🌞", "sun with face", &["sun_with_face"], Nature, "sky & weather", (1, 0), ANY, Common),
+	emoji("🌝", "full moon face", &["full_moon_face"], Nature, "sky & weather", (1, 0), ANY, Common),
+	emoji("🌛", "first quarter moon face", &["first_quarter_moon_face"], Nature, "sky & weather", (0, 6), NO_MIRROR, Common),
+	emoji("🌜", "last quarter moon face", &["last_quarter_moon_face"], Nature, "sky & weather", (1, 0), NO_MIRROR, Common),
+	emoji("🌚", "new moon face", &["new_moon_face"], Nature, "sky & weather", (1, 0), NO_MIRROR, Common),
+	emoji("🌕", "full moon", &["full_moon"], Nature, "sky & weather", (0, 6), FIXED, Common),
+	emoji("🌖", "waning gibbous moon", &["waning_gibbous_moon"], Nature, "sky & weather", (1, 0), FIXED, Common),
+	emoji("🌗", "last quarter moon", &["last_quarter_moon"], Nature, "sky & weather", (1, 0), FIXED, Common),
+	emoji("🌘", "waning crescent moon", &["waning_crescent_moon"], Nature, "sky & weather", (1, 0), FIXED, Common),
+	emoji("🌑", "new moon", &["new_moon"], Nature, "sky & weather", (0, 6), FIXED, Common),
+	emoji("🌒", "waxing crescent moon", &["waxing_crescent_moon"], Nature, "sky & weather", (1, 0), FIXED, Common),
+	emoji("🌓", "first quarter moon", &["first_quarter_moon"], Nature, "sky & weather", (0, 6), FIXED, Common),
+	emoji("🌔", "waxing gibbous moon", &["waxing_gibbous_moon"], Nature, "sky & weather", (0, 6), FIXED, Common),
+	emoji("🌙", "crescent moon", &["crescent_moon"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("🌎", "globe showing Americas", &["globe_showing_americas"], Nature, "place-map", (1, 0), NO_MIRROR, Common),
+	emoji("🌍", "globe showing Europe-Africa", &["globe_showing_europe_africa"], Nature, "place-map", (1, 0), NO_MIRROR, Common),
+	emoji("🌏", "globe showing Asia-Australia", &["globe_showing_asia_australia"], Nature, "place-map", (0, 6), NO_MIRROR, Common),
+	emoji("🪐", "ringed planet", &["ringed_planet"], Nature, "sky & weather", (12, 0), ANY, Legendary),
+	emoji("💫", "dizzy", &["dizzy"], Nature, "emotion", (0, 6), ANY, Common),
+	emoji("⭐", "star", &["star", "star_outline"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("🌟", "glowing star", &["glowing_star", "star2"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("✨", "sparkles", &["sparkles"], Nature, "event", (0, 6), ANY, Common),
+	emoji("⚡", "high voltage", &["high_voltage", "zap"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("☄️", "comet", &["comet"], Nature, "sky & weather", (1, 0), ANY, Legendary),
+	emoji("💥", "collision", &["collision", "boom"], Nature, "emotion", (0, 6), ANY, Common),
+	emoji("🔥", "fire", &["fire", "flame"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("🌪️", "tornado", &["tornado"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("🌈", "rainbow", &["rainbow"], Nature, "sky & weather", (0, 6), ANY, Legendary),
+	emoji("☀️", "sun", &["sun", "sunny"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("🌤️", "sun behind small cloud", &["sun_behind_small_cloud"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("⛅", "sun behind cloud", &["sun_behind_cloud"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("🌥️", "sun behind large cloud", &["sun_behind_large_cloud"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("☁️", "cloud", &["cloud"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("🌦️", "sun behind rain cloud", &["sun_behind_rain_cloud"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("🌧️", "cloud with rain", &["cloud_with_rain"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("⛈️", "cloud with lightning and rain", &["cloud_with_lightning_and_rain"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("🌩️", "cloud with lightning", &["cloud_with_lightning"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("🌨️", "cloud with snow", &["cloud_with_snow"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("❄️", "snowflake", &["snowflake"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("☃️", "snowman", &["snowman", "snowman2"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("⛄", "snowman without snow", &["snowman_without_snow"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("🌬️", "wind face", &["wind_face"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("💨", "dashing away", &["dashing_away", "dash"], Nature, "emotion", (0, 6), ANY, Common),
+	emoji("💧", "droplet", &["droplet"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("💦", "sweat droplets", &["sweat_droplets", "sweat_drops"], Nature, "emotion", (0, 6), ANY, Common),
+	emoji("🫧", "bubbles", &["bubbles"], Nature, "household", (14, 0), ANY, Rare),
+	emoji("☔", "umbrella with rain drops", &["umbrella_with_rain_drops"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("☂️", "umbrella", &["umbrella"], Nature, "sky & weather", (0, 7), ANY, Common),
+	emoji("🌊", "water wave", &["water_wave", "ocean"], Nature, "sky & weather", (0, 6), ANY, Common),
+	emoji("🌫️", "fog", &["fog"], Nature, "sky & weather", (0, 7), ANY, Common),
 	// food
-	"🍏",
-	"🍎",
-	"🍐",
-	"🍊",
-	"🍋",
-	"🍌",
-	"🍉",
-	"🍇",
-	"🫐",
-	"🍓",
-	"🍈",
-	"🍒",
-	"🍑",
-	"🥭",
-	"🍍",
-	"🥥",
-	"🥝",
-	"🍅",
-	"🍆",
-	"🥑",
-	"🫒",
-	"🥦",
-	"🥬",
-	"🫑",
-	"🥒",
-	"🌶️",
-	"🌽",
-	"🥕",
-	"🧄",
-	"🧅",
-	"🥔",
-	"🍠",
-	"🥐",
-	"🥯",
-	"🍞",
-	"🥖",
-	"🫓",
-	"🥨",
-	"🧀",
-	"🥚",
-	"🍳",
-	"🧈",
-	"🥞",
-	"🧇",
-	"🥓",
-	"🥩",
-	"🍗",
-	"🍖",
-	"🦴",
-	"🌭",
-	"🍔",
-	"🍟",
-	"🍕",
-	"🥪",
-	"🥙",
-	"🧆",
-	"🌮",
-	"🌯",
-	"🫔",
-	"🥗",
-	"🥘",
-	"🫕",
-	"🥫",
-	"🫙",
-	"🍝",
-	"🍜",
-	"🍲",
-	"🍛",
-	"🍣",
-	"🍱",
-	"🥟",
-	"🦪",
-	"🍤",
-	"🍙",
-	"🍚",
-	"🍘",
-	"🍥",
-	"🥠",
-	"🥮",
-	"🍢",
-	"🍡",
-	"🍧",
-	"🍨",
-	"🍦",
-	"🥧",
-	"🧁",
-	"🍰",
-	"🎂",
-	"🍮",
-	"🍭",
-	"🍬",
-	"🍫",
-	"🍿",
-	"🍩",
-	"🍪",
-	"🌰",
-	"🥜",
-	"🫘",
-	"🍯",
-	"🥛",
-	"🫗",
-	"🍼",
-	"🫖",
-	"☕",
-	"🍵",
-	"🧉",
-	"🧃",
-	"🥤",
-	"🧋",
-	"🍶",
-	"🍺",
-	"🍻",
-	"🥂",
-	"🍷",
-	"🥃",
-	"🍸",
-	"🍹",
-	"🍾",
-	"🧊",
-	"🥄",
-	"🍴",
-	"🍽️",
-	"🥣",
-	"🥡",
-	"🥢",
-	"🧂",
+	emoji("🍏", "green apple", &["green_apple"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍎", "red apple", &["red_apple", "apple"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍐", "pear", &["pear"], Food, "food-fruit", (1, 0), ANY, Common),
+	emoji("🍊", "tangerine", &["tangerine"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍋", "lemon", &["lemon"], Food, "food-fruit", (1, 0), ANY, Common),
+	emoji("🍌", "banana", &["banana"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍉", "watermelon", &["watermelon"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍇", "grapes", &["grapes"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🫐", "blueberries", &["blueberries"], Food, "food-fruit", (13, 0), ANY, Rare),
+	emoji("🍓", "strawberry", &["strawberry"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍈", "melon", &["melon"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍒", "cherries", &["cherries"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍑", "peach", &["peach"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🥭", "mango", &["mango"], Food, "food-fruit", (11, 0), ANY, Uncommon),
+	emoji("🍍", "pineapple", &["pineapple"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🥥", "coconut", &["coconut"], Food, "food-fruit", (5, 0), ANY, Common),
+	emoji("🥝", "kiwi fruit", &["kiwi_fruit"], Food, "food-fruit", (3, 0), ANY, Common),
+	emoji("🍅", "tomato", &["tomato"], Food, "food-fruit", (0, 6), ANY, Common),
+	emoji("🍆", "eggplant", &["eggplant", "aubergine"], Food, "food-vegetable", (0, 6), ANY, Common),
+	emoji("🥑", "avocado", &["avocado"], Food, "food-vegetable", (3, 0), ANY, Common),
+	emoji("🫒", "olive", &["olive"], Food, "food-fruit", (13, 0), ANY, Rare),
+	emoji("🥦", "broccoli", &["broccoli"], Food, "food-vegetable", (5, 0), ANY, Common),
+	emoji("🥬", "leafy green", &["leafy_green"], Food, "food-vegetable", (11, 0), ANY, Uncommon),
+	emoji("🫑", "bell pepper", &["bell_pepper"], Food, "food-vegetable", (13, 0), ANY, Rare),
+	emoji("🥒", "cucumber", &["cucumber"], Food, "food-vegetable", (3, 0), ANY, Common),
+	emoji("🌶️", "hot pepper", &["hot_pepper"], Food, "food-vegetable", (0, 7), ANY, Common),
+	emoji("🌽", "ear of corn", &["ear_of_corn", "corn"], Food, "food-vegetable", (0, 6), ANY, Common),
+	emoji("🥕", "carrot", &["carrot"], Food, "food-vegetable", (3, 0), ANY, Common),
+	emoji("🧄", "garlic", &["garlic"], Food, "food-vegetable", (12, 0), ANY, Uncommon),
+	emoji("🧅", "onion", &["onion"], Food, "food-vegetable", (12, 0), ANY, Uncommon),
+	emoji("🥔", "potato", &["potato"], Food, "food-vegetable", (3, 0), ANY, Common),
+	emoji("🍠", "roasted sweet potato", &["roasted_sweet_potato"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🥐", "croissant", &["croissant"], Food, "food-prepared", (3, 0), ANY, Common),
+	emoji("🥯", "bagel", &["bagel"], Food, "food-prepared", (11, 0), ANY, Uncommon),
+	emoji("🍞", "bread", &["bread"], Food, "food-prepared", (0, 6), ANY, Common),
+	emoji("🥖", "baguette bread", &["baguette_bread"], Food, "food-prepared", (3, 0), ANY, Common),
+	emoji("🫓", "flatbread", &["flatbread"], Food, "food-prepared", (13, 0), ANY, Rare),
+	emoji("🥨", "pretzel", &["pretzel"], Food, "food-prepared", (5, 0), ANY, Common),
+	emoji("🧀", "cheese wedge", &["cheese_wedge"], Food, "food-prepared", (1, 0), ANY, Common),
+	emoji("🥚", "egg", &["egg"], Food, "food-prepared", (3, 0), ANY, Common),
+	emoji("🍳", "cooking", &["cooking"], Food, "food-prepared", (0, 6), ANY, Common),
+	emoji("🧈", "butter", &["butter"], Food, "food-prepared", (12, 0), ANY, Uncommon),
+	emoji("🥞", "pancakes", &["pancakes"], Food, "food-prepared", (3, 0), ANY, Common),
+	emoji("🧇", "waffle", &["waffle"], Food, "food-prepared", (12, 0), ANY, Uncommon),
+	emoji("🥓", "bacon", &["bacon"], Food, "food-prepared", (3, 0), ANY, Common),
+	emoji("🥩", "cut of meat", &["cut_of_meat"], Food, "food-prepared", (5, 0), ANY, Common),
+	emoji("🍗", "poultry leg", &["poultry_leg"], Food, "food-prepared", (0, 6), ANY, Common),
+	emoji("🍖", "meat on bone", &["meat_on_bone"], Food, "food-prepared", (0, 6), ANY, Common),
+	emoji("🦴", "bone", &["bone"], Food, "body-parts", (11, 0), ANY, Uncommon),
+	emoji("🌭", "hot dog", &["hot_dog", "hotdog"], Food, "food-prepared", (1, 0), ANY, Common),
+	emoji("🍔", "hamburger", &["hamburger"], Food, "food-prepared", (0, 6), ANY, Common),
+	emoji("🍟", "french fries", &["french_fries", "fries"], Food, "food-prepared", (0, 6), ANY, Common),
+	emoji("🍕", "pizza", &["pizza"], Food, "food-prepared", (0, 6), ANY, Common),
+	emoji("🥪", "sandwich", &["sandwich"], Food, "food-prepared", (5, 0), ANY, Common),
+	emoji("🥙", "stuffed flatbread", &["stuffed_flatbread"], Food, "food-prepared", (3, 0), ANY, Common),
+	emoji("🧆", "falafel", &["falafel"], Food, "food-prepared", (12, 0), ANY, Uncommon),
+	emoji("🌮", "taco", &["taco"], Food, "food-prepared", (1, 0), ANY, Common),
+	emoji("🌯", "burrito", &["burrito"], Food, "food-prepared", (1, 0), ANY, Common),
+	emoji("🫔", "tamale", &["tamale"], Food, "food-prepared", (13, 0), ANY, Rare),
+	emoji("🥗", "green salad", &["green_salad"], Food, "food-prepared", (3, 0), ANY, Common),
+	emoji("🥘", "shallow pan of food", &["shallow_pan_of_food"], Food, "food-prepared", (3, 0), ANY, Common),
+	emoji("🫕", "fondue", &["fondue"], Food, "food-prepared", (13, 0), ANY, Rare),
+	emoji("🥫", "canned food", &["canned_food"], Food, "food-prepared", (5, 0), ANY, Common),
+	emoji("🫙", "jar", &["jar"], Food, "dishware", (14, 0), ANY, Rare),
+	emoji("🍝", "spaghetti", &["spaghetti"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍜", "steaming bowl", &["steaming_bowl", "ramen"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍲", "pot of food", &["pot_of_food"], Food, "food-prepared", (0, 6), ANY, Common),
+	emoji("🍛", "curry rice", &["curry_rice"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍣", "sushi", &["sushi"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍱", "bento box", &["bento_box"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🥟", "dumpling", &["dumpling"], Food, "food-asian", (5, 0), ANY, Common),
+	emoji("🦪", "oyster", &["oyster"], Food, "food-marine", (12, 0), ANY, Uncommon),
+	emoji("🍤", "fried shrimp", &["fried_shrimp"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍙", "rice ball", &["rice_ball"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍚", "cooked rice", &["cooked_rice"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍘", "rice cracker", &["rice_cracker"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍥", "fish cake with swirl", &["fish_cake_with_swirl"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🥠", "fortune cookie", &["fortune_cookie"], Food, "food-asian", (5, 0), ANY, Common),
+	emoji("🥮", "moon cake", &["moon_cake"], Food, "food-asian", (11, 0), ANY, Uncommon),
+	emoji("🍢", "oden", &["oden"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍡", "dango", &["dango"], Food, "food-asian", (0, 6), ANY, Common),
+	emoji("🍧", "shaved ice", &["shaved_ice"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🍨", "ice cream", &["ice_cream"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🍦", "soft ice cream", &["soft_ice_cream", "icecream"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🥧", "pie", &["pie"], Food, "food-sweet", (5, 0), ANY, Common),
+	emoji("🧁", "cupcake", &["cupcake"], Food, "food-sweet", (11, 0), ANY, Uncommon),
+	emoji("🍰", "shortcake", &["shortcake", "cake"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🎂", "birthday cake", &["birthday_cake", "birthday"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🍮", "custard", &["custard"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🍭", "lollipop", &["lollipop"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🍬", "candy", &["candy"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🍫", "chocolate bar", &["chocolate_bar"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🍿", "popcorn", &["popcorn"], Food, "food-prepared", (1, 0), ANY, Common),
+	emoji("🍩", "doughnut", &["doughnut"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🍪", "cookie", &["cookie"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🌰", "chestnut", &["chestnut"], Food, "food-vegetable", (0, 6), ANY, Common),
+	emoji("🥜", "peanuts", &["peanuts"], Food, "food-vegetable", (3, 0), ANY, Common),
+	emoji("🫘", "beans", &["beans"], Food, "food-vegetable", (14, 0), ANY, Rare),
+	emoji("🍯", "honey pot", &["honey_pot"], Food, "food-sweet", (0, 6), ANY, Common),
+	emoji("🥛", "glass of milk", &["glass_of_milk"], Food, "drink", (3, 0), ANY, Common),
+	emoji("🫗", "pouring liquid", &["pouring_liquid"], Food, "drink", (14, 0), ANY, Rare),
+	emoji("🍼", "baby bottle", &["baby_bottle"], Food, "drink", (1, 0), ANY, Common),
+	emoji("🫖", "teapot", &["teapot"], Food, "drink", (13, 0), ANY, Rare),
+	emoji("☕", "hot beverage", &["hot_beverage", "coffee"], Food, "drink", (0, 6), ANY, Common),
+	emoji("🍵", "teacup without handle", &["teacup_without_handle", "tea"], Food, "drink", (0, 6), ANY, Common),
+	emoji("🧉", "mate", &["mate"], Food, "drink", (12, 0), ANY, Uncommon),
+	emoji("🧃", "beverage box", &["beverage_box"], Food, "drink", (12, 0), ANY, Uncommon),
+	emoji("🥤", "cup with straw", &["cup_with_straw"], Food, "drink", (5, 0), ANY, Common),
+	emoji("🧋", "bubble tea", &["bubble_tea"], Food, "drink", (13, 0), ANY, Rare),
+	emoji("🍶", "sake", &["sake"], Food, "drink", (0, 6), ANY, Common),
+	emoji("🍺", "beer mug", &["beer_mug", "beer"], Food, "drink", (0, 6), ANY, Common),
+	emoji("🍻", "clinking beer mugs", &["clinking_beer_mugs", "beers"], Food, "drink", (0, 6), ANY, Common),
+	emoji("🥂", "clinking glasses", &["clinking_glasses", "champagne_glass"], Food, "drink", (3, 0), ANY, Common),
+	emoji("🍷", "wine glass", &["wine_glass"], Food, "drink", (0, 6), ANY, Common),
+	emoji("🥃", "tumbler glass", &["tumbler_glass"], Food, "drink", (3, 0), ANY, Common),
+	emoji("🍸", "cocktail glass", &["cocktail_glass"], Food, "drink", (0, 6), ANY, Common),
+	emoji("🍹", "tropical drink", &["tropical_drink"], Food, "drink", (0, 6), ANY, Common),
+	emoji("🍾", "bottle with popping cork", &["bottle_with_popping_cork", "champagne"], Food, "drink", (1, 0), ANY, Common),
+	emoji("🧊", "ice", &["ice"], Food, "drink", (12, 0), ANY, Uncommon),
+	emoji("🥄", "spoon", &["spoon"], Food, "dishware", (3, 0), ANY, Common),
+	emoji("🍴", "fork and knife", &["fork_and_knife"], Food, "dishware", (0, 6), ANY, Common),
+	emoji("🍽️", "fork and knife with plate", &["fork_and_knife_with_plate"], Food, "dishware", (0, 7), ANY, Common),
+	emoji("🥣", "bowl with spoon", &["bowl_with_spoon"], Food, "food-prepared", (5, 0), ANY, Common),
+	emoji("🥡", "takeout box", &["takeout_box"], Food, "food-asian", (5, 0), ANY, Common),
+	emoji("🥢", "chopsticks", &["chopsticks"], Food, "dishware", (5, 0), ANY, Common),
+	emoji("🧂", "salt", &["salt"], Food, "food-prepared", (11, 0), ANY, Uncommon),
 	// activity
-	"⚽",
-	"🏀",
-	"🏈",
-	"⚾",
-	"🥎",
-	"🎾",
-	"🏐",
-	"🏉",
-	"🥏",
-	"🎱",
-	"🪀",
-	"🏓",
-	"🏸",
-	"🏒",
-	"🏑",
-	"🥍",
-	"🏏",
-	"🪃",
-	"🥅",
-	"⛳",
-	"🪁",
-	"🛝",
-	"🏹",
-	"🎣",
-	"🤿",
-	"🥊",
-	"🥋",
-	"🎽",
-	"🛹",
-	"🛼",
-	"🛷",
-	"⛸️",
-	"🥌",
-	"🎿",
-	"⛷️",
-	"🏂",
-	"🪂",
-	"🏋️",
-	"🏋️‍♀️",
-	"🏋️‍♂️",
-	"🤼",
-	"🤼‍♀️",
-	"🤼‍♂️",
-	"🤸",
-	"🤸‍♀️",
-	"🤸‍♂️",
-	"⛹️",
-	"⛹️‍♀️",
-	"⛹️‍♂️",
-	"🤺",
-	"🤾",
-	"🤾‍♀️",
-	"🤾‍♂️",
-	"🏌️",
-	"🏌️‍♀️",
-	"🏌️‍♂️",
-	"🏇",
-	"🧘",
-	"🧘‍♀️",
-	"🧘‍♂️",
-	"🏄",
-	"🏄‍♀️",
-	"🏄‍♂️",
-	"🏊",
-	"🏊‍♀️",
-	"🏊‍♂️",
-	"🤽",
-	"🤽‍♀️",
-	"🤽‍♂️",
-	"🚣",
-	"🚣‍♀️",
-	"🚣‍♂️",
-	"🧗",
-	"🧗‍♀️",
-	"🧗‍♂️",
-	"🚵",
-	"🚵‍♀️",
-	"🚵‍♂️",
-	"🚴",
-	"🚴‍♀️",
-	"🚴‍♂️",
-	"🏆",
-	"🥇",
-	"🥈",
-	"🥉",
-	"🏅",
-	"🎖️",
-	"🏵️",
-	"🎗️",
-	"🎫",
-	"🎟️",
-	"🎪",
-	"🤹",
-	"🤹‍♀️",
-	"🤹‍♂️",
-	"🎭",
-	"🩰",
-	"🎨",
-	"🎬",
-	"🎤",
-	"🎧",
-	"🎼",
-	"🎹",
-	"🥁",
-	"🪘",
-	"🎷",
-	"🎺",
-	"🪗",
-	"🎸",
-	"🪕",
-	"🎻",
-	"🎲",
-	"♟️",
-	"🎯",
-	"🎳",
-	"🎮",
-	"🎰",
-	"🧩",
+	emoji("⚽", "soccer ball", &["soccer_ball", "soccer"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🏀", "basketball", &["basketball"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🏈", "american football", &["american_football", "football"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("⚾", "baseball", &["baseball"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🥎", "softball", &["softball"], Activity, "sport", (11, 0), ANY, Uncommon),
+	emoji("🎾", "tennis", &["tennis"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🏐", "volleyball", &["volleyball"], Activity, "sport", (1, 0), ANY, Common),
+	emoji("🏉", "rugby football", &["rugby_football"], Activity, "sport", (1, 0), ANY, Common),
+	emoji("🥏", "flying disc", &["flying_disc"], Activity, "sport", (11, 0), ANY, Uncommon),
+	emoji("🎱", "pool 8 ball", &["pool_8_ball", "8ball"], Activity, "game", (0, 6), ANY, Common),
+	emoji("🪀", "yo-yo", &["yo_yo"], Activity, "game", (12, 0), ANY, Uncommon),
+	emoji("🏓", "ping pong", &["ping_pong"], Activity, "sport", (1, 0), ANY, Common),
+	emoji("🏸", "badminton", &["badminton"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🏒", "ice hockey", &["ice_hockey"], Activity, "sport", (1, 0), ANY, Common),
+	emoji("🏑", "field hockey", &["field_hockey"], Activity, "sport", (1, 0), ANY, Common),
+	emoji("🥍", "lacrosse", &["lacrosse"], Activity, "sport", (11, 0), ANY, Uncommon),
+	emoji("🏏", "cricket game", &["cricket_game"], Activity, "sport", (1, 0), ANY, Common),
+	emoji("🪃", "boomerang", &["boomerang"], Activity, "tool", (13, 0), ANY, Rare),
+	emoji("🥅", "goal net", &["goal_net"], Activity, "sport", (3, 0), ANY, Common),
+	emoji("⛳", "flag in hole", &["flag_in_hole"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🪁", "kite", &["kite"], Activity, "game", (12, 0), ANY, Uncommon),
+	emoji("🛝", "playground slide", &["playground_slide"], Activity, "place-other", (14, 0), ANY, Rare),
+	emoji("🏹", "bow and arrow", &["bow_and_arrow"], Activity, "tool", (1, 0), ANY, Common),
+	emoji("🎣", "fishing pole", &["fishing_pole"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🤿", "diving mask", &["diving_mask"], Activity, "sport", (12, 0), ANY, Uncommon),
+	emoji("🥊", "boxing glove", &["boxing_glove"], Activity, "sport", (3, 0), ANY, Common),
+	emoji("🥋", "martial arts uniform", &["martial_arts_uniform"], Activity, "sport", (3, 0), ANY, Common),
+	emoji("🎽", "running shirt", &["running_shirt"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🛹", "skateboard", &["skateboard"], Activity, "transport-ground", (11, 0), ANY, Uncommon),
+	emoji("🛼", "roller skate", &["roller_skate"], Activity, "transport-ground", (13, 0), ANY, Rare),
+	emoji("🛷", "sled", &["sled"], Activity, "sport", (5, 0), ANY, Common),
+	emoji("⛸️", "ice skate", &["ice_skate"], Activity, "sport", (0, 7), ANY, Common),
+	emoji("🥌", "curling stone", &["curling_stone"], Activity, "sport", (5, 0), ANY, Common),
+	emoji("🎿", "skis", &["skis"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("⛷️", "skier", &["skier"], Activity, "person-sport", (0, 7), ANY, Common),
+	emoji("🏂", "snowboarder", &["snowboarder"], Activity, "person-sport", (0, 6), ANY, Common),
+	emoji("🪂", "parachute", &["parachute"], Activity, "transport-air", (12, 0), ANY, Uncommon),
+	emoji("🏋️", "person lifting weights", &["person_lifting_weights"], Activity, "person-sport", (0, 7), ANY, Common),
+	emoji("🏋️‍♀️", "woman lifting weights", &["woman_lifting_weights"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🏋️‍♂️", "man lifting weights", &["man_lifting_weights"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤼", "people wrestling", &["people_wrestling"], Activity, "person-sport", (3, 0), ANY, Common),
+	emoji("🤼‍♀️", "women wrestling", &["women_wrestling"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤼‍♂️", "men wrestling", &["men_wrestling"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤸", "person cartwheeling", &["person_cartwheeling"], Activity, "person-sport", (3, 0), ANY, Common),
+	emoji("🤸‍♀️", "woman cartwheeling", &["woman_cartwheeling"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤸‍♂️", "man cartwheeling", &["man_cartwheeling"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("⛹️", "person bouncing ball", &["person_bouncing_ball"], Activity, "person-sport", (0, 7), ANY, Common),
+	emoji("⛹️‍♀️", "woman bouncing ball", &["woman_bouncing_ball"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("⛹️‍♂️", "man bouncing ball", &["man_bouncing_ball"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤺", "person fencing", &["person_fencing"], Activity, "person-sport", (0, 6), ANY, Common),
+	emoji("🤾", "person playing handball", &["person_playing_handball"], Activity, "person-sport", (3, 0), ANY, Common),
+	emoji("🤾‍♀️", "woman playing handball", &["woman_playing_handball"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤾‍♂️", "man playing handball", &["man_playing_handball"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🏌️", "person golfing", &["person_golfing"], Activity, "person-sport", (0, 7), ANY, Common),
+	emoji("🏌️‍♀️", "woman golfing", &["woman_golfing"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🏌️‍♂️", "man golfing", &["man_golfing"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🏇", "horse racing", &["horse_racing"], Activity, "person-sport", (1, 0), ANY, Common),
+	emoji("🧘", "person in lotus position", &["person_in_lotus_position"], Activity, "person-resting", (5, 0), ANY, Common),
+	emoji("🧘‍♀️", "woman in lotus position", &["woman_in_lotus_position"], Activity, "person-resting", (5, 0), ANY, Common),
+	emoji("🧘‍♂️", "man in lotus position", &["man_in_lotus_position"], Activity, "person-resting", (5, 0), ANY, Common),
+	emoji("🏄", "person surfing", &["person_surfing"], Activity, "person-sport", (0, 6), ANY, Common),
+	emoji("🏄‍♀️", "woman surfing", &["woman_surfing"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🏄‍♂️", "man surfing", &["man_surfing"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🏊", "person swimming", &["person_swimming"], Activity, "person-sport", (0, 6), ANY, Common),
+	emoji("🏊‍♀️", "woman swimming", &["woman_swimming"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🏊‍♂️", "man swimming", &["man_swimming"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤽", "person playing water polo", &["person_playing_water_polo"], Activity, "person-sport", (3, 0), ANY, Common),
+	emoji("🤽‍♀️", "woman playing water polo", &["woman_playing_water_polo"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤽‍♂️", "man playing water polo", &["man_playing_water_polo"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🚣", "person rowing boat", &["person_rowing_boat"], Activity, "person-sport", (1, 0), ANY, Common),
+	emoji("🚣‍♀️", "woman rowing boat", &["woman_rowing_boat"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🚣‍♂️", "man rowing boat", &["man_rowing_boat"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🧗", "person climbing", &["person_climbing"], Activity, "person-activity", (5, 0), ANY, Common),
+	emoji("🧗‍♀️", "woman climbing", &["woman_climbing"], Activity, "person-activity", (5, 0), ANY, Common),
+	emoji("🧗‍♂️", "man climbing", &["man_climbing"], Activity, "person-activity", (5, 0), ANY, Common),
+	emoji("🚵", "person mountain biking", &["person_mountain_biking"], Activity, "person-sport", (1, 0), ANY, Common),
+	emoji("🚵‍♀️", "woman mountain biking", &["woman_mountain_biking"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🚵‍♂️", "man mountain biking", &["man_mountain_biking"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🚴", "person biking", &["person_biking"], Activity, "person-sport", (1, 0), ANY, Common),
+	emoji("🚴‍♀️", "woman biking", &["woman_biking"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🚴‍♂️", "man biking", &["man_biking"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🏆", "trophy", &["trophy"], Activity, "award-medal", (0, 6), ANY, Legendary),
+	emoji("🥇", "1st place medal", &["1st_place_medal", "first_place"], Activity, "award-medal", (3, 0), NO_MIRROR, Common),
+	emoji("🥈", "2nd place medal", &["2nd_place_medal", "second_place"], Activity, "award-medal", (3, 0), NO_MIRROR, Common),
+	emoji("🥉", "3rd place medal", &["3rd_place_medal", "third_place"], Activity, "award-medal", (3, 0), NO_MIRROR, Common),
+	emoji("🏅", "sports medal", &["sports_medal"], Activity, "award-medal", (1, 0), ANY, Common),
+	emoji("🎖️", "military medal", &["military_medal"], Activity, "award-medal", (1, 0), ANY, Common),
+	emoji("🏵️", "rosette", &["rosette"], Activity, "plant-flower", (1, 0), ANY, Common),
+	emoji("🎗️", "reminder ribbon", &["reminder_ribbon"], Activity, "event", (1, 0), ANY, Common),
+	emoji("🎫", "ticket", &["ticket"], Activity, "event", (1, 0), ANY, Common),
+	emoji("🎟️", "admission tickets", &["admission_tickets"], Activity, "event", (1, 0), ANY, Common),
+	emoji("🎪", "circus tent", &["circus_tent"], Activity, "place-other", (0, 6), ANY, Common),
+	emoji("🤹", "person juggling", &["person_juggling"], Activity, "person-sport", (3, 0), ANY, Common),
+	emoji("🤹‍♀️", "woman juggling", &["woman_juggling"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🤹‍♂️", "man juggling", &["man_juggling"], Activity, "person-sport", (4, 0), ANY, Common),
+	emoji("🎭", "performing arts", &["performing_arts"], Activity, "arts & crafts", (0, 6), ANY, Common),
+	emoji("🩰", "ballet shoes", &["ballet_shoes"], Activity, "clothing", (12, 0), ANY, Uncommon),
+	emoji("🎨", "artist palette", &["artist_palette", "art"], Activity, "arts & crafts", (0, 6), ANY, Common),
+	emoji("🎬", "clapper board", &["clapper_board"], Activity, "light & video", (0, 6), NO_MIRROR, Common),
+	emoji("🎤", "microphone", &["microphone"], Activity, "music", (0, 6), ANY, Common),
+	emoji("🎧", "headphone", &["headphone", "headphones"], Activity, "music", (0, 6), ANY, Common),
+	emoji("🎼", "musical score", &["musical_score"], Activity, "music", (0, 6), NO_MIRROR, Common),
+	emoji("🎹", "musical keyboard", &["musical_keyboard"], Activity, "musical-instrument", (0, 6), ANY, Common),
+	emoji("🥁", "drum", &["drum"], Activity, "musical-instrument", (3, 0), ANY, Common),
+	emoji("🪘", "long drum", &["long_drum"], Activity, "musical-instrument", (13, 0), ANY, Rare),
+	emoji("🎷", "saxophone", &["saxophone"], Activity, "musical-instrument", (0, 6), ANY, Common),
+	emoji("🎺", "trumpet", &["trumpet"], Activity, "musical-instrument", (0, 6), ANY, Common),
+	emoji("🪗", "accordion", &["accordion"], Activity, "musical-instrument", (13, 0), NO_MIRROR, Rare),
+	emoji("🎸", "guitar", &["guitar"], Activity, "musical-instrument", (0, 6), ANY, Common),
+	emoji("🪕", "banjo", &["banjo"], Activity, "musical-instrument", (12, 0), ANY, Uncommon),
+	emoji("🎻", "violin", &["violin"], Activity, "musical-instrument", (0, 6), ANY, Common),
+	emoji("🎲", "game die", &["game_die"], Activity, "game", (0, 6), ANY, Common),
+	emoji("♟️", "chess pawn", &["chess_pawn"], Activity, "game", (11, 0), ANY, Uncommon),
+	emoji("🎯", "bullseye", &["bullseye", "dart"], Activity, "game", (0, 6), ANY, Common),
+	emoji("🎳", "bowling", &["bowling"], Activity, "sport", (0, 6), ANY, Common),
+	emoji("🎮", "video game", &["video_game", "video_game_controller"], Activity, "game", (0, 6), ANY, Common),
+	emoji("🎰", "slot machine", &["slot_machine"], Activity, "game", (0, 6), NO_MIRROR, Common),
+	emoji("🧩", "puzzle piece", &["puzzle_piece", "jigsaw"], Activity, "game", (11, 0), ANY, Uncommon),
 	// travel
-	"🚗",
-	"🚕",
-	"🚙",
-	"🛻",
-	"🚌",
-	"🚎",
-	"🏎️",
-	"🚓",
-	"🚑",
-	"🚒",
-	"🚐",
-	"🚚",
-	"🚛",
-	"🚜",
-	"🦯",
-	"🩼",
-	"🦽",
-	"🦼",
-	"🛴",
-	"🚲",
-	"🛵",
-	"🏍️",
-	"🛺",
-	"🛞",
-	"🚨",
-	"🚔",
-	"🚍",
-	"🚘",
-	"🚖",
-	"🚡",
-	"🚠",
-	"🚟",
-	"🚃",
-	"🚋",
-	"🚞",
-	"🚝",
-	"🚄",
-	"🚅",
-	"🚈",
-	"🚂",
-	"🚆",
-	"🚇",
-	"🚊",
-	"🚉",
-	"✈️",
-	"🛫",
-	"🛬",
-	"🛩️",
-	"💺",
-	"🛰️",
-	"🚀",
-	"🛸",
-	"🚁",
-	"🛶",
-	"⛵",
-	"🚤",
-	"🛥️",
-	"🛳️",
-	"⛴️",
-	"🚢",
-	"🛟",
-	"⚓",
-	"🪝",
-	"⛽",
-	"🚧",
-	"🚦",
-	"🚥",
-	"🚏",
-	"🗺️",
-	"🗿",
-	"🗽",
-	"🗼",
-	"🏰",
-	"🏯",
-	"🏟️",
-	"🎡",
-	"🎢",
-	"🎠",
-	"⛲",
-	"⛱️",
-	"🏖️",
-	"🏝️",
-	"🏜️",
-	"🌋",
-	"⛰️",
-	"🏔️",
-	"🗻",
-	"🏕️",
-	"⛺",
-	"🏠",
-	"🏡",
-	"🏘️",
-	"🏚️",
-	"🛖",
-	"🏗️",
-	"🏭",
-	"🏢",
-	"🏬",
-	"🏣",
-	"🏤",
-	"🏥",
-	"🏦",
-	"🏨",
-	"🏪",
-	"🏫",
-	"🏩",
-	"💒",
-	"🏛️",
-	"⛪",
-	"🕌",
-	"🕍",
-	"🛕",
-	"🕋",
-	"⛩️",
-	"🛤️",
-	"🛣️",
-	"🗾",
-	"🎑",
-	"🏞️",
-	"🌅",
-	"🌄",
-	"🌠",
-	"🎇",
-	"🎆",
-	"🌇",
-	"🌆",
-	"🏙️",
-	"🌃",
-	"🌌",
-	"🌉",
-	"🌁",
+	emoji("🚗", "automobile", &["automobile", "red_car", "car"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚕", "taxi", &["taxi"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚙", "sport utility vehicle", &["sport_utility_vehicle"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🛻", "pickup truck", &["pickup_truck"], Travel, "transport-ground", (13, 0), ANY, Rare),
+	emoji("🚌", "bus", &["bus"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚎", "trolleybus", &["trolleybus"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🏎️", "racing car", &["racing_car"], Travel, "transport-ground", (0, 7), NO_MIRROR, Common),
+	emoji("🚓", "police car", &["police_car"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚑", "ambulance", &["ambulance"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚒", "fire engine", &["fire_engine"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚐", "minibus", &["minibus"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚚", "delivery truck", &["delivery_truck"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚛", "articulated lorry", &["articulated_lorry"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚜", "tractor", &["tractor"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🦯", "white cane", &["white_cane"], Travel, "tool", (12, 0), ANY, Uncommon),
+	emoji("🩼", "crutch", &["crutch"], Travel, "medical", (14, 0), ANY, Rare),
+	emoji("🦽", "manual wheelchair", &["manual_wheelchair"], Travel, "transport-ground", (12, 0), ANY, Uncommon),
+	emoji("🦼", "motorized wheelchair", &["motorized_wheelchair"], Travel, "transport-ground", (12, 0), ANY, Uncommon),
+	emoji("🛴", "kick scooter", &["kick_scooter"], Travel, "transport-ground", (3, 0), ANY, Common),
+	emoji("🚲", "bicycle", &["bicycle", "bike"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🛵", "motor scooter", &["motor_scooter"], Travel, "transport-ground", (3, 0), ANY, Common),
+	emoji("🏍️", "motorcycle", &["motorcycle"], Travel, "transport-ground", (0, 7), ANY, Common),
+	emoji("🛺", "auto rickshaw", &["auto_rickshaw"], Travel, "transport-ground", (12, 0), ANY, Uncommon),
+	emoji("🛞", "wheel", &["wheel"], Travel, "transport-ground", (14, 0), ANY, Rare),
+	emoji("🚨", "police car light", &["police_car_light", "rotating_light"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚔", "oncoming police car", &["oncoming_police_car"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚍", "oncoming bus", &["oncoming_bus"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚘", "oncoming automobile", &["oncoming_automobile"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚖", "oncoming taxi", &["oncoming_taxi"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚡", "aerial tramway", &["aerial_tramway"], Travel, "transport-air", (1, 0), ANY, Common),
+	emoji("🚠", "mountain cableway", &["mountain_cableway"], Travel, "transport-air", (1, 0), ANY, Common),
+	emoji("🚟", "suspension railway", &["suspension_railway"], Travel, "transport-air", (1, 0), ANY, Common),
+	emoji("🚃", "railway car", &["railway_car"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚋", "tram car", &["tram_car"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚞", "mountain railway", &["mountain_railway"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚝", "monorail", &["monorail"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚄", "high-speed train", &["high_speed_train"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚅", "bullet train", &["bullet_train"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚈", "light rail", &["light_rail"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚂", "locomotive", &["locomotive"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚆", "train", &["train"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚇", "metro", &["metro"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚊", "tram", &["tram"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚉", "station", &["station"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("✈️", "airplane", &["airplane"], Travel, "transport-air", (0, 6), ANY, Common),
+	emoji("🛫", "airplane departure", &["airplane_departure"], Travel, "transport-air", (1, 0), ANY, Common),
+	emoji("🛬", "airplane arrival", &["airplane_arrival"], Travel, "transport-air", (1, 0), ANY, Common),
+	emoji("🛩️", "small airplane", &["small_airplane"], Travel, "transport-air", (1, 0), ANY, Common),
+	emoji("💺", "seat", &["seat"], Travel, "transport-air", (0, 6), ANY, Common),
+	emoji("🛰️", "satellite", &["satellite"], Travel, "transport-air", (1, 0), ANY, Common),
+	emoji("🚀", "rocket", &["rocket"], Travel, "transport-air", (0, 6), ANY, Common),
+	emoji("🛸", "flying saucer", &["flying_saucer"], Travel, "transport-air", (5, 0), ANY, Legendary),
+	emoji("🚁", "helicopter", &["helicopter"], Travel, "transport-air", (1, 0), ANY, Common),
+	emoji("🛶", "canoe", &["canoe"], Travel, "transport-water", (3, 0), ANY, Common),
+	emoji("⛵", "sailboat", &["sailboat"], Travel, "transport-water", (0, 6), ANY, Common),
+	emoji("🚤", "speedboat", &["speedboat"], Travel, "transport-water", (0, 6), ANY, Common),
+	emoji("🛥️", "motor boat", &["motor_boat"], Travel, "transport-water", (1, 0), ANY, Common),
+	emoji("🛳️", "passenger ship", &["passenger_ship"], Travel, "transport-water", (1, 0), ANY, Common),
+	emoji("⛴️", "ferry", &["ferry"], Travel, "transport-water", (1, 0), ANY, Common),
+	emoji("🚢", "ship", &["ship"], Travel, "transport-water", (0, 6), ANY, Common),
+	emoji("🛟", "ring buoy", &["ring_buoy"], Travel, "transport-water", (14, 0), ANY, Rare),
+	emoji("⚓", "anchor", &["anchor"], Travel, "transport-water", (0, 6), ANY, Common),
+	emoji("🪝", "hook", &["hook"], Travel, "tool", (13, 0), ANY, Rare),
+	emoji("⛽", "fuel pump", &["fuel_pump"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚧", "construction", &["construction"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚦", "vertical traffic light", &["vertical_traffic_light"], Travel, "transport-ground", (1, 0), ANY, Common),
+	emoji("🚥", "horizontal traffic light", &["horizontal_traffic_light"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🚏", "bus stop", &["bus_stop"], Travel, "transport-ground", (0, 6), ANY, Common),
+	emoji("🗺️", "world map", &["world_map"], Travel, "place-map", (1, 0), ANY, Common),
+	emoji("🗿", "moai", &["moai", "moyai"], Travel, "other-object", (0, 6), ANY, Legendary),
+	emoji("🗽", "Statue of Liberty", &["statue_of_liberty"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🗼", "Tokyo tower", &["tokyo_tower"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏰", "castle", &["castle", "european_castle"], Travel, "place-building", (0, 6), ANY, Legendary),
+	emoji("🏯", "Japanese castle", &["japanese_castle"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏟️", "stadium", &["stadium"], Travel, "place-building", (1, 0), ANY, Common),
+	emoji("🎡", "ferris wheel", &["ferris_wheel"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🎢", "roller coaster", &["roller_coaster"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🎠", "carousel horse", &["carousel_horse"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("⛲", "fountain", &["fountain"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("⛱️", "umbrella on ground", &["umbrella_on_ground"], Travel, "sky & weather", (0, 7), ANY, Common),
+	emoji("🏖️", "beach with umbrella", &["beach_with_umbrella"], Travel, "place-geographic", (1, 0), ANY, Common),
+	emoji("🏝️", "desert island", &["desert_island"], Travel, "place-geographic", (1, 0), ANY, Common),
+	emoji("🏜️", "desert", &["desert"], Travel, "place-geographic", (1, 0), ANY, Common),
+	emoji("🌋", "volcano", &["volcano"], Travel, "place-geographic", (0, 6), ANY, Legendary),
+	emoji("⛰️", "mountain", &["mountain"], Travel, "place-geographic", (0, 7), ANY, Common),
+	emoji("🏔️", "snow-capped mountain", &["snow_capped_mountain"], Travel, "place-geographic", (0, 7), ANY, Common),
+	emoji("🗻", "mount fuji", &["mount_fuji"], Travel, "place-geographic", (0, 6), ANY, Common),
+	emoji("🏕️", "camping", &["camping"], Travel, "place-geographic", (1, 0), ANY, Common),
+	emoji("⛺", "tent", &["tent"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🏠", "house", &["house"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏡", "house with garden", &["house_with_garden"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏘️", "houses", &["houses"], Travel, "place-building", (1, 0), ANY, Common),
+	emoji("🏚️", "derelict house", &["derelict_house"], Travel, "place-building", (1, 0), ANY, Common),
+	emoji("🛖", "hut", &["hut"], Travel, "place-building", (13, 0), ANY, Rare),
+	emoji("🏗️", "building construction", &["building_construction"], Travel, "place-building", (1, 0), ANY, Common),
+	emoji("🏭", "factory", &["factory"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏢", "office building", &["office_building"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏬", "department store", &["department_store"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏣", "Japanese post office", &["japanese_post_office"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏤", "post office", &["post_office"], Travel, "place-building", (1, 0), ANY, Common),
+	emoji("🏥", "hospital", &["hospital"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏦", "bank", &["bank"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏨", "hotel", &["hotel"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏪", "convenience store", &["convenience_store"], Travel, "place-building", (0, 6), NO_MIRROR, Common),
+	emoji("🏫", "school", &["school"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏩", "love hotel", &["love_hotel"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("💒", "wedding", &["wedding"], Travel, "place-building", (0, 6), ANY, Common),
+	emoji("🏛️", "classical building", &["classical_building"], Travel, "place-building", (1, 0), ANY, Common),
+	emoji("⛪", "church", &["church"], Travel, "place-religious", (0, 6), ANY, Common),
+	emoji("🕌", "mosque", &["mosque"], Travel, "place-religious", (1, 0), ANY, Common),
+	emoji("🕍", "synagogue", &["synagogue"], Travel, "place-religious", (1, 0), ANY, Common),
+	emoji("🛕", "hindu temple", &["hindu_temple"], Travel, "place-religious", (12, 0), ANY, Uncommon),
+	emoji("🕋", "kaaba", &["kaaba"], Travel, "place-religious", (1, 0), ANY, Common),
+	emoji("⛩️", "shinto shrine", &["shinto_shrine"], Travel, "place-religious", (0, 7), ANY, Common),
+	emoji("🛤️", "railway track", &["railway_track"], Travel, "transport-ground", (0, 7), ANY, Common),
+	emoji("🛣️", "motorway", &["motorway"], Travel, "transport-ground", (0, 7), ANY, Common),
+	emoji("🗾", "map of Japan", &["map_of_japan"], Travel, "place-map", (0, 6), NO_MIRROR, Common),
+	emoji("🎑", "moon viewing ceremony", &["moon_viewing_ceremony"], Travel, "event", (0, 6), ANY, Common),
+	emoji("🏞️", "national park", &["national_park"], Travel, "place-geographic", (1, 0), ANY, Common),
+	emoji("🌅", "sunrise", &["sunrise"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🌄", "sunrise over mountains", &["sunrise_over_mountains"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🌠", "shooting star", &["shooting_star"], Travel, "sky & weather", (0, 6), ANY, Common),
+	emoji("🎇", "sparkler", &["sparkler"], Travel, "event", (0, 6), ANY, Legendary),
+	emoji("🎆", "fireworks", &["fireworks"], Travel, "event", (0, 6), ANY, Legendary),
+	emoji("🌇", "sunset", &["sunset"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🌆", "cityscape at dusk", &["cityscape_at_dusk"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🏙️", "cityscape", &["cityscape"], Travel, "place-other", (1, 0), ANY, Common),
+	emoji("🌃", "night with stars", &["night_with_stars"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🌌", "milky way", &["milky_way"], Travel, "sky & weather", (0, 6), ANY, Legendary),
+	emoji("🌉", "bridge at night", &["bridge_at_night"], Travel, "place-other", (0, 6), ANY, Common),
+	emoji("🌁", "foggy", &["foggy"], Travel, "place-other", (0, 6), ANY, Common),
 	// objects
-	"⌚",
-	"📱",
-	"📲",
-	"💻",
-	"⌨️",
-	"🖥️",
-	"🖨️",
-	"🖱️",
-	"🖲️",
-	"🕹️",
-	"🗜️",
-	"💽",
-	"💾",
-	"💿",
-	"📀",
-	"📼",
-	"📷",
-	"📸",
-	"📹",
-	"🎥",
-	"📽️",
-	"🎞️",
-	"📞",
-	"☎️",
-	"📟",
-	"📠",
-	"📺",
-	"📻",
-	"🎙️",
-	"🎚️",
-	"🎛️",
-	"🧭",
-	"⏱️",
-	"⏲️",
-	"⏰",
-	"🕰️",
-	"⌛",
-	"⏳",
-	"📡",
-	"🔋",
-	"🪫",
-	"🔌",
-	"💡",
-	"🔦",
-	"🕯️",
-	"🪔",
-	"🧯",
-	"🛢️",
-	"💸",
-	"💵",
-	"💴",
-	"💶",
-	"💷",
-	"🪙",
-	"💰",
-	"💳",
-	"🪪",
-	"💎",
-	"⚖️",
-	"🪜",
-	"🧰",
-	"🪛",
-	"🔧",
-	"🔨",
-	"⚒️",
-	"🛠️",
-	"⛏️",
-	"🪚",
-	"🔩",
-	"⚙️",
-	"🪤",
-	"🧱",
-	"⛓️",
-	"🧲",
-	"🔫",
-	"💣",
-	"🧨",
-	"🪓",
-	"🔪",
-	"🗡️",
-	"⚔️",
-	"🛡️",
-	"🚬",
-	"⚰️",
-	"🪦",
-	"⚱️",
-	"🏺",
-	"🔮",
-	"📿",
-	"🧿",
-	"🪬",
-	"💈",
-	"⚗️",
-	"🔭",
-	"🔬",
-	"🕳️",
-	"🩻",
-	"🩹",
-	"🩺",
-	"💊",
-	"💉",
-	"🩸",
-	"🧬",
-	"🦠",
-	"🧫",
-	"🧪",
-	"🌡️",
-	"🧹",
-	"🪠",
-	"🧺",
-	"🧻",
-	"🚽",
-	"🚰",
-	"🚿",
-	"🛁",
-	"🛀",
-	"🧼",
-	"🪥",
-	"🪒",
-	"🧽",
-	"🪣",
-	"🧴",
-	"🛎️",
-	"🔑",
-	"🗝️",
-	"🚪",
-	"🪑",
-	"🛋️",
-	"🛏️",
-	"🛌",
-	"🧸",
-	"🪆",
-	"🖼️",
-	"🪞",
-	"🪟",
-	"🛍️",
-	"🛒",
-	"🎁",
-	"🎈",
-	"🎏",
-	"🎀",
-	"🪄",
-	"🪅",
-	"🎊",
-	"🎉",
-	"🎎",
-	"🏮",
-	"🎐",
-	"🪩",
-	"🧧",
-	"✉️",
-	"📩",
-	"📨",
-	"📧",
-	"💌",
-	"📥",
-	"📤",
-	"📦",
-	"🏷️",
-	"🪧",
-	"📪",
-	"📫",
-	"📬",
-	"📭",
-	"📮",
-	"📯",
-	"📜",
-	"📃",
-	"📄",
-	"📑",
-	"🧾",
-	"📊",
-	"📈",
-	"📉",
-	"🗒️",
-	"🗓️",
-	"📆",
-	"📅",
-	"🗑️",
-	"📇",
-	"🗃️",
-	"🗳️",
-	"🗄️",
-	"📋",
-	"📁",
-	"📂",
-	"🗂️",
-	"🗞️",
-	"📰",
-	"📓",
-	"📔",
-	"📒",
-	"📕",
-	"📗",
-	"📘",
-	"📙",
-	"📚",
-	"📖",
-	"🔖",
-	"🧷",
-	"🔗",
-	"📎",
-	"🖇️",
-	"📐",
-	"📏",
-	"🧮",
-	"📌",
-	"📍",
-	"✂️",
-	"🖊️",
-	"🖋️",
-	"✒️",
-	"🖌️",
-	"🖍️",
-	"📝",
-	"✏️",
-	"🔍",
-	"🔎",
-	"🔏",
-	"🔐",
-	"🔒",
-	"🔓",
+	emoji("⌚", "watch", &["watch"], Objects, "time", (0, 6), ANY, Common),
+	emoji("📱", "mobile phone", &["mobile_phone", "iphone"], Objects, "phone", (0, 6), ANY, Common),
+	emoji("📲", "mobile phone with arrow", &["mobile_phone_with_arrow"], Objects, "phone", (0, 6), ANY, Common),
+	emoji("💻", "laptop", &["laptop", "computer"], Objects, "computer", (0, 6), ANY, Common),
+	emoji("⌨️", "keyboard", &["keyboard"], Objects, "computer", (1, 0), ANY, Common),
+	emoji("🖥️", "desktop computer", &["desktop_computer", "desktop"], Objects, "computer", (0, 7), ANY, Common),
+	emoji("🖨️", "printer", &["printer"], Objects, "computer", (0, 7), ANY, Common),
+	emoji("🖱️", "computer mouse", &["computer_mouse"], Objects, "computer", (0, 7), ANY, Common),
+	emoji("🖲️", "trackball", &["trackball"], Objects, "computer", (0, 7), ANY, Common),
+	emoji("🕹️", "joystick", &["joystick"], Objects, "game", (0, 7), ANY, Common),
+	emoji("🗜️", "clamp", &["clamp"], Objects, "tool", (0, 7), ANY, Common),
+	emoji("💽", "computer disk", &["computer_disk"], Objects, "computer", (0, 6), NO_MIRROR, Common),
+	emoji("💾", "floppy disk", &["floppy_disk"], Objects, "computer", (0, 6), NO_MIRROR, Common),
+	emoji("💿", "optical disk", &["optical_disk", "cd"], Objects, "computer", (0, 6), ANY, Common),
+	emoji("📀", "dvd", &["dvd"], Objects, "computer", (0, 6), ANY, Common),
+	emoji("📼", "videocassette", &["videocassette"], Objects, "light & video", (0, 6), ANY, Common),
+	emoji("📷", "camera", &["camera"], Objects, "light & video", (0, 6), NO_MIRROR, Common),
+	emoji("📸", "camera with flash", &["camera_with_flash"], Objects, "light & video", (1, 0), NO_MIRROR, Common),
+	emoji("📹", "video camera", &["video_camera"], Objects, "light & video", (0, 6), NO_MIRROR, Common),
+	emoji("🎥", "movie camera", &["movie_camera"], Objects, "light & video", (0, 6), NO_MIRROR, Common),
+	emoji("📽️", "film projector", &["film_projector"], Objects, "light & video", (0, 7), NO_MIRROR, Common),
+	emoji("🎞️", "film frames", &["film_frames"], Objects, "light & video", (1, 0), ANY, Common),
+	emoji("📞", "telephone receiver", &["telephone_receiver"], Objects, "phone", (0, 6), ANY, Common),
+	emoji("☎️", "telephone", &["telephone", "phone"], Objects, "phone", (0, 6), ANY, Common),
+	emoji("📟", "pager", &["pager"], Objects, "phone", (0, 6), NO_MIRROR, Common),
+	emoji("📠", "fax machine", &["fax_machine"], Objects, "phone", (0, 6), NO_MIRROR, Common),
+	emoji("📺", "television", &["television", "tv"], Objects, "light & video", (0, 6), ANY, Common),
+	emoji("📻", "radio", &["radio"], Objects, "music", (0, 6), ANY, Common),
+	emoji("🎙️", "studio microphone", &["studio_microphone"], Objects, "music", (1, 0), ANY, Common),
+	emoji("🎚️", "level slider", &["level_slider"], Objects, "music", (1, 0), ANY, Common),
+	emoji("🎛️", "control knobs", &["control_knobs"], Objects, "music", (1, 0), ANY, Common),
+	emoji("🧭", "compass", &["compass"], Objects, "place-map", (11, 0), ANY, Uncommon),
+	emoji("⏱️", "stopwatch", &["stopwatch"], Objects, "time", (1, 0), ANY, Common),
+	emoji("⏲️", "timer clock", &["timer_clock"], Objects, "time", (1, 0), ANY, Common),
+	emoji("⏰", "alarm clock", &["alarm_clock"], Objects, "time", (0, 6), ANY, Common),
+	emoji("🕰️", "mantelpiece clock", &["mantelpiece_clock"], Objects, "time", (0, 7), ANY, Common),
+	emoji("⌛", "hourglass done", &["hourglass_done"], Objects, "time", (0, 6), ANY, Common),
+	emoji("⏳", "hourglass not done", &["hourglass_not_done"], Objects, "time", (0, 6), ANY, Common),
+	emoji("📡", "satellite antenna", &["satellite_antenna"], Objects, "science", (0, 6), ANY, Common),
+	emoji("🔋", "battery", &["battery"], Objects, "computer", (0, 6), ANY, Common),
+	emoji("🪫", "low battery", &["low_battery"], Objects, "computer", (14, 0), ANY, Rare),
+	emoji("🔌", "electric plug", &["electric_plug"], Objects, "computer", (0, 6), ANY, Common),
+	emoji("💡", "light bulb", &["light_bulb", "bulb"], Objects, "light & video", (0, 6), ANY, Common),
+	emoji("🔦", "flashlight", &["flashlight"], Objects, "light & video", (0, 6), ANY, Common),
+	emoji("🕯️", "candle", &["candle"], Objects, "light & video", (0, 7), ANY, Common),
+	emoji("🪔", "diya lamp", &["diya_lamp"], Objects, "light & video", (12, 0), ANY, Uncommon),
+	emoji("🧯", "fire extinguisher", &["fire_extinguisher"], Objects, "household", (11, 0), ANY, Uncommon),
+	emoji("🛢️", "oil drum", &["oil_drum"], Objects, "transport-ground", (1, 0), ANY, Common),
+	emoji("💸", "money with wings", &["money_with_wings"], Objects, "money", (0, 6), NO_MIRROR, Common),
+	emoji("💵", "dollar banknote", &["dollar_banknote", "dollar"], Objects, "money", (0, 6), NO_MIRROR, Common),
+	emoji("💴", "yen banknote", &["yen_banknote"], Objects, "money", (0, 6), NO_MIRROR, Common),
+	emoji("💶", "euro banknote", &["euro_banknote"], Objects, "money", (1, 0), NO_MIRROR, Common),
+	emoji("💷", "pound banknote", &["pound_banknote"], Objects, "money", (1, 0), NO_MIRROR, Common),
+	emoji("🪙", "coin", &["coin"], Objects, "money", (13, 0), ANY, Rare),
+	emoji("💰", "money bag", &["money_bag", "moneybag"], Objects, "money", (0, 6), NO_MIRROR, Common),
+	emoji("💳", "credit card", &["credit_card"], Objects, "money", (0, 6), NO_MIRROR, Common),
+	emoji("🪪", "identification card", &["identification_card"], Objects, "other-object", (14, 0), NO_MIRROR, Rare),
+	emoji("💎", "gem stone", &["gem_stone", "gem"], Objects, "clothing", (0, 6), ANY, Legendary),
+	emoji("⚖️", "balance scale", &["balance_scale"], Objects, "tool", (1, 0), ANY, Common),
+	emoji("🪜", "ladder", &["ladder"], Objects, "tool", (13, 0), ANY, Rare),
+	emoji("🧰", "toolbox", &["toolbox"], Objects, "tool", (11, 0), ANY, Uncommon),
+	emoji("🪛", "screwdriver", &["screwdriver"], Objects, "tool", (13, 0), ANY, Rare),
+	emoji("🔧", "wrench", &["wrench"], Objects, "tool", (0, 6), ANY, Common),
+	emoji("🔨", "hammer", &["hammer"], Objects, "tool", (0, 6), ANY, Common),
+	emoji("⚒️", "hammer and pick", &["hammer_and_pick"], Objects, "tool", (1, 0), ANY, Common),
+	emoji("🛠️", "hammer and wrench", &["hammer_and_wrench"], Objects, "tool", (1, 0), ANY, Common),
+	emoji("⛏️", "pick", &["pick"], Objects, "tool", (0, 7), ANY, Common),
+	emoji("🪚", "carpentry saw", &["carpentry_saw"], Objects, "tool", (13, 0), ANY, Rare),
+	emoji("🔩", "nut and bolt", &["nut_and_bolt"], Objects, "tool", (0, 6), ANY, Common),
+	emoji("⚙️", "gear", &["gear"], Objects, "tool", (1, 0), ANY, Common),
+	emoji("🪤", "mouse trap", &["mouse_trap"], Objects, "household", (13, 0), ANY, Rare),
+	emoji("🧱", "brick", &["brick"], Objects, "place-building", (11, 0), ANY, Uncommon),
+	emoji("⛓️", "chains", &["chains"], Objects, "tool", (0, 7), ANY, Common),
+	emoji("🧲", "magnet", &["magnet"], Objects, "tool", (11, 0), ANY, Uncommon),
+	emoji("🔫", "water pistol", &["water_pistol", "gun"], Objects, "game", (0, 6), ANY, Common),
+	emoji("💣", "bomb", &["bomb"], Objects, "tool", (0, 6), ANY, Common),
+	emoji("🧨", "firecracker", &["firecracker"], Objects, "event", (11, 0), ANY, Uncommon),
+	emoji("🪓", "axe", &["axe"], Objects, "tool", (12, 0), ANY, Uncommon),
+	emoji("🔪", "kitchen knife", &["kitchen_knife", "knife", "hocho"], Objects, "dishware", (0, 6), ANY, Common),
+	emoji("🗡️", "dagger", &["dagger"], Objects, "tool", (1, 0), ANY, Common),
+	emoji("⚔️", "crossed swords", &["crossed_swords"], Objects, "tool", (1, 0), ANY, Common),
+	emoji("🛡️", "shield", &["shield"], Objects, "tool", (1, 0), ANY, Common),
+	emoji("🚬", "cigarette", &["cigarette", "smoking"], Objects, "other-object", (0, 6), ANY, Common),
+	emoji("⚰️", "coffin", &["coffin"], Objects, "other-object", (1, 0), ANY, Common),
+	emoji("🪦", "headstone", &["headstone"], Objects, "other-object", (13, 0), ANY, Rare),
+	emoji("⚱️", "funeral urn", &["funeral_urn"], Objects, "other-object", (1, 0), ANY, Common),
+	emoji("🏺", "amphora", &["amphora"], Objects, "dishware", (1, 0), ANY, Common),
+	emoji("🔮", "crystal ball", &["crystal_ball"], Objects, "game", (0, 6), ANY, Legendary),
+	emoji("📿", "prayer beads", &["prayer_beads"], Objects, "clothing", (1, 0), ANY, Common),
+	emoji("🧿", "nazar amulet", &["nazar_amulet"], Objects, "other-object", (11, 0), ANY, Uncommon),
+	emoji("🪬", "hamsa", &["hamsa"], Objects, "other-object", (14, 0), ANY, Rare),
+	emoji("💈", "barber pole", &["barber_pole"], Objects, "place-other", (0, 6), ANY, Common),
+	emoji("⚗️", "alembic", &["alembic"], Objects, "science", (1, 0), ANY, Common),
+	emoji("🔭", "telescope", &["telescope"], Objects, "science", (1, 0), ANY, Common),
+	emoji("🔬", "microscope", &["microscope"], Objects, "science", (1, 0), ANY, Common),
+	emoji("🕳️", "hole", &["hole"], Objects, "emotion", (0, 7), ANY, Common),
+	emoji("🩻", "x-ray", &["x_ray"], Objects, "medical", (14, 0), ANY, Rare),
+	emoji("🩹", "adhesive bandage", &["adhesive_bandage"], Objects, "medical", (12, 0), ANY, Uncommon),
+	emoji("🩺", "stethoscope", &["stethoscope"], Objects, "medical", (12, 0), ANY, Uncommon),
+	emoji("💊", "pill", &["pill"], Objects, "medical", (0, 6), ANY, Common),
+	emoji("💉", "syringe", &["syringe"], Objects, "medical", (0, 6), ANY, Common),
+	emoji("🩸", "drop of blood", &["drop_of_blood"], Objects, "medical", (12, 0), ANY, Uncommon),
+	emoji("🧬", "dna double helix", &["dna_double_helix", "double_helix"], Objects, "science", (11, 0), NO_MIRROR, Uncommon),
+	emoji("🦠", "microbe", &["microbe"], Objects, "animal-bug", (11, 0), ANY, Uncommon),
+	emoji("🧫", "petri dish", &["petri_dish"], Objects, "science", (11, 0), ANY, Uncommon),
+	emoji("🧪", "test tube", &["test_tube"], Objects, "science", (11, 0), ANY, Uncommon),
+	emoji("🌡️", "thermometer", &["thermometer"], Objects, "sky & weather", (0, 7), ANY, Common),
+	emoji("🧹", "broom", &["broom"], Objects, "household", (11, 0), ANY, Uncommon),
+	emoji("🪠", "plunger", &["plunger"], Objects, "household", (13, 0), ANY, Rare),
+	emoji("🧺", "basket", &["basket"], Objects, "household", (11, 0), ANY, Uncommon),
+	emoji("🧻", "roll of paper", &["roll_of_paper"], Objects, "household", (11, 0), ANY, Uncommon),
+	emoji("🚽", "toilet", &["toilet"], Objects, "household", (0, 6), ANY, Common),
+	emoji("🚰", "potable water symbol", &["potable_water_symbol"], Objects, "transport-sign", (1, 0), NO_MIRROR, Common),
+	emoji("🚿", "shower", &["shower"], Objects, "household", (1, 0), ANY, Common),
+	emoji("🛁", "bathtub", &["bathtub"], Objects, "household", (1, 0), ANY, Common),
+	emoji("🛀", "person taking bath", &["person_taking_bath"], Objects, "person-resting", (1, 0), ANY, Common),
+	emoji("🧼", "soap", &["soap"], Objects, "household", (11, 0), ANY, Uncommon),
+	emoji("🪥", "toothbrush", &["toothbrush"], Objects, "household", (13, 0), ANY, Rare),
+	emoji("🪒", "razor", &["razor"], Objects, "household", (12, 0), ANY, Uncommon),
+	emoji("🧽", "sponge", &["sponge"], Objects, "household", (11, 0), ANY, Uncommon),
+	emoji("🪣", "bucket", &["bucket"], Objects, "household", (13, 0), ANY, Rare),
+	emoji("🧴", "lotion bottle", &["lotion_bottle"], Objects, "household", (11, 0), ANY, Uncommon),
+	emoji("🛎️", "bellhop bell", &["bellhop_bell"], Objects, "hotel", (0, 7), ANY, Common),
+	emoji("🔑", "key", &["key"], Objects, "lock", (0, 6), ANY, Common),
+	emoji("🗝️", "old key", &["old_key"], Objects, "lock", (0, 7), ANY, Common),
+	emoji("🚪", "door", &["door"], Objects, "household", (0, 6), ANY, Common),
+	emoji("🪑", "chair", &["chair"], Objects, "household", (12, 0), ANY, Uncommon),
+	emoji("🛋️", "couch and lamp", &["couch_and_lamp"], Objects, "household", (0, 7), ANY, Common),
+	emoji("🛏️", "bed", &["bed"], Objects, "household", (0, 7), ANY, Common),
+	emoji("🛌", "person in bed", &["person_in_bed"], Objects, "person-resting", (1, 0), ANY, Common),
+	emoji("🧸", "teddy bear", &["teddy_bear"], Objects, "game", (11, 0), ANY, Uncommon),
+	emoji("🪆", "nesting dolls", &["nesting_dolls"], Objects, "game", (13, 0), ANY, Rare),
+	emoji("🖼️", "framed picture", &["framed_picture"], Objects, "arts & crafts", (0, 7), ANY, Common),
+	emoji("🪞", "mirror", &["mirror"], Objects, "household", (13, 0), ANY, Rare),
+	emoji("🪟", "window", &["window"], Objects, "household", (13, 0), ANY, Rare),
+	emoji("🛍️", "shopping bags", &["shopping_bags"], Objects, "clothing", (0, 7), ANY, Common),
+	emoji("🛒", "shopping cart", &["shopping_cart"], Objects, "household", (3, 0), ANY, Common),
+	emoji("🎁", "wrapped gift", &["wrapped_gift", "gift"], Objects, "event", (0, 6), ANY, Common),
+	emoji("🎈", "balloon", &["balloon"], Objects, "event", (0, 6), ANY, Common),
+	emoji("🎏", "carp streamer", &["carp_streamer"], Objects, "event", (0, 6), ANY, Common),
+	emoji("🎀", "ribbon", &["ribbon"], Objects, "event", (0, 6), ANY, Common),
+	emoji("🪄", "magic wand", &["magic_wand"], Objects, "game", (13, 0), ANY, Legendary),
+	emoji("🪅", "piñata", &["pinata"], Objects, "game", (13, 0), ANY, Rare),
+	emoji("🎊", "confetti ball", &["confetti_ball"], Objects, "event", (0, 6), ANY, Common),
+	emoji("🎉", "party popper", &["party_popper", "tada"], Objects, "event", (0, 6), ANY, Common),
+	emoji("🎎", "Japanese dolls", &["japanese_dolls"], Objects, "event", (0, 6), ANY, Common),
+	emoji("🏮", "red paper lantern", &["red_paper_lantern"], Objects, "light & video", (0, 6), ANY, Common),
+	emoji("🎐", "wind chime", &["wind_chime"], Objects, "event", (0, 6), ANY, Common),
+	emoji("🪩", "mirror ball", &["mirror_ball"], Objects, "game", (14, 0), ANY, Rare),
+	emoji("🧧", "red envelope", &["red_envelope"], Objects, "event", (11, 0), ANY, Uncommon),
+	emoji("✉️", "envelope", &["envelope"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("📩", "envelope with arrow", &["envelope_with_arrow"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("📨", "incoming envelope", &["incoming_envelope"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("📧", "e-mail", &["e_mail"], Objects, "mail", (0, 6), NO_MIRROR, Common),
+	emoji("💌", "love letter", &["love_letter"], Objects, "heart", (0, 6), ANY, Common),
+	emoji("📥", "inbox tray", &["inbox_tray"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("📤", "outbox tray", &["outbox_tray"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("📦", "package", &["package"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("🏷️", "label", &["label"], Objects, "book-paper", (1, 0), ANY, Common),
+	emoji("🪧", "placard", &["placard"], Objects, "other-object", (13, 0), ANY, Rare),
+	emoji("📪", "closed mailbox with lowered flag", &["closed_mailbox_with_lowered_flag"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("📫", "closed mailbox with raised flag", &["closed_mailbox_with_raised_flag"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("📬", "open mailbox with raised flag", &["open_mailbox_with_raised_flag"], Objects, "mail", (1, 0), ANY, Common),
+	emoji("📭", "open mailbox with lowered flag", &["open_mailbox_with_lowered_flag"], Objects, "mail", (1, 0), ANY, Common),
+	emoji("📮", "postbox", &["postbox"], Objects, "mail", (0, 6), ANY, Common),
+	emoji("📯", "postal horn", &["postal_horn"], Objects, "sound", (1, 0), NO_MIRROR, Common),
+	emoji("📜", "scroll", &["scroll"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📃", "page with curl", &["page_with_curl"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📄", "page facing up", &["page_facing_up"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📑", "bookmark tabs", &["bookmark_tabs"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("🧾", "receipt", &["receipt"], Objects, "money", (11, 0), ANY, Uncommon),
+	emoji("📊", "bar chart", &["bar_chart"], Objects, "office", (0, 6), ANY, Common),
+	emoji("📈", "chart increasing", &["chart_increasing", "chart_with_upwards_trend"], Objects, "office", (0, 6), NO_MIRROR, Common),
+	emoji("📉", "chart decreasing", &["chart_decreasing", "chart_with_downwards_trend"], Objects, "office", (0, 6), NO_MIRROR, Common),
+	emoji("🗒️", "spiral notepad", &["spiral_notepad"], Objects, "office", (0, 7), ANY, Common),
+	emoji("🗓️", "spiral calendar", &["spiral_calendar"], Objects, "office", (0, 7), ANY, Common),
+	emoji("📆", "tear-off calendar", &["tear_off_calendar"], Objects, "office", (0, 6), NO_MIRROR, Common),
+	emoji("📅", "calendar", &["calendar", "date"], Objects, "office", (0, 6), NO_MIRROR, Common),
+	emoji("🗑️", "wastebasket", &["wastebasket"], Objects, "office", (0, 7), ANY, Common),
+	emoji("📇", "card index", &["card_index"], Objects, "office", (0, 6), ANY, Common),
+	emoji("🗃️", "card file box", &["card_file_box"], Objects, "office", (0, 7), ANY, Common),
+	emoji("🗳️", "ballot box with ballot", &["ballot_box_with_ballot"], Objects, "mail", (1, 0), ANY, Common),
+	emoji("🗄️", "file cabinet", &["file_cabinet"], Objects, "office", (0, 7), ANY, Common),
+	emoji("📋", "clipboard", &["clipboard"], Objects, "office", (0, 6), ANY, Common),
+	emoji("📁", "file folder", &["file_folder"], Objects, "office", (0, 6), ANY, Common),
+	emoji("📂", "open file folder", &["open_file_folder"], Objects, "office", (0, 6), ANY, Common),
+	emoji("🗂️", "card index dividers", &["card_index_dividers"], Objects, "office", (0, 7), ANY, Common),
+	emoji("🗞️", "rolled-up newspaper", &["rolled_up_newspaper"], Objects, "book-paper", (0, 7), ANY, Common),
+	emoji("📰", "newspaper", &["newspaper"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📓", "notebook", &["notebook"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📔", "notebook with decorative cover", &["notebook_with_decorative_cover"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📒", "ledger", &["ledger"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📕", "closed book", &["closed_book"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📗", "green book", &["green_book"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📘", "blue book", &["blue_book"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📙", "orange book", &["orange_book"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📚", "books", &["books"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("📖", "open book", &["open_book"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("🔖", "bookmark", &["bookmark"], Objects, "book-paper", (0, 6), ANY, Common),
+	emoji("🧷", "safety pin", &["safety_pin"], Objects, "household", (11, 0), ANY, Uncommon),
+	emoji("🔗", "link", &["link"], Objects, "tool", (0, 6), ANY, Common),
+	emoji("📎", "paperclip", &["paperclip"], Objects, "office", (0, 6), ANY, Common),
+	emoji("🖇️", "linked paperclips", &["linked_paperclips"], Objects, "office", (0, 7), ANY, Common),
+	emoji("📐", "triangular ruler", &["triangular_ruler"], Objects, "office", (0, 6), ANY, Common),
+	emoji("📏", "straight ruler", &["straight_ruler"], Objects, "office", (0, 6), ANY, Common),
+	emoji("🧮", "abacus", &["abacus"], Objects, "computer", (11, 0), ANY, Uncommon),
+	emoji("📌", "pushpin", &["pushpin"], Objects, "office", (0, 6), ANY, Common),
+	emoji("📍", "round pushpin", &["round_pushpin"], Objects, "office", (0, 6), ANY, Common),
+	emoji("✂️", "scissors", &["scissors"], Objects, "office", (0, 6), ANY, Common),
+	emoji("🖊️", "pen", &["pen"], Objects, "writing", (0, 7), ANY, Common),
+	emoji("🖋️", "fountain pen", &["fountain_pen"], Objects, "writing", (0, 7), ANY, Common),
+	emoji("✒️", "black nib", &["black_nib"], Objects, "writing", (0, 6), ANY, Common),
+	emoji("🖌️", "paintbrush", &["paintbrush"], Objects, "writing", (0, 7), ANY, Common),
+	emoji("🖍️", "crayon", &["crayon"], Objects, "writing", (0, 7), ANY, Common),
+	emoji("📝", "memo", &["memo", "pencil_paper"], Objects, "writing", (0, 6), ANY, Common),
+	emoji("✏️", "pencil", &["pencil", "pencil2"], Objects, "writing", (0, 6), ANY, Common),
+	emoji("🔍", "magnifying glass tilted left", &["magnifying_glass_tilted_left", "mag"], Objects, "light & video", (0, 6), NO_MIRROR, Common),
+	emoji("🔎", "magnifying glass tilted right", &["magnifying_glass_tilted_right", "mag_right"], Objects, "light & video", (0, 6), NO_MIRROR, Common),
+	emoji("🔏", "locked with pen", &["locked_with_pen"], Objects, "lock", (0, 6), ANY, Common),
+	emoji("🔐", "locked with key", &["locked_with_key"], Objects, "lock", (0, 6), ANY, Common),
+	emoji("🔒", "locked", &["locked"], Objects, "lock", (0, 6), ANY, Common),
+	emoji("🔓", "unlocked", &["unlocked", "unlock"], Objects, "lock", (0, 6), ANY, Common),
 	// symbols
-	"❤️",
-	"🧡",
-	"💛",
-	"💚",
-	"💙",
-	"💜",
-	"🖤",
-	"🤎",
-	"🤍",
-	"💔",
-	"❣️",
-	"💕",
-	"💞",
-	"💓",
-	"💗",
-	"💖",
-	"💘",
-	"💝",
-	"❤️‍🩹",
-	"❤️‍🔥",
-	"💟",
-	"☮️",
-	"✝️",
-	"☪️",
-	"🕉️",
-	"☸️",
-	"✡️",
-	"🔯",
-	"🕎",
-	"☯️",
-	"☦️",
-	"🛐",
-	"⛎",
-	"♈",
-	"♉",
-	"♊",
-	"♋",
-	"♌",
-	"♍",
-	"♎",
-	"♏",
-	"♐",
-	"♑",
-	"♒",
-	"♓",
-	"🆔",
-	"⚛️",
-	"🉑",
-	"☢️",
-	"☣️",
-	"📴",
-	"📳",
-	"🈶",
-	"🈚",
-	"🈸",
-	"🈺",
-	"🈷️",
-	"✴️",
-	"🆚",
-	"💮",
-	"🉐",
-	"㊙️",
-	"㊗️",
-	"🈴",
-	"🈵",
-	"🈹",
-	"🈲",
-	"🅰️",
-	"🅱️",
-	"🆎",
-	"🆑",
-	"🅾️",
-	"🆘",
-	"❌",
-	"⭕",
-	"🛑",
-	"⛔",
-	"📛",
-	"🚫",
-	"💯",
-	"💢",
-	"♨️",
-	"🚷",
-	"🚯",
-	"🚳",
-	"🚱",
-	"🔞",
-	"📵",
-	"🚭",
-	"❗",
-	"❕",
-	"❓",
-	"❔",
-	"‼️",
-	"⁉️",
-	"🔅",
-	"🔆",
-	"〽️",
-	"⚠️",
-	"🚸",
-	"🔱",
-	"⚜️",
-	"🔰",
-	"♻️",
-	"✅",
-	"🈯",
-	"💹",
-	"❇️",
-	"✳️",
-	"❎",
-	"🌐",
-	"💠",
-	"Ⓜ️",
-	"🌀",
-	"💤",
-	"🏧",
-	"🚾",
-	"♿",
-	"🅿️",
-	"🈳",
-	"🈂️",
-	"🛂",
-	"🛃",
-	"🛄",
-	"🛅",
-	"🛗",
-	"🚹",
-	"🚺",
-	"🚼",
-	"🚻",
-	"🚮",
-	"🎦",
-	"📶",
-	"🈁",
-	"🔣",
-	"ℹ️",
-	"🔤",
-	"🔡",
-	"🔠",
-	"🆖",
-	"🆗",
-	"🆙",
-	"🆒",
-	"🆕",
-	"🆓",
-	"0️⃣",
-	"1️⃣",
-	"2️⃣",
-	"3️⃣",
-	"4️⃣",
-	"5️⃣",
-	"6️⃣",
-	"7️⃣",
-	"8️⃣",
-	"9️⃣",
-	"🔟",
-	"🔢",
-	"#️⃣",
-	"*️⃣",
-	"⏏️",
-	"▶️",
-	"⏸️",
-	"⏯️",
-	"⏹️",
-	"⏺️",
-	"⏭️",
-	"⏮️",
-	"⏩",
-	"⏪",
-	"⏫",
-	"⏬",
-	"◀️",
-	"🔼",
-	"🔽",
-	"➡️",
-	"⬅️",
-	"⬆️",
-	"⬇️",
-	"↗️",
-	"↘️",
-	"↙️",
-	"↖️",
-	"↕️",
-	"↔️",
-	"↪️",
-	"↩️",
-	"⤴️",
-	"⤵️",
-	"🔀",
-	"🔁",
-	"🔂",
-	"🔄",
-	"🔃",
-	"🎵",
-	"🎶",
-	"➕",
-	"➖",
-	"➗",
-	"✖️",
-	"🟰",
-	"♾️",
-	"💲",
-	"💱",
-	"™️",
-	"©️",
-	"®️",
-	"〰️",
-	"➰",
-	"➿",
-	"🔚",
-	"🔙",
-	"🔛",
-	"🔝",
-	"🔜",
-	"✔️",
-	"☑️",
-	"🔘",
-	"⚪",
-	"⚫",
-	"🔴",
-	"🔵",
-	"🟤",
-	"🟣",
-	"🟢",
-	"🟡",
-	"🟠",
-	"🔺",
-	"🔻",
-	"🔸",
-	"🔹",
-	"🔶",
-	"🔷",
-	"🔳",
-	"🔲",
-	"▪️",
-	"▫️",
-	"◾",
-	"◽",
-	"◼️",
-	"◻️",
-	"⬛",
-	"⬜",
-	"🟧",
-	"🟦",
-	"🟥",
-	"🟫",
-	"🟪",
-	"🟩",
-	"🟨",
-	"🔈",
-	"🔇",
-	"🔉",
-	"🔊",
-	"🔔",
-	"🔕",
-	"📣",
-	"📢",
-	"🗨️",
-	"👁‍🗨",
-	"💬",
-	"💭",
-	"🗯️",
-	"♠️",
-	"♣️",
-	"♥️",
-	"♦️",
-	"🃏",
-	"🎴",
-	"🀄",
-	"🕐",
-	"🕑",
-	"🕒",
-	"🕓",
-	"🕔",
-	"🕕",
-	"🕖",
-	"🕗",
-	"🕘",
-	"🕙",
-	"🕚",
-	"🕛",
-	"🕜",
-	"🕝",
-	"🕞",
-	"🕟",
-	"🕠",
-	"🕡",
-	"🕢",
-	"🕣",
-	"🕤",
-	"🕥",
-	"🕦",
-	"🕧",
-	"♀️",
-	"♂️",
-	"⚧",
-	"⚕️",
-	"🇿",
-	"🇾",
-	"🇽",
-	"🇼",
-	"🇻",
-	"🇺",
-	"🇹",
-	"🇸",
-	"🇷",
-	"🇶",
-	"🇵",
-	"🇴",
-	"🇳",
-	"🇲",
-	"🇱",
-	"🇰",
-	"🇯",
-	"🇮",
-	"🇭",
-	"🇬",
-	"🇫",
-	"🇪",
-	"🇩",
-	"🇨",
-	"🇧",
-	"🇦",
+	emoji("❤️", "red heart", &["red_heart", "heart"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("🧡", "orange heart", &["orange_heart"], Symbols, "heart", (5, 0), ANY, Common),
+	emoji("💛", "yellow heart", &["yellow_heart"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💚", "green heart", &["green_heart"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💙", "blue heart", &["blue_heart"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💜", "purple heart", &["purple_heart"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("🖤", "black heart", &["black_heart"], Symbols, "heart", (3, 0), ANY, Common),
+	emoji("🤎", "brown heart", &["brown_heart"], Symbols, "heart", (12, 0), ANY, Uncommon),
+	emoji("🤍", "white heart", &["white_heart"], Symbols, "heart", (12, 0), ANY, Uncommon),
+	emoji("💔", "broken heart", &["broken_heart"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("❣️", "heart exclamation", &["heart_exclamation"], Symbols, "heart", (1, 0), ANY, Common),
+	emoji("💕", "two hearts", &["two_hearts"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💞", "revolving hearts", &["revolving_hearts"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💓", "beating heart", &["beating_heart"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💗", "growing heart", &["growing_heart", "heartpulse"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💖", "sparkling heart", &["sparkling_heart"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💘", "heart with arrow", &["heart_with_arrow", "cupid"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("💝", "heart with ribbon", &["heart_with_ribbon"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("❤️‍🩹", "mending heart", &["mending_heart"], Symbols, "heart", (13, 1), ANY, Rare),
+	emoji("❤️‍🔥", "heart on fire", &["heart_on_fire"], Symbols, "heart", (13, 1), ANY, Rare),
+	emoji("💟", "heart decoration", &["heart_decoration"], Symbols, "heart", (0, 6), ANY, Common),
+	emoji("☮️", "peace symbol", &["peace_symbol", "peace"], Symbols, "religion", (1, 0), NO_MIRROR, Common),
+	emoji("✝️", "latin cross", &["latin_cross", "cross"], Symbols, "religion", (1, 0), NO_MIRROR, Common),
+	emoji("☪️", "star and crescent", &["star_and_crescent"], Symbols, "religion", (0, 7), NO_MIRROR, Common),
+	emoji("🕉️", "om", &["om"], Symbols, "religion", (1, 0), NO_MIRROR, Common),
+	emoji("☸️", "wheel of dharma", &["wheel_of_dharma"], Symbols, "religion", (0, 7), NO_MIRROR, Common),
+	emoji("✡️", "star of David", &["star_of_david"], Symbols, "religion", (0, 7), NO_MIRROR, Common),
+	emoji("🔯", "dotted six-pointed star", &["dotted_six_pointed_star"], Symbols, "religion", (0, 6), NO_MIRROR, Common),
+	emoji("🕎", "menorah", &["menorah"], Symbols, "religion", (1, 0), NO_MIRROR, Common),
+	emoji("☯️", "yin yang", &["yin_yang"], Symbols, "religion", (0, 7), NO_MIRROR, Common),
+	emoji("☦️", "orthodox cross", &["orthodox_cross"], Symbols, "religion", (1, 0), NO_MIRROR, Common),
+	emoji("🛐", "place of worship", &["place_of_worship"], Symbols, "religion", (1, 0), NO_MIRROR, Common),
+	emoji("⛎", "Ophiuchus", &["ophiuchus"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♈", "Aries", &["aries"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♉", "Taurus", &["taurus"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♊", "Gemini", &["gemini"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♋", "Cancer", &["cancer"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♌", "Leo", &["leo"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♍", "Virgo", &["virgo"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♎", "Libra", &["libra"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♏", "Scorpio", &["scorpio"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♐", "Sagittarius", &["sagittarius"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♑", "Capricorn", &["capricorn"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♒", "Aquarius", &["aquarius"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("♓", "Pisces", &["pisces"], Symbols, "zodiac", (0, 6), NO_MIRROR, Common),
+	emoji("🆔", "ID button", &["id_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("⚛️", "atom symbol", &["atom_symbol"], Symbols, "religion", (1, 0), NO_MIRROR, Common),
+	emoji("🉑", "Japanese \"acceptable\" button", &["japanese_acceptable_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("☢️", "radioactive", &["radioactive"], Symbols, "warning", (1, 0), NO_MIRROR, Common),
+	emoji("☣️", "biohazard", &["biohazard"], Symbols, "warning", (1, 0), NO_MIRROR, Common),
+	emoji("📴", "mobile phone off", &["mobile_phone_off"], Symbols, "av-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("📳", "vibration mode", &["vibration_mode"], Symbols, "av-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🈶", "Japanese \"not free of charge\" button", &["japanese_not_free_of_charge_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈚", "Japanese \"free of charge\" button", &["japanese_free_of_charge_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈸", "Japanese \"application\" button", &["japanese_application_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈺", "Japanese \"open for business\" button", &["japanese_open_for_business_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈷️", "Japanese \"monthly amount\" button", &["japanese_monthly_amount_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("✴️", "eight-pointed star", &["eight_pointed_star"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🆚", "VS button", &["vs_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("💮", "white flower", &["white_flower"], Symbols, "plant-flower", (0, 6), NO_MIRROR, Common),
+	emoji("🉐", "Japanese \"bargain\" button", &["japanese_bargain_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("㊙️", "Japanese \"secret\" button", &["japanese_secret_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("㊗️", "Japanese \"congratulations\" button", &["japanese_congratulations_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈴", "Japanese \"passing grade\" button", &["japanese_passing_grade_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈵", "Japanese \"no vacancy\" button", &["japanese_no_vacancy_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈹", "Japanese \"discount\" button", &["japanese_discount_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈲", "Japanese \"prohibited\" button", &["japanese_prohibited_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🅰️", "A button (blood type)", &["a_button_blood_type"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🅱️", "B button (blood type)", &["b_button_blood_type"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆎", "AB button (blood type)", &["ab_button_blood_type"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆑", "CL button", &["cl_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🅾️", "O button (blood type)", &["o_button_blood_type"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆘", "SOS button", &["sos_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("❌", "cross mark", &["cross_mark", "x"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("⭕", "hollow red circle", &["hollow_red_circle", "o"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🛑", "stop sign", &["stop_sign", "octagonal_sign"], Symbols, "transport-ground", (3, 0), NO_MIRROR, Common),
+	emoji("⛔", "no entry", &["no_entry"], Symbols, "warning", (0, 6), NO_MIRROR, Common),
+	emoji("📛", "name badge", &["name_badge"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🚫", "prohibited", &["prohibited", "no_entry_sign"], Symbols, "warning", (0, 6), NO_MIRROR, Common),
+	emoji("💯", "hundred points", &["hundred_points", "100"], Symbols, "emotion", (0, 6), NO_MIRROR, Common),
+	emoji("💢", "anger symbol", &["anger_symbol", "anger"], Symbols, "emotion", (0, 6), NO_MIRROR, Common),
+	emoji("♨️", "hot springs", &["hot_springs"], Symbols, "place-other", (0, 6), NO_MIRROR, Common),
+	emoji("🚷", "no pedestrians", &["no_pedestrians"], Symbols, "warning", (1, 0), NO_MIRROR, Common),
+	emoji("🚯", "no littering", &["no_littering"], Symbols, "warning", (1, 0), NO_MIRROR, Common),
+	emoji("🚳", "no bicycles", &["no_bicycles"], Symbols, "warning", (1, 0), NO_MIRROR, Common),
+	emoji("🚱", "non-potable water", &["non_potable_water"], Symbols, "warning", (1, 0), NO_MIRROR, Common),
+	emoji("🔞", "no one under eighteen", &["no_one_under_eighteen"], Symbols, "warning", (0, 6), NO_MIRROR, Common),
+	emoji("📵", "no mobile phones", &["no_mobile_phones"], Symbols, "warning", (1, 0), NO_MIRROR, Common),
+	emoji("🚭", "no smoking", &["no_smoking"], Symbols, "warning", (0, 6), NO_MIRROR, Common),
+	emoji("❗", "red exclamation mark", &["red_exclamation_mark", "exclamation"], Symbols, "punctuation", (0, 6), NO_MIRROR, Common),
+	emoji("❕", "white exclamation mark", &["white_exclamation_mark"], Symbols, "punctuation", (0, 6), NO_MIRROR, Common),
+	emoji("❓", "red question mark", &["red_question_mark", "question"], Symbols, "punctuation", (0, 6), NO_MIRROR, Common),
+	emoji("❔", "white question mark", &["white_question_mark"], Symbols, "punctuation", (0, 6), NO_MIRROR, Common),
+	emoji("‼️", "double exclamation mark", &["double_exclamation_mark", "bangbang"], Symbols, "punctuation", (0, 6), NO_MIRROR, Common),
+	emoji("⁉️", "exclamation question mark", &["exclamation_question_mark", "interrobang"], Symbols, "punctuation", (0, 6), NO_MIRROR, Common),
+	emoji("🔅", "dim button", &["dim_button"], Symbols, "av-symbol", (1, 0), NO_MIRROR, Common),
+	emoji("🔆", "bright button", &["bright_button"], Symbols, "av-symbol", (1, 0), NO_MIRROR, Common),
+	emoji("〽️", "part alternation mark", &["part_alternation_mark"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("⚠️", "warning", &["warning"], Symbols, "warning", (0, 6), NO_MIRROR, Common),
+	emoji("🚸", "children crossing", &["children_crossing"], Symbols, "warning", (1, 0), NO_MIRROR, Common),
+	emoji("🔱", "trident emblem", &["trident_emblem"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Legendary),
+	emoji("⚜️", "fleur-de-lis", &["fleur_de_lis"], Symbols, "other-symbol", (1, 0), NO_MIRROR, Common),
+	emoji("🔰", "Japanese symbol for beginner", &["japanese_symbol_for_beginner"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("♻️", "recycling symbol", &["recycling_symbol", "recycle"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("✅", "check mark button", &["check_mark_button", "white_check_mark"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🈯", "Japanese \"reserved\" button", &["japanese_reserved_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("💹", "chart increasing with yen", &["chart_increasing_with_yen"], Symbols, "money", (0, 6), NO_MIRROR, Common),
+	emoji("❇️", "sparkle", &["sparkle"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("✳️", "eight-spoked asterisk", &["eight_spoked_asterisk"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("❎", "cross mark button", &["cross_mark_button"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🌐", "globe with meridians", &["globe_with_meridians"], Symbols, "place-map", (1, 0), NO_MIRROR, Common),
+	emoji("💠", "diamond with a dot", &["diamond_with_a_dot"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("Ⓜ️", "circled M", &["circled_m"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🌀", "cyclone", &["cyclone"], Symbols, "sky & weather", (0, 6), NO_MIRROR, Common),
+	emoji("💤", "ZZZ", &["zzz"], Symbols, "emotion", (0, 6), NO_MIRROR, Common),
+	emoji("🏧", "ATM sign", &["atm_sign"], Symbols, "transport-sign", (0, 6), NO_MIRROR, Common),
+	emoji("🚾", "water closet", &["water_closet"], Symbols, "transport-sign", (0, 6), NO_MIRROR, Common),
+	emoji("♿", "wheelchair symbol", &["wheelchair_symbol"], Symbols, "transport-sign", (0, 6), NO_MIRROR, Common),
+	emoji("🅿️", "P button", &["p_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈳", "Japanese \"vacancy\" button", &["japanese_vacancy_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🈂️", "Japanese \"service charge\" button", &["japanese_service_charge_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🛂", "passport control", &["passport_control"], Symbols, "transport-sign", (1, 0), NO_MIRROR, Common),
+	emoji("🛃", "customs", &["customs"], Symbols, "transport-sign", (1, 0), NO_MIRROR, Common),
+	emoji("🛄", "baggage claim", &["baggage_claim"], Symbols, "transport-sign", (1, 0), NO_MIRROR, Common),
+	emoji("🛅", "left luggage", &["left_luggage"], Symbols, "transport-sign", (1, 0), NO_MIRROR, Common),
+	emoji("🛗", "elevator", &["elevator"], Symbols, "household", (13, 0), NO_MIRROR, Rare),
+	emoji("🚹", "men's room", &["mens_room"], Symbols, "transport-sign", (0, 6), NO_MIRROR, Common),
+	emoji("🚺", "women's room", &["womens_room"], Symbols, "transport-sign", (0, 6), NO_MIRROR, Common),
+	emoji("🚼", "baby symbol", &["baby_symbol"], Symbols, "transport-sign", (0, 6), NO_MIRROR, Common),
+	emoji("🚻", "restroom", &["restroom"], Symbols, "transport-sign", (0, 6), NO_MIRROR, Common),
+	emoji("🚮", "litter in bin sign", &["litter_in_bin_sign"], Symbols, "transport-sign", (1, 0), NO_MIRROR, Common),
+	emoji("🎦", "cinema", &["cinema"], Symbols, "av-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("📶", "antenna bars", &["antenna_bars"], Symbols, "av-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🈁", "Japanese \"here\" button", &["japanese_here_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🔣", "input symbols", &["input_symbols"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("ℹ️", "information", &["information", "information_source"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🔤", "input latin letters", &["input_latin_letters"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🔡", "input latin lowercase", &["input_latin_lowercase"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🔠", "input latin uppercase", &["input_latin_uppercase"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆖", "NG button", &["ng_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆗", "OK button", &["ok_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆙", "UP! button", &["up_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆒", "COOL button", &["cool_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆕", "NEW button", &["new_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("🆓", "FREE button", &["free_button"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("0️⃣", "keycap: 0", &["keycap_0", "zero"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("1️⃣", "keycap: 1", &["keycap_1", "one"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("2️⃣", "keycap: 2", &["keycap_2", "two"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("3️⃣", "keycap: 3", &["keycap_3", "three"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("4️⃣", "keycap: 4", &["keycap_4", "four"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("5️⃣", "keycap: 5", &["keycap_5", "five"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("6️⃣", "keycap: 6", &["keycap_6", "six"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("7️⃣", "keycap: 7", &["keycap_7", "seven"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("8️⃣", "keycap: 8", &["keycap_8", "eight"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("9️⃣", "keycap: 9", &["keycap_9", "nine"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("🔟", "keycap: 10", &["keycap_10", "keycap_ten"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("🔢", "input numbers", &["input_numbers"], Symbols, "alphanum", (0, 6), NO_MIRROR, Common),
+	emoji("#️⃣", "keycap: #", &["keycap_hash", "hash"], Symbols, "keycap", (0, 6), NO_MIRROR, Common),
+	emoji("*️⃣", "keycap: *", &["keycap_asterisk", "asterisk"], Symbols, "keycap", (2, 0), NO_MIRROR, Common),
+	emoji("⏏️", "eject button", &["eject_button"], Symbols, "av-symbol", (0, 7), NO_MIRROR, Common),
+	emoji("▶️", "play button", &["play_button", "arrow_forward"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("⏸️", "pause button", &["pause_button"], Symbols, "av-symbol", (0, 7), NO_MIRROR, Common),
+	emoji("⏯️", "play or pause button", &["play_or_pause_button"], Symbols, "av-symbol", (1, 0), NO_MIRROR, Common),
+	emoji("⏹️", "stop button", &["stop_button"], Symbols, "av-symbol", (0, 7), NO_MIRROR, Common),
+	emoji("⏺️", "record button", &["record_button"], Symbols, "av-symbol", (0, 7), NO_MIRROR, Common),
+	emoji("⏭️", "next track button", &["next_track_button"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("⏮️", "last track button", &["last_track_button"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("⏩", "fast-forward button", &["fast_forward_button"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("⏪", "fast reverse button", &["fast_reverse_button"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("⏫", "fast up button", &["fast_up_button"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("⏬", "fast down button", &["fast_down_button"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("◀️", "reverse button", &["reverse_button", "arrow_backward"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("🔼", "upwards button", &["upwards_button"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("🔽", "downwards button", &["downwards_button"], Symbols, "av-symbol", (0, 6), FIXED, Common),
+	emoji("➡️", "right arrow", &["right_arrow", "arrow_right"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("⬅️", "left arrow", &["left_arrow", "arrow_left"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("⬆️", "up arrow", &["up_arrow", "arrow_up"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("⬇️", "down arrow", &["down_arrow", "arrow_down"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("↗️", "up-right arrow", &["up_right_arrow"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("↘️", "down-right arrow", &["down_right_arrow"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("↙️", "down-left arrow", &["down_left_arrow"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("↖️", "up-left arrow", &["up_left_arrow"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("↕️", "up-down arrow", &["up_down_arrow"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("↔️", "left-right arrow", &["left_right_arrow"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("↪️", "left arrow curving right", &["left_arrow_curving_right"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("↩️", "right arrow curving left", &["right_arrow_curving_left"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("⤴️", "right arrow curving up", &["right_arrow_curving_up"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("⤵️", "right arrow curving down", &["right_arrow_curving_down"], Symbols, "arrow", (0, 6), FIXED, Common),
+	emoji("🔀", "shuffle tracks button", &["shuffle_tracks_button"], Symbols, "av-symbol", (1, 0), NO_MIRROR, Common),
+	emoji("🔁", "repeat button", &["repeat_button"], Symbols, "av-symbol", (1, 0), NO_MIRROR, Common),
+	emoji("🔂", "repeat single button", &["repeat_single_button"], Symbols, "av-symbol", (1, 0), NO_MIRROR, Common),
+	emoji("🔄", "counterclockwise arrows button", &["counterclockwise_arrows_button", "arrows_counterclockwise"], Symbols, "arrow", (1, 0), NO_MIRROR, Common),
+	emoji("🔃", "clockwise vertical arrows", &["clockwise_vertical_arrows"], Symbols, "arrow", (0, 6), NO_MIRROR, Common),
+	emoji("🎵", "musical note", &["musical_note", "musical_note_single"], Symbols, "music", (0, 6), NO_MIRROR, Common),
+	emoji("🎶", "musical notes", &["musical_notes", "notes"], Symbols, "music", (0, 6), NO_MIRROR, Common),
+	emoji("➕", "plus", &["plus", "heavy_plus_sign"], Symbols, "math", (0, 6), NO_MIRROR, Common),
+	emoji("➖", "minus", &["minus", "heavy_minus_sign"], Symbols, "math", (0, 6), NO_MIRROR, Common),
+	emoji("➗", "divide", &["divide", "heavy_division_sign"], Symbols, "math", (0, 6), NO_MIRROR, Common),
+	emoji("✖️", "multiply", &["multiply", "heavy_multiplication_x"], Symbols, "math", (0, 6), NO_MIRROR, Common),
+	emoji("🟰", "heavy equals sign", &["heavy_equals_sign"], Symbols, "math", (14, 0), NO_MIRROR, Rare),
+	emoji("♾️", "infinity", &["infinity"], Symbols, "math", (11, 0), NO_MIRROR, Uncommon),
+	emoji("💲", "heavy dollar sign", &["heavy_dollar_sign"], Symbols, "currency", (0, 6), NO_MIRROR, Common),
+	emoji("💱", "currency exchange", &["currency_exchange"], Symbols, "currency", (0, 6), NO_MIRROR, Common),
+	emoji("™️", "trade mark", &["trade_mark", "tm"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("©️", "copyright", &["copyright"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("®️", "registered", &["registered"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("〰️", "wavy dash", &["wavy_dash"], Symbols, "punctuation", (0, 6), NO_MIRROR, Common),
+	emoji("➰", "curly loop", &["curly_loop"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("➿", "double curly loop", &["double_curly_loop"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🔚", "END arrow", &["end_arrow"], Symbols, "arrow", (0, 6), NO_MIRROR, Common),
+	emoji("🔙", "BACK arrow", &["back_arrow"], Symbols, "arrow", (0, 6), NO_MIRROR, Common),
+	emoji("🔛", "ON! arrow", &["on_arrow"], Symbols, "arrow", (0, 6), NO_MIRROR, Common),
+	emoji("🔝", "TOP arrow", &["top_arrow"], Symbols, "arrow", (0, 6), NO_MIRROR, Common),
+	emoji("🔜", "SOON arrow", &["soon_arrow"], Symbols, "arrow", (0, 6), NO_MIRROR, Common),
+	emoji("✔️", "check mark", &["check_mark", "heavy_check_mark"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("☑️", "check box with check", &["check_box_with_check"], Symbols, "other-symbol", (0, 6), NO_MIRROR, Common),
+	emoji("🔘", "radio button", &["radio_button"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("⚪", "white circle", &["white_circle"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("⚫", "black circle", &["black_circle"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔴", "red circle", &["red_circle"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔵", "blue circle", &["blue_circle"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🟤", "brown circle", &["brown_circle"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟣", "purple circle", &["purple_circle"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟢", "green circle", &["green_circle"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟡", "yellow circle", &["yellow_circle"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟠", "orange circle", &["orange_circle"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🔺", "red triangle pointed up", &["red_triangle_pointed_up"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔻", "red triangle pointed down", &["red_triangle_pointed_down"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔸", "small orange diamond", &["small_orange_diamond"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔹", "small blue diamond", &["small_blue_diamond"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔶", "large orange diamond", &["large_orange_diamond"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔷", "large blue diamond", &["large_blue_diamond"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔳", "white square button", &["white_square_button"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🔲", "black square button", &["black_square_button"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("▪️", "black small square", &["black_small_square"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("▫️", "white small square", &["white_small_square"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("◾", "black medium-small square", &["black_medium_small_square"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("◽", "white medium-small square", &["white_medium_small_square"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("◼️", "black medium square", &["black_medium_square"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("◻️", "white medium square", &["white_medium_square"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("⬛", "black large square", &["black_large_square"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("⬜", "white large square", &["white_large_square"], Symbols, "geometric", (0, 6), NO_MIRROR, Common),
+	emoji("🟧", "orange square", &["orange_square"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟦", "blue square", &["blue_square"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟥", "red square", &["red_square"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟫", "brown square", &["brown_square"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟪", "purple square", &["purple_square"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟩", "green square", &["green_square"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🟨", "yellow square", &["yellow_square"], Symbols, "geometric", (12, 0), NO_MIRROR, Uncommon),
+	emoji("🔈", "speaker low volume", &["speaker_low_volume"], Symbols, "sound", (0, 6), NO_MIRROR, Common),
+	emoji("🔇", "muted speaker", &["muted_speaker"], Symbols, "sound", (1, 0), NO_MIRROR, Common),
+	emoji("🔉", "speaker medium volume", &["speaker_medium_volume"], Symbols, "sound", (1, 0), NO_MIRROR, Common),
+	emoji("🔊", "speaker high volume", &["speaker_high_volume"], Symbols, "sound", (0, 6), NO_MIRROR, Common),
+	emoji("🔔", "bell", &["bell"], Symbols, "sound", (0, 6), NO_MIRROR, Common),
+	emoji("🔕", "bell with slash", &["bell_with_slash", "no_bell"], Symbols, "sound", (1, 0), NO_MIRROR, Common),
+	emoji("📣", "megaphone", &["megaphone"], Symbols, "sound", (0, 6), NO_MIRROR, Common),
+	emoji("📢", "loudspeaker", &["loudspeaker"], Symbols, "sound", (0, 6), NO_MIRROR, Common),
+	emoji("🗨️", "left speech bubble", &["left_speech_bubble"], Symbols, "emotion", (2, 0), NO_MIRROR, Common),
+	emoji("👁‍🗨", "eye in speech bubble", &["eye_in_speech_bubble"], Symbols, "emotion", (2, 0), NO_MIRROR, Common),
+	emoji("💬", "speech balloon", &["speech_balloon"], Symbols, "emotion", (0, 6), NO_MIRROR, Common),
+	emoji("💭", "thought balloon", &["thought_balloon"], Symbols, "emotion", (1, 0), NO_MIRROR, Common),
+	emoji("🗯️", "right anger bubble", &["right_anger_bubble"], Symbols, "emotion", (1, 0), NO_MIRROR, Common),
+	emoji("♠️", "spade suit", &["spade_suit"], Symbols, "game", (0, 6), NO_MIRROR, Common),
+	emoji("♣️", "club suit", &["club_suit"], Symbols, "game", (0, 6), NO_MIRROR, Common),
+	emoji("♥️", "heart suit", &["heart_suit"], Symbols, "game", (0, 6), NO_MIRROR, Common),
+	emoji("♦️", "diamond suit", &["diamond_suit"], Symbols, "game", (0, 6), NO_MIRROR, Common),
+	emoji("🃏", "joker", &["joker"], Symbols, "game", (0, 6), NO_MIRROR, Common),
+	emoji("🎴", "flower playing cards", &["flower_playing_cards"], Symbols, "game", (0, 6), NO_MIRROR, Common),
+	emoji("🀄", "mahjong red dragon", &["mahjong_red_dragon"], Symbols, "game", (0, 6), NO_MIRROR, Common),
+	emoji("🕐", "one o'clock", &["one_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕑", "two o'clock", &["two_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕒", "three o'clock", &["three_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕓", "four o'clock", &["four_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕔", "five o'clock", &["five_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕕", "six o'clock", &["six_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕖", "seven o'clock", &["seven_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕗", "eight o'clock", &["eight_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕘", "nine o'clock", &["nine_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕙", "ten o'clock", &["ten_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕚", "eleven o'clock", &["eleven_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕛", "twelve o'clock", &["twelve_oclock"], Symbols, "time", (0, 6), FIXED, Common),
+	emoji("🕜", "one-thirty", &["one_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕝", "two-thirty", &["two_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕞", "three-thirty", &["three_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕟", "four-thirty", &["four_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕠", "five-thirty", &["five_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕡", "six-thirty", &["six_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕢", "seven-thirty", &["seven_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕣", "eight-thirty", &["eight_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕤", "nine-thirty", &["nine_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕥", "ten-thirty", &["ten_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕦", "eleven-thirty", &["eleven_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("🕧", "twelve-thirty", &["twelve_thirty"], Symbols, "time", (1, 0), FIXED, Common),
+	emoji("♀️", "female sign", &["female_sign"], Symbols, "gender", (4, 0), NO_MIRROR, Common),
+	emoji("♂️", "male sign", &["male_sign"], Symbols, "gender", (4, 0), NO_MIRROR, Common),
+	emoji("⚧", "transgender symbol", &["transgender_symbol"], Symbols, "gender", (13, 0), NO_MIRROR, Rare),
+	emoji("⚕️", "medical symbol", &["medical_symbol"], Symbols, "other-symbol", (4, 0), NO_MIRROR, Common),
+	emoji("🇿", "regional indicator Z", &["regional_indicator_z"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇾", "regional indicator Y", &["regional_indicator_y"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇽", "regional indicator X", &["regional_indicator_x"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇼", "regional indicator W", &["regional_indicator_w"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇻", "regional indicator V", &["regional_indicator_v"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇺", "regional indicator U", &["regional_indicator_u"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇹", "regional indicator T", &["regional_indicator_t"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇸", "regional indicator S", &["regional_indicator_s"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇷", "regional indicator R", &["regional_indicator_r"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇶", "regional indicator Q", &["regional_indicator_q"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇵", "regional indicator P", &["regional_indicator_p"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇴", "regional indicator O", &["regional_indicator_o"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇳", "regional indicator N", &["regional_indicator_n"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇲", "regional indicator M", &["regional_indicator_m"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇱", "regional indicator L", &["regional_indicator_l"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇰", "regional indicator K", &["regional_indicator_k"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇯", "regional indicator J", &["regional_indicator_j"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇮", "regional indicator I", &["regional_indicator_i"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇭", "regional indicator H", &["regional_indicator_h"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇬", "regional indicator G", &["regional_indicator_g"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇫", "regional indicator F", &["regional_indicator_f"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇪", "regional indicator E", &["regional_indicator_e"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇩", "regional indicator D", &["regional_indicator_d"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇨", "regional indicator C", &["regional_indicator_c"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇧", "regional indicator B", &["regional_indicator_b"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
+	emoji("🇦", "regional indicator A", &["regional_indicator_a"], Symbols, "regional-indicator", (2, 0), NO_MIRROR, Common),
 	// flags
-	"🏳️",
-	"🏴",
-	"🏁",
-	"🚩",
-	"🏳️‍🌈",
-	"🏳️‍⚧️",
-	"🏴‍☠️",
-	"🇦🇫",
-	"🇦🇽",
-	"🇦🇱",
-	"🇩🇿",
-	"🇦🇸",
-	"🇦🇩",
-	"🇦🇴",
-	"🇦🇮",
-	"🇦🇶",
-	"🇦🇬",
-	"🇦🇷",
-	"🇦🇲",
-	"🇦🇼",
-	"🇦🇺",
-	"🇦🇹",
-	"🇦🇿",
-	"🇧🇸",
-	"🇧🇭",
-	"🇧🇩",
-	"🇧🇧",
-	"🇧🇾",
-	"🇧🇪",
-	"🇧🇿",
-	"🇧🇯",
-	"🇧🇲",
-	"🇧🇹",
-	"🇧🇴",
-	"🇧🇦",
-	"🇧🇼",
-	"🇧🇷",
-	"🇮🇴",
-	"🇻🇬",
-	"🇧🇳",
-	"🇧🇬",
-	"🇧🇫",
-	"🇧🇮",
-	"🇰🇭",
-	"🇨🇲",
-	"🇨🇦",
-	"🇮🇨",
-	"🇨🇻",
-	"🇧🇶",
-	"🇰🇾",
-	"🇨🇫",
-	"🇹🇩",
-	"🇨🇱",
-	"🇨🇳",
-	"🇨🇽",
-	"🇨🇨",
-	"🇨🇴",
-	"🇰🇲",
-	"🇨🇬",
-	"🇨🇩",
-	"🇨🇰",
-	"🇨🇷",
-	"🇨🇮",
-	"🇭🇷",
-	"🇨🇺",
-	"🇨🇼",
-	"🇨🇾",
-	"🇨🇿",
-	"🇩🇰",
-	"🇩🇯",
-	"🇩🇲",
-	"🇩🇴",
-	"🇪🇨",
-	"🇪🇬",
-	"🇸🇻",
-	"🇬🇶",
-	"🇪🇷",
-	"🇪🇪",
-	"🇪🇹",
-	"🇪🇺",
-	"🇫🇰",
-	"🇫🇴",
-	"🇫🇯",
-	"🇫🇮",
-	"🇫🇷",
-	"🇬🇫",
-	"🇵🇫",
-	"🇹🇫",
-	"🇬🇦",
-	"🇬🇲",
-	"🇬🇪",
-	"🇩🇪",
-	"🇬🇭",
-	"🇬🇮",
-	"🇬🇷",
-	"🇬🇱",
-	"🇬🇩",
-	"🇬🇵",
-	"🇬🇺",
-	"🇬🇹",
-	"🇬🇬",
-	"🇬🇳",
-	"🇬🇼",
-	"🇬🇾",
-	"🇭🇹",
-	"🇭🇳",
-	"🇭🇰",
-	"🇭🇺",
-	"🇮🇸",
-	"🇮🇳",
-	"🇮🇩",
-	"🇮🇷",
-	"🇮🇶",
-	"🇮🇪",
-	"🇮🇲",
-	"🇮🇱",
-	"🇮🇹",
-	"🇯🇲",
-	"🇯🇵",
-	"🎌",
-	"🇯🇪",
-	"🇯🇴",
-	"🇰🇿",
-	"🇰🇪",
-	"🇰🇮",
-	"🇽🇰",
-	"🇰🇼",
-	"🇰🇬",
-	"🇱🇦",
-	"🇱🇻",
-	"🇱🇧",
-	"🇱🇸",
-	"🇱🇷",
-	"🇱🇾",
-	"🇱🇮",
-	"🇱🇹",
-	"🇱🇺",
-	"🇲🇴",
-	"🇲🇰",
-	"🇲🇬",
-	"🇲🇼",
-	"🇲🇾",
-	"🇲🇻",
-	"🇲🇱",
-	"🇲🇹",
-	"🇲🇭",
-	"🇲🇶",
-	"🇲🇷",
-	"🇲🇺",
-	"🇾🇹",
-	"🇲🇽",
-	"🇫🇲",
-	"🇲🇩",
-	"🇲🇨",
-	"🇲🇳",
-	"🇲🇪",
-	"🇲🇸",
-	"🇲🇦",
-	"🇲🇿",
-	"🇲🇲",
-	"🇳🇦",
-	"🇳🇷",
-	"🇳🇵",
-	"🇳🇱",
-	"🇳🇨",
-	"🇳🇿",
-	"🇳🇮",
-	"🇳🇪",
-	"🇳🇬",
-	"🇳🇺",
-	"🇳🇫",
-	"🇰🇵",
-	"🇲🇵",
-	"🇳🇴",
-	"🇴🇲",
-	"🇵🇰",
-	"🇵🇼",
-	"🇵🇸",
-	"🇵🇦",
-	"🇵🇬",
-	"🇵🇾",
-	"🇵🇪",
-	"🇵🇭",
-	"🇵🇳",
-	"🇵🇱",
-	"🇵🇹",
-	"🇵🇷",
-	"🇶🇦",
-	"🇷🇪",
-	"🇷🇴",
-	"🇷🇺",
-	"🇷🇼",
-	"🇼🇸",
-	"🇸🇲",
-	"🇸🇹",
-	"🇸🇦",
-	"🇸🇳",
-	"🇷🇸",
-	"🇸🇨",
-	"🇸🇱",
-	"🇸🇬",
-	"🇸🇽",
-	"🇸🇰",
-	"🇸🇮",
-	"🇬🇸",
-	"🇸🇧",
-	"🇸🇴",
-	"🇿🇦",
-	"🇰🇷",
-	"🇸🇸",
-	"🇪🇸",
-	"🇱🇰",
-	"🇧🇱",
-	"🇸🇭",
-	"🇰🇳",
-	"🇱🇨",
-	"🇵🇲",
-	"🇻🇨",
-	"🇸🇩",
-	"🇸🇷",
-	"🇸🇿",
-	"🇸🇪",
-	"🇨🇭",
-	"🇸🇾",
-	"🇹🇼",
-	"🇹🇯",
-	"🇹🇿",
-	"🇹🇭",
-	"🇹🇱",
-	"🇹🇬",
-	"🇹🇰",
-	"🇹🇴",
-	"🇹🇹",
-	"🇹🇳",
-	"🇹🇷",
-	"🇹🇲",
-	"🇹🇨",
-	"🇻🇮",
-	"🇹🇻",
-	"🇺🇬",
-	"🇺🇦",
-	"🇦🇪",
-	"🇬🇧",
-	"🏴󠁧󠁢󠁥󠁮󠁧󠁿",
-	"🏴󠁧󠁢󠁳󠁣󠁴󠁿",
-	"🏴󠁧󠁢󠁷󠁬󠁳󠁿",
-	"🇺🇸",
-	"🇺🇾",
-	"🇺🇿",
-	"🇻🇺",
-	"🇻🇦",
-	"🇻🇪",
-	"🇻🇳",
-	"🇼🇫",
-	"🇪🇭",
-	"🇾🇪",
-	"🇿🇲",
-	"🇿🇼",
-	"🇦🇨",
-	"🇧🇻",
-	"🇨🇵",
-	"🇪🇦",
-	"🇩🇬",
-	"🇭🇲",
-	"🇲🇫",
-	"🇸🇯",
-	"🇹🇦",
-	"🇺🇲",
-	"🇺🇳",
+	emoji("🏳️", "white flag", &["white_flag"], Flags, "flag", (0, 7), NO_MIRROR, Uncommon),
+	emoji("🏴", "black flag", &["black_flag"], Flags, "flag", (1, 0), NO_MIRROR, Uncommon),
+	emoji("🏁", "chequered flag", &["chequered_flag", "checkered_flag"], Flags, "flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🚩", "triangular flag", &["triangular_flag", "triangular_flag_on_post"], Flags, "flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🏳️‍🌈", "rainbow flag", &["rainbow_flag", "pride_flag"], Flags, "flag", (4, 0), NO_MIRROR, Uncommon),
+	emoji("🏳️‍⚧️", "transgender flag", &["transgender_flag"], Flags, "flag", (13, 0), NO_MIRROR, Rare),
+	emoji("🏴‍☠️", "pirate flag", &["pirate_flag"], Flags, "flag", (11, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇫", "flag: Afghanistan", &["flag_afghanistan", "flag_af"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇽", "flag: Åland Islands", &["flag_aland_islands", "flag_ax"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇱", "flag: Albania", &["flag_albania", "flag_al"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇩🇿", "flag: Algeria", &["flag_algeria", "flag_dz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇸", "flag: American Samoa", &["flag_american_samoa", "flag_as"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇩", "flag: Andorra", &["flag_andorra", "flag_ad"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇴", "flag: Angola", &["flag_angola", "flag_ao"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇮", "flag: Anguilla", &["flag_anguilla", "flag_ai"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇶", "flag: Antarctica", &["flag_antarctica", "flag_aq"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇬", "flag: Antigua & Barbuda", &["flag_antigua_and_barbuda", "flag_ag"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇷", "flag: Argentina", &["flag_argentina", "flag_ar"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇲", "flag: Armenia", &["flag_armenia", "flag_am"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇼", "flag: Aruba", &["flag_aruba", "flag_aw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇺", "flag: Australia", &["flag_australia", "flag_au"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇹", "flag: Austria", &["flag_austria", "flag_at"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇿", "flag: Azerbaijan", &["flag_azerbaijan", "flag_az"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇸", "flag: Bahamas", &["flag_bahamas", "flag_bs"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇭", "flag: Bahrain", &["flag_bahrain", "flag_bh"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇩", "flag: Bangladesh", &["flag_bangladesh", "flag_bd"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇧", "flag: Barbados", &["flag_barbados", "flag_bb"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇾", "flag: Belarus", &["flag_belarus", "flag_by"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇪", "flag: Belgium", &["flag_belgium", "flag_be"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇿", "flag: Belize", &["flag_belize", "flag_bz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇯", "flag: Benin", &["flag_benin", "flag_bj"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇲", "flag: Bermuda", &["flag_bermuda", "flag_bm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇹", "flag: Bhutan", &["flag_bhutan", "flag_bt"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇴", "flag: Bolivia", &["flag_bolivia", "flag_bo"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇦", "flag: Bosnia & Herzegovina", &["flag_bosnia_and_herzegovina", "flag_ba"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇼", "flag: Botswana", &["flag_botswana", "flag_bw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇷", "flag: Brazil", &["flag_brazil", "flag_br"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇴", "flag: British Indian Ocean Territory", &["flag_british_indian_ocean_territory", "flag_io"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇻🇬", "flag: British Virgin Islands", &["flag_british_virgin_islands", "flag_vg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇳", "flag: Brunei", &["flag_brunei", "flag_bn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇬", "flag: Bulgaria", &["flag_bulgaria", "flag_bg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇫", "flag: Burkina Faso", &["flag_burkina_faso", "flag_bf"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇮", "flag: Burundi", &["flag_burundi", "flag_bi"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇭", "flag: Cambodia", &["flag_cambodia", "flag_kh"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇲", "flag: Cameroon", &["flag_cameroon", "flag_cm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇦", "flag: Canada", &["flag_canada", "flag_ca"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇨", "flag: Canary Islands", &["flag_canary_islands", "flag_ic"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇻", "flag: Cape Verde", &["flag_cape_verde", "flag_cv"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇶", "flag: Caribbean Netherlands", &["flag_caribbean_netherlands", "flag_bq"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇾", "flag: Cayman Islands", &["flag_cayman_islands", "flag_ky"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇫", "flag: Central African Republic", &["flag_central_african_republic", "flag_cf"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇩", "flag: Chad", &["flag_chad", "flag_td"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇱", "flag: Chile", &["flag_chile", "flag_cl"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇳", "flag: China", &["flag_china", "flag_cn"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇨🇽", "flag: Christmas Island", &["flag_christmas_island", "flag_cx"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇨", "flag: Cocos (Keeling) Islands", &["flag_cocos_keeling_islands", "flag_cc"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇴", "flag: Colombia", &["flag_colombia", "flag_co"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇲", "flag: Comoros", &["flag_comoros", "flag_km"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇬", "flag: Congo - Brazzaville", &["flag_congo_brazzaville", "flag_cg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇩", "flag: Congo - Kinshasa", &["flag_congo_kinshasa", "flag_cd"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇰", "flag: Cook Islands", &["flag_cook_islands", "flag_ck"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇷", "flag: Costa Rica", &["flag_costa_rica", "flag_cr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇮", "flag: Côte d'Ivoire", &["flag_cote_divoire", "flag_ci"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇭🇷", "flag: Croatia", &["flag_croatia", "flag_hr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇺", "flag: Cuba", &["flag_cuba", "flag_cu"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇼", "flag: Curaçao", &["flag_curacao", "flag_cw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇾", "flag: Cyprus", &["flag_cyprus", "flag_cy"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇿", "flag: Czechia", &["flag_czechia", "flag_cz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇩🇰", "flag: Denmark", &["flag_denmark", "flag_dk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇩🇯", "flag: Djibouti", &["flag_djibouti", "flag_dj"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇩🇲", "flag: Dominica", &["flag_dominica", "flag_dm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇩🇴", "flag: Dominican Republic", &["flag_dominican_republic", "flag_do"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇨", "flag: Ecuador", &["flag_ecuador", "flag_ec"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇬", "flag: Egypt", &["flag_egypt", "flag_eg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇻", "flag: El Salvador", &["flag_el_salvador", "flag_sv"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇶", "flag: Equatorial Guinea", &["flag_equatorial_guinea", "flag_gq"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇷", "flag: Eritrea", &["flag_eritrea", "flag_er"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇪", "flag: Estonia", &["flag_estonia", "flag_ee"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇹", "flag: Ethiopia", &["flag_ethiopia", "flag_et"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇺", "flag: European Union", &["flag_european_union", "flag_eu"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇫🇰", "flag: Falkland Islands", &["flag_falkland_islands", "flag_fk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇫🇴", "flag: Faroe Islands", &["flag_faroe_islands", "flag_fo"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇫🇯", "flag: Fiji", &["flag_fiji", "flag_fj"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇫🇮", "flag: Finland", &["flag_finland", "flag_fi"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇫🇷", "flag: France", &["flag_france", "flag_fr"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇬🇫", "flag: French Guiana", &["flag_french_guiana", "flag_gf"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇫", "flag: French Polynesia", &["flag_french_polynesia", "flag_pf"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇫", "flag: French Southern Territories", &["flag_french_southern_territories", "flag_tf"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇦", "flag: Gabon", &["flag_gabon", "flag_ga"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇲", "flag: Gambia", &["flag_gambia", "flag_gm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇪", "flag: Georgia", &["flag_georgia", "flag_ge"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇩🇪", "flag: Germany", &["flag_germany", "flag_de"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇬🇭", "flag: Ghana", &["flag_ghana", "flag_gh"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇮", "flag: Gibraltar", &["flag_gibraltar", "flag_gi"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇷", "flag: Greece", &["flag_greece", "flag_gr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇱", "flag: Greenland", &["flag_greenland", "flag_gl"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇩", "flag: Grenada", &["flag_grenada", "flag_gd"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇵", "flag: Guadeloupe", &["flag_guadeloupe", "flag_gp"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇺", "flag: Guam", &["flag_guam", "flag_gu"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇹", "flag: Guatemala", &["flag_guatemala", "flag_gt"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇬", "flag: Guernsey", &["flag_guernsey", "flag_gg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇳", "flag: Guinea", &["flag_guinea", "flag_gn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇼", "flag: Guinea-Bissau", &["flag_guinea_bissau", "flag_gw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇾", "flag: Guyana", &["flag_guyana", "flag_gy"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇭🇹", "flag: Haiti", &["flag_haiti", "flag_ht"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇭🇳", "flag: Honduras", &["flag_honduras", "flag_hn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇭🇰", "flag: Hong Kong SAR China", &["flag_hong_kong_sar_china", "flag_hk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇭🇺", "flag: Hungary", &["flag_hungary", "flag_hu"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇸", "flag: Iceland", &["flag_iceland", "flag_is"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇳", "flag: India", &["flag_india", "flag_in"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇩", "flag: Indonesia", &["flag_indonesia", "flag_id"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇷", "flag: Iran", &["flag_iran", "flag_ir"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇶", "flag: Iraq", &["flag_iraq", "flag_iq"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇪", "flag: Ireland", &["flag_ireland", "flag_ie"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇲", "flag: Isle of Man", &["flag_isle_of_man", "flag_im"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇱", "flag: Israel", &["flag_israel", "flag_il"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇮🇹", "flag: Italy", &["flag_italy", "flag_it"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇯🇲", "flag: Jamaica", &["flag_jamaica", "flag_jm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇯🇵", "flag: Japan", &["flag_japan", "flag_jp"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🎌", "crossed flags", &["crossed_flags"], Flags, "flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇯🇪", "flag: Jersey", &["flag_jersey", "flag_je"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇯🇴", "flag: Jordan", &["flag_jordan", "flag_jo"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇿", "flag: Kazakhstan", &["flag_kazakhstan", "flag_kz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇪", "flag: Kenya", &["flag_kenya", "flag_ke"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇮", "flag: Kiribati", &["flag_kiribati", "flag_ki"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇽🇰", "flag: Kosovo", &["flag_kosovo", "flag_xk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇼", "flag: Kuwait", &["flag_kuwait", "flag_kw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇬", "flag: Kyrgyzstan", &["flag_kyrgyzstan", "flag_kg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇦", "flag: Laos", &["flag_laos", "flag_la"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇻", "flag: Latvia", &["flag_latvia", "flag_lv"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇧", "flag: Lebanon", &["flag_lebanon", "flag_lb"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇸", "flag: Lesotho", &["flag_lesotho", "flag_ls"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇷", "flag: Liberia", &["flag_liberia", "flag_lr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇾", "flag: Libya", &["flag_libya", "flag_ly"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇮", "flag: Liechtenstein", &["flag_liechtenstein", "flag_li"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇹", "flag: Lithuania", &["flag_lithuania", "flag_lt"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇺", "flag: Luxembourg", &["flag_luxembourg", "flag_lu"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇴", "flag: Macao SAR China", &["flag_macao_sar_china", "flag_mo"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇰", "flag: North Macedonia", &["flag_north_macedonia", "flag_mk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇬", "flag: Madagascar", &["flag_madagascar", "flag_mg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇼", "flag: Malawi", &["flag_malawi", "flag_mw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇾", "flag: Malaysia", &["flag_malaysia", "flag_my"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇻", "flag: Maldives", &["flag_maldives", "flag_mv"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇱", "flag: Mali", &["flag_mali", "flag_ml"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇹", "flag: Malta", &["flag_malta", "flag_mt"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇭", "flag: Marshall Islands", &["flag_marshall_islands", "flag_mh"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇶", "flag: Martinique", &["flag_martinique", "flag_mq"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇷", "flag: Mauritania", &["flag_mauritania", "flag_mr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇺", "flag: Mauritius", &["flag_mauritius", "flag_mu"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇾🇹", "flag: Mayotte", &["flag_mayotte", "flag_yt"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇽", "flag: Mexico", &["flag_mexico", "flag_mx"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇫🇲", "flag: Micronesia", &["flag_micronesia", "flag_fm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇩", "flag: Moldova", &["flag_moldova", "flag_md"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇨", "flag: Monaco", &["flag_monaco", "flag_mc"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇳", "flag: Mongolia", &["flag_mongolia", "flag_mn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇪", "flag: Montenegro", &["flag_montenegro", "flag_me"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇸", "flag: Montserrat", &["flag_montserrat", "flag_ms"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇦", "flag: Morocco", &["flag_morocco", "flag_ma"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇿", "flag: Mozambique", &["flag_mozambique", "flag_mz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇲", "flag: Myanmar (Burma)", &["flag_myanmar_burma", "flag_mm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇦", "flag: Namibia", &["flag_namibia", "flag_na"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇷", "flag: Nauru", &["flag_nauru", "flag_nr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇵", "flag: Nepal", &["flag_nepal", "flag_np"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇱", "flag: Netherlands", &["flag_netherlands", "flag_nl"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇨", "flag: New Caledonia", &["flag_new_caledonia", "flag_nc"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇿", "flag: New Zealand", &["flag_new_zealand", "flag_nz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇮", "flag: Nicaragua", &["flag_nicaragua", "flag_ni"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇪", "flag: Niger", &["flag_niger", "flag_ne"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇬", "flag: Nigeria", &["flag_nigeria", "flag_ng"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇺", "flag: Niue", &["flag_niue", "flag_nu"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇫", "flag: Norfolk Island", &["flag_norfolk_island", "flag_nf"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇵", "flag: North Korea", &["flag_north_korea", "flag_kp"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇵", "flag: Northern Mariana Islands", &["flag_northern_mariana_islands", "flag_mp"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇳🇴", "flag: Norway", &["flag_norway", "flag_no"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇴🇲", "flag: Oman", &["flag_oman", "flag_om"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇰", "flag: Pakistan", &["flag_pakistan", "flag_pk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇼", "flag: Palau", &["flag_palau", "flag_pw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇸", "flag: Palestinian Territories", &["flag_palestinian_territories", "flag_ps"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇦", "flag: Panama", &["flag_panama", "flag_pa"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇬", "flag: Papua New Guinea", &["flag_papua_new_guinea", "flag_pg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇾", "flag: Paraguay", &["flag_paraguay", "flag_py"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇪", "flag: Peru", &["flag_peru", "flag_pe"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇭", "flag: Philippines", &["flag_philippines", "flag_ph"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇳", "flag: Pitcairn Islands", &["flag_pitcairn_islands", "flag_pn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇱", "flag: Poland", &["flag_poland", "flag_pl"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇹", "flag: Portugal", &["flag_portugal", "flag_pt"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇷", "flag: Puerto Rico", &["flag_puerto_rico", "flag_pr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇶🇦", "flag: Qatar", &["flag_qatar", "flag_qa"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇷🇪", "flag: Réunion", &["flag_reunion", "flag_re"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇷🇴", "flag: Romania", &["flag_romania", "flag_ro"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇷🇺", "flag: Russia", &["flag_russia", "flag_ru"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇷🇼", "flag: Rwanda", &["flag_rwanda", "flag_rw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇼🇸", "flag: Samoa", &["flag_samoa", "flag_ws"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇲", "flag: San Marino", &["flag_san_marino", "flag_sm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇹", "flag: São Tomé & Príncipe", &["flag_sao_tome_and_principe", "flag_st"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇦", "flag: Saudi Arabia", &["flag_saudi_arabia", "flag_sa"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇳", "flag: Senegal", &["flag_senegal", "flag_sn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇷🇸", "flag: Serbia", &["flag_serbia", "flag_rs"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇨", "flag: Seychelles", &["flag_seychelles", "flag_sc"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇱", "flag: Sierra Leone", &["flag_sierra_leone", "flag_sl"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇬", "flag: Singapore", &["flag_singapore", "flag_sg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇽", "flag: Sint Maarten", &["flag_sint_maarten", "flag_sx"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇰", "flag: Slovakia", &["flag_slovakia", "flag_sk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇮", "flag: Slovenia", &["flag_slovenia", "flag_si"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇸", "flag: South Georgia & South Sandwich Islands", &["flag_south_georgia_and_south_sandwich_islands", "flag_gs"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇧", "flag: Solomon Islands", &["flag_solomon_islands", "flag_sb"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇴", "flag: Somalia", &["flag_somalia", "flag_so"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇿🇦", "flag: South Africa", &["flag_south_africa", "flag_za"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇷", "flag: South Korea", &["flag_south_korea", "flag_kr"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇸🇸", "flag: South Sudan", &["flag_south_sudan", "flag_ss"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇸", "flag: Spain", &["flag_spain", "flag_es"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇱🇰", "flag: Sri Lanka", &["flag_sri_lanka", "flag_lk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇱", "flag: St. Barthélemy", &["flag_st_barthelemy", "flag_bl"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇭", "flag: St. Helena", &["flag_st_helena", "flag_sh"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇰🇳", "flag: St. Kitts & Nevis", &["flag_st_kitts_and_nevis", "flag_kn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇱🇨", "flag: St. Lucia", &["flag_st_lucia", "flag_lc"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇵🇲", "flag: St. Pierre & Miquelon", &["flag_st_pierre_and_miquelon", "flag_pm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇻🇨", "flag: St. Vincent & Grenadines", &["flag_st_vincent_and_grenadines", "flag_vc"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇩", "flag: Sudan", &["flag_sudan", "flag_sd"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇷", "flag: Suriname", &["flag_suriname", "flag_sr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇿", "flag: Eswatini", &["flag_eswatini", "flag_sz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇪", "flag: Sweden", &["flag_sweden", "flag_se"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇭", "flag: Switzerland", &["flag_switzerland", "flag_ch"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇾", "flag: Syria", &["flag_syria", "flag_sy"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇼", "flag: Taiwan", &["flag_taiwan", "flag_tw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇯", "flag: Tajikistan", &["flag_tajikistan", "flag_tj"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇿", "flag: Tanzania", &["flag_tanzania", "flag_tz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇭", "flag: Thailand", &["flag_thailand", "flag_th"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇱", "flag: Timor-Leste", &["flag_timor_leste", "flag_tl"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇬", "flag: Togo", &["flag_togo", "flag_tg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇰", "flag: Tokelau", &["flag_tokelau", "flag_tk"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇴", "flag: Tonga", &["flag_tonga", "flag_to"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇹", "flag: Trinidad & Tobago", &["flag_trinidad_and_tobago", "flag_tt"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇳", "flag: Tunisia", &["flag_tunisia", "flag_tn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇷", "flag: Türkiye", &["flag_turkiye", "flag_tr"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇲", "flag: Turkmenistan", &["flag_turkmenistan", "flag_tm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇨", "flag: Turks & Caicos Islands", &["flag_turks_and_caicos_islands", "flag_tc"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇻🇮", "flag: U.S. Virgin Islands", &["flag_us_virgin_islands", "flag_vi"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇻", "flag: Tuvalu", &["flag_tuvalu", "flag_tv"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇺🇬", "flag: Uganda", &["flag_uganda", "flag_ug"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇺🇦", "flag: Ukraine", &["flag_ukraine", "flag_ua"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇪", "flag: United Arab Emirates", &["flag_united_arab_emirates", "flag_ae"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇬🇧", "flag: United Kingdom", &["flag_united_kingdom", "flag_gb"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🏴󠁧󠁢󠁥󠁮󠁧󠁿", "flag: England", &["flag_england"], Flags, "subdivision-flag", (5, 0), NO_MIRROR, Uncommon),
+	emoji("🏴󠁧󠁢󠁳󠁣󠁴󠁿", "flag: Scotland", &["flag_scotland"], Flags, "subdivision-flag", (5, 0), NO_MIRROR, Uncommon),
+	emoji("🏴󠁧󠁢󠁷󠁬󠁳󠁿", "flag: Wales", &["flag_wales"], Flags, "subdivision-flag", (5, 0), NO_MIRROR, Uncommon),
+	emoji("🇺🇸", "flag: United States", &["flag_united_states", "flag_us"], Flags, "country-flag", (0, 6), NO_MIRROR, Uncommon),
+	emoji("🇺🇾", "flag: Uruguay", &["flag_uruguay", "flag_uy"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇺🇿", "flag: Uzbekistan", &["flag_uzbekistan", "flag_uz"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇻🇺", "flag: Vanuatu", &["flag_vanuatu", "flag_vu"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇻🇦", "flag: Vatican City", &["flag_vatican_city", "flag_va"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇻🇪", "flag: Venezuela", &["flag_venezuela", "flag_ve"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇻🇳", "flag: Vietnam", &["flag_vietnam", "flag_vn"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇼🇫", "flag: Wallis & Futuna", &["flag_wallis_and_futuna", "flag_wf"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇭", "flag: Western Sahara", &["flag_western_sahara", "flag_eh"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇾🇪", "flag: Yemen", &["flag_yemen", "flag_ye"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇿🇲", "flag: Zambia", &["flag_zambia", "flag_zm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇿🇼", "flag: Zimbabwe", &["flag_zimbabwe", "flag_zw"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇦🇨", "flag: Ascension Island", &["flag_ascension_island", "flag_ac"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇧🇻", "flag: Bouvet Island", &["flag_bouvet_island", "flag_bv"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇨🇵", "flag: Clipperton Island", &["flag_clipperton_island", "flag_cp"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇪🇦", "flag: Ceuta & Melilla", &["flag_ceuta_and_melilla", "flag_ea"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇩🇬", "flag: Diego Garcia", &["flag_diego_garcia", "flag_dg"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇭🇲", "flag: Heard & McDonald Islands", &["flag_heard_and_mcdonald_islands", "flag_hm"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇲🇫", "flag: St. Martin", &["flag_st_martin", "flag_mf"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇸🇯", "flag: Svalbard & Jan Mayen", &["flag_svalbard_and_jan_mayen", "flag_sj"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇹🇦", "flag: Tristan da Cunha", &["flag_tristan_da_cunha", "flag_ta"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇺🇲", "flag: U.S. Outlying Islands", &["flag_us_outlying_islands", "flag_um"], Flags, "country-flag", (2, 0), NO_MIRROR, Uncommon),
+	emoji("🇺🇳", "flag: United Nations", &["flag_united_nations", "flag_un"], Flags, "country-flag", (4, 0), NO_MIRROR, Uncommon),
 ];
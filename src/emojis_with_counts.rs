@@ -1,10 +1,11 @@
 use std::{collections::HashMap, fmt::Display};
 
-use serenity::model::prelude::UserId;
+use serenity::model::prelude::{GuildId, UserId};
 use sqlx::{query, Pool, Sqlite};
 
 use crate::{
 	emoji::{Emoji, EmojiMap},
+	error::BotError,
 	images::generate::EmojiToRender,
 	special_characters::ZWNJ,
 };
@@ -26,33 +27,61 @@ impl EmojisWithCounts {
 	pub async fn from_database_for_user(
 		executor: &Pool<Sqlite>,
 		emoji_map: &EmojiMap,
+		guild: GuildId,
 		user: UserId,
-	) -> Self {
+	) -> Result<Self, BotError> {
+		let guild_id = guild.get() as i64;
 		let user_id = user.get() as i64;
 		let mut emojis = query!(
 			"
 			SELECT emoji, COUNT(*) AS count
 			FROM emoji_inventory
-			WHERE user = ?
+			WHERE guild = ? AND user = ?
 			GROUP BY emoji
 			",
+			guild_id,
 			user_id
 		)
 		.fetch_all(executor)
-		.await
-		.unwrap()
+		.await?
 		.into_iter()
-		.filter_map(|record| {
-			(record.count > 0).then_some((
-				emoji_map
-					.get(record.emoji.as_str())
-					.expect("Emoji from database was somehow not in map."),
-				record.count as u32,
-			))
+		.filter(|record| record.count > 0)
+		.map(|record| {
+			let emoji = emoji_map.get_from_database(&record.emoji)?;
+			Ok((emoji, record.count as u32))
 		})
-		.collect::<Vec<_>>();
+		.collect::<Result<Vec<_>, BotError>>()?;
 		emojis.sort_unstable();
-		Self(emojis)
+		Ok(Self(emojis))
+	}
+	/// The user's emojis that are locked in trade offers or escrow, and can't be traded or recycled until they are released.
+	pub async fn locked_for_user(
+		executor: &Pool<Sqlite>,
+		emoji_map: &EmojiMap,
+		guild: GuildId,
+		user: UserId,
+	) -> Result<Self, BotError> {
+		let guild_id = guild.get() as i64;
+		let user_id = user.get() as i64;
+		let emojis = query!(
+			"
+			SELECT emoji, COUNT(*) AS count
+			FROM emoji_inventory
+			WHERE guild = ? AND user = ? AND lock IS NOT NULL
+			GROUP BY emoji
+			",
+			guild_id,
+			user_id
+		)
+		.fetch_all(executor)
+		.await?
+		.into_iter()
+		.map(|record| {
+			let emoji = emoji_map.get_from_database(&record.emoji)?;
+			Ok((emoji, record.count as u32))
+		})
+		.collect::<Result<Vec<_>, BotError>>()?;
+		Ok(Self::new(emojis))
 	}
 	pub fn from_flat<'l>(iter: impl IntoIterator<Item = &'l Emoji>) -> Self {
 		let mut emojis = HashMap::new();
@@ -89,32 +118,59 @@ impl EmojisWithCounts {
 	}
 
 	/// Check a user's emoji inventory to see if it has the emojis.
-	pub async fn are_owned_by_user(&self, database: &Pool<Sqlite>, user: UserId) -> bool {
+	pub async fn are_owned_by_user(
+		&self,
+		database: &Pool<Sqlite>,
+		guild: GuildId,
+		user: UserId,
+	) -> Result<bool, BotError> {
+		self.are_in_inventory(database, guild, user, true).await
+	}
+
+	/// Like `are_owned_by_user`, but without the emojis locked in escrow, which can't be traded or recycled.
+	pub async fn are_available_to_user(
+		&self,
+		database: &Pool<Sqlite>,
+		guild: GuildId,
+		user: UserId,
+	) -> Result<bool, BotError> {
+		self.are_in_inventory(database, guild, user, false).await
+	}
+
+	async fn are_in_inventory(
+		&self,
+		database: &Pool<Sqlite>,
+		guild: GuildId,
+		user: UserId,
+		count_locked: bool,
+	) -> Result<bool, BotError> {
+		let guild_id = guild.get() as i64;
 		let user_id = user.get() as i64;
-		let mut transaction = database.begin().await.unwrap();
+		let mut transaction = database.begin().await?;
 		for (emoji, target_count) in &self.0 {
 			let emoji = emoji.as_str();
 			let count = query!(
 				"
 				SELECT COUNT(*) AS count
 				FROM emoji_inventory
-				WHERE user = ? AND emoji = ?
+				WHERE guild = ? AND user = ? AND emoji = ? AND (? OR lock IS NULL)
 				",
+				guild_id,
 				user_id,
-				emoji
+				emoji,
+				count_locked
 			)
 			.fetch_optional(&mut *transaction)
-			.await
-			.unwrap()
+			.await?
 			.map(|record| record.count)
 			.unwrap_or(0);
 			if (count as u32) < *target_count {
-				transaction.commit().await.unwrap();
-				return false;
+				transaction.commit().await?;
+				return Ok(false);
 			}
 		}
-		transaction.commit().await.unwrap();
-		true
+		transaction.commit().await?;
+		Ok(true)
 	}
 
 	pub fn iter(&'_ self) -> std::slice::Iter<'_, (Emoji, u32)> {
@@ -134,6 +190,26 @@ impl EmojisWithCounts {
 	pub fn is_empty(&self) -> bool {
 		self.0.is_empty()
 	}
+
+	/// How many of the emoji there are, if any.
+	pub fn count_of(&self, emoji: Emoji) -> u32 {
+		self.0
+			.binary_search_by_key(&emoji, |(emoji, _)| *emoji)
+			.map_or(0, |index| self.0[index].1)
+	}
+
+	/// What is left after taking away as many of each emoji as the other list has.
+	pub fn without(&self, other: &Self) -> Self {
+		Self(
+			self.0
+				.iter()
+				.filter_map(|(emoji, count)| {
+					let left = count.saturating_sub(other.count_of(*emoji));
+					(left > 0).then_some((*emoji, left))
+				})
+				.collect(),
+		)
+	}
 }
 
 impl Display for EmojisWithCounts {
@@ -142,7 +218,7 @@ impl Display for EmojisWithCounts {
 			f.write_str(emoji.as_str())?;
 			if *count > 1 {
 				f.write_fmt(format_args!("x{count}"))?;
-			} else if (1580..=1605).contains(&emoji.index()) {
+			} else if emoji.subcategory() == "regional-indicator" {
 				f.write_str(ZWNJ)?; // To avoid regional indicator emojis combining inappropriately.
 			}
 		}
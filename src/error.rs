@@ -0,0 +1,107 @@
+use std::fmt::Display;
+
+use serenity::all::{CommandDataOptionValue, CommandInteraction};
+
+use crate::{discord::Discord, util::ReplyShortcuts};
+
+/// Everything that can go wrong while handling an interaction.
+#[derive(Debug)]
+pub enum BotError {
+	/// A problem with what the user asked for. The message is shown to them as is, and is not logged.
+	User(String),
+	/// The interaction did not have the shape the command was registered with.
+	InvalidInteraction(&'static str),
+	/// An emoji from the database is not in the emoji list.
+	UnknownEmoji(String),
+	Database(sqlx::Error),
+	/// Boxed, as it is much larger than the other variants.
+	Discord(Box<serenity::Error>),
+}
+
+impl Display for BotError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::User(message) => f.write_str(message),
+			Self::InvalidInteraction(problem) => write!(f, "Invalid interaction: {problem}"),
+			Self::UnknownEmoji(emoji) => {
+				write!(
+					f,
+					"Emoji \"{emoji}\" from the database is not in the emoji list."
+				)
+			}
+			Self::Database(error) => write!(f, "Database error: {error}"),
+			Self::Discord(error) => write!(f, "Discord error: {error}"),
+		}
+	}
+}
+
+impl From<sqlx::Error> for BotError {
+	fn from(error: sqlx::Error) -> Self {
+		Self::Database(error)
+	}
+}
+
+impl From<serenity::Error> for BotError {
+	fn from(error: serenity::Error) -> Self {
+		Self::Discord(Box::new(error))
+	}
+}
+
+impl From<String> for BotError {
+	fn from(message: String) -> Self {
+		Self::User(message)
+	}
+}
+
+impl From<&str> for BotError {
+	fn from(message: &str) -> Self {
+		Self::User(String::from(message))
+	}
+}
+
+/// Logs the error if it is not the user's doing, and tells the user about it, so the interaction never stays unanswered.
+pub async fn report_error(
+	discord: &dyn Discord,
+	interaction: &CommandInteraction,
+	error: BotError,
+) {
+	let message = match &error {
+		BotError::User(message) => message.as_str(),
+		_ => {
+			eprintln!(
+				"Error handling {} for user {} in guild {}: {error}",
+				command_path(interaction),
+				interaction.user.id,
+				interaction
+					.guild_id
+					.map(|guild| guild.to_string())
+					.unwrap_or_else(|| String::from("none")),
+			);
+			"Something went wrong while handling that command. Please try again later."
+		}
+	};
+	// If the interaction was already responded to, a follow-up is the only way left to say something.
+	if interaction.ephemeral_reply(discord, message).await.is_err() {
+		let _ = discord
+			.follow_up(interaction, String::from(message), true)
+			.await;
+	}
+}
+
+/// The command name with any subcommand group and subcommand, like "/group remove from".
+fn command_path(interaction: &CommandInteraction) -> String {
+	let mut path = format!("/{}", interaction.data.name);
+	let mut options = &interaction.data.options;
+	while let Some(option) = options.first() {
+		match &option.value {
+			CommandDataOptionValue::SubCommand(sub_options)
+			| CommandDataOptionValue::SubCommandGroup(sub_options) => {
+				path.push(' ');
+				path.push_str(&option.name);
+				options = sub_options;
+			}
+			_ => break,
+		}
+	}
+	path
+}
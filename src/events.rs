@@ -0,0 +1,212 @@
+//! Time-boxed events, during which some emojis drop more often, and the command to see what is on.
+
+use std::{collections::HashMap, fmt::Write};
+
+use chrono::{DateTime, Utc};
+use rand::{seq::SliceRandom, thread_rng, Rng, RngCore};
+use serenity::{
+	all::{CommandDataOptionValue, CommandInteraction, CommandOptionType},
+	async_trait,
+	builder::{CreateCommand, CreateCommandOption},
+};
+
+use crate::{
+	commands::SlashCommand, config::Event, context::Context, drop_table::DropTable, emoji::Emoji,
+	error::BotError, util::ReplyShortcuts,
+};
+
+/// Wraps a guild's drop table to boost the emojis of the events that are on.
+pub struct EventTable<'l> {
+	base: &'l dyn DropTable,
+	/// The boost of every boosted emoji. When events overlap, the largest boost counts.
+	boosts: HashMap<Emoji, u32>,
+	max_boost: u32,
+}
+
+impl<'l> EventTable<'l> {
+	/// Without any events on at the time, this just passes through to the base table.
+	pub fn new(base: &'l dyn DropTable, events: &[Event], time: DateTime<Utc>) -> Self {
+		let mut boosts = HashMap::new();
+		for event in events.iter().filter(|event| event.is_active(time)) {
+			for emoji in &event.emojis {
+				let boost = boosts.entry(*emoji).or_insert(1);
+				*boost = event.boost.max(*boost);
+			}
+		}
+		let max_boost = boosts.values().copied().max().unwrap_or(1);
+		Self {
+			base,
+			boosts,
+			max_boost,
+		}
+	}
+}
+
+impl DropTable for EventTable<'_> {
+	/// Rejecting emojis by how far short of the largest boost they fall multiplies every emoji's chance by its boost, while keeping the base table's weights otherwise.
+	fn pick(&self, rng: &mut dyn RngCore) -> Emoji {
+		loop {
+			let emoji = self.base.pick(rng);
+			let boost = self.boosts.get(&emoji).copied().unwrap_or(1);
+			if rng.gen_ratio(boost, self.max_boost) {
+				return emoji;
+			}
+		}
+	}
+}
+
+/// One extra emoji for every event with bonus drops that is on at the time.
+pub fn bonus_drops(events: &[Event], time: DateTime<Utc>) -> Vec<Emoji> {
+	let mut rng = thread_rng();
+	events
+		.iter()
+		.filter(|event| event.bonus_drop && event.is_active(time))
+		.filter_map(|event| event.emojis.choose(&mut rng).copied())
+		.collect()
+}
+
+/// Like "<t:1729814400:f>", which Discord shows in the reader's own timezone.
+fn timestamp(time: DateTime<Utc>, style: char) -> String {
+	format!("<t:{}:{style}>", time.timestamp())
+}
+
+fn describe_events(events: &[Event], time: DateTime<Utc>) -> String {
+	let mut content = String::new();
+	for event in events.iter().filter(|event| event.is_active(time)) {
+		let emojis: String = event.emojis.iter().map(Emoji::as_str).collect();
+		write!(
+			content,
+			"**{}** ends {} ({}). {emojis} drop {} times as often",
+			event.name,
+			timestamp(event.end, 'f'),
+			timestamp(event.end, 'R'),
+			event.boost
+		)
+		.unwrap();
+		if event.bonus_drop {
+			content.push_str(", and every drop comes with one of them as a bonus");
+		}
+		content.push_str(".\n");
+	}
+	if content.is_empty() {
+		content.push_str("There is no event on right now.");
+		if let Some(next) = events
+			.iter()
+			.filter(|event| event.start > time)
+			.min_by_key(|event| event.start)
+		{
+			write!(
+				content,
+				" The next one is **{}**, starting {}.",
+				next.name,
+				timestamp(next.start, 'R')
+			)
+			.unwrap();
+		}
+	}
+	content
+}
+
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let subcommand = interaction
+		.data
+		.options
+		.first()
+		.ok_or(BotError::InvalidInteraction("subcommand missing"))?;
+	let CommandDataOptionValue::SubCommand(_) = &subcommand.value else {
+		return Err(BotError::InvalidInteraction("expected a subcommand"));
+	};
+	let content = match subcommand.name.as_str() {
+		"current" => describe_events(&context.guild_config().events, Utc::now()),
+		_ => return Err(BotError::InvalidInteraction("unknown subcommand")),
+	};
+	interaction
+		.ephemeral_reply(context.discord, content)
+		.await?;
+	Ok(())
+}
+
+fn register() -> CreateCommand {
+	CreateCommand::new("event")
+		.description("Seasonal events, during which some emojis drop more often.")
+		.add_option(CreateCommandOption::new(
+			CommandOptionType::SubCommand,
+			"current",
+			"Shows the events that are on, and when they end.",
+		))
+}
+
+pub struct EventCommand;
+
+#[async_trait]
+impl SlashCommand for EventCommand {
+	fn name(&self) -> &'static str {
+		"event"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::TimeDelta;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use crate::{drop_table::RarityTable, emoji_list::Rarity};
+
+	use super::*;
+
+	#[test]
+	fn event_emojis_drop_more_often_while_it_is_on() {
+		let base = RarityTable::new(|rarity| (rarity == Rarity::Common) as u32).unwrap();
+		let commons = Emoji::all()
+			.filter(|emoji| emoji.rarity() == Rarity::Common)
+			.count();
+		let emojis: Vec<_> = Emoji::all()
+			.filter(|emoji| emoji.rarity() == Rarity::Common)
+			.take(2)
+			.collect();
+		let start = DateTime::parse_from_rfc3339("2024-10-25T00:00:00Z")
+			.unwrap()
+			.to_utc();
+		let events = [Event {
+			name: String::from("Halloween"),
+			start,
+			end: start + TimeDelta::weeks(1),
+			emojis: emojis.clone(),
+			boost: 50,
+			bonus_drop: true,
+		}];
+
+		let share = |time| {
+			let table = EventTable::new(&base, &events, time);
+			let mut rng = StdRng::seed_from_u64(1);
+			const DRAWS: u32 = 20_000;
+			let boosted = (0..DRAWS)
+				.filter(|_| emojis.contains(&table.pick(&mut rng)))
+				.count();
+			boosted as f64 / DRAWS as f64
+		};
+		let expected = 100.0 / (commons - 2 + 100) as f64;
+		let during = share(start + TimeDelta::days(3));
+		assert!(
+			(during - expected).abs() < 0.01,
+			"{during} instead of {expected}"
+		);
+		let after = share(start + TimeDelta::weeks(1));
+		assert!(after < 0.01, "{after} after the event");
+
+		assert_eq!(bonus_drops(&events, start).len(), 1);
+		assert!(bonus_drops(&events, start - TimeDelta::seconds(1)).is_empty());
+		assert!(describe_events(&events, start - TimeDelta::days(1)).contains("starting"));
+		assert!(describe_events(&events, start).starts_with("**Halloween** ends"));
+	}
+}
@@ -1,14 +1,23 @@
 use std::fmt::Write;
 
 use serenity::{
-	all::{CommandDataOptionValue, CommandInteraction, CommandOptionType, UserId},
+	all::{CommandDataOptionValue, CommandInteraction, CommandOptionType, GuildId, UserId},
+	async_trait,
 	builder::{CreateCommand, CreateCommandOption},
 };
 use sqlx::{query, Pool, Sqlite};
 
-use crate::{context::Context, emoji::Emoji, util::ReplyShortcuts};
+use crate::{
+	autocomplete, commands::SlashCommand, context::Context, discord::Choice, emoji::Emoji,
+	error::BotError, util::ReplyShortcuts,
+};
 
-async fn find_emoji_users(executor: &Pool<Sqlite>, emoji: Emoji) -> Vec<(UserId, i64)> {
+async fn find_emoji_users(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	emoji: Emoji,
+) -> Result<Vec<(UserId, i64)>, BotError> {
+	let guild_id = guild.get() as i64;
 	let emoji = emoji.as_str();
 	let result = query!(
 		"
@@ -16,25 +25,35 @@ async fn find_emoji_users(executor: &Pool<Sqlite>, emoji: Emoji) -> Vec<(UserId,
 		FROM emoji_inventory
 			LEFT JOIN user_settings
 			ON emoji_inventory.user = user_settings.user
-		WHERE IFNULL(private, 0) = 0 AND emoji = ?
+		WHERE IFNULL(listed_as_owner, 1) = 1 AND guild = ? AND emoji = ?
 		GROUP BY emoji_inventory.user, emoji
 		",
+		guild_id,
 		emoji
 	)
 	.fetch_all(executor)
-	.await
-	.unwrap();
+	.await?;
 
-	result
+	Ok(result
 		.into_iter()
 		.map(|record| (UserId::new(record.user as u64), record.count))
-		.collect()
+		.collect())
+}
+
+/// Like "🦄 is legendary: 2% of drops here are legendary."
+fn describe_rarity(context: Context<'_>, emoji: Emoji) -> String {
+	let rarity = emoji.rarity();
+	let percentage = context.guild_config().drop_table.chance(rarity) * 100.0;
+	// Rounded to at most one decimal.
+	let percentage = (percentage * 10.0).round() / 10.0;
+	format!("{emoji} is {rarity}: {percentage}% of drops here are {rarity}.")
 }
 
-pub async fn execute(context: Context<'_>, interaction: CommandInteraction) {
-	let options = &interaction.data.options.first().unwrap().value;
-	let CommandDataOptionValue::SubCommand(options) = options else {
-		panic!()
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let Some(CommandDataOptionValue::SubCommand(options)) =
+		interaction.data.options.first().map(|option| &option.value)
+	else {
+		return Err(BotError::InvalidInteraction("expected a subcommand"));
 	};
 	let input = options
 		.first()
@@ -42,30 +61,32 @@ pub async fn execute(context: Context<'_>, interaction: CommandInteraction) {
 		.unwrap_or("");
 
 	let Some(emoji) = context.emoji_map.get(input) else {
-		let content = format!("Could not find \"{}\" as an emoji in my list.", input);
-		let _ = interaction.ephemeral_reply(context.http, content).await;
-		return;
+		return Err(format!("Could not find \"{}\" as an emoji in my list.", input).into());
 	};
 
 	let is_public = options.get(1).is_some();
 
-	let users = find_emoji_users(context.database, emoji).await;
+	let users = find_emoji_users(context.database, context.guild, emoji).await?;
 
 	if users.is_empty() {
-		let _ = interaction
+		interaction
 			.reply(
-				context.http,
-				format!("Nobody with a public inventory has {}.", emoji),
+				context.discord,
+				format!(
+					"{} Nobody with a public inventory has it.",
+					describe_rarity(context, emoji)
+				),
 				!is_public,
 			)
-			.await;
-		return;
+			.await?;
+		return Ok(());
 	}
-	let mut output = if users.len() == 1 {
-		format!("The only user with a public inventory with {} is ", emoji)
+	let mut output = format!("{} ", describe_rarity(context, emoji));
+	if users.len() == 1 {
+		output.push_str("The only user with a public inventory with it is ");
 	} else {
-		format!("The users with public inventories with {} are ", emoji)
-	};
+		output.push_str("The users with public inventories with it are ");
+	}
 
 	let mut first = true;
 	for (user, count) in users {
@@ -85,10 +106,13 @@ pub async fn execute(context: Context<'_>, interaction: CommandInteraction) {
 	}
 	output.push('.');
 
-	let _ = interaction.reply(context.http, output, !is_public).await;
+	interaction
+		.reply(context.discord, output, !is_public)
+		.await?;
+	Ok(())
 }
 
-pub fn register() -> CreateCommand {
+fn register() -> CreateCommand {
 	CreateCommand::new("who")
 		.description("Required field that shows up nowhere")
 		.add_option(
@@ -103,7 +127,8 @@ pub fn register() -> CreateCommand {
 					"emoji",
 					"The emoji to look for.",
 				)
-				.required(true),
+				.required(true)
+				.set_autocomplete(true),
 			)
 			.add_sub_option(
 				CreateCommandOption::new(
@@ -116,3 +141,34 @@ pub fn register() -> CreateCommand {
 			),
 		)
 }
+
+pub struct WhoCommand;
+
+#[async_trait]
+impl SlashCommand for WhoCommand {
+	fn name(&self) -> &'static str {
+		"who"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+	async fn autocomplete(
+		&self,
+		_context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<Vec<Choice>, BotError> {
+		let input = interaction
+			.data
+			.autocomplete()
+			.ok_or(BotError::InvalidInteraction("no option to autocomplete"))?
+			.value;
+		Ok(autocomplete::any_emoji(input))
+	}
+}
@@ -5,16 +5,20 @@ use std::f32::consts::PI;
 use rand::Rng;
 use rand_distr::Distribution;
 use serenity::{
-	all::{CommandInteraction, CommandOptionType, UserId},
+	all::{CommandInteraction, CommandOptionType, GuildId, UserId},
+	async_trait,
 	builder::{CreateCommand, CreateCommandOption},
 	model::Permissions,
 };
 use sqlx::{Pool, Sqlite};
 
 use crate::{
+	commands::SlashCommand,
+	config::ImageConfig,
 	context::Context,
 	emoji::{Emoji, EmojiMap, EmojiWithImage},
 	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
 	inventory::queries::get_group_contents,
 	util::{parse_emoji_input, parse_emoji_input_with_modifiers, ReplyShortcuts},
 };
@@ -22,8 +26,6 @@ use crate::{
 /// The base size (in pixels across) of an emoji rendered based on a single inventory emoji.
 const EMOJI_SIZE: f32 = 90.0;
 
-const CANVAS_WIDTH: u32 = 500;
-const CANVAS_HEIGHT: u32 = 250;
 const EMOJI_REPETITION: usize = 5;
 
 /// Angle in radians.
@@ -112,20 +114,21 @@ fn place_emoji_randomly(
 async fn parse_emoji_and_group_input(
 	database: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	user: UserId,
 	input: &str,
-) -> Result<EmojisWithCounts, String> {
+) -> Result<EmojisWithCounts, BotError> {
 	let mut emojis = Vec::new();
 	for substring in input.split(',') {
 		let substring = substring.trim();
 		if let Ok(parsed_emojis) = parse_emoji_input(emoji_map, substring) {
 			emojis.extend(parsed_emojis);
 		} else {
-			let group_emojis = get_group_contents(database, emoji_map, user, substring)
-				.await
+			let group_emojis = get_group_contents(database, emoji_map, guild, user, substring)
+				.await?
 				.flatten();
 			if group_emojis.is_empty() {
-				return Err(format!("You do not have a group named \"{substring}\"."));
+				return Err(format!("You do not have a group named \"{substring}\".").into());
 			}
 			emojis.extend(group_emojis);
 		}
@@ -133,8 +136,12 @@ async fn parse_emoji_and_group_input(
 	Ok(EmojisWithCounts::from_flat(&emojis))
 }
 
-fn generate<'l>(emojis: impl IntoIterator<Item = EmojiToRender<'l>>) -> resvg::tiny_skia::Pixmap {
-	let mut canvas = resvg::tiny_skia::Pixmap::new(CANVAS_WIDTH, CANVAS_HEIGHT).unwrap();
+fn generate<'l>(
+	config: &ImageConfig,
+	emojis: impl IntoIterator<Item = EmojiToRender<'l>>,
+) -> resvg::tiny_skia::Pixmap {
+	let mut canvas =
+		resvg::tiny_skia::Pixmap::new(config.canvas_width, config.canvas_height).unwrap();
 
 	let mut rng = rand::thread_rng();
 	let canvas_mut = &mut canvas.as_mut();
@@ -146,32 +153,32 @@ fn generate<'l>(emojis: impl IntoIterator<Item = EmojiToRender<'l>>) -> resvg::t
 	canvas
 }
 
-pub async fn execute_test(context: Context<'_>, interaction: CommandInteraction) {
-	let emojis = match parse_emoji_input_with_modifiers(
+async fn execute_test(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+) -> Result<(), BotError> {
+	let emojis = parse_emoji_input_with_modifiers(
 		context.emoji_map,
 		interaction
 			.data
 			.options
 			.first()
 			.and_then(|option| option.value.as_str())
-			.unwrap(),
-	) {
-		Ok(emojis) => emojis,
-		Err(text) => {
-			let _ = interaction.ephemeral_reply(context.http, text).await;
-			return;
-		}
-	};
-	let image = generate(emojis).encode_png().unwrap();
-	let _ = interaction
-		.public_reply_image(context.http, &image, "test.png")
-		.await;
+			.ok_or(BotError::InvalidInteraction("emojis option missing"))?,
+	)?;
+	let image = generate(&context.config.images, emojis)
+		.encode_png()
+		.unwrap();
+	interaction
+		.public_reply_image(context.discord, &image, "test.png")
+		.await?;
 	// let _ = interaction
-	// 	.public_reply(context.http, "No test currently active.")
+	// 	.public_reply(context.discord, "No test currently active.")
 	// 	.await;
+	Ok(())
 }
 
-pub fn register_test() -> CreateCommand {
+fn register_test() -> CreateCommand {
 	CreateCommand::new("testimage")
 		.description("IDK")
 		.default_member_permissions(Permissions::ADMINISTRATOR)
@@ -185,40 +192,35 @@ pub fn register_test() -> CreateCommand {
 		)
 }
 
-pub async fn execute(context: Context<'_>, interaction: CommandInteraction) {
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
 	let input = interaction
 		.data
 		.options
 		.first()
 		.and_then(|option| option.value.as_str())
-		.unwrap();
-	let emojis = match parse_emoji_and_group_input(
+		.ok_or(BotError::InvalidInteraction("emojis option missing"))?;
+	let emojis = parse_emoji_and_group_input(
 		context.database,
 		context.emoji_map,
+		context.guild,
 		interaction.user.id,
 		input,
 	)
-	.await
-	{
-		Ok(emojis) => emojis,
-		Err(message) => {
-			let _ = interaction.ephemeral_reply(context.http, message).await;
-			return;
-		}
-	};
+	.await?;
 	if !emojis
-		.are_owned_by_user(context.database, interaction.user.id)
-		.await
+		.are_owned_by_user(context.database, context.guild, interaction.user.id)
+		.await?
 	{
-		let _ = interaction
-			.ephemeral_reply(context.http, "You don't own all specified emojis.")
-			.await;
-		return;
+		interaction
+			.ephemeral_reply(context.discord, "You don't own all specified emojis.")
+			.await?;
+		return Ok(());
 	}
 
 	let emoji_count = emojis.emoji_count() as usize;
 
 	let canvas = generate(
+		&context.config.images,
 		emojis
 			.flatten()
 			.into_iter()
@@ -231,12 +233,13 @@ pub async fn execute(context: Context<'_>, interaction: CommandInteraction) {
 	);
 	let image = canvas.encode_png().unwrap();
 
-	let _ = interaction
-		.public_reply_image(context.http, image.as_slice(), "image.png")
-		.await;
+	interaction
+		.public_reply_image(context.discord, image.as_slice(), "image.png")
+		.await?;
+	Ok(())
 }
 
-pub fn register() -> CreateCommand {
+fn register() -> CreateCommand {
 	CreateCommand::new("generate")
 		.description("Generage an image using your emojis.")
 		.add_option(
@@ -249,75 +252,73 @@ pub fn register() -> CreateCommand {
 		)
 }
 
-pub async fn execute_v2(context: Context<'_>, interaction: CommandInteraction) {
+async fn execute_v2(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+) -> Result<(), BotError> {
 	let input = interaction
 		.data
 		.options
 		.first()
 		.and_then(|option| option.value.as_str())
-		.unwrap();
-	let emojis = match parse_emoji_input_with_modifiers(context.emoji_map, input) {
-		Ok(emojis) => emojis,
-		Err(message) => {
-			let _ = interaction.ephemeral_reply(context.http, message).await;
-			return;
-		}
-	};
+		.ok_or(BotError::InvalidInteraction("emojis option missing"))?;
+	let emojis = parse_emoji_input_with_modifiers(context.emoji_map, input)?;
 	if !EmojisWithCounts::from_emojis_to_render(&emojis)
-		.are_owned_by_user(context.database, interaction.user.id)
-		.await
+		.are_owned_by_user(context.database, context.guild, interaction.user.id)
+		.await?
 	{
-		let _ = interaction
+		interaction
 			.ephemeral_reply(
-				context.http,
+				context.discord,
 				"You don't own all specified emojis in the required amounts.",
 			)
-			.await;
-		return;
+			.await?;
+		return Ok(());
 	}
 
-	const EMOJI_LIMIT: usize = 1_000;
 	const EMOJI_MIN_FRACTION: f32 = 0.01;
 
 	let count: usize = emojis.iter().map(|emoji| emoji.count).sum();
-	if count > EMOJI_LIMIT {
-		let _ = interaction
+	let emoji_limit = context.config.images.emoji_limit;
+	if count > emoji_limit {
+		interaction
 			.ephemeral_reply(
-				context.http,
-				format!("You can only place {} emojis.", EMOJI_LIMIT),
+				context.discord,
+				format!("You can only place {} emojis.", emoji_limit),
 			)
-			.await;
-		return;
+			.await?;
+		return Ok(());
 	} else if count == 0 {
-		let _ = interaction
-			.ephemeral_reply(context.http, "You ended up with 0 emojis.")
-			.await;
-		return;
+		interaction
+			.ephemeral_reply(context.discord, "You ended up with 0 emojis.")
+			.await?;
+		return Ok(());
 	}
 	if emojis
 		.iter()
 		.any(|emoji| emoji.fraction < EMOJI_MIN_FRACTION)
 	{
-		let _ = interaction
+		interaction
 			.ephemeral_reply(
-				context.http,
+				context.discord,
 				format!("Minimum emoji size is {}.", EMOJI_MIN_FRACTION),
 			)
-			.await;
-		return;
+			.await?;
+		return Ok(());
 	}
 
-	let _ = interaction.defer(context.http).await;
+	context.discord.defer(interaction).await?;
 
-	let canvas = generate(emojis);
+	let canvas = generate(&context.config.images, emojis);
 	let image = canvas.encode_png().unwrap();
 
-	let _ = interaction
-		.follow_up_image(context.http, image.as_slice(), "image.png")
-		.await;
+	interaction
+		.follow_up_image(context.discord, image.as_slice(), "image.png")
+		.await?;
+	Ok(())
 }
 
-pub fn register_v2() -> CreateCommand {
+fn register_v2() -> CreateCommand {
 	CreateCommand::new("generate2")
 	 .description("Generage an image using your emojis.")
 	 .add_option(
@@ -329,3 +330,60 @@ pub fn register_v2() -> CreateCommand {
 		.required(true),
 	)
 }
+
+pub struct GenerateCommand;
+
+#[async_trait]
+impl SlashCommand for GenerateCommand {
+	fn name(&self) -> &'static str {
+		"generate"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+}
+
+pub struct Generate2Command;
+
+#[async_trait]
+impl SlashCommand for Generate2Command {
+	fn name(&self) -> &'static str {
+		"generate2"
+	}
+	fn register(&self) -> CreateCommand {
+		register_v2()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute_v2(context, interaction).await
+	}
+}
+
+pub struct TestImageCommand;
+
+#[async_trait]
+impl SlashCommand for TestImageCommand {
+	fn name(&self) -> &'static str {
+		"testimage"
+	}
+	fn register(&self) -> CreateCommand {
+		register_test()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute_test(context, interaction).await
+	}
+}
@@ -1,5 +1,6 @@
 //! Module for image generation.
 
 pub mod generate;
+#[allow(dead_code)] // Not hooked up to `/inventory` yet.
 pub mod inventory;
 pub mod rasterize;
@@ -2,34 +2,38 @@
 
 use serenity::{
 	all::{CommandInteraction, CommandOptionType},
+	async_trait,
 	builder::{CreateCommand, CreateCommandOption},
 };
 
-use crate::{context::Context, emojis_with_counts::EmojisWithCounts, util::ReplyShortcuts};
+use crate::{
+	autocomplete, commands::SlashCommand, context::Context, discord::Choice,
+	emojis_with_counts::EmojisWithCounts, error::BotError, util::ReplyShortcuts,
+};
 
-pub async fn execute(context: Context<'_>, interaction: CommandInteraction) {
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
 	let input_emoji = interaction
 		.data
 		.options
 		.get(0)
 		.and_then(|option| option.value.as_str())
-		.unwrap()
+		.ok_or(BotError::InvalidInteraction("emoji option missing"))?
 		.trim();
 	let Some(emoji) = context.emoji_map.get_with_image(input_emoji) else {
-		let _ = interaction
-			.ephemeral_reply(context.http, "No such emoji in my list.")
-			.await;
-		return;
+		interaction
+			.ephemeral_reply(context.discord, "No such emoji in my list.")
+			.await?;
+		return Ok(());
 	};
 
 	if !EmojisWithCounts::from_iter([(emoji.emoji(), 1)])
-		.are_owned_by_user(context.database, interaction.user.id)
-		.await
+		.are_owned_by_user(context.database, context.guild, interaction.user.id)
+		.await?
 	{
-		let _ = interaction
-			.ephemeral_reply(context.http, "You do not have that emoji.")
-			.await;
-		return;
+		interaction
+			.ephemeral_reply(context.discord, "You do not have that emoji.")
+			.await?;
+		return Ok(());
 	}
 
 	let png = {
@@ -44,12 +48,13 @@ pub async fn execute(context: Context<'_>, interaction: CommandInteraction) {
 		pixmap.encode_png().unwrap()
 	};
 
-	let _ = interaction
-		.public_reply_image(context.http, png.as_slice(), "emoji.png")
-		.await;
+	interaction
+		.public_reply_image(context.discord, png.as_slice(), "emoji.png")
+		.await?;
+	Ok(())
 }
 
-pub fn register() -> CreateCommand {
+fn register() -> CreateCommand {
 	CreateCommand::new("image")
 		.description("Generates a raster image version of a specified emoji from your inventory.")
 		.add_option(
@@ -58,6 +63,38 @@ pub fn register() -> CreateCommand {
 				"emoji",
 				"The emoji to rasterize.",
 			)
-			.required(true),
+			.required(true)
+			.set_autocomplete(true),
 		)
 }
+
+pub struct ImageCommand;
+
+#[async_trait]
+impl SlashCommand for ImageCommand {
+	fn name(&self) -> &'static str {
+		"image"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+	async fn autocomplete(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<Vec<Choice>, BotError> {
+		let input = interaction
+			.data
+			.autocomplete()
+			.ok_or(BotError::InvalidInteraction("no option to autocomplete"))?
+			.value;
+		autocomplete::owned_emoji(context, interaction.user.id, input).await
+	}
+}
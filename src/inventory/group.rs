@@ -2,12 +2,17 @@ use std::fmt::Write;
 
 use serenity::{
 	all::{CommandDataOption, CommandDataOptionValue, CommandInteraction, CommandOptionType},
+	async_trait,
 	builder::{CreateCommand, CreateCommandOption},
 };
 
 use crate::{
+	autocomplete,
+	commands::SlashCommand,
 	context::Context,
+	discord::Choice,
 	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
 	util::{get_and_parse_emoji_option, ReplyShortcuts},
 };
 
@@ -16,73 +21,83 @@ use super::queries::{
 	rename_group, reposition_group, RenameGroupError, RepositionOutcome,
 };
 
-pub async fn execute(context: Context<'_>, mut interaction: CommandInteraction) {
-	let subcommand = interaction.data.options.pop().unwrap();
-	let options = match subcommand.value {
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let subcommand = interaction
+		.data
+		.options
+		.first()
+		.ok_or(BotError::InvalidInteraction("subcommand missing"))?;
+	let options = match &subcommand.value {
 		CommandDataOptionValue::SubCommand(options) => options,
 		CommandDataOptionValue::SubCommandGroup(options) => options,
-		_ => panic!("Received wrong argument"),
+		_ => return Err(BotError::InvalidInteraction("expected a subcommand")),
 	};
 	match subcommand.name.as_str() {
-		"add" => {
-			add(context, interaction, options).await;
-		}
-		"remove" => {
-			remove(context, interaction, options).await;
-		}
-		"rename" => {
-			rename(context, interaction, options).await;
-		}
-		"list" => {
-			list(context, interaction).await;
-		}
-		"view" => {
-			view(context, interaction, options).await;
-		}
-		"ungrouped" => {
-			view_ungrouped(context, interaction).await;
-		}
-		"reposition" => {
-			// let _ = ephemeral_reply(context, interaction, "Not yet implemented.").await;
-			// return;
-			reposition(context, interaction, options).await;
-		}
-		_ => panic!("Received invalid subcommand name."),
+		"add" => add(context, interaction, options).await,
+		"remove" => remove(context, interaction, options).await,
+		"rename" => rename(context, interaction, options).await,
+		"list" => list(context, interaction).await,
+		"view" => view(context, interaction, options).await,
+		"ungrouped" => view_ungrouped(context, interaction).await,
+		"reposition" => reposition(context, interaction, options).await,
+		_ => Err(BotError::InvalidInteraction("unknown subcommand")),
 	}
 }
 
-async fn add(
+async fn autocomplete(
 	context: Context<'_>,
-	interaction: CommandInteraction,
-	options: Vec<CommandDataOption>,
-) {
-	let group_name = options
-		.get(0)
+	interaction: &CommandInteraction,
+) -> Result<Vec<Choice>, BotError> {
+	let option = interaction
+		.data
+		.autocomplete()
+		.ok_or(BotError::InvalidInteraction("no option to autocomplete"))?;
+	let user = interaction.user.id;
+	match option.name {
+		"group" => autocomplete::group_names(context, user, option.value).await,
+		"emojis" => autocomplete::owned_emojis(context, user, option.value).await,
+		_ => Ok(Vec::new()),
+	}
+}
+
+/// Gets the group name, which every subcommand that takes one has as its first option.
+fn get_group_option(options: &[CommandDataOption]) -> Result<&str, BotError> {
+	options
+		.first()
 		.and_then(|option| option.value.as_str())
-		.unwrap();
+		.ok_or(BotError::InvalidInteraction("group option missing"))
+}
 
-	let emojis = match get_and_parse_emoji_option(context.emoji_map, options.get(1)) {
-		Ok(emojis) => emojis,
-		Err(error) => {
-			let _ = interaction.ephemeral_reply(context.http, error).await;
-			return;
-		}
-	};
+async fn add(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+	options: &[CommandDataOption],
+) -> Result<(), BotError> {
+	let group_name = get_group_option(options)?;
+	let emojis = get_and_parse_emoji_option(context.emoji_map, options.get(1))?;
 
 	let emojis = EmojisWithCounts::from_flat(&emojis);
 	let emoji_count = emojis.emoji_count();
 
-	let (group_name, added_emojis, group_is_new) =
-		add_to_group(context.database, interaction.user.id, group_name, &emojis).await;
+	let (group_name, added_emojis, group_is_new) = add_to_group(
+		context.database,
+		context.guild,
+		interaction.user.id,
+		group_name,
+		&emojis,
+	)
+	.await?;
 
 	if added_emojis.is_empty() {
 		let message = match emoji_count {
-			1 => "You do not have that emoji.",
-			2 => "You do not have either of those emojis.",
-			_ => "You did not have any of those emojis.",
+			1 => "You do not have that emoji, or it is locked in a trade offer or escrow.",
+			2 => "You do not have either of those emojis, or they are locked in trade offers or escrow.",
+			_ => "You did not have any of those emojis, or they are locked in trade offers or escrow.",
 		};
-		let _ = interaction.ephemeral_reply(context.http, message).await;
-		return;
+		interaction
+			.ephemeral_reply(context.discord, message)
+			.await?;
+		return Ok(());
 	}
 
 	let dropped_emojis = emoji_count - added_emojis.emoji_count();
@@ -95,45 +110,51 @@ async fn add(
 
 	match dropped_emojis {
 		0 => (),
-		1 => message.push_str(" You did not have the other emoji."),
-		n => write!(message, " You did not have the other {} emojis.", n).unwrap(),
+		1 => message.push_str(" You did not have the other emoji, or it is locked."),
+		n => write!(
+			message,
+			" You did not have the other {} emojis, or they are locked.",
+			n
+		)
+		.unwrap(),
 	}
 
-	let _ = interaction.ephemeral_reply(context.http, message).await;
+	interaction
+		.ephemeral_reply(context.discord, message)
+		.await?;
+	Ok(())
 }
 
 async fn remove(
 	context: Context<'_>,
-	interaction: CommandInteraction,
-	options: Vec<CommandDataOption>,
-) {
-	let subcommand = options.get(0).unwrap();
+	interaction: &CommandInteraction,
+	options: &[CommandDataOption],
+) -> Result<(), BotError> {
+	let subcommand = options
+		.first()
+		.ok_or(BotError::InvalidInteraction("subcommand missing"))?;
 	let CommandDataOptionValue::SubCommand(ref options) = subcommand.value else {
-		panic!();
+		return Err(BotError::InvalidInteraction("expected a subcommand"));
 	};
 	let (group, emojis) = if subcommand.name.as_str() == "from" {
-		let group_name = options
-			.get(0)
-			.and_then(|option| option.value.as_str())
-			.unwrap();
-		(Some(group_name), options.get(1))
+		(Some(get_group_option(options)?), options.get(1))
 	} else {
-		(None, options.get(0))
+		(None, options.first())
 	};
 
-	let emojis = match get_and_parse_emoji_option(context.emoji_map, emojis) {
-		Ok(emojis) => emojis,
-		Err(error) => {
-			let _ = interaction.ephemeral_reply(context.http, error).await;
-			return;
-		}
-	};
+	let emojis = get_and_parse_emoji_option(context.emoji_map, emojis)?;
 
 	let emoji_count = emojis.len() as u32;
 	let emojis = EmojisWithCounts::from_flat(&emojis);
 
-	let degrouped_emojis =
-		remove_from_group(context.database, interaction.user.id, &emojis, group).await;
+	let degrouped_emojis = remove_from_group(
+		context.database,
+		context.guild,
+		interaction.user.id,
+		&emojis,
+		group,
+	)
+	.await?;
 
 	if degrouped_emojis.is_empty() {
 		let message = match (emoji_count, group.is_some()) {
@@ -144,8 +165,10 @@ async fn remove(
 			(2, false) => "You do not have either of those emojis.",
 			(_, false) => "You don't have any of those emojis.",
 		};
-		let _ = interaction.ephemeral_reply(context.http, message).await;
-		return;
+		interaction
+			.ephemeral_reply(context.discord, message)
+			.await?;
+		return Ok(());
 	}
 
 	let skipped_emojis = emoji_count - degrouped_emojis.emoji_count();
@@ -166,52 +189,63 @@ async fn remove(
 		(n, false) => write!(message, " You did not have the other {}.", n).unwrap(),
 	}
 
-	let _ = interaction.ephemeral_reply(context.http, message).await;
+	interaction
+		.ephemeral_reply(context.discord, message)
+		.await?;
+	Ok(())
 }
 
 async fn rename(
 	context: Context<'_>,
-	interaction: CommandInteraction,
-	options: Vec<CommandDataOption>,
-) {
-	let group = options
-		.get(0)
-		.and_then(|option| option.value.as_str())
-		.unwrap();
+	interaction: &CommandInteraction,
+	options: &[CommandDataOption],
+) -> Result<(), BotError> {
+	let group = get_group_option(options)?;
 	let new_name = options
 		.get(1)
 		.and_then(|option| option.value.as_str())
-		.unwrap();
+		.ok_or(BotError::InvalidInteraction("new name option missing"))?;
 
-	let old_name = match rename_group(context.database, interaction.user.id, group, new_name).await
+	let old_name = match rename_group(
+		context.database,
+		context.guild,
+		interaction.user.id,
+		group,
+		new_name,
+	)
+	.await?
 	{
 		Ok(old_name) => old_name,
 		Err(RenameGroupError::NoSuchGroup) => {
-			let _ = interaction
+			interaction
 				.ephemeral_reply(
-					context.http,
+					context.discord,
 					format!("You have no group called \"{group}\"."),
 				)
-				.await;
-			return;
+				.await?;
+			return Ok(());
 		}
 		Err(RenameGroupError::NameTaken(taken_name)) => {
-			let _ = interaction
+			interaction
 				.ephemeral_reply(
-					context.http,
+					context.discord,
 					format!("There is already a group named \"{taken_name}\"."),
 				)
-				.await;
-			return;
+				.await?;
+			return Ok(());
 		}
 	};
 
 	let message = format!("Renamed group {} to {}.", old_name, new_name);
-	let _ = interaction.ephemeral_reply(context.http, message).await;
+	interaction
+		.ephemeral_reply(context.discord, message)
+		.await?;
+	Ok(())
 }
 
-async fn list(context: Context<'_>, interaction: CommandInteraction) {
-	let (groups, ungrouped) = list_groups(context.database, interaction.user.id).await;
+async fn list(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let (groups, ungrouped) =
+		list_groups(context.database, context.guild, interaction.user.id).await?;
 
 	let s = if ungrouped == 1 { "" } else { "s" };
 	let message = match groups.len() {
@@ -244,83 +278,99 @@ async fn list(context: Context<'_>, interaction: CommandInteraction) {
 		}
 	};
 
-	let _ = interaction.ephemeral_reply(context.http, message).await;
+	interaction
+		.ephemeral_reply(context.discord, message)
+		.await?;
+	Ok(())
 }
 
 async fn view(
 	context: Context<'_>,
-	interaction: CommandInteraction,
-	options: Vec<CommandDataOption>,
-) {
-	let group = options
-		.get(0)
-		.and_then(|option| option.value.as_str())
-		.unwrap();
+	interaction: &CommandInteraction,
+	options: &[CommandDataOption],
+) -> Result<(), BotError> {
+	let group = get_group_option(options)?;
 
 	let Some((name, emojis)) = group_name_and_contents(
 		context.database,
 		context.emoji_map,
+		context.guild,
 		interaction.user.id,
 		group,
 	)
-	.await
+	.await?
 	else {
-		_ = interaction
+		interaction
 			.ephemeral_reply(
-				context.http,
+				context.discord,
 				format!("You have no group called \"{group}\"."),
 			)
-			.await;
-		return;
+			.await?;
+		return Ok(());
 	};
 
 	let message = format!("Contents of group {}: {}", name, emojis);
-	_ = interaction.ephemeral_reply(context.http, message).await;
+	interaction
+		.ephemeral_reply(context.discord, message)
+		.await?;
+	Ok(())
 }
 
-async fn view_ungrouped(context: Context<'_>, interaction: CommandInteraction) {
-	let emojis =
-		get_ungrouped_emojis(context.database, context.emoji_map, interaction.user.id).await;
+async fn view_ungrouped(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+) -> Result<(), BotError> {
+	let emojis = get_ungrouped_emojis(
+		context.database,
+		context.emoji_map,
+		context.guild,
+		interaction.user.id,
+	)
+	.await?;
 
 	let message = if emojis.is_empty() {
 		String::from("You have no ungrouped emojis.")
 	} else {
 		format!("Ungrouped emojis: {}", emojis)
 	};
-	_ = interaction.ephemeral_reply(context.http, message).await;
+	interaction
+		.ephemeral_reply(context.discord, message)
+		.await?;
+	Ok(())
 }
 
 async fn reposition(
 	context: Context<'_>,
-	interaction: CommandInteraction,
-	options: Vec<CommandDataOption>,
-) {
-	let group = options
-		.get(0)
-		.and_then(|option| option.value.as_str())
-		.unwrap();
+	interaction: &CommandInteraction,
+	options: &[CommandDataOption],
+) -> Result<(), BotError> {
+	let group = get_group_option(options)?;
 	let Some::<u32>(new_position) = options
 		.get(1)
 		.and_then(|option| option.value.as_i64())
 		.and_then(|num| num.try_into().ok())
 	else {
-		eprintln!("Somehow received a number that can't be represented as u32.");
-		let _ = interaction
-			.ephemeral_reply(context.http, "Error: invalid number.")
-			.await;
-		return;
+		return Err(BotError::InvalidInteraction(
+			"position option missing or not a u32",
+		));
 	};
 
-	let Ok((name, outcome, old_position, group_count)) =
-		reposition_group(context.database, interaction.user.id, group, new_position).await
+	let Some((name, outcome, old_position, group_count)) = reposition_group(
+		context.database,
+		context.guild,
+		interaction.user.id,
+		group,
+		new_position,
+	)
+	.await?
 	else {
-		let _ = interaction
+		interaction
 			.ephemeral_reply(
-				context.http,
+				context.discord,
 				format!("You have no group called \"{group}\"."),
 			)
-			.await;
-		return;
+			.await?;
+		return Ok(());
 	};
 
 	let message = if group_count == 1 {
@@ -360,36 +410,65 @@ async fn reposition(
 		}
 	};
 
-	let _ = interaction.ephemeral_reply(context.http, message).await;
+	interaction
+		.ephemeral_reply(context.discord, message)
+		.await?;
+	Ok(())
 }
 
 #[rustfmt::skip]
-pub fn register() -> CreateCommand {
+fn register() -> CreateCommand {
 	CreateCommand::new("group").description("Interact with emoji groups.")
 		.add_option(CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Add emojis to a group. Makes the group if it doesn't already exist.")
-			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to add the emojis to.").max_length(50).required(true))
-			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "emojis", "The emojis to add to the group.").required(true))
+			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to add the emojis to.").max_length(50).set_autocomplete(true).required(true))
+			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "emojis", "The emojis to add to the group.").required(true).set_autocomplete(true))
 		)
 		.add_option(CreateCommandOption::new(CommandOptionType::SubCommandGroup, "remove", "Removes emojis from a group.")
 			.add_sub_option(CreateCommandOption::new(CommandOptionType::SubCommand, "from", "Removes emojis from a specific group.")
-				.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to remove the emojis from.").max_length(50).required(true))
-				.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "emojis", "The emojis to remove from the group.").required(true))
+				.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to remove the emojis from.").max_length(50).set_autocomplete(true).required(true))
+				.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "emojis", "The emojis to remove from the group.").required(true).set_autocomplete(true))
 			)
 			.add_sub_option(CreateCommandOption::new(CommandOptionType::SubCommand, "emojis", "Removes emojis from whatever group.")
-				.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "emojis", "The emojis to remove from whatever group they're in.").required(true))
+				.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "emojis", "The emojis to remove from whatever group they're in.").required(true).set_autocomplete(true))
 			)
 		)
 		.add_option(CreateCommandOption::new(CommandOptionType::SubCommand, "rename", "Renames an emoji group.")
-			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to rename.").max_length(50).required(true))
+			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to rename.").max_length(50).set_autocomplete(true).required(true))
 			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "new_name", "The new name for the group.").max_length(50).required(true))
 		)
 		.add_option(CreateCommandOption::new(CommandOptionType::SubCommand, "list", "Lists all your emoji groups."))
 		.add_option(CreateCommandOption::new(CommandOptionType::SubCommand, "view", "Views the contents of one of your emoji groups.")
-			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to view the contents of.").max_length(50).required(true))
+			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to view the contents of.").max_length(50).set_autocomplete(true).required(true))
 		)
 		.add_option(CreateCommandOption::new(CommandOptionType::SubCommand, "ungrouped", "Views the ungrouped emojis."))
 		.add_option(CreateCommandOption::new(CommandOptionType::SubCommand, "reposition", "Repositions the group in the group list. This is mostly relevant when viewing inventory.")
-			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to reposition.").max_length(50).required(true))
+			.add_sub_option(CreateCommandOption::new(CommandOptionType::String, "group", "The group to reposition.").max_length(50).set_autocomplete(true).required(true))
 			.add_sub_option(CreateCommandOption::new(CommandOptionType::Integer, "position", "The position to move the group to, where 0 is the first.").min_int_value(0).required(true))
 		)
 }
+
+pub struct GroupCommand;
+
+#[async_trait]
+impl SlashCommand for GroupCommand {
+	fn name(&self) -> &'static str {
+		"group"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+	async fn autocomplete(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<Vec<Choice>, BotError> {
+		autocomplete(context, interaction).await
+	}
+}
@@ -1,41 +1,52 @@
 use std::cmp::Ordering;
 
-use serenity::model::prelude::UserId;
-use sqlx::{query, Pool, Sqlite, SqliteExecutor, Transaction};
+use serenity::model::prelude::{GuildId, UserId};
+use sqlx::{query, query_scalar, Pool, Sqlite, SqliteExecutor, Transaction};
 
-use crate::{emoji::EmojiMap, emojis_with_counts::EmojisWithCounts};
+use crate::{emoji::EmojiMap, emojis_with_counts::EmojisWithCounts, error::BotError};
 
-pub async fn remove_empty_groups(executor: &mut Transaction<'_, Sqlite>, user: UserId) {
+pub async fn remove_empty_groups(
+	executor: &mut Transaction<'_, Sqlite>,
+	guild: GuildId,
+	user: UserId,
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let deleted_any = query!(
 		"
 		DELETE FROM emoji_inventory_groups
-		WHERE user = ? AND (
+		WHERE guild = ? AND user = ? AND (
 			SELECT COUNT(*)
 			FROM emoji_inventory
 			WHERE emoji_inventory.user = emoji_inventory_groups.user AND emoji_inventory.group_id = emoji_inventory_groups.id
 		) = 0
-		", user_id
-	).execute(&mut **executor).await.unwrap().rows_affected() > 0;
+		", guild_id, user_id
+	).execute(&mut **executor).await?.rows_affected() > 0;
 	if deleted_any {
-		close_ordering_gaps(executor, user).await;
+		close_ordering_gaps(executor, guild, user).await?;
 	}
+	Ok(())
 }
 
-async fn close_ordering_gaps(executor: &mut Transaction<'_, Sqlite>, user: UserId) {
+async fn close_ordering_gaps(
+	executor: &mut Transaction<'_, Sqlite>,
+	guild: GuildId,
+	user: UserId,
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let groups = query!(
 		"
 		SELECT name
 		FROM emoji_inventory_groups
-		WHERE user = ?
+		WHERE guild = ? AND user = ?
 		ORDER BY sort_order ASC
 		",
+		guild_id,
 		user_id
 	)
 	.fetch_all(&mut **executor)
-	.await
-	.unwrap();
+	.await?;
 
 	for (index, group) in groups.into_iter().enumerate() {
 		let group_name = group.name;
@@ -44,53 +55,56 @@ async fn close_ordering_gaps(executor: &mut Transaction<'_, Sqlite>, user: UserI
 			"
 			UPDATE emoji_inventory_groups
 			SET sort_order = ? + 1
-			WHERE user = ? AND name = ?
+			WHERE guild = ? AND user = ? AND name = ?
 			",
 			sort_order,
+			guild_id,
 			user_id,
 			group_name
 		)
 		.execute(&mut **executor)
-		.await
-		.unwrap();
+		.await?;
 	}
+	Ok(())
 }
 
 /// Returns the group name, the emojis successfully added to that group, and whether the group was newly made.
 pub(super) async fn add_to_group(
 	executor: &Pool<Sqlite>,
+	guild: GuildId,
 	user: UserId,
 	group_name: &str,
 	emojis: &EmojisWithCounts,
-) -> (String, EmojisWithCounts, bool) {
+) -> Result<(String, EmojisWithCounts, bool), BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
-	let mut transaction = executor.begin().await.unwrap();
+	let mut transaction = executor.begin().await?;
 	let group_count = query!(
 		"
 		SELECT COUNT(*) AS group_count
 		FROM emoji_inventory_groups
-		WHERE user = ?
+		WHERE guild = ? AND user = ?
 		",
+		guild_id,
 		user_id
 	)
 	.fetch_one(&mut *transaction)
-	.await
-	.unwrap()
+	.await?
 	.group_count;
 
 	let group_is_new = query!(
 		"
-		INSERT INTO emoji_inventory_groups (user, name, sort_order)
-		VALUES (?, ?, ? + 1)
-		ON CONFLICT (user, name COLLATE NOCASE) DO NOTHING;
+		INSERT INTO emoji_inventory_groups (guild, user, name, sort_order)
+		VALUES (?, ?, ?, ? + 1)
+		ON CONFLICT (guild, user, name COLLATE NOCASE) DO NOTHING;
 		",
+		guild_id,
 		user_id,
 		group_name,
 		group_count
 	)
 	.execute(&mut *transaction)
-	.await
-	.unwrap()
+	.await?
 	.rows_affected()
 		> 0;
 
@@ -98,14 +112,14 @@ pub(super) async fn add_to_group(
 		"
 		SELECT id, name
 		FROM emoji_inventory_groups
-		WHERE user = ? AND name = ?;
+		WHERE guild = ? AND user = ? AND name = ?;
 		",
+		guild_id,
 		user_id,
 		group_name,
 	)
 	.fetch_one(&mut *transaction)
-	.await
-	.unwrap();
+	.await?;
 
 	let group_id = group.id;
 	let mut added_emojis = Vec::with_capacity(emojis.unique_emoji_count());
@@ -115,53 +129,57 @@ pub(super) async fn add_to_group(
 			"
 			UPDATE emoji_inventory
 			SET group_id = ?
-			WHERE user = ? AND emoji = ? AND rowid IN (
+			WHERE guild = ? AND user = ? AND emoji = ? AND rowid IN (
 				SELECT emoji_inventory.rowid
 				FROM emoji_inventory
 				LEFT JOIN emoji_inventory_groups
 				ON emoji_inventory.group_id = emoji_inventory_groups.id
-				WHERE emoji_inventory.user = ? AND emoji_inventory.emoji = ?
+				WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ? AND emoji_inventory.emoji = ?
+					AND emoji_inventory.lock IS NULL
 				ORDER BY IFNULL(sort_order, 9223372036854775807) DESC
 				LIMIT ?
 			)
 			",
 			group_id,
+			guild_id,
 			user_id,
 			emoji_str,
+			guild_id,
 			user_id,
 			emoji_str,
 			*count
 		)
 		.execute(&mut *transaction)
-		.await
-		.unwrap()
+		.await?
 		.rows_affected();
 		if rows_affected > 0 {
 			added_emojis.push((*emoji, rows_affected as u32));
 		}
 	}
 
-	remove_empty_groups(&mut transaction, user).await;
+	remove_empty_groups(&mut transaction, guild, user).await?;
 
-	transaction.commit().await.unwrap();
+	transaction.commit().await?;
 
-	(
+	Ok((
 		group.name,
 		EmojisWithCounts::new(added_emojis),
 		group_is_new,
-	)
+	))
 }
 
 pub(super) async fn remove_from_group(
 	executor: &Pool<Sqlite>,
+	guild: GuildId,
 	user: UserId,
 	emojis: &EmojisWithCounts,
 	group: Option<&str>,
-) -> EmojisWithCounts {
+) -> Result<EmojisWithCounts, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let mut degrouped_emojis = Vec::new();
 
-	let mut transaction = executor.begin().await.unwrap();
+	let mut transaction = executor.begin().await?;
 
 	for (emoji, count) in emojis {
 		let emoji_str = emoji.as_str();
@@ -170,50 +188,52 @@ pub(super) async fn remove_from_group(
 				"
 				UPDATE emoji_inventory
 				SET group_id = NULL
-				WHERE user = ? AND emoji = ? AND emoji_inventory.group_id IS NOT NULL AND rowid IN (
+				WHERE guild = ? AND user = ? AND emoji = ? AND emoji_inventory.group_id IS NOT NULL AND rowid IN (
 					SELECT emoji_inventory.rowid
 					FROM emoji_inventory
 					LEFT JOIN emoji_inventory_groups
 					ON emoji_inventory.group_id = emoji_inventory_groups.id
-					WHERE emoji_inventory.user = ? AND emoji_inventory.emoji = ? AND emoji_inventory_groups.name = ?
+					WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ? AND emoji_inventory.emoji = ? AND emoji_inventory_groups.name = ?
 					LIMIT ?
 				)
 				",
+				guild_id,
 				user_id,
 				emoji_str,
+				guild_id,
 				user_id,
 				emoji_str,
 				group,
 				count
 			)
 			.execute(&mut *transaction)
-			.await
-			.unwrap()
+			.await?
 			.rows_affected()
 		} else {
 			query!(
 				"
 				UPDATE emoji_inventory
 				SET group_id = NULL
-				WHERE user = ? AND emoji = ? AND rowid IN (
+				WHERE guild = ? AND user = ? AND emoji = ? AND rowid IN (
 					SELECT emoji_inventory.rowid
 					FROM emoji_inventory
 					LEFT JOIN emoji_inventory_groups
 					ON emoji_inventory.group_id = emoji_inventory_groups.id
-					WHERE emoji_inventory.user = ? AND emoji_inventory.emoji = ?
+					WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ? AND emoji_inventory.emoji = ?
 					ORDER BY IFNULL(sort_order, 9223372036854775807) DESC
 					LIMIT ?
 				)
 				",
+				guild_id,
 				user_id,
 				emoji_str,
+				guild_id,
 				user_id,
 				emoji_str,
 				count
 			)
 			.execute(&mut *transaction)
-			.await
-			.unwrap()
+			.await?
 			.rows_affected()
 		};
 		if rows_affected > 0 {
@@ -221,32 +241,34 @@ pub(super) async fn remove_from_group(
 		}
 	}
 
-	remove_empty_groups(&mut transaction, user).await;
+	remove_empty_groups(&mut transaction, guild, user).await?;
 
-	transaction.commit().await.unwrap();
+	transaction.commit().await?;
 
-	EmojisWithCounts::new(degrouped_emojis)
+	Ok(EmojisWithCounts::new(degrouped_emojis))
 }
 
 async fn get_current_group_name<'a, E: SqliteExecutor<'a>>(
 	executor: E,
+	guild: GuildId,
 	user: UserId,
 	group: &str,
-) -> Option<String> {
+) -> Result<Option<String>, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
-	query!(
+	let record = query!(
 		"
 		SELECT name
 		FROM emoji_inventory_groups
-		WHERE user = ? AND name = ?
+		WHERE guild = ? AND user = ? AND name = ?
 		",
+		guild_id,
 		user_id,
 		group
 	)
 	.fetch_optional(executor)
-	.await
-	.unwrap()
-	.map(|record| record.name)
+	.await?;
+	Ok(record.map(|record| record.name))
 }
 
 pub(super) enum RenameGroupError {
@@ -256,23 +278,27 @@ pub(super) enum RenameGroupError {
 
 pub(super) async fn rename_group(
 	executor: &Pool<Sqlite>,
+	guild: GuildId,
 	user: UserId,
 	group: &str,
 	new_name: &str,
-) -> Result<String, RenameGroupError> {
-	let mut transaction = executor.begin().await.unwrap();
-	let Some(old_name) = get_current_group_name(&mut *transaction, user, group).await else {
-		return Err(RenameGroupError::NoSuchGroup);
+) -> Result<Result<String, RenameGroupError>, BotError> {
+	let mut transaction = executor.begin().await?;
+	let Some(old_name) = get_current_group_name(&mut *transaction, guild, user, group).await?
+	else {
+		return Ok(Err(RenameGroupError::NoSuchGroup));
 	};
 
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	if let Err(error) = query!(
 		"
 		UPDATE emoji_inventory_groups
 		SET name = ?
-		WHERE user = ? AND name = ?
+		WHERE guild = ? AND user = ? AND name = ?
 		",
 		new_name,
+		guild_id,
 		user_id,
 		group
 	)
@@ -285,23 +311,25 @@ pub(super) async fn rename_group(
 				.map(|err| err.is_unique_violation()),
 			Some(true)
 		) {
-			let taken_name = get_current_group_name(&mut *transaction, user, new_name)
-				.await
-				.unwrap();
-			return Err(RenameGroupError::NameTaken(taken_name));
+			let taken_name = get_current_group_name(&mut *transaction, guild, user, new_name)
+				.await?
+				.unwrap_or_else(|| String::from(new_name));
+			return Ok(Err(RenameGroupError::NameTaken(taken_name)));
 		}
-		panic!();
+		return Err(error.into());
 	};
-	transaction.commit().await.unwrap();
+	transaction.commit().await?;
 
-	Ok(old_name)
+	Ok(Ok(old_name))
 }
 
 /// Returns group names with their emoji counts, and uncategorized emoji count.
 pub(super) async fn list_groups(
 	executor: &Pool<Sqlite>,
+	guild: GuildId,
 	user: UserId,
-) -> (Vec<(String, u32)>, u32) {
+) -> Result<(Vec<(String, u32)>, u32), BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let records = query!(
 		"
@@ -309,15 +337,15 @@ pub(super) async fn list_groups(
 		FROM emoji_inventory
 		LEFT JOIN emoji_inventory_groups
 		ON emoji_inventory_groups.id = emoji_inventory.group_id
-		WHERE emoji_inventory.user = ?
+		WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ?
 		GROUP BY emoji_inventory_groups.name, emoji_inventory_groups.user
 		ORDER BY emoji_inventory_groups.sort_order ASC
 		",
+		guild_id,
 		user_id
 	)
 	.fetch_all(executor)
-	.await
-	.unwrap();
+	.await?;
 
 	let mut ungrouped = 0;
 
@@ -332,16 +360,41 @@ pub(super) async fn list_groups(
 		})
 		.collect();
 
-	(groups, ungrouped)
+	Ok((groups, ungrouped))
+}
+
+/// The names of the user's groups, in order.
+pub(crate) async fn get_group_names(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+) -> Result<Vec<String>, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let names = query_scalar!(
+		"
+		SELECT name
+		FROM emoji_inventory_groups
+		WHERE guild = ? AND user = ?
+		ORDER BY sort_order ASC
+		",
+		guild_id,
+		user_id
+	)
+	.fetch_all(executor)
+	.await?;
+	Ok(names)
 }
 
 /// As empty groups shouldn't be able to exist, an empty result set can be treated as a missing group.
 pub(crate) async fn get_group_contents<'a, E: SqliteExecutor<'a>>(
 	executor: E,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	user: UserId,
 	group: &str,
-) -> EmojisWithCounts {
+) -> Result<EmojisWithCounts, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let records = query!(
 		"
@@ -349,63 +402,76 @@ pub(crate) async fn get_group_contents<'a, E: SqliteExecutor<'a>>(
 		FROM emoji_inventory
 		LEFT JOIN emoji_inventory_groups
 		ON emoji_inventory.group_id = emoji_inventory_groups.id
-		WHERE emoji_inventory.user = ? AND emoji_inventory_groups.name = ?
+		WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ? AND emoji_inventory_groups.name = ?
 		GROUP BY emoji
 		",
+		guild_id,
 		user_id,
 		group
 	)
 	.fetch_all(executor)
-	.await
-	.unwrap();
-	EmojisWithCounts::from_iter(records.into_iter().map(|record| {
-		(
-			emoji_map.get(record.emoji.as_str()).unwrap(),
-			record.count as u32,
-		)
-	}))
+	.await?;
+	let emojis = records
+		.into_iter()
+		.map(|record| {
+			Ok((
+				emoji_map.get_from_database(&record.emoji)?,
+				record.count as u32,
+			))
+		})
+		.collect::<Result<_, BotError>>()?;
+	Ok(EmojisWithCounts::new(emojis))
 }
 
 pub(super) async fn group_name_and_contents(
 	database: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	user: UserId,
 	group: &str,
-) -> Option<(String, EmojisWithCounts)> {
-	let mut transaction = database.begin().await.unwrap();
+) -> Result<Option<(String, EmojisWithCounts)>, BotError> {
+	let mut transaction = database.begin().await?;
 
-	let name = get_current_group_name(&mut *transaction, user, group).await?;
-	let emojis = get_group_contents(&mut *transaction, emoji_map, user, &name).await;
+	let Some(name) = get_current_group_name(&mut *transaction, guild, user, group).await? else {
+		return Ok(None);
+	};
+	let emojis = get_group_contents(&mut *transaction, emoji_map, guild, user, &name).await?;
 
-	transaction.commit().await.unwrap();
+	transaction.commit().await?;
 
-	Some((name, emojis))
+	Ok(Some((name, emojis)))
 }
 
 pub(super) async fn get_ungrouped_emojis(
 	database: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	user: UserId,
-) -> EmojisWithCounts {
+) -> Result<EmojisWithCounts, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let records = query!(
 		"
 		SELECT emoji, COUNT(*) as count
 		FROM emoji_inventory
-		WHERE user = ? AND group_id IS NULL
+		WHERE guild = ? AND user = ? AND group_id IS NULL
 		GROUP BY emoji
 		",
+		guild_id,
 		user_id,
 	)
 	.fetch_all(database)
-	.await
-	.unwrap();
-	EmojisWithCounts::from_iter(records.into_iter().map(|record| {
-		(
-			emoji_map.get(record.emoji.as_str()).unwrap(),
-			record.count as u32,
-		)
-	}))
+	.await?;
+	let emojis = records
+		.into_iter()
+		.map(|record| {
+			Ok((
+				emoji_map.get_from_database(&record.emoji)?,
+				record.count as u32,
+			))
+		})
+		.collect::<Result<_, BotError>>()?;
+	Ok(EmojisWithCounts::new(emojis))
 }
 
 pub(super) enum RepositionOutcome {
@@ -415,34 +481,36 @@ pub(super) enum RepositionOutcome {
 	DidNotMove,
 }
 
-/// Returns Err if no such group existed, otherwise Ok(name, old_position, group_count).
+/// Returns None if no such group existed, otherwise (name, outcome, old_position, group_count).
 ///
 /// Note: in Rust code and in user commands, the ordering starts with 0, but in the database it starts with 1.
 pub(super) async fn reposition_group(
 	database: &Pool<Sqlite>,
+	guild: GuildId,
 	user: UserId,
 	group: &str,
 	new_position: u32,
-) -> Result<(String, RepositionOutcome, u32, u32), ()> {
+) -> Result<Option<(String, RepositionOutcome, u32, u32)>, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 
-	let mut transaction = database.begin().await.unwrap();
+	let mut transaction = database.begin().await?;
 
-	let Some(name) = get_current_group_name(&mut *transaction, user, group).await else {
-		return Err(());
+	let Some(name) = get_current_group_name(&mut *transaction, guild, user, group).await? else {
+		return Ok(None);
 	};
 	let group = query!(
 		r#"
 		SELECT name, sort_order - 1 AS "sort_order!: u32"
 		FROM emoji_inventory_groups
-		WHERE user = ? AND name  = ?
+		WHERE guild = ? AND user = ? AND name  = ?
 		"#,
+		guild_id,
 		user_id,
 		name
 	)
 	.fetch_one(&mut *transaction)
-	.await
-	.unwrap();
+	.await?;
 
 	let name = group.name;
 	let current_position = group.sort_order;
@@ -451,26 +519,26 @@ pub(super) async fn reposition_group(
 		"
 		SELECT COUNT(*) as count
 		FROM emoji_inventory_groups
-		WHERE user = ?
+		WHERE guild = ? AND user = ?
 		",
+		guild_id,
 		user_id
 	)
 	.fetch_one(&mut *transaction)
-	.await
-	.unwrap()
+	.await?
 	.count as u32;
 
 	let new_position = new_position.min(group_count - 1);
 
 	let scoot_offset = match u32::cmp(&new_position, &current_position) {
 		Ordering::Equal => {
-			transaction.commit().await.unwrap();
-			return Ok((
+			transaction.commit().await?;
+			return Ok(Some((
 				name,
 				RepositionOutcome::DidNotMove,
 				current_position,
 				group_count,
-			));
+			)));
 		}
 		Ordering::Greater => -1,
 		Ordering::Less => 1,
@@ -482,30 +550,32 @@ pub(super) async fn reposition_group(
 		"
 		UPDATE emoji_inventory_groups
 		SET sort_order = -sort_order
-		WHERE user = ? AND sort_order >= ? AND sort_order <= ?;
+		WHERE guild = ? AND user = ? AND sort_order >= ? AND sort_order <= ?;
 
 		UPDATE emoji_inventory_groups
 		SET sort_order = ? + 1
-		WHERE user = ? AND name = ?;
+		WHERE guild = ? AND user = ? AND name = ?;
 
 		UPDATE emoji_inventory_groups
 		SET sort_order = -sort_order + ?
-		WHERE user = ? AND sort_order >= -? AND sort_order <= -?;
+		WHERE guild = ? AND user = ? AND sort_order >= -? AND sort_order <= -?;
 		",
+		guild_id,
 		user_id,
 		lower,
 		upper,
 		new_position,
+		guild_id,
 		user_id,
 		name,
 		scoot_offset,
+		guild_id,
 		user_id,
 		upper,
 		lower,
 	)
 	.execute(&mut *transaction)
-	.await
-	.unwrap();
+	.await?;
 
 	let outcome = if new_position == 0 {
 		RepositionOutcome::MovedToFront
@@ -518,22 +588,24 @@ pub(super) async fn reposition_group(
 			"
 			SELECT name
 			FROM emoji_inventory_groups
-			WHERE (sort_order IN (? + 1, ? + 1)) AND user = ?
+			WHERE (sort_order IN (? + 1, ? + 1)) AND guild = ? AND user = ?
 			ORDER BY sort_order ASC
 			",
 			before,
 			after,
+			guild_id,
 			user_id
 		)
 		.fetch_all(&mut *transaction)
-		.await
-		.unwrap();
-		let neighbour_after = neighbours.pop().unwrap().name;
-		let neighbour_before = neighbours.pop().unwrap().name;
-		RepositionOutcome::MovedBetween([neighbour_before, neighbour_after])
+		.await?;
+		let (Some(neighbour_after), Some(neighbour_before)) = (neighbours.pop(), neighbours.pop())
+		else {
+			return Err(BotError::Database(sqlx::Error::RowNotFound));
+		};
+		RepositionOutcome::MovedBetween([neighbour_before.name, neighbour_after.name])
 	};
 
-	transaction.commit().await.unwrap();
+	transaction.commit().await?;
 
-	Ok((name, outcome, current_position, group_count))
+	Ok(Some((name, outcome, current_position, group_count)))
 }
@@ -2,19 +2,25 @@ use std::{borrow::Cow, fmt::Write};
 
 use serenity::{
 	all::{CommandDataOptionValue, CommandInteraction, CommandOptionType},
+	async_trait,
 	builder::{CreateCommand, CreateCommandOption},
 };
 
 use crate::{
-	context::Context, queries::get_user_emojis_grouped, user_settings::private::is_private,
-	util::ReplyShortcuts,
+	commands::SlashCommand, context::Context, emoji_list::Rarity,
+	emojis_with_counts::EmojisWithCounts, error::BotError, queries::get_user_emojis_grouped,
+	user_settings::privacy::get_privacy_settings, util::ReplyShortcuts,
 };
 
-pub async fn execute(context: Context<'_>, mut interaction: CommandInteraction) {
-	let subcommand = interaction.data.options.pop().unwrap();
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let subcommand = interaction
+		.data
+		.options
+		.first()
+		.ok_or(BotError::InvalidInteraction("subcommand missing"))?;
 	let targets_own = subcommand.name == "own";
-	let CommandDataOptionValue::SubCommand(options) = subcommand.value else {
-		panic!("Received wrong option");
+	let CommandDataOptionValue::SubCommand(options) = &subcommand.value else {
+		return Err(BotError::InvalidInteraction("expected a subcommand"));
 	};
 	let target = if targets_own {
 		interaction.user.id
@@ -22,7 +28,7 @@ pub async fn execute(context: Context<'_>, mut interaction: CommandInteraction)
 		options
 			.first()
 			.and_then(|option| option.value.as_user_id())
-			.unwrap()
+			.ok_or(BotError::InvalidInteraction("user option missing"))?
 	};
 
 	let is_public = if targets_own {
@@ -33,33 +39,33 @@ pub async fn execute(context: Context<'_>, mut interaction: CommandInteraction)
 	.is_some();
 
 	let name = if !targets_own || is_public {
-		Some(
-			context
-				.get_user_name(interaction.guild_id.unwrap(), target)
-				.await,
-		)
+		Some(context.get_user_name(target).await)
 	} else {
 		None
 	};
 
-	if !targets_own && is_private(context.database, target).await {
-		let _ = interaction
+	if !targets_own
+		&& !get_privacy_settings(context.database, target)
+			.await?
+			.show_inventory
+	{
+		interaction
 			.reply(
-				context.http,
+				context.discord,
 				format!("{}'s inventory is set to private.", name.unwrap()),
 				!is_public,
 			)
-			.await;
-		return;
+			.await?;
+		return Ok(());
 	}
 
 	let (groups, ungrouped) =
-		get_user_emojis_grouped(context.database, context.emoji_map, target).await;
+		get_user_emojis_grouped(context.database, context.emoji_map, context.guild, target).await?;
 
 	// groups.extend(ungrouped);
 	// let image = make_inventory_image(groups, &context.emoji_map);
 	// interaction
-	// 	.reply_image(context.http, image.as_slice(), "inventory.png", !is_public)
+	// 	.reply_image(context.discord, image.as_slice(), "inventory.png", !is_public)
 	// 	.await
 	// 	.unwrap();
 	// return;
@@ -73,10 +79,9 @@ pub async fn execute(context: Context<'_>, mut interaction: CommandInteraction)
 			.map(|name| Cow::from(format!("{name} has no emojis. 🤔")))
 			.unwrap_or_else(|| Cow::from("You have no emojis. 🤔"));
 		interaction
-			.reply(context.http, message, !is_public)
-			.await
-			.unwrap();
-		return;
+			.reply(context.discord, message, !is_public)
+			.await?;
+		return Ok(());
 	}
 	let mut output = match (emoji_count, name) {
 		(1, Some(name)) => format!("{name} only has "),
@@ -85,6 +90,15 @@ pub async fn execute(context: Context<'_>, mut interaction: CommandInteraction)
 		(n, None) => format!("You have the following {n} emojis: "),
 	};
 
+	let mut rarity_counts = Rarity::ALL.map(|rarity| (rarity, 0));
+	for (emoji, count) in groups.iter().chain(&ungrouped).flatten() {
+		for (rarity, rarity_count) in &mut rarity_counts {
+			if emoji.rarity() == *rarity {
+				*rarity_count += count;
+			}
+		}
+	}
+
 	for emojis in groups.into_iter() {
 		output.write_fmt(format_args!("[{}]", emojis)).unwrap();
 	}
@@ -93,13 +107,32 @@ pub async fn execute(context: Context<'_>, mut interaction: CommandInteraction)
 	}
 
 	output.push('.');
+	let rarity_counts = rarity_counts
+		.into_iter()
+		.rev()
+		.filter(|(_, count)| *count > 0)
+		.map(|(rarity, count)| format!("{count} {rarity}"))
+		.collect::<Vec<_>>();
+	write!(output, "\nBy rarity: {}.", rarity_counts.join(", ")).unwrap();
+	if targets_own {
+		let locked = EmojisWithCounts::locked_for_user(
+			context.database,
+			context.emoji_map,
+			context.guild,
+			target,
+		)
+		.await?;
+		if !locked.is_empty() {
+			write!(output, "\nLocked in trade offers or escrow: {locked}.").unwrap();
+		}
+	}
 	interaction
-		.reply(context.http, output, !is_public)
-		.await
-		.unwrap();
+		.reply(context.discord, output, !is_public)
+		.await?;
+	Ok(())
 }
 
-pub fn register() -> CreateCommand {
+fn register() -> CreateCommand {
 	CreateCommand::new("inventory")
 		.description("Check someone else's emoji inventory or your own.")
 		.add_option(
@@ -143,3 +176,22 @@ pub fn register() -> CreateCommand {
 			),
 		)
 }
+
+pub struct InventoryCommand;
+
+#[async_trait]
+impl SlashCommand for InventoryCommand {
+	fn name(&self) -> &'static str {
+		"inventory"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+}
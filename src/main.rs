@@ -2,38 +2,66 @@
 
 use std::fs;
 
+use config::Config;
 use discord_events::DiscordEventHandler;
 use emoji::EmojiMap;
 use serenity::prelude::GatewayIntents;
-use sqlx::sqlite::SqlitePoolOptions;
-use trading::trading_roles::get_trading_roles;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
 
+mod autocomplete;
+mod commands;
+mod config;
 mod context;
+mod discord;
 mod discord_events;
+mod drop_table;
 mod emoji;
 mod emoji_list;
 mod emojis_with_counts;
+mod error;
+mod events;
 mod find_emoji;
 mod images;
 mod inventory;
+mod migrations;
 mod periodic_emoji;
+mod pity;
 mod queries;
+mod registration;
 mod special_characters;
+mod streaks;
+#[cfg(test)]
+mod testing;
 mod trading;
 mod user_settings;
 mod util;
 
 #[tokio::main]
 async fn main() {
-	let discord_token =
-		fs::read_to_string("./discord_token.txt").expect("Could not read Discord token file");
+	let config = match Config::load() {
+		Ok(config) => config,
+		Err(error) => {
+			eprint!("{error}");
+			std::process::exit(1);
+		}
+	};
 
+	fs::create_dir_all("./data").expect("Could not create data directory");
 	let db_pool = SqlitePoolOptions::new()
 		.max_connections(4)
-		.connect("./data/db.db")
+		.connect_with(
+			SqliteConnectOptions::new()
+				.filename("./data/db.db")
+				.create_if_missing(true),
+		)
 		.await
 		.unwrap();
 
+	if let Err(error) = migrations::migrate(&db_pool, config.home_guild).await {
+		eprintln!("Could not migrate the database: {error}");
+		std::process::exit(1);
+	}
+
 	let version = sqlx::query!("SELECT sqlite_version() as version;")
 		.fetch_one(&db_pool)
 		.await
@@ -42,7 +70,8 @@ async fn main() {
 
 	let emoji_map = EmojiMap::load();
 
-	let handler = DiscordEventHandler::new(db_pool, emoji_map, get_trading_roles());
+	let discord_token = config.discord_token.clone();
+	let handler = DiscordEventHandler::new(db_pool, emoji_map, config);
 	let mut client = serenity::Client::builder(
 		&discord_token,
 		GatewayIntents::GUILDS | GatewayIntents::GUILD_MESSAGES,
@@ -0,0 +1,272 @@
+//! Versioned schema migrations, applied at startup.
+//!
+//! Migrations are the numbered SQL files in `./migrations`, compiled into the binary. The version a database is at is kept in its `schema_version` table. `schema.db`, which sqlx checks queries against at compile time, should always be an empty database with every migration applied.
+
+use std::fmt::Display;
+
+use serenity::all::GuildId;
+use sqlx::{query, query_scalar, raw_sql, Pool, Sqlite, SqliteConnection};
+
+const MIGRATIONS: [(i64, &str); 16] = [
+	(1, include_str!("../migrations/0001_initial.sql")),
+	(2, include_str!("../migrations/0002_per_guild.sql")),
+	(3, include_str!("../migrations/0003_drop_period_keys.sql")),
+	(4, include_str!("../migrations/0004_streaks.sql")),
+	(5, include_str!("../migrations/0005_pity.sql")),
+	(6, include_str!("../migrations/0006_notifications.sql")),
+	(7, include_str!("../migrations/0007_privacy_settings.sql")),
+	(8, include_str!("../migrations/0008_trade_limits.sql")),
+	(9, include_str!("../migrations/0009_offer_expiry.sql")),
+	(10, include_str!("../migrations/0010_counter_offers.sql")),
+	(11, include_str!("../migrations/0011_circle_trades.sql")),
+	(12, include_str!("../migrations/0012_market.sql")),
+	(13, include_str!("../migrations/0013_wildcards.sql")),
+	(14, include_str!("../migrations/0014_auctions.sql")),
+	(
+		15,
+		include_str!("../migrations/0015_offer_reservations.sql"),
+	),
+	(
+		16,
+		include_str!("../migrations/0016_streak_reward_notices.sql"),
+	),
+];
+
+/// The schema version this build of the bot expects.
+pub const LATEST_VERSION: i64 = MIGRATIONS[MIGRATIONS.len() - 1].0;
+
+#[derive(Debug)]
+pub enum MigrationError {
+	/// The database was migrated by a newer version of the bot.
+	NewerSchema(i64),
+	Migration(i64, sqlx::Error),
+	Database(sqlx::Error),
+}
+
+impl Display for MigrationError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::NewerSchema(version) => write!(
+				f,
+				"The database is at schema version {version}, but this version of the bot only knows up to version {LATEST_VERSION}."
+			),
+			Self::Migration(version, error) => {
+				write!(f, "Migration {version} failed: {error}")
+			}
+			Self::Database(error) => write!(f, "Database error: {error}"),
+		}
+	}
+}
+
+impl From<sqlx::Error> for MigrationError {
+	fn from(error: sqlx::Error) -> Self {
+		Self::Database(error)
+	}
+}
+
+/// Brings the database up to the latest schema version, one migration at a time.
+///
+/// Existing data that predates per-guild economies gets assigned to the home guild.
+pub async fn migrate(
+	database: &Pool<Sqlite>,
+	home_guild: Option<GuildId>,
+) -> Result<(), MigrationError> {
+	let mut connection = database.acquire().await?;
+	let current_version = get_schema_version(&mut connection).await?;
+	if current_version > LATEST_VERSION {
+		return Err(MigrationError::NewerSchema(current_version));
+	}
+
+	let home_guild = home_guild.map(|guild| guild.get() as i64);
+	raw_sql("CREATE TEMP TABLE IF NOT EXISTS migration_parameters (home_guild INTEGER);")
+		.execute(&mut *connection)
+		.await?;
+	raw_sql("DELETE FROM temp.migration_parameters;")
+		.execute(&mut *connection)
+		.await?;
+	query("INSERT INTO temp.migration_parameters (home_guild) VALUES (?)")
+		.bind(home_guild)
+		.execute(&mut *connection)
+		.await?;
+
+	for (version, migration) in MIGRATIONS {
+		if version <= current_version {
+			continue;
+		}
+		apply_migration(&mut connection, version, migration)
+			.await
+			.map_err(|error| MigrationError::Migration(version, error))?;
+		println!("Migrated database to schema version {version}.");
+	}
+
+	raw_sql("DROP TABLE temp.migration_parameters;")
+		.execute(&mut *connection)
+		.await?;
+	Ok(())
+}
+
+/// Foreign keys are turned off while migrating, so tables can be rebuilt without cascading deletes and updates.
+async fn apply_migration(
+	connection: &mut SqliteConnection,
+	version: i64,
+	migration: &str,
+) -> Result<(), sqlx::Error> {
+	raw_sql("PRAGMA foreign_keys = OFF;")
+		.execute(&mut *connection)
+		.await?;
+	let result = async {
+		let mut transaction = sqlx::Connection::begin(&mut *connection).await?;
+		raw_sql(migration).execute(&mut *transaction).await?;
+		let violations = query_scalar::<_, i64>("SELECT COUNT(*) FROM pragma_foreign_key_check")
+			.fetch_one(&mut *transaction)
+			.await?;
+		if violations > 0 {
+			return Err(sqlx::Error::Protocol(format!(
+				"{violations} foreign key violations after migrating"
+			)));
+		}
+		raw_sql(
+			"
+			CREATE TABLE IF NOT EXISTS schema_version (version INTEGER NOT NULL);
+			DELETE FROM schema_version;
+			",
+		)
+		.execute(&mut *transaction)
+		.await?;
+		query("INSERT INTO schema_version (version) VALUES (?)")
+			.bind(version)
+			.execute(&mut *transaction)
+			.await?;
+		transaction.commit().await
+	}
+	.await;
+	raw_sql("PRAGMA foreign_keys = ON;")
+		.execute(&mut *connection)
+		.await?;
+	result
+}
+
+/// Databases from before versioned migrations have no `schema_version` table, so their version is worked out from what tables and columns exist.
+async fn get_schema_version(connection: &mut SqliteConnection) -> Result<i64, sqlx::Error> {
+	let has_table = |name: &'static str| {
+		query_scalar::<_, bool>(
+			"SELECT COUNT(*) > 0 FROM sqlite_master WHERE type = 'table' AND name = ?",
+		)
+		.bind(name)
+	};
+	if has_table("schema_version")
+		.fetch_one(&mut *connection)
+		.await?
+	{
+		return query_scalar("SELECT version FROM schema_version")
+			.fetch_one(&mut *connection)
+			.await;
+	}
+	if !has_table("emoji_inventory")
+		.fetch_one(&mut *connection)
+		.await?
+	{
+		return Ok(0);
+	}
+	let has_guild_column = query_scalar::<_, bool>(
+		"SELECT COUNT(*) > 0 FROM pragma_table_info('emoji_inventory') WHERE name = 'guild'",
+	)
+	.fetch_one(&mut *connection)
+	.await?;
+	Ok(if has_guild_column { 2 } else { 1 })
+}
+
+#[cfg(test)]
+mod tests {
+	use sqlx::sqlite::SqlitePoolOptions;
+
+	use super::*;
+
+	async fn empty_database() -> Pool<Sqlite> {
+		SqlitePoolOptions::new()
+			.max_connections(1)
+			.connect("sqlite::memory:")
+			.await
+			.unwrap()
+	}
+
+	#[tokio::test]
+	async fn fresh_database_is_migrated_to_latest() {
+		let database = empty_database().await;
+		migrate(&database, None).await.unwrap();
+		let mut connection = database.acquire().await.unwrap();
+		assert_eq!(
+			get_schema_version(&mut connection).await.unwrap(),
+			LATEST_VERSION
+		);
+		// Migrating again should do nothing.
+		drop(connection);
+		migrate(&database, None).await.unwrap();
+	}
+
+	#[tokio::test]
+	async fn legacy_data_goes_to_home_guild() {
+		let database = empty_database().await;
+		raw_sql(MIGRATIONS[0].1).execute(&database).await.unwrap();
+		raw_sql(
+			"
+			INSERT INTO emoji_inventory_groups (id, user, name, sort_order) VALUES (1, 5, 'faces', 1);
+			INSERT INTO emoji_inventory (user, emoji, group_id) VALUES (5, '🙂', 1), (5, '🙃', NULL);
+			INSERT INTO trade_offers (id, user, target_user) VALUES (1, 5, 6);
+			INSERT INTO trade_offer_contents (trade, emoji, count) VALUES (1, '🙂', -1);
+			INSERT INTO last_seen (user, date) VALUES (5, '2024-03-10');
+			INSERT INTO user_settings (user, private) VALUES (5, 1), (6, 0);
+			",
+		)
+		.execute(&database)
+		.await
+		.unwrap();
+
+		assert!(matches!(
+			migrate(&database, None).await,
+			Err(MigrationError::Migration(2, _))
+		));
+		migrate(&database, Some(GuildId::new(42))).await.unwrap();
+
+		let guilds: Vec<i64> = query_scalar("SELECT guild FROM emoji_inventory")
+			.fetch_all(&database)
+			.await
+			.unwrap();
+		assert_eq!(guilds, [42, 42]);
+		let grouped: i64 = query_scalar("SELECT COUNT(*) FROM emoji_inventory WHERE group_id = 1")
+			.fetch_one(&database)
+			.await
+			.unwrap();
+		assert_eq!(grouped, 1);
+		let offer_contents: i64 = query_scalar("SELECT COUNT(*) FROM trade_offer_contents")
+			.fetch_one(&database)
+			.await
+			.unwrap();
+		assert_eq!(offer_contents, 1);
+		let last_seen: (i64, String) = sqlx::query_as("SELECT guild, period FROM last_seen")
+			.fetch_one(&database)
+			.await
+			.unwrap();
+		assert_eq!(last_seen, (42, String::from("2024-W10")));
+		let shown: Vec<i64> =
+			query_scalar("SELECT show_inventory FROM user_settings ORDER BY user")
+				.fetch_all(&database)
+				.await
+				.unwrap();
+		assert_eq!(shown, [0, 1]);
+	}
+
+	#[tokio::test]
+	async fn newer_schema_is_refused() {
+		let database = empty_database().await;
+		migrate(&database, None).await.unwrap();
+		raw_sql("UPDATE schema_version SET version = version + 1;")
+			.execute(&database)
+			.await
+			.unwrap();
+		assert!(matches!(
+			migrate(&database, None).await,
+			Err(MigrationError::NewerSchema(_))
+		));
+	}
+}
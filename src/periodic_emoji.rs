@@ -1,56 +1,216 @@
+use chrono::{DateTime, FixedOffset, TimeDelta, Utc};
 use serenity::{
-	model::prelude::{Message, UserId},
+	model::prelude::{GuildId, Message, UserId},
 	prelude::Context,
 };
 use sqlx::{query, Pool, Sqlite};
 
-use crate::{emoji::Emoji, queries::give_emoji, user_settings::private::is_private};
+use crate::{
+	config::{Config, DropPeriod, GuildConfig},
+	discord::SerenityDiscord,
+	emoji::Emoji,
+	error::BotError,
+	events::bonus_drops,
+	pity::{drop_table_for, record_drop},
+	queries::give_emoji,
+	streaks::{reward_milestone, MilestoneReward},
+	user_settings::notifications::{notify, Notice, Notifications},
+};
+
+/// Reacted with when a streak reward is waiting to be picked with `/streak`.
+const REWARD_TO_PICK: char = '🎁';
+
+/// A key that is the same for two points in time exactly when they are in the same period.
+fn period_key(period: DropPeriod, timezone: FixedOffset, time: DateTime<Utc>) -> String {
+	let local = time.with_timezone(&timezone);
+	match period {
+		DropPeriod::Daily => local.format("%Y-%m-%d").to_string(),
+		// %G is ISO 8601 year corresponding to %V. %V is ISO 8601 week. It is basically a week that is not interrupted by year changes.
+		DropPeriod::Weekly => local.format("%G-W%V").to_string(),
+		DropPeriod::Hours(hours) => {
+			// Counting from midnight local time at the Unix epoch makes periods that fit in a day start at midnight every day.
+			let local_seconds = local.naive_local().and_utc().timestamp();
+			let period_seconds = hours as i64 * 60 * 60;
+			let start = local_seconds - local_seconds.rem_euclid(period_seconds);
+			let start = DateTime::from_timestamp(start, 0).unwrap_or_default();
+			format!("{}+{hours}h", start.format("%Y-%m-%dT%H:%M"))
+		}
+	}
+}
 
-/// Period is currently one week.
-async fn seen_this_period(database: &Pool<Sqlite>, user: UserId) -> bool {
+/// The key of the period the time is in, followed by the keys of the two periods before it.
+pub(crate) fn recent_period_keys(
+	period: DropPeriod,
+	timezone: FixedOffset,
+	time: DateTime<Utc>,
+) -> [String; 3] {
+	let length = match period {
+		DropPeriod::Daily => TimeDelta::days(1),
+		DropPeriod::Weekly => TimeDelta::weeks(1),
+		DropPeriod::Hours(hours) => TimeDelta::hours(hours as i64),
+	};
+	[0, 1, 2].map(|ago| period_key(period, timezone, time - length * ago))
+}
+
+/// Records that the user was seen at the time. Returns None if they had already been seen in that period, and otherwise their streak, including this period.
+///
+/// Missing a single period doesn't break a streak, but doesn't count towards it either.
+async fn record_seen(
+	database: &Pool<Sqlite>,
+	guild_config: &GuildConfig,
+	time: DateTime<Utc>,
+	guild: GuildId,
+	user: UserId,
+) -> Result<Option<u32>, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
-	// %G is ISO 8601 year corresponding to %V. %V is ISO 8601 week. It is basically a week that is not interrupted by year changes.
-	let seen = query!(
-		r#"
-		SELECT CASE
-			WHEN strftime('%G-%V', date) == strftime('%G-%V', date()) THEN true
-			ELSE false
-			END "seen_this_period!: bool"
+	let [this_period, previous_period, period_before] =
+		recent_period_keys(guild_config.drop_period, guild_config.timezone, time);
+	let last_seen = query!(
+		"
+		SELECT period, streak, best_streak
 		FROM last_seen
-		WHERE user = ?
-		"#,
+		WHERE guild = ? AND user = ?
+		",
+		guild_id,
 		user_id
 	)
 	.fetch_optional(database)
-	.await
-	.unwrap()
-	.map(|record| record.seen_this_period)
-	.unwrap_or(false);
-	if !seen {
-		query!(
-			"
-			INSERT INTO last_seen (user)
-			VALUES (?)
-			",
-			user_id
-		)
-		.execute(database)
-		.await
-		.unwrap();
-	}
-	seen
+	.await?;
+	let (streak, best_streak) = match last_seen {
+		Some(last_seen) if last_seen.period == this_period => return Ok(None),
+		Some(last_seen)
+			if last_seen.period == previous_period || last_seen.period == period_before =>
+		{
+			let streak = last_seen.streak + 1;
+			(streak, streak.max(last_seen.best_streak))
+		}
+		Some(last_seen) => (1, last_seen.best_streak),
+		None => (1, 1),
+	};
+	query!(
+		"
+		INSERT INTO last_seen (guild, user, period, streak, best_streak)
+		VALUES (?, ?, ?, ?, ?)
+		",
+		guild_id,
+		user_id,
+		this_period,
+		streak,
+		best_streak
+	)
+	.execute(database)
+	.await?;
+	Ok(Some(streak as u32))
 }
 
 pub async fn maybe_give_periodic_emoji(
 	database: &Pool<Sqlite>,
+	config: &Config,
 	context: Context,
 	message: Message,
 ) {
-	if !seen_this_period(database, message.author.id).await {
-		let emoji = Emoji::random();
-		give_emoji(database, message.author.id, emoji).await;
-		if !is_private(database, message.author.id).await {
-			let _ = message.react(context, emoji).await;
+	let Some(guild) = message.guild_id else {
+		return;
+	};
+	let user = message.author.id;
+	let guild_config = config.guild(guild);
+	let result = async {
+		if let Some(streak) = record_seen(database, guild_config, Utc::now(), guild, user).await? {
+			let drop_table = drop_table_for(database, guild_config, guild, user).await?;
+			let emoji = Emoji::random(&drop_table);
+			record_drop(database, guild, user, drop_table.owns(emoji)).await?;
+			give_emoji(database, guild, user, emoji).await?;
+			let mut emojis = vec![emoji];
+			for bonus in bonus_drops(&guild_config.events, Utc::now()) {
+				give_emoji(database, guild, user, bonus).await?;
+				emojis.push(bonus);
+			}
+			let reward = reward_milestone(database, guild_config, guild, user, streak).await?;
+			if let Some(MilestoneReward::Emoji(emoji)) = reward {
+				emojis.push(emoji);
+			}
+
+			let discord = SerenityDiscord {
+				http: &context.http,
+				cache: &context.cache,
+				shard: &context.shard,
+			};
+			let mut notices: Vec<_> = emojis.iter().copied().map(Notice::Emoji).collect();
+			if let Some(MilestoneReward::Choice) = reward {
+				notices.push(Notice::StreakReward);
+			}
+			if notify(database, &discord, guild, user, &notices).await? == Notifications::Reaction {
+				for emoji in emojis {
+					message.react(&context, emoji).await?;
+				}
+				if let Some(MilestoneReward::Choice) = reward {
+					message.react(&context, REWARD_TO_PICK).await?;
+				}
+			}
+		}
+		Ok::<_, BotError>(())
+	}
+	.await;
+	if let Err(error) = result {
+		eprintln!("Error giving periodic emoji to user {user} in guild {guild}: {error}");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::testing::{self, GUILD};
+
+	use super::*;
+
+	#[test]
+	fn periods_follow_the_timezone() {
+		let utc = FixedOffset::east_opt(0).unwrap();
+		let tokyo = FixedOffset::east_opt(9 * 60 * 60).unwrap();
+		// A Sunday evening in UTC, which is already Monday in Tokyo.
+		let time = DateTime::parse_from_rfc3339("2024-03-10T20:30:00Z")
+			.unwrap()
+			.to_utc();
+		assert_eq!(period_key(DropPeriod::Daily, utc, time), "2024-03-10");
+		assert_eq!(period_key(DropPeriod::Daily, tokyo, time), "2024-03-11");
+		assert_eq!(period_key(DropPeriod::Weekly, utc, time), "2024-W10");
+		assert_eq!(period_key(DropPeriod::Weekly, tokyo, time), "2024-W11");
+		assert_eq!(
+			period_key(DropPeriod::Hours(6), utc, time),
+			"2024-03-10T18:00+6h"
+		);
+		assert_eq!(
+			period_key(DropPeriod::Hours(6), tokyo, time),
+			"2024-03-11T00:00+6h"
+		);
+	}
+
+	#[tokio::test]
+	async fn one_missed_period_keeps_the_streak() {
+		let database = testing::database().await;
+		let config = testing::config();
+		let guild_config = config.guild(GUILD);
+		let user = UserId::new(1);
+		let in_week = |week: i64| {
+			DateTime::parse_from_rfc3339("2024-01-03T12:00:00Z")
+				.unwrap()
+				.to_utc() + TimeDelta::weeks(week)
+		};
+		let mut streaks = Vec::new();
+		for (week, times) in [(0, 1), (1, 2), (3, 1), (6, 1), (7, 1)] {
+			for _ in 0..times {
+				streaks.push(
+					record_seen(&database, guild_config, in_week(week), GUILD, user)
+						.await
+						.unwrap(),
+				);
+			}
 		}
+		assert_eq!(streaks, [Some(1), Some(2), None, Some(3), Some(1), Some(2)]);
+		let best: i64 = sqlx::query_scalar("SELECT best_streak FROM last_seen")
+			.fetch_one(&database)
+			.await
+			.unwrap();
+		assert_eq!(best, 3);
 	}
 }
@@ -0,0 +1,307 @@
+//! Bad luck protection: the more duplicate emojis a user is handed in a row, the likelier the next one is an emoji they lack.
+
+use std::{collections::HashSet, fmt::Write};
+
+use chrono::Utc;
+use rand::{seq::SliceRandom, Rng, RngCore};
+use serenity::{
+	all::{CommandInteraction, CommandOptionType, GuildId, UserId},
+	async_trait,
+	builder::{CreateCommand, CreateCommandOption},
+	model::Permissions,
+};
+use sqlx::{query, query_scalar, Pool, Sqlite, SqliteExecutor};
+
+use crate::{
+	commands::SlashCommand,
+	config::{GuildConfig, PityConfig},
+	context::Context,
+	drop_table::DropTable,
+	emoji::Emoji,
+	error::BotError,
+	events::EventTable,
+	util::ReplyShortcuts,
+};
+
+/// How many more times the underlying table is asked for an emoji the user lacks, before giving up on its weights.
+const REROLLS: usize = 100;
+
+/// Wraps a guild's drop table for a specific user, so it can favour emojis they lack.
+pub struct PityTable<'l> {
+	base: Box<dyn DropTable + 'l>,
+	owned: HashSet<String>,
+	/// The chance that a duplicate gets swapped for a missing emoji.
+	chance: f64,
+}
+
+impl PityTable<'_> {
+	pub fn owns(&self, emoji: Emoji) -> bool {
+		self.owned.contains(emoji.as_str())
+	}
+}
+
+impl DropTable for PityTable<'_> {
+	fn pick(&self, rng: &mut dyn RngCore) -> Emoji {
+		let emoji = self.base.pick(rng);
+		if !self.owns(emoji) || !rng.gen_bool(self.chance) {
+			return emoji;
+		}
+		for _ in 0..REROLLS {
+			let emoji = self.base.pick(rng);
+			if !self.owns(emoji) {
+				return emoji;
+			}
+		}
+		// Whatever the table favours is all owned, so anything missing will do.
+		let missing: Vec<_> = Emoji::all().filter(|emoji| !self.owns(*emoji)).collect();
+		missing.choose(rng).copied().unwrap_or(emoji)
+	}
+}
+
+/// The chance that a duplicate gets swapped for a missing emoji, after this many duplicates in a row.
+fn pity_chance(pity: Option<PityConfig>, duplicates: u32) -> f64 {
+	match pity {
+		Some(pity) if duplicates >= pity.after => {
+			(pity.increase * (duplicates - pity.after + 1) as f64).min(1.0)
+		}
+		_ => 0.0,
+	}
+}
+
+async fn get_duplicates(
+	database: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+) -> Result<u32, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let duplicates = query_scalar!(
+		"
+		SELECT duplicates
+		FROM pity_counters
+		WHERE guild = ? AND user = ?
+		",
+		guild_id,
+		user_id
+	)
+	.fetch_optional(database)
+	.await?;
+	Ok(duplicates.unwrap_or(0) as u32)
+}
+
+/// The table to hand the user emojis from. Without bad luck protection or events on, this just passes through to the guild's table.
+pub async fn drop_table_for<'l>(
+	database: &Pool<Sqlite>,
+	guild_config: &'l GuildConfig,
+	guild: GuildId,
+	user: UserId,
+) -> Result<PityTable<'l>, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let owned = query_scalar!(
+		"
+		SELECT DISTINCT emoji
+		FROM emoji_inventory
+		WHERE guild = ? AND user = ?
+		",
+		guild_id,
+		user_id
+	)
+	.fetch_all(database)
+	.await?
+	.into_iter()
+	.collect();
+	let duplicates = get_duplicates(database, guild, user).await?;
+	Ok(PityTable {
+		base: Box::new(EventTable::new(
+			&guild_config.drop_table,
+			&guild_config.events,
+			Utc::now(),
+		)),
+		owned,
+		chance: pity_chance(guild_config.pity, duplicates),
+	})
+}
+
+/// Counts a duplicate towards the streak of them, or ends the streak.
+pub async fn record_drop<'c, E: SqliteExecutor<'c>>(
+	executor: E,
+	guild: GuildId,
+	user: UserId,
+	duplicate: bool,
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	query!(
+		"
+		INSERT INTO pity_counters (guild, user, duplicates)
+		VALUES (?, ?, ?)
+		ON CONFLICT (guild, user)
+			DO UPDATE SET duplicates = CASE WHEN excluded.duplicates = 0 THEN 0 ELSE duplicates + 1 END
+		",
+		guild_id,
+		user_id,
+		duplicate
+	)
+	.execute(executor)
+	.await?;
+	Ok(())
+}
+
+/// The users with the most duplicates in a row, most first.
+async fn get_unluckiest(
+	database: &Pool<Sqlite>,
+	guild: GuildId,
+	limit: i64,
+) -> Result<Vec<(UserId, u32)>, BotError> {
+	let guild_id = guild.get() as i64;
+	Ok(query!(
+		"
+		SELECT user, duplicates
+		FROM pity_counters
+		WHERE guild = ? AND duplicates > 0
+		ORDER BY duplicates DESC
+		LIMIT ?
+		",
+		guild_id,
+		limit
+	)
+	.fetch_all(database)
+	.await?
+	.into_iter()
+	.map(|record| (UserId::new(record.user as u64), record.duplicates as u32))
+	.collect())
+}
+
+fn describe_chance(pity: Option<PityConfig>, duplicates: u32) -> String {
+	let percentage = (pity_chance(pity, duplicates) * 100.0).round();
+	format!("{percentage}% chance of a missing emoji instead of a duplicate")
+}
+
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let pity = context.guild_config().pity;
+	let mut content = match pity {
+		Some(pity) => format!(
+			"Bad luck protection kicks in after {} duplicates in a row, adding {}% per further duplicate.\n",
+			pity.after,
+			(pity.increase * 100.0).round()
+		),
+		None => String::from(
+			"Bad luck protection is off in this server, but duplicates are still counted.\n",
+		),
+	};
+	let user = interaction
+		.data
+		.options
+		.first()
+		.and_then(|option| option.value.as_user_id());
+	if let Some(user) = user {
+		let duplicates = get_duplicates(context.database, context.guild, user).await?;
+		let name = context.get_user_name(user).await;
+		write!(
+			content,
+			"{name} has had {duplicates} duplicates in a row, for a {}.",
+			describe_chance(pity, duplicates)
+		)
+		.unwrap();
+	} else {
+		let unluckiest = get_unluckiest(context.database, context.guild, 10).await?;
+		if unluckiest.is_empty() {
+			content.push_str("Nobody has had a duplicate in a row yet.");
+		}
+		for (user, duplicates) in unluckiest {
+			let name = context.get_user_name(user).await;
+			writeln!(
+				content,
+				"{name}: {duplicates} in a row, for a {}",
+				describe_chance(pity, duplicates)
+			)
+			.unwrap();
+		}
+	}
+	interaction
+		.ephemeral_reply(context.discord, content)
+		.await?;
+	Ok(())
+}
+
+fn register() -> CreateCommand {
+	CreateCommand::new("pity")
+		.description(
+			"Shows the bad luck protection counters of the unluckiest users, or of one user.",
+		)
+		.default_member_permissions(Permissions::ADMINISTRATOR)
+		.add_option(CreateCommandOption::new(
+			CommandOptionType::User,
+			"user",
+			"Whose counter to show.",
+		))
+}
+
+pub struct PityCommand;
+
+#[async_trait]
+impl SlashCommand for PityCommand {
+	fn name(&self) -> &'static str {
+		"pity"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use crate::{
+		drop_table::RarityTable,
+		testing::{self, GUILD},
+	};
+
+	use super::*;
+
+	#[tokio::test]
+	async fn duplicates_make_missing_emojis_likelier() {
+		let database = testing::database().await;
+		let user = UserId::new(1);
+		for duplicate in [true, true, false, true, true, true] {
+			record_drop(&database, GUILD, user, duplicate)
+				.await
+				.unwrap();
+		}
+		let duplicates = get_duplicates(&database, GUILD, user).await.unwrap();
+		assert_eq!(duplicates, 3);
+
+		let pity = Some(PityConfig {
+			after: 2,
+			increase: 0.5,
+		});
+		assert_eq!(pity_chance(pity, 1), 0.0);
+		assert_eq!(pity_chance(pity, 2), 0.5);
+		assert_eq!(pity_chance(pity, duplicates), 1.0);
+		assert_eq!(pity_chance(None, duplicates), 0.0);
+
+		// Owning everything but one emoji, that is the only thing that can drop at full pity.
+		let last = Emoji::all().last().unwrap();
+		let table = PityTable {
+			base: Box::new(RarityTable::default()),
+			owned: Emoji::all()
+				.filter(|emoji| *emoji != last)
+				.map(|emoji| String::from(emoji.as_str()))
+				.collect(),
+			chance: 1.0,
+		};
+		let mut rng = StdRng::seed_from_u64(1);
+		for _ in 0..10 {
+			assert_eq!(table.pick(&mut rng), last);
+		}
+	}
+}
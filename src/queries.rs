@@ -1,18 +1,21 @@
 use std::collections::HashMap;
 
-use serenity::model::prelude::UserId;
+use serenity::model::prelude::{GuildId, UserId};
 use sqlx::{query, Pool, Sqlite, SqliteExecutor};
 
 use crate::{
 	emoji::{Emoji, EmojiMap},
 	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
 };
 
 pub async fn get_user_emojis_grouped(
 	database: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	user: UserId,
-) -> (Vec<EmojisWithCounts>, Option<EmojisWithCounts>) {
+) -> Result<(Vec<EmojisWithCounts>, Option<EmojisWithCounts>), BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let records = query!(
 		"
@@ -20,22 +23,20 @@ pub async fn get_user_emojis_grouped(
 		FROM emoji_inventory
 		LEFT JOIN emoji_inventory_groups
 		ON emoji_inventory.group_id = emoji_inventory_groups.id
-		WHERE emoji_inventory.user = ?
+		WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ?
 		GROUP BY emoji_inventory.user, emoji, group_id
 		",
+		guild_id,
 		user_id
 	)
 	.fetch_all(database)
-	.await
-	.unwrap();
+	.await?;
 
 	let mut emoji_groups = HashMap::<Option<u32>, Vec<(Emoji, u32)>>::new();
 	for record in records {
 		let sort_order = record.sort_order.map(|n| n as u32);
 		if record.count > 0 {
-			let emoji = emoji_map
-				.get(record.emoji.as_str())
-				.expect("Emoji from database was somehow not in map.");
+			let emoji = emoji_map.get_from_database(&record.emoji)?;
 			emoji_groups
 				.entry(sort_order)
 				.or_default()
@@ -52,21 +53,28 @@ pub async fn get_user_emojis_grouped(
 		.map(|(_ready, group)| EmojisWithCounts::new(group))
 		.collect();
 
-	(emoji_groups, ungrouped)
+	Ok((emoji_groups, ungrouped))
 }
 
-pub async fn give_emoji<'c, E: SqliteExecutor<'c>>(database: E, user: UserId, emoji: Emoji) {
+pub async fn give_emoji<'c, E: SqliteExecutor<'c>>(
+	database: E,
+	guild: GuildId,
+	user: UserId,
+	emoji: Emoji,
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let emoji = emoji.as_str();
 	query!(
 		"
-		INSERT INTO emoji_inventory (user, emoji)
-		VALUES (?, ?)
+		INSERT INTO emoji_inventory (guild, user, emoji)
+		VALUES (?, ?, ?)
 		",
+		guild_id,
 		user_id,
 		emoji
 	)
 	.execute(database)
-	.await
-	.unwrap();
+	.await?;
+	Ok(())
 }
@@ -0,0 +1,262 @@
+//! Keeps the slash commands Discord has in line with the ones in the command registry.
+//!
+//! Rather than overwriting every command on every start, the commands Discord reports are compared with the wanted ones, and only what differs is created, updated or deleted.
+
+use std::fmt::Display;
+
+use serenity::{
+	all::{Command, CommandId, GuildId},
+	builder::CreateCommand,
+	http::Http,
+	json::{self, Value},
+};
+
+use crate::{commands::CommandRegistry, config::CommandScope};
+
+/// The keys of a command that are compared. The rest are set by Discord, like IDs and versions.
+const COMPARED_KEYS: [&str; 5] = [
+	"name",
+	"description",
+	"options",
+	"default_member_permissions",
+	"nsfw",
+];
+
+#[derive(Clone, Copy)]
+enum Target {
+	Global,
+	Guild(GuildId),
+}
+
+impl Display for Target {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Global => f.write_str("global slash commands"),
+			Self::Guild(guild) => write!(f, "slash commands in guild {guild}"),
+		}
+	}
+}
+
+/// Brings the global commands up to date. When commands are registered per guild, this removes any left over global commands.
+pub async fn sync_global_commands(
+	http: &Http,
+	registry: &CommandRegistry,
+	scope: &CommandScope,
+) -> Result<(), serenity::Error> {
+	let desired = match scope {
+		CommandScope::Global => registry.create_commands(),
+		_ => Vec::new(),
+	};
+	sync(http, Target::Global, desired).await
+}
+
+/// Brings a guild's commands up to date. Guilds that should not have commands of their own get theirs removed.
+pub async fn sync_guild_commands(
+	http: &Http,
+	registry: &CommandRegistry,
+	scope: &CommandScope,
+	guild: GuildId,
+) -> Result<(), serenity::Error> {
+	let desired = if scope.includes_guild(guild) {
+		registry.create_commands()
+	} else {
+		Vec::new()
+	};
+	sync(http, Target::Guild(guild), desired).await
+}
+
+async fn sync(
+	http: &Http,
+	target: Target,
+	desired: Vec<CreateCommand>,
+) -> Result<(), serenity::Error> {
+	let existing = match target {
+		Target::Global => Command::get_global_commands(http).await?,
+		Target::Guild(guild) => guild.get_commands(http).await?,
+	};
+	let diff = CommandDiff::new(desired, &existing);
+	if diff.is_empty() {
+		return Ok(());
+	}
+
+	// Creating a command with the name of an existing one overwrites it.
+	for (_, command) in diff.added.iter().chain(&diff.changed) {
+		match target {
+			Target::Global => {
+				Command::create_global_command(http, command.clone()).await?;
+			}
+			Target::Guild(guild) => {
+				guild.create_command(http, command.clone()).await?;
+			}
+		}
+	}
+	for (_, id) in &diff.removed {
+		match target {
+			Target::Global => Command::delete_global_command(http, *id).await?,
+			Target::Guild(guild) => guild.delete_command(http, *id).await?,
+		}
+	}
+
+	println!("Updated {target}: {diff}");
+	Ok(())
+}
+
+/// How the wanted commands differ from the ones Discord has.
+struct CommandDiff {
+	added: Vec<(String, CreateCommand)>,
+	changed: Vec<(String, CreateCommand)>,
+	removed: Vec<(String, CommandId)>,
+	unchanged: usize,
+}
+
+impl CommandDiff {
+	fn new(desired: Vec<CreateCommand>, existing: &[Command]) -> Self {
+		let mut existing: Vec<_> = existing
+			.iter()
+			.map(|command| (command, comparable(json::to_value(command))))
+			.collect();
+		let mut diff = Self {
+			added: Vec::new(),
+			changed: Vec::new(),
+			removed: Vec::new(),
+			unchanged: 0,
+		};
+		for command in desired {
+			let wanted = comparable(json::to_value(&command));
+			let name = wanted["name"].as_str().unwrap_or_default().to_owned();
+			let index = existing
+				.iter()
+				.position(|(command, _)| command.name == name);
+			match index.map(|index| existing.swap_remove(index)) {
+				None => diff.added.push((name, command)),
+				Some((_, current)) if current != wanted => diff.changed.push((name, command)),
+				Some(_) => diff.unchanged += 1,
+			}
+		}
+		diff.removed = existing
+			.into_iter()
+			.map(|(command, _)| (command.name.clone(), command.id))
+			.collect();
+		diff
+	}
+
+	fn is_empty(&self) -> bool {
+		self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+	}
+}
+
+impl Display for CommandDiff {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let lists: [(&str, Vec<&String>); 3] = [
+			("added", self.added.iter().map(|(name, _)| name).collect()),
+			(
+				"changed",
+				self.changed.iter().map(|(name, _)| name).collect(),
+			),
+			(
+				"removed",
+				self.removed.iter().map(|(name, _)| name).collect(),
+			),
+		];
+		for (label, names) in lists {
+			if names.is_empty() {
+				continue;
+			}
+			write!(f, "{label}")?;
+			for name in names {
+				write!(f, " /{name}")?;
+			}
+			f.write_str("; ")?;
+		}
+		write!(f, "{} unchanged.", self.unchanged)
+	}
+}
+
+/// The parts of a command that are ours to decide, as JSON. Leaving out a key and setting it to null are the same to Discord, so nulls are left out.
+fn comparable(command: serenity::Result<Value>) -> Value {
+	let Ok(Value::Object(mut object)) = command else {
+		return Value::Null;
+	};
+	object.retain(|key, value| COMPARED_KEYS.contains(&key.as_str()) && !value.is_null());
+	Value::Object(object)
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::{
+		all::{CommandOptionType, Permissions},
+		builder::CreateCommandOption,
+		json::json,
+	};
+
+	use super::*;
+
+	/// What Discord would report back after registering the command.
+	fn as_registered(command: &CreateCommand, id: u64) -> Command {
+		let mut value = json::to_value(command).unwrap();
+		let object = value.as_object_mut().unwrap();
+		for (key, field) in [
+			("id", json!(id.to_string())),
+			("application_id", json!("1")),
+			("version", json!("1")),
+			("type", json!(1)),
+			("default_member_permissions", Value::Null),
+		] {
+			object.entry(key).or_insert(field);
+		}
+		json::from_value(value).unwrap()
+	}
+
+	fn test_command(name: &str) -> CreateCommand {
+		CreateCommand::new(name)
+			.description("A command.")
+			.add_option(
+				CreateCommandOption::new(CommandOptionType::String, "emojis", "Some emojis.")
+					.required(true),
+			)
+	}
+
+	#[test]
+	fn only_differences_are_pushed() {
+		let unchanged = test_command("inventory");
+		let changed = test_command("group");
+		let existing = [
+			as_registered(&unchanged, 10),
+			as_registered(&changed, 11),
+			as_registered(&test_command("old"), 12),
+		];
+		let desired = vec![
+			unchanged,
+			changed.default_member_permissions(Permissions::ADMINISTRATOR),
+			test_command("new"),
+		];
+
+		let diff = CommandDiff::new(desired, &existing);
+		let names = |list: &[(String, CreateCommand)]| {
+			list.iter()
+				.map(|(name, _)| name.clone())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(names(&diff.added), ["new"]);
+		assert_eq!(names(&diff.changed), ["group"]);
+		assert_eq!(diff.removed, [(String::from("old"), CommandId::new(12))]);
+		assert_eq!(diff.unchanged, 1);
+		assert_eq!(
+			diff.to_string(),
+			"added /new; changed /group; removed /old; 1 unchanged."
+		);
+	}
+
+	#[test]
+	fn registered_commands_match_themselves() {
+		let registry = CommandRegistry::new();
+		let desired = registry.create_commands();
+		let existing: Vec<_> = desired
+			.iter()
+			.enumerate()
+			.map(|(index, command)| as_registered(command, index as u64 + 1))
+			.collect();
+		let diff = CommandDiff::new(desired, &existing);
+		assert!(diff.is_empty(), "{diff}");
+	}
+}
@@ -0,0 +1,395 @@
+//! Streaks of drop periods in a row that a user was seen in, the bonus emojis for reaching milestones, and the command to check on them.
+
+use chrono::Utc;
+use serenity::{
+	all::{ButtonStyle, CommandInteraction, GuildId, UserId},
+	async_trait,
+	builder::CreateCommand,
+};
+use sqlx::{query, Pool, Sqlite};
+
+use crate::{
+	commands::SlashCommand,
+	config::{DropPeriod, GuildConfig, StreakReward},
+	context::Context,
+	discord::Button,
+	emoji::{Emoji, EmojiMap},
+	error::BotError,
+	periodic_emoji::recent_period_keys,
+	queries::give_emoji,
+	util::ReplyShortcuts,
+};
+
+/// Button IDs for the emojis to pick from, which is also how many there are.
+const CHOICE_IDS: [&str; 3] = ["0", "1", "2"];
+
+pub enum MilestoneReward {
+	Emoji(Emoji),
+	/// Waiting to be picked with `/streak`.
+	Choice,
+}
+
+/// Gives the reward if the streak just reached a milestone. A choice is only stored, for the user to pick from later.
+pub async fn reward_milestone(
+	database: &Pool<Sqlite>,
+	guild_config: &GuildConfig,
+	guild: GuildId,
+	user: UserId,
+	streak: u32,
+) -> Result<Option<MilestoneReward>, BotError> {
+	if !guild_config.streak_milestones.contains(&streak) {
+		return Ok(None);
+	}
+	match guild_config.streak_reward {
+		StreakReward::Emoji => {
+			let emoji = Emoji::random(&guild_config.drop_table);
+			give_emoji(database, guild, user, emoji).await?;
+			Ok(Some(MilestoneReward::Emoji(emoji)))
+		}
+		StreakReward::Choice => {
+			let mut choices = Vec::new();
+			while choices.len() < CHOICE_IDS.len() {
+				let emoji = Emoji::random(&guild_config.drop_table);
+				if !choices.contains(&emoji) {
+					choices.push(emoji);
+				}
+			}
+			add_reward_choices(database, guild, user, &choices).await?;
+			Ok(Some(MilestoneReward::Choice))
+		}
+	}
+}
+
+async fn add_reward_choices(
+	database: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	choices: &[Emoji],
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let mut transaction = database.begin().await?;
+	let reward_id = query!(
+		"
+		INSERT INTO streak_rewards (guild, user)
+		VALUES (?, ?)
+		",
+		guild_id,
+		user_id
+	)
+	.execute(&mut *transaction)
+	.await?
+	.last_insert_rowid();
+	for emoji in choices {
+		let emoji = emoji.as_str();
+		query!(
+			"
+			INSERT INTO streak_reward_choices (reward, emoji)
+			VALUES (?, ?)
+			",
+			reward_id,
+			emoji
+		)
+		.execute(&mut *transaction)
+		.await?;
+	}
+	transaction.commit().await?;
+	Ok(())
+}
+
+/// The oldest reward the user has yet to pick, with the emojis to pick from.
+async fn get_reward_choices(
+	database: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	guild: GuildId,
+	user: UserId,
+) -> Result<Option<(i64, Vec<Emoji>)>, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let Some(reward_id) = query!(
+		"
+		SELECT id
+		FROM streak_rewards
+		WHERE guild = ? AND user = ?
+		ORDER BY id ASC
+		LIMIT 1
+		",
+		guild_id,
+		user_id
+	)
+	.fetch_optional(database)
+	.await?
+	.map(|record| record.id) else {
+		return Ok(None);
+	};
+	let choices = query!(
+		"
+		SELECT emoji
+		FROM streak_reward_choices
+		WHERE reward = ?
+		",
+		reward_id
+	)
+	.fetch_all(database)
+	.await?
+	.into_iter()
+	.map(|record| emoji_map.get_from_database(&record.emoji))
+	.collect::<Result<_, _>>()?;
+	Ok(Some((reward_id, choices)))
+}
+
+/// Returns false if the reward was already picked.
+async fn pick_reward(
+	database: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	reward_id: i64,
+	emoji: Emoji,
+) -> Result<bool, BotError> {
+	let mut transaction = database.begin().await?;
+	let picked = query!(
+		"
+		DELETE FROM streak_rewards
+		WHERE id = ?
+		",
+		reward_id
+	)
+	.execute(&mut *transaction)
+	.await?
+	.rows_affected()
+		> 0;
+	if picked {
+		give_emoji(&mut *transaction, guild, user, emoji).await?;
+	}
+	transaction.commit().await?;
+	Ok(picked)
+}
+
+struct StreakStatus {
+	/// Zero if the streak is broken.
+	current: u32,
+	best: u32,
+	/// Whether the streak ends unless the user is seen in this period.
+	at_risk: bool,
+}
+
+async fn get_streak(
+	database: &Pool<Sqlite>,
+	guild_config: &GuildConfig,
+	guild: GuildId,
+	user: UserId,
+) -> Result<StreakStatus, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let [this_period, previous_period, period_before] =
+		recent_period_keys(guild_config.drop_period, guild_config.timezone, Utc::now());
+	let Some(last_seen) = query!(
+		"
+		SELECT period, streak, best_streak
+		FROM last_seen
+		WHERE guild = ? AND user = ?
+		",
+		guild_id,
+		user_id
+	)
+	.fetch_optional(database)
+	.await?
+	else {
+		return Ok(StreakStatus {
+			current: 0,
+			best: 0,
+			at_risk: false,
+		});
+	};
+	let alive = [&this_period, &previous_period, &period_before].contains(&&last_seen.period);
+	Ok(StreakStatus {
+		current: if alive { last_seen.streak as u32 } else { 0 },
+		best: last_seen.best_streak as u32,
+		at_risk: last_seen.period == period_before,
+	})
+}
+
+/// Like "3 weeks".
+fn periods(period: DropPeriod, count: u32) -> String {
+	let s = if count == 1 { "" } else { "s" };
+	match period {
+		DropPeriod::Daily => format!("{count} day{s}"),
+		DropPeriod::Weekly => format!("{count} week{s}"),
+		DropPeriod::Hours(hours) => format!("{count} period{s} of {hours} hours"),
+	}
+}
+
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let user = interaction.user.id;
+	let guild_config = context.guild_config();
+	let period = guild_config.drop_period;
+	let status = get_streak(context.database, guild_config, context.guild, user).await?;
+
+	let mut content = if status.current == 0 {
+		String::from("You don't have a streak going. Send a message in this server to start one.")
+	} else {
+		format!("Your streak is {}.", periods(period, status.current))
+	};
+	if status.best > 0 {
+		content += &format!(" Your best streak is {}.", periods(period, status.best));
+	}
+	if status.at_risk {
+		content +=
+			" You missed the last drop period, so send a message in this one to keep your streak.";
+	}
+	if let Some(milestone) = guild_config
+		.streak_milestones
+		.iter()
+		.filter(|milestone| **milestone > status.current)
+		.min()
+	{
+		content += &format!(
+			" You get a bonus emoji when your streak reaches {}.",
+			periods(period, *milestone)
+		);
+	}
+
+	let Some((reward_id, choices)) =
+		get_reward_choices(context.database, context.emoji_map, context.guild, user).await?
+	else {
+		interaction
+			.ephemeral_reply(context.discord, content)
+			.await?;
+		return Ok(());
+	};
+
+	content += "\nYou reached a streak milestone! Pick your bonus emoji:";
+	let buttons: Vec<_> = CHOICE_IDS
+		.iter()
+		.zip(&choices)
+		.map(|(id, emoji)| Button {
+			id,
+			label: emoji.as_str(),
+			style: ButtonStyle::Secondary,
+		})
+		.collect();
+	let button_press = context
+		.discord
+		.ask_with_buttons(
+			interaction,
+			content,
+			&buttons,
+			guild_config.trade_confirmation_timeout,
+		)
+		.await?;
+	let Some(button_press) = button_press else {
+		context
+			.discord
+			.follow_up(
+				interaction,
+				String::from("You can pick your bonus emoji later with /streak."),
+				true,
+			)
+			.await?;
+		context.discord.delete_reply(interaction).await?;
+		return Ok(());
+	};
+	let emoji = CHOICE_IDS
+		.iter()
+		.position(|id| *id == button_press.custom_id)
+		.and_then(|index| choices.get(index))
+		.ok_or(BotError::InvalidInteraction("unknown button"))?;
+	let content = if pick_reward(context.database, context.guild, user, reward_id, *emoji).await? {
+		format!("You picked {emoji}.")
+	} else {
+		String::from("You already picked this bonus emoji.")
+	};
+	context
+		.discord
+		.update_button_message(&button_press, content)
+		.await?;
+	Ok(())
+}
+
+fn register() -> CreateCommand {
+	CreateCommand::new("streak").description(
+		"Shows your streak of drop periods in a row, and lets you pick streak rewards.",
+	)
+}
+
+pub struct StreakCommand;
+
+#[async_trait]
+impl SlashCommand for StreakCommand {
+	fn name(&self) -> &'static str {
+		"streak"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::json::json;
+	use sqlx::query_scalar;
+
+	use crate::{
+		discord::fake::{FakeDiscord, Sent},
+		testing::{self, GUILD},
+	};
+
+	use super::*;
+
+	#[tokio::test]
+	async fn milestone_choice_is_picked_with_streak() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let discord = FakeDiscord::new();
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		let user = UserId::new(1);
+		let mut guild_config = config.guild(GUILD).clone();
+		guild_config.streak_reward = StreakReward::Choice;
+
+		assert!(reward_milestone(&database, &guild_config, GUILD, user, 3)
+			.await
+			.unwrap()
+			.is_none());
+		assert!(matches!(
+			reward_milestone(&database, &guild_config, GUILD, user, 4)
+				.await
+				.unwrap(),
+			Some(MilestoneReward::Choice)
+		));
+		let (_, choices) = get_reward_choices(&database, &emoji_map, GUILD, user)
+			.await
+			.unwrap()
+			.unwrap();
+
+		discord.press_button(Some("1"));
+		execute(
+			context,
+			&testing::command_interaction(user, "streak", json!([])),
+		)
+		.await
+		.unwrap();
+
+		let sent = discord.take_sent();
+		assert!(
+			matches!(&sent[..], [Sent::Buttons { buttons, .. }, Sent::ButtonUpdate { .. }] if buttons == &CHOICE_IDS),
+			"{sent:?}"
+		);
+		let inventory: Vec<String> = query_scalar("SELECT emoji FROM emoji_inventory")
+			.fetch_all(&database)
+			.await
+			.unwrap();
+		assert_eq!(inventory, [choices[1].as_str()]);
+		assert!(get_reward_choices(&database, &emoji_map, GUILD, user)
+			.await
+			.unwrap()
+			.is_none());
+	}
+}
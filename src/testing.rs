@@ -0,0 +1,99 @@
+//! Shared fixtures for tests that run commands from start to finish.
+
+use serenity::{
+	all::{CommandInteraction, GuildId, UserId},
+	json::{self, json, Value},
+};
+use sqlx::{query_scalar, sqlite::SqlitePoolOptions, Pool, Sqlite};
+
+use crate::{commands::SlashCommand, config::Config, context::Context, migrations};
+
+pub const GUILD: GuildId = GuildId::new(10);
+/// The role that allows trading in the test guild.
+pub const TRADER: u64 = 5;
+
+/// An empty in-memory database with the full schema.
+pub async fn database() -> Pool<Sqlite> {
+	// Every connection to an in-memory database gets its own database, so there can be only one.
+	let database = SqlitePoolOptions::new()
+		.max_connections(1)
+		.connect("sqlite::memory:")
+		.await
+		.unwrap();
+	migrations::migrate(&database, None).await.unwrap();
+	database
+}
+
+pub fn config() -> Config {
+	Config::parse(
+		&format!(
+			r#"
+			discord_token = "test"
+
+			[guilds.{GUILD}]
+			trading_roles = [{TRADER}]
+			"#
+		),
+		None,
+	)
+	.unwrap()
+}
+
+/// A slash command as Discord would send it, used by `user` in the test guild. `options` is the JSON array of options.
+pub fn command_interaction(user: UserId, name: &str, options: Value) -> CommandInteraction {
+	json::from_value(json!({
+		"id": "1",
+		"application_id": "2",
+		"type": 2,
+		"data": {
+			"id": "3",
+			"name": name,
+			"type": 1,
+			"options": options,
+		},
+		"guild_id": GUILD.to_string(),
+		"channel_id": "4",
+		"user": {
+			"id": user.to_string(),
+			"username": "user",
+			"discriminator": "0",
+			"global_name": null,
+			"avatar": null,
+		},
+		"token": "token",
+		"version": 1,
+		"app_permissions": null,
+		"locale": "en-US",
+		"entitlements": [],
+	}))
+	.unwrap()
+}
+
+/// Runs a subcommand of the command as `user` in the test guild. `options` is the JSON array of the subcommand's options. Returns what it failed with, or an empty string if it didn't.
+pub async fn run_subcommand(
+	context: Context<'_>,
+	command: &dyn SlashCommand,
+	user: UserId,
+	subcommand: &str,
+	options: Value,
+) -> String {
+	let interaction = command_interaction(
+		user,
+		command.name(),
+		json!([{ "name": subcommand, "type": 1, "options": options }]),
+	);
+	match command.execute(context, &interaction).await {
+		Ok(()) => String::new(),
+		Err(error) => error.to_string(),
+	}
+}
+
+/// The emojis the user has in the test guild.
+pub async fn inventory(database: &Pool<Sqlite>, user: UserId) -> Vec<String> {
+	query_scalar("SELECT emoji FROM emoji_inventory WHERE guild = ? AND user = ?")
+		.bind(GUILD.get() as i64)
+		.bind(user.get() as i64)
+		.fetch_all(database)
+		.await
+		.unwrap()
+}
@@ -0,0 +1,95 @@
+use chrono::NaiveDateTime;
+use serenity::model::prelude::{GuildId, UserId};
+
+use crate::{drop_table::rarity_index, emoji_list::Rarity, emojis_with_counts::EmojisWithCounts};
+
+use super::trade_offer::TradeOffer;
+
+/// Emojis a user put up for auction in a guild until a set time, with the bids other users made for them.
+///
+/// Both the lot and the bids are held in escrow while the auction runs.
+pub(super) struct Auction {
+	id: i64,
+	guild: GuildId,
+	seller: UserId,
+	lot: EmojisWithCounts,
+	ends: NaiveDateTime,
+	/// Oldest first.
+	bids: Vec<Bid>,
+}
+
+pub(super) struct Bid {
+	pub user: UserId,
+	pub emojis: EmojisWithCounts,
+}
+
+impl Bid {
+	/// How many emojis of each rarity the bid has, rarest first. Bids rank by their rarest emojis, so one more legendary emoji beats any number of rare ones, and so on.
+	fn rank(&self) -> [u32; Rarity::ALL.len()] {
+		let mut counts = [0; Rarity::ALL.len()];
+		for (emoji, count) in self.emojis.iter() {
+			// `Rarity::ALL` goes from the most common, so it's counted from the end.
+			counts[Rarity::ALL.len() - 1 - rarity_index(emoji.rarity())] += count;
+		}
+		counts
+	}
+}
+
+impl Auction {
+	pub fn from_database(
+		id: i64,
+		guild: GuildId,
+		seller: UserId,
+		lot: EmojisWithCounts,
+		ends: NaiveDateTime,
+		bids: Vec<Bid>,
+	) -> Self {
+		Self {
+			id,
+			guild,
+			seller,
+			lot,
+			ends,
+			bids,
+		}
+	}
+	pub fn id(&self) -> i64 {
+		self.id
+	}
+	pub fn guild(&self) -> GuildId {
+		self.guild
+	}
+	pub fn seller(&self) -> UserId {
+		self.seller
+	}
+	pub fn lot(&self) -> &EmojisWithCounts {
+		&self.lot
+	}
+	pub fn ends(&self) -> NaiveDateTime {
+		self.ends
+	}
+	pub fn bid_by(&self, user: UserId) -> Option<&Bid> {
+		self.bids.iter().find(|bid| bid.user == user)
+	}
+	/// The bids from best to worst. Of bids that rank the same, the older one goes first.
+	pub fn ranked_bids(&self) -> Vec<&Bid> {
+		let mut bids = self.bids.iter().collect::<Vec<_>>();
+		// The sort is stable, so older bids stay ahead of equal newer ones.
+		bids.sort_by_key(|bid| std::cmp::Reverse(bid.rank()));
+		bids
+	}
+	/// The bid that wins if the auction closes without the seller picking one.
+	pub fn best_bid(&self) -> Option<&Bid> {
+		self.ranked_bids().first().copied()
+	}
+	/// The trade that selling the lot for the bid comes down to.
+	pub fn trade_with(&self, bid: &Bid) -> Result<TradeOffer, String> {
+		TradeOffer::new(
+			self.guild,
+			self.seller,
+			bid.user,
+			self.lot.clone(),
+			bid.emojis.clone(),
+		)
+	}
+}
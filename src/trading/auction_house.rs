@@ -0,0 +1,684 @@
+//! Timed auctions, where a user puts emojis up and others bid emojis for them: `/auction` to start, bid on, view and accept them, and the background task that closes them.
+//!
+//! The lot and every bid are locked in escrow until the auction closes, so they can't be traded or recycled in the meantime.
+
+use std::{fmt::Write, sync::Arc, time::Duration};
+
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
+use serenity::{
+	all::{
+		ButtonStyle, CommandDataOption, CommandDataOptionValue, CommandInteraction,
+		CommandOptionType, ShardMessenger, UserId,
+	},
+	async_trait,
+	builder::{CreateCommand, CreateCommandOption},
+	client::Cache,
+	http::Http,
+};
+use sqlx::{Pool, Sqlite};
+
+use crate::{
+	autocomplete,
+	commands::SlashCommand,
+	context::Context,
+	discord::{Button, Choice, Discord, SerenityDiscord},
+	emoji::EmojiMap,
+	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
+	util::{describe_duration, find_option, get_and_parse_emoji_option, ReplyShortcuts},
+};
+
+use super::{
+	auction::Auction,
+	queries::{
+		add_auction, close_auction, get_auction, get_auctions, get_ended_auctions, place_bid,
+		remove_invalidated_trade_offers, withdraw_bid,
+	},
+	trading_roles::has_trading_role,
+};
+
+/// How often ended auctions are looked for. Auctions can stay open for up to this long after they end.
+const CLOSING_INTERVAL: Duration = Duration::from_secs(60);
+
+fn auction_option(options: &[CommandDataOption]) -> Result<i64, BotError> {
+	find_option(options, "auction")
+		.and_then(|option| option.value.as_i64())
+		.ok_or(BotError::InvalidInteraction("auction option missing"))
+}
+
+fn describe_closing(ends: NaiveDateTime) -> String {
+	let left = ends.and_utc() - Utc::now();
+	if left > TimeDelta::zero() {
+		format!("closes in {}", describe_duration(left))
+	} else {
+		String::from("closes any moment now")
+	}
+}
+
+/// An auction that is still taking bids.
+async fn get_running_auction(context: Context<'_>, id: i64) -> Result<Auction, BotError> {
+	match get_auction(context.database, context.emoji_map, context.guild, id).await? {
+		Some(auction) if auction.ends() > Utc::now().naive_utc() => Ok(auction),
+		_ => Err(format!("There is no running auction #{id}.").into()),
+	}
+}
+
+async fn try_start(
+	context: Context<'_>,
+	options: &[CommandDataOption],
+	user: UserId,
+) -> Result<String, BotError> {
+	if !has_trading_role(context, user).await {
+		return Err("You do not have a role that allows trading.".into());
+	}
+	let lot = get_and_parse_emoji_option(context.emoji_map, find_option(options, "lot"))?;
+	if lot.is_empty() {
+		return Err("Lot is empty.".into());
+	}
+	let lot = EmojisWithCounts::from_flat(&lot);
+	let length = TimeDelta::from_std(context.guild_config().auction_length).unwrap_or_default();
+	let ends = Utc::now().naive_utc() + length;
+	let Some(id) = add_auction(context.database, context.guild, user, &lot, ends).await? else {
+		return Err("You don't have those emojis, or some of them are in escrow.".into());
+	};
+	remove_invalidated_trade_offers(context.database, context.guild, &[user]).await?;
+	let name = context.get_user_name(user).await;
+	Ok(format!(
+		"{name} put up {lot} for auction #{id}, which {}. Bid on it with `/auction bid`.",
+		describe_closing(ends)
+	))
+}
+
+async fn try_bid(
+	context: Context<'_>,
+	options: &[CommandDataOption],
+	user: UserId,
+) -> Result<String, BotError> {
+	let id = auction_option(options)?;
+	let auction = get_running_auction(context, id).await?;
+	if auction.seller() == user {
+		return Err("You can't bid on your own auction.".into());
+	}
+	if !has_trading_role(context, user).await {
+		return Err("You do not have a role that allows trading.".into());
+	}
+	let emojis = get_and_parse_emoji_option(context.emoji_map, find_option(options, "emojis"))?;
+	if emojis.is_empty() {
+		return Err("Bid is empty.".into());
+	}
+	let emojis = EmojisWithCounts::from_flat(&emojis);
+	if emojis
+		.iter()
+		.any(|(emoji, _)| auction.lot().iter().any(|(other, _)| other == emoji))
+	{
+		return Err("You can't bid emojis that are up for auction.".into());
+	}
+	let replaced = auction.bid_by(user).is_some();
+	if !place_bid(context.database, &auction, user, &emojis).await? {
+		return Err("You don't have those emojis, or some of them are in escrow.".into());
+	}
+	remove_invalidated_trade_offers(context.database, context.guild, &[user]).await?;
+	let name = context.get_user_name(user).await;
+	let verb = if replaced { "changed their bid" } else { "bid" };
+	Ok(format!(
+		"{name} {verb} on auction #{id}: {emojis} for {}.",
+		auction.lot()
+	))
+}
+
+async fn try_withdraw(
+	context: Context<'_>,
+	options: &[CommandDataOption],
+	user: UserId,
+) -> Result<String, BotError> {
+	let id = auction_option(options)?;
+	get_running_auction(context, id).await?;
+	if !withdraw_bid(context.database, id, user).await? {
+		return Err(format!("You have no bid on auction #{id}.").into());
+	}
+	Ok(format!("You withdrew your bid on auction #{id}."))
+}
+
+async fn view(context: Context<'_>) -> Result<String, BotError> {
+	let auctions = get_auctions(
+		context.database,
+		context.emoji_map,
+		context.guild,
+		Utc::now().naive_utc(),
+	)
+	.await?;
+	if auctions.is_empty() {
+		return Ok(String::from(
+			"There are no running auctions. Start one with `/auction start`.",
+		));
+	}
+	let mut content = String::new();
+	for auction in auctions {
+		let name = context.get_user_name(auction.seller()).await;
+		writeln!(
+			content,
+			"#{}: {name} sells {} ({})",
+			auction.id(),
+			auction.lot(),
+			describe_closing(auction.ends())
+		)
+		.unwrap();
+		for (place, bid) in auction.ranked_bids().into_iter().enumerate() {
+			let bidder_name = context.get_user_name(bid.user).await;
+			writeln!(
+				content,
+				"  {}. {bidder_name} bids {}",
+				place + 1,
+				bid.emojis
+			)
+			.unwrap();
+		}
+	}
+	Ok(content)
+}
+
+/// Lets the seller close the auction early, selling the lot for the bid they pick.
+async fn try_accept(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+	options: &[CommandDataOption],
+	user: UserId,
+) -> Result<(), BotError> {
+	let id = auction_option(options)?;
+	let auction = get_running_auction(context, id).await?;
+	if auction.seller() != user {
+		return Err(format!("Auction #{id} isn't yours.").into());
+	}
+	let bidder = find_option(options, "bidder")
+		.and_then(|option| option.value.as_user_id())
+		.ok_or(BotError::InvalidInteraction("bidder option missing"))?;
+	let bidder_name = context.get_user_name(bidder).await;
+	let Some(bid) = auction.bid_by(bidder) else {
+		return Err(format!("{bidder_name} has no bid on auction #{id}.").into());
+	};
+
+	let content = format!("You are about to close auction #{id} and sell to {bidder_name}.\nYou will **lose**: {}\nYou will **gain**: {}\nDo you want to proceed?", auction.lot(), bid.emojis);
+	let buttons = [
+		Button {
+			id: "yes",
+			label: "Yes",
+			style: ButtonStyle::Primary,
+		},
+		Button {
+			id: "no",
+			label: "No",
+			style: ButtonStyle::Secondary,
+		},
+	];
+	let button_press = context
+		.discord
+		.ask_with_buttons(
+			interaction,
+			content,
+			&buttons,
+			context.guild_config().trade_confirmation_timeout,
+		)
+		.await?;
+
+	let Some(button_press) = button_press else {
+		context
+			.discord
+			.follow_up(
+				interaction,
+				String::from("The trade confirmation has timed out."),
+				true,
+			)
+			.await?;
+		context.discord.delete_reply(interaction).await?;
+		return Ok(());
+	};
+	match button_press.custom_id.as_str() {
+		"yes" => {
+			if close_auction(context.database, context.emoji_map, &auction, Some(bid)).await? {
+				remove_invalidated_trade_offers(context.database, context.guild, &[user, bidder])
+					.await?;
+				let seller_name = context.get_user_name(user).await;
+				let content = format!(
+					"{seller_name} closed auction #{id}, selling {} to {bidder_name} for {}.",
+					auction.lot(),
+					bid.emojis
+				);
+				context
+					.discord
+					.reply_to_button(&button_press, content, false)
+					.await?;
+			} else {
+				let content = format!(
+					"Auction #{id} has already closed, or {bidder_name} changed or withdrew their bid."
+				);
+				context
+					.discord
+					.reply_to_button(&button_press, content, true)
+					.await?;
+			}
+			context.discord.delete_reply(interaction).await?;
+		}
+		"no" => {
+			context
+				.discord
+				.update_button_message(&button_press, String::from("You have cancelled the sale."))
+				.await?;
+		}
+		_ => return Err(BotError::InvalidInteraction("unknown button")),
+	}
+	Ok(())
+}
+
+/// There is no message to answer when an auction closes by itself, so everyone in it hears by direct message.
+async fn tell(discord: &dyn Discord, user: UserId, content: String) {
+	if let Err(error) = discord.direct_message(user, content).await {
+		eprintln!("Could not send a direct message to user {user}: {error}");
+	}
+}
+
+/// Sells every auction that has ended for its best bid, and gives everything else in escrow back.
+async fn close_ended_auctions(
+	database: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	discord: &dyn Discord,
+	now: DateTime<Utc>,
+) -> Result<(), BotError> {
+	for auction in get_ended_auctions(database, emoji_map, now.naive_utc()).await? {
+		let winning_bid = auction.best_bid();
+		if !close_auction(database, emoji_map, &auction, winning_bid).await? {
+			continue;
+		}
+		let (id, guild, seller) = (auction.id(), auction.guild(), auction.seller());
+		let seller_name = discord.user_name(guild, seller).await;
+		let Some(winning_bid) = winning_bid else {
+			let content = format!(
+				"Your auction #{id} closed without bids, so you can trade {} again.",
+				auction.lot()
+			);
+			tell(discord, seller, content).await;
+			continue;
+		};
+		remove_invalidated_trade_offers(database, guild, &[seller, winning_bid.user]).await?;
+		let winner_name = discord.user_name(guild, winning_bid.user).await;
+		let content = format!(
+			"Your auction #{id} closed, and {winner_name} got {} for {}.",
+			auction.lot(),
+			winning_bid.emojis
+		);
+		tell(discord, seller, content).await;
+		for bid in auction.ranked_bids() {
+			let content = if bid.user == winning_bid.user {
+				format!(
+					"You won auction #{id} from {seller_name}, and got {} for {}.",
+					auction.lot(),
+					bid.emojis
+				)
+			} else {
+				format!(
+					"Auction #{id} from {seller_name} closed, and your bid of {} can be traded again.",
+					bid.emojis
+				)
+			};
+			tell(discord, bid.user, content).await;
+		}
+	}
+	Ok(())
+}
+
+/// Closes ended auctions every so often, forever.
+pub async fn run_auction_closing(
+	database: Pool<Sqlite>,
+	emoji_map: Arc<EmojiMap>,
+	http: Arc<Http>,
+	cache: Arc<Cache>,
+	shard: ShardMessenger,
+) {
+	let discord = SerenityDiscord {
+		http: &http,
+		cache: &cache,
+		shard: &shard,
+	};
+	let mut interval = tokio::time::interval(CLOSING_INTERVAL);
+	loop {
+		interval.tick().await;
+		if let Err(error) = close_ended_auctions(&database, &emoji_map, &discord, Utc::now()).await
+		{
+			eprintln!("Could not close ended auctions: {error}");
+		}
+	}
+}
+
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let subcommand = interaction
+		.data
+		.options
+		.first()
+		.ok_or(BotError::InvalidInteraction("subcommand missing"))?;
+	let CommandDataOptionValue::SubCommand(options) = &subcommand.value else {
+		return Err(BotError::InvalidInteraction("expected a subcommand"));
+	};
+	let user = interaction.user.id;
+
+	let mut ephemeral = false;
+	let message = match subcommand.name.as_str() {
+		"start" => try_start(context, options, user).await?,
+		"bid" => try_bid(context, options, user).await?,
+		"withdraw" => {
+			ephemeral = true;
+			try_withdraw(context, options, user).await?
+		}
+		"view" => {
+			ephemeral = true;
+			view(context).await?
+		}
+		"accept" => return try_accept(context, interaction, options, user).await,
+		_ => return Err(BotError::InvalidInteraction("unknown subcommand")),
+	};
+	interaction
+		.reply(context.discord, message, ephemeral)
+		.await?;
+	Ok(())
+}
+
+/// Lots and bids are finished from the caller's emojis.
+async fn autocomplete(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+) -> Result<Vec<Choice>, BotError> {
+	let option = interaction
+		.data
+		.autocomplete()
+		.ok_or(BotError::InvalidInteraction("no option to autocomplete"))?;
+	match option.name {
+		"lot" | "emojis" => {
+			autocomplete::owned_emojis(context, interaction.user.id, option.value).await
+		}
+		_ => Ok(Vec::new()),
+	}
+}
+
+fn auction_number() -> CreateCommandOption {
+	CreateCommandOption::new(
+		CommandOptionType::Integer,
+		"auction",
+		"The number of the auction.",
+	)
+	.required(true)
+}
+
+fn register() -> CreateCommand {
+	CreateCommand::new("auction")
+		.description("Put emojis up for auction, or bid on the auctions of others.")
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"start",
+				"Put emojis up for auction. They are held in escrow until it closes.",
+			)
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::String,
+					"lot",
+					"The emojis you are putting up. Repeat emojis for multiples.",
+				)
+				.required(true)
+				.set_autocomplete(true),
+			),
+		)
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"bid",
+				"Bid on an auction, replacing your last bid on it. Your bid is held in escrow until it closes.",
+			)
+			.add_sub_option(auction_number())
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::String,
+					"emojis",
+					"The emojis you are bidding. Repeat emojis for multiples.",
+				)
+				.required(true)
+				.set_autocomplete(true),
+			),
+		)
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"withdraw",
+				"Take back your bid on an auction.",
+			)
+			.add_sub_option(auction_number()),
+		)
+		.add_option(CreateCommandOption::new(
+			CommandOptionType::SubCommand,
+			"view",
+			"Show the running auctions, with their bids from best to worst.",
+		))
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"accept",
+				"Close your auction early, selling to the bidder you pick.",
+			)
+			.add_sub_option(auction_number())
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::User,
+					"bidder",
+					"Whose bid to accept.",
+				)
+				.required(true),
+			),
+		)
+}
+
+pub struct AuctionCommand;
+
+#[async_trait]
+impl SlashCommand for AuctionCommand {
+	fn name(&self) -> &'static str {
+		"auction"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+	async fn autocomplete(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<Vec<Choice>, BotError> {
+		autocomplete(context, interaction).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::{all::RoleId, json::json};
+	use sqlx::query_scalar;
+
+	use crate::{
+		discord::fake::{FakeDiscord, Sent},
+		queries::give_emoji,
+		testing::{self, run_subcommand, GUILD, TRADER},
+		trading::trade::TradeCommand,
+	};
+
+	use super::*;
+
+	#[tokio::test]
+	async fn best_bid_wins_and_the_rest_leave_escrow() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let trader = [RoleId::new(TRADER)];
+		let (alice, bob, carol) = (UserId::new(1), UserId::new(2), UserId::new(3));
+		let discord = FakeDiscord::new()
+			.with_member(alice, "Alice", &trader)
+			.with_member(bob, "Bob", &trader)
+			.with_member(carol, "Carol", &trader);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		for (user, emoji) in [
+			(alice, "🦊"),
+			(bob, "🐶"),
+			(bob, "🐶"),
+			(bob, "🐱"),
+			(carol, "🦄"),
+		] {
+			give_emoji(&database, GUILD, user, emoji_map.get(emoji).unwrap())
+				.await
+				.unwrap();
+		}
+		let bid = |emojis: &str| {
+			json!([
+				{ "name": "auction", "type": 4, "value": 1 },
+				{ "name": "emojis", "type": 3, "value": emojis },
+			])
+		};
+
+		let lot = json!([{ "name": "lot", "type": 3, "value": "🦊" }]);
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, alice, "start", lot.clone()).await,
+			""
+		);
+		assert!(
+			run_subcommand(context, &AuctionCommand, alice, "start", lot)
+				.await
+				.contains("in escrow")
+		);
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, bob, "bid", bid("🐶🐶")).await,
+			""
+		);
+		let offer = json!([
+			{ "name": "user", "type": 6, "value": carol.to_string() },
+			{ "name": "offer", "type": 3, "value": "🐶" },
+			{ "name": "request", "type": 3, "value": "🦄" },
+		]);
+		assert!(run_subcommand(context, &TradeCommand, bob, "offer", offer)
+			.await
+			.contains("locked in other trade offers or escrow"));
+		// Replacing a bid releases the old one first.
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, bob, "bid", bid("🐶🐶🐱")).await,
+			""
+		);
+		// One legendary emoji beats any number of common ones.
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, carol, "bid", bid("🦄")).await,
+			""
+		);
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, bob, "view", json!([])).await,
+			""
+		);
+		assert!(matches!(
+			discord.take_sent().last(),
+			Some(Sent::Reply { content, .. })
+				if content.contains("1. Carol bids 🦄\n  2. Bob bids 🐶x2🐱")
+		));
+
+		let now = Utc::now();
+		close_ended_auctions(&database, &emoji_map, &discord, now)
+			.await
+			.unwrap();
+		assert!(discord.take_sent().is_empty());
+		close_ended_auctions(&database, &emoji_map, &discord, now + TimeDelta::days(2))
+			.await
+			.unwrap();
+		let sent = discord.take_sent();
+		assert!(
+			matches!(
+				&sent[..],
+				[
+					Sent::DirectMessage { user: seller, content },
+					Sent::DirectMessage { user: winner, .. },
+					Sent::DirectMessage { user: loser, .. },
+				] if *seller == alice && content.contains("Carol got 🦊 for 🦄") && *winner == carol && *loser == bob
+			),
+			"{sent:?}"
+		);
+
+		assert_eq!(testing::inventory(&database, alice).await, ["🦄"]);
+		assert_eq!(testing::inventory(&database, carol).await, ["🦊"]);
+		let locked: i64 =
+			query_scalar("SELECT COUNT(*) FROM emoji_inventory WHERE lock IS NOT NULL")
+				.fetch_one(&database)
+				.await
+				.unwrap();
+		assert_eq!(locked, 0);
+	}
+
+	#[tokio::test]
+	async fn bid_withdrawn_during_confirmation_is_not_sold_for() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let trader = [RoleId::new(TRADER)];
+		let (alice, bob) = (UserId::new(1), UserId::new(2));
+		let discord = FakeDiscord::new()
+			.with_member(alice, "Alice", &trader)
+			.with_member(bob, "Bob", &trader);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		for (user, emoji) in [(alice, "🦊"), (bob, "🐶"), (bob, "🐶")] {
+			give_emoji(&database, GUILD, user, emoji_map.get(emoji).unwrap())
+				.await
+				.unwrap();
+		}
+		let bid = |emojis: &str| {
+			json!([
+				{ "name": "auction", "type": 4, "value": 1 },
+				{ "name": "emojis", "type": 3, "value": emojis },
+			])
+		};
+		let lot = json!([{ "name": "lot", "type": 3, "value": "🦊" }]);
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, alice, "start", lot).await,
+			""
+		);
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, bob, "bid", bid("🐶🐶")).await,
+			""
+		);
+
+		// What the seller is asked to confirm, loaded before the bid changes.
+		let confirming = get_auction(&database, &emoji_map, GUILD, 1)
+			.await
+			.unwrap()
+			.unwrap();
+		let stale_bid = confirming.bid_by(bob);
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, bob, "bid", bid("🐶")).await,
+			""
+		);
+		assert!(
+			!close_auction(&database, &emoji_map, &confirming, stale_bid)
+				.await
+				.unwrap()
+		);
+		let withdraw = json!([{ "name": "auction", "type": 4, "value": 1 }]);
+		assert_eq!(
+			run_subcommand(context, &AuctionCommand, bob, "withdraw", withdraw).await,
+			""
+		);
+		assert!(
+			!close_auction(&database, &emoji_map, &confirming, stale_bid)
+				.await
+				.unwrap()
+		);
+
+		assert_eq!(testing::inventory(&database, alice).await, ["🦊"]);
+		assert_eq!(testing::inventory(&database, bob).await, ["🐶", "🐶"]);
+		assert!(get_auction(&database, &emoji_map, GUILD, 1)
+			.await
+			.unwrap()
+			.is_some());
+	}
+}
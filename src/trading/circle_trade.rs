@@ -0,0 +1,69 @@
+use itertools::Itertools;
+use serenity::model::prelude::{GuildId, UserId};
+
+use crate::emojis_with_counts::EmojisWithCounts;
+
+/// A trade around a circle of three or more users in a guild, where each user gives emojis to the next one, and the last gives to the first.
+///
+/// The first user is the one who proposed it.
+pub(super) struct CircleTrade {
+	guild: GuildId,
+	gifts: Vec<(UserId, EmojisWithCounts)>,
+}
+
+impl CircleTrade {
+	/// Fails unless there are at least three different users who all give something, and nobody gives an emoji they get.
+	pub fn new(guild: GuildId, gifts: Vec<(UserId, EmojisWithCounts)>) -> Result<Self, String> {
+		if gifts.len() < 3 {
+			return Err(String::from(
+				"A trade around a circle takes at least three users.",
+			));
+		}
+		if !gifts.iter().map(|(user, _)| user).all_unique() {
+			return Err(String::from("Everyone can only be in the circle once."));
+		}
+		if gifts.iter().any(|(_, emojis)| emojis.is_empty()) {
+			return Err(String::from(
+				"Everyone in the circle has to give something.",
+			));
+		}
+		let trade = Self { guild, gifts };
+		for (user, _, given) in trade.gifts() {
+			let gotten = trade.gotten_by(user);
+			if given
+				.iter()
+				.any(|(emoji, _)| gotten.iter().any(|(other, _)| other == emoji))
+			{
+				return Err(String::from(
+					"Someone would give an emoji that they also get in this trade.",
+				));
+			}
+		}
+		Ok(trade)
+	}
+	pub fn guild(&self) -> GuildId {
+		self.guild
+	}
+	pub fn proposer(&self) -> UserId {
+		self.gifts[0].0
+	}
+	pub fn participants(&self) -> impl Iterator<Item = UserId> + '_ {
+		self.gifts.iter().map(|(user, _)| *user)
+	}
+	/// Every gift around the circle, as who gives it, to whom, and what it is.
+	pub fn gifts(&self) -> impl Iterator<Item = (UserId, UserId, &EmojisWithCounts)> {
+		self.gifts
+			.iter()
+			.zip(self.gifts.iter().cycle().skip(1))
+			.map(|((user, emojis), (next_user, _))| (*user, *next_user, emojis))
+	}
+	/// What the user gets from whoever comes before them in the circle. Panics if the user isn't in it.
+	pub fn gotten_by(&self, user: UserId) -> &EmojisWithCounts {
+		let position = self
+			.gifts
+			.iter()
+			.position(|(participant, _)| *participant == user)
+			.expect("user is in the circle");
+		&self.gifts[(position + self.gifts.len() - 1) % self.gifts.len()].1
+	}
+}
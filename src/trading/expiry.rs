@@ -0,0 +1,135 @@
+//! Removes trade offers that outlived their guild's offer lifetime, and tells the users who made them.
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serenity::{all::ShardMessenger, client::Cache, http::Http};
+use sqlx::{Pool, Sqlite};
+
+use crate::{
+	config::Config,
+	discord::{Discord, SerenityDiscord},
+	error::BotError,
+	user_settings::notifications::{notify, Notice, Notifications},
+};
+
+use super::queries::{get_guilds_with_trade_offers, remove_expired_trade_offers};
+
+/// How often expired offers are looked for. Offers can outlive their lifetime by up to this long.
+const PURGE_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+async fn purge_expired_offers(
+	database: &Pool<Sqlite>,
+	config: &Config,
+	discord: &dyn Discord,
+	now: DateTime<Utc>,
+) -> Result<(), BotError> {
+	for guild in get_guilds_with_trade_offers(database).await? {
+		let lifetime = TimeDelta::from_std(config.guild(guild).offer_lifetime).unwrap_or_default();
+		let cutoff = (now - lifetime).naive_utc();
+		for (user, target_user) in remove_expired_trade_offers(database, guild, cutoff).await? {
+			let notice = Notice::ExpiredOffer(target_user);
+			if notify(database, discord, guild, user, &[notice]).await? != Notifications::Reaction {
+				continue;
+			}
+			// There is no message to react to, so users who like reactions get a direct message instead.
+			let name = discord.user_name(guild, target_user).await;
+			let content = format!("Your trade offer to {name} expired.");
+			if let Err(error) = discord.direct_message(user, content).await {
+				eprintln!("Could not send a direct message to user {user}: {error}");
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Purges expired offers every so often, forever.
+pub async fn run_offer_expiry(
+	database: Pool<Sqlite>,
+	config: Arc<Config>,
+	http: Arc<Http>,
+	cache: Arc<Cache>,
+	shard: ShardMessenger,
+) {
+	let discord = SerenityDiscord {
+		http: &http,
+		cache: &cache,
+		shard: &shard,
+	};
+	let mut interval = tokio::time::interval(PURGE_INTERVAL);
+	loop {
+		interval.tick().await;
+		if let Err(error) = purge_expired_offers(&database, &config, &discord, Utc::now()).await {
+			eprintln!("Could not purge expired trade offers: {error}");
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::{all::UserId, json::json};
+	use sqlx::query_scalar;
+
+	use crate::{
+		commands::SlashCommand,
+		context::Context,
+		discord::fake::{FakeDiscord, Sent},
+		emoji::EmojiMap,
+		queries::give_emoji,
+		testing::{self, GUILD},
+		trading::{trade::TradeCommand, view_offers},
+	};
+
+	use super::*;
+
+	#[tokio::test]
+	async fn expired_offers_are_purged_and_their_offerers_told() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let (alice, bob) = (UserId::new(1), UserId::new(2));
+		let discord = FakeDiscord::new().with_member(bob, "Bob", &[]);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		give_emoji(&database, GUILD, alice, emoji_map.get("🙂").unwrap())
+			.await
+			.unwrap();
+		let offer = testing::command_interaction(
+			alice,
+			"trade",
+			json!([{
+				"name": "offer",
+				"type": 1,
+				"options": [
+					{ "name": "user", "type": 6, "value": bob.to_string() },
+					{ "name": "offer", "type": 3, "value": "🙂" },
+					{ "name": "request", "type": 3, "value": "🙃" },
+				],
+			}]),
+		);
+		TradeCommand.execute(context, &offer).await.unwrap();
+		discord.take_sent();
+		let view = view_offers(context, alice).await.unwrap();
+		assert!(view.contains("(expires in 7 days)"), "{view}");
+
+		let now = Utc::now();
+		purge_expired_offers(&database, &config, &discord, now + TimeDelta::days(6))
+			.await
+			.unwrap();
+		assert!(discord.take_sent().is_empty());
+		purge_expired_offers(&database, &config, &discord, now + TimeDelta::days(8))
+			.await
+			.unwrap();
+		assert_eq!(
+			discord.take_sent(),
+			[Sent::DirectMessage {
+				user: alice,
+				content: String::from("Your trade offer to Bob expired."),
+			}]
+		);
+		let offers: i64 = query_scalar("SELECT COUNT(*) FROM trade_offers")
+			.fetch_one(&database)
+			.await
+			.unwrap();
+		assert_eq!(offers, 0);
+	}
+}
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use serenity::model::prelude::{GuildId, UserId};
+
+use crate::{emoji::Emoji, emojis_with_counts::EmojisWithCounts};
+
+use super::trade_offer::TradeOffer;
+
+/// An open trade offer in a guild's market, which any user can fill, with an offered and a requested list of emojis.
+///
+/// If any one of the requested emojis will do, the filler picks which one they give, and every count in the request is one.
+#[derive(PartialEq, Eq)]
+pub(super) struct Listing {
+	guild: GuildId,
+	user: UserId,
+	offer: EmojisWithCounts,
+	request: EmojisWithCounts,
+	any_one: bool,
+}
+
+impl Listing {
+	/// Fails if the same emoji exists on both sides of the listing, but performs no other checks.
+	pub fn new(
+		guild: GuildId,
+		user: UserId,
+		offer: EmojisWithCounts,
+		request: EmojisWithCounts,
+		any_one: bool,
+	) -> Result<Self, String> {
+		if offer.iter().any(|(emoji, _)| request.count_of(*emoji) > 0) {
+			return Err(String::from(
+				"You put an emoji on both sides of the listing.",
+			));
+		}
+		let request = if any_one {
+			EmojisWithCounts::from_iter(request.into_iter().map(|(emoji, _)| (emoji, 1)))
+		} else {
+			request
+		};
+		Ok(Self {
+			guild,
+			user,
+			offer,
+			request,
+			any_one,
+		})
+	}
+	/// Unflattens the emojis from the way the database has them, like `TradeOffer::from_database`.
+	pub fn from_database(
+		guild: GuildId,
+		user: UserId,
+		any_one: bool,
+		contents: Vec<(Emoji, i64)>,
+	) -> Self {
+		let (request, offer): (Vec<_>, Vec<_>) =
+			contents.into_iter().partition(|(_, count)| *count > 0);
+		Self {
+			guild,
+			user,
+			offer: EmojisWithCounts::new(
+				offer
+					.into_iter()
+					.map(|(emoji, count)| (emoji, (-count) as u32))
+					.collect(),
+			),
+			request: EmojisWithCounts::new(
+				request
+					.into_iter()
+					.map(|(emoji, count)| (emoji, count as u32))
+					.collect(),
+			),
+			any_one,
+		}
+	}
+	pub fn guild(&self) -> GuildId {
+		self.guild
+	}
+	pub fn user(&self) -> UserId {
+		self.user
+	}
+	pub fn offer(&self) -> &EmojisWithCounts {
+		&self.offer
+	}
+	pub fn any_one(&self) -> bool {
+		self.any_one
+	}
+	/// The same format as `TradeOffer::to_database_format`.
+	pub fn to_database_format(&self) -> Vec<(Emoji, i64)> {
+		self.request
+			.iter()
+			.map(|(emoji, count)| (*emoji, *count as i64))
+			.chain(
+				self.offer
+					.iter()
+					.map(|(emoji, count)| (*emoji, -(*count as i64))),
+			)
+			.collect()
+	}
+	/// The trade that filling the listing comes down to. If any one of the requested emojis will do, the chosen one is all the filler gives.
+	pub fn trade_with(&self, filler: UserId, chosen: Option<Emoji>) -> Result<TradeOffer, String> {
+		let request = match chosen {
+			Some(chosen) if self.any_one => {
+				if !self.request.iter().any(|(emoji, _)| *emoji == chosen) {
+					return Err(format!("The listing asks for one of {}.", self.request));
+				}
+				EmojisWithCounts::from_iter([(chosen, 1)])
+			}
+			None if self.any_one => {
+				return Err(format!(
+					"Pick which one of {} you give, with the `give` option.",
+					self.request
+				))
+			}
+			_ => self.request.clone(),
+		};
+		TradeOffer::new(self.guild, self.user, filler, self.offer.clone(), request)
+	}
+}
+
+impl Display for Listing {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{} for ", self.offer)?;
+		if self.any_one && self.request.unique_emoji_count() > 1 {
+			f.write_str("any one of ")?;
+		}
+		write!(f, "{}", self.request)
+	}
+}
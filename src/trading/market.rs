@@ -0,0 +1,505 @@
+//! Open trade listings that any user with a trading role can fill, and `/market` to list, browse, fill and remove them.
+
+use std::fmt::Write;
+
+use serenity::{
+	all::{
+		ButtonStyle, CommandDataOption, CommandDataOptionValue, CommandInteraction,
+		CommandOptionType, UserId,
+	},
+	async_trait,
+	builder::{CreateCommand, CreateCommandOption},
+};
+
+use crate::{
+	autocomplete,
+	commands::SlashCommand,
+	context::Context,
+	discord::{Button, Choice},
+	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
+	util::{find_option, get_and_parse_emoji_option, ReplyShortcuts},
+};
+
+use super::{
+	listing::Listing,
+	queries::{
+		add_listing, complete_listing, get_listing, get_listings, remove_invalidated_trade_offers,
+		remove_listing,
+	},
+	trading_roles::has_trading_role,
+};
+
+/// How many listings `/market browse` shows at most.
+const BROWSE_LIMIT: i64 = 20;
+
+fn listing_option(options: &[CommandDataOption]) -> Result<i64, BotError> {
+	find_option(options, "listing")
+		.and_then(|option| option.value.as_i64())
+		.ok_or(BotError::InvalidInteraction("listing option missing"))
+}
+
+async fn try_list(
+	context: Context<'_>,
+	options: &[CommandDataOption],
+	user: UserId,
+) -> Result<String, BotError> {
+	if !has_trading_role(context, user).await {
+		return Err("You do not have a role that allows trading.".into());
+	}
+	let offer = get_and_parse_emoji_option(context.emoji_map, find_option(options, "offer"))?;
+	let request = get_and_parse_emoji_option(context.emoji_map, find_option(options, "request"))?;
+	let any_one = find_option(options, "any_one")
+		.and_then(|option| option.value.as_bool())
+		.unwrap_or(false);
+	let listing = Listing::new(
+		context.guild,
+		user,
+		EmojisWithCounts::from_flat(&offer),
+		EmojisWithCounts::from_flat(&request),
+		any_one,
+	)?;
+	if !listing
+		.offer()
+		.are_available_to_user(context.database, context.guild, user)
+		.await?
+	{
+		return Err("You don't have those emojis to offer.".into());
+	}
+	let id = add_listing(context.database, &listing).await?;
+	let name = context.get_user_name(user).await;
+	Ok(format!(
+		"{name} listed #{id}: {listing}. Anyone can fill it with `/market fill`."
+	))
+}
+
+async fn browse(context: Context<'_>, options: &[CommandDataOption]) -> Result<String, BotError> {
+	let emoji = match find_option(options, "emoji") {
+		Some(option) => get_and_parse_emoji_option(context.emoji_map, Some(option))?
+			.first()
+			.copied(),
+		None => None,
+	};
+	let listings = get_listings(
+		context.database,
+		context.emoji_map,
+		context.guild,
+		emoji,
+		BROWSE_LIMIT,
+	)
+	.await?;
+	if listings.is_empty() {
+		return Ok(match emoji {
+			Some(emoji) => format!("There are no listings with {}.", emoji.as_str()),
+			None => String::from("There are no listings. Make one with `/market list`."),
+		});
+	}
+	let mut content = String::new();
+	for (id, listing) in listings {
+		let name = context.get_user_name(listing.user()).await;
+		writeln!(content, "#{id}: {name} gives {listing}").unwrap();
+	}
+	Ok(content)
+}
+
+async fn try_remove(
+	context: Context<'_>,
+	options: &[CommandDataOption],
+	user: UserId,
+) -> Result<String, BotError> {
+	let id = listing_option(options)?;
+	if !remove_listing(context.database, context.guild, user, id).await? {
+		return Err(format!("You have no listing #{id}.").into());
+	}
+	Ok(format!("Listing #{id} removed."))
+}
+
+async fn try_fill(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+	options: &[CommandDataOption],
+	user: UserId,
+) -> Result<(), BotError> {
+	let id = listing_option(options)?;
+	let Some(listing) = get_listing(context.database, context.emoji_map, context.guild, id).await?
+	else {
+		return Err(format!("There is no listing #{id}.").into());
+	};
+	let lister = listing.user();
+	if lister == user {
+		return Err("That is your own listing. Remove it with `/market remove` instead.".into());
+	}
+	if !has_trading_role(context, user).await {
+		return Err("You do not have a role that allows trading.".into());
+	}
+	let lister_name = context.get_user_name(lister).await;
+	if !has_trading_role(context, lister).await {
+		return Err(format!("{lister_name} does not have a role that allows trading.").into());
+	}
+	let chosen = match find_option(options, "give") {
+		Some(option) => match get_and_parse_emoji_option(context.emoji_map, Some(option))?[..] {
+			[emoji] => Some(emoji),
+			_ => return Err("Pick a single emoji to give.".into()),
+		},
+		None => None,
+	};
+	let trade = listing.trade_with(user, chosen)?;
+	if !trade
+		.request()
+		.are_available_to_user(context.database, context.guild, user)
+		.await?
+	{
+		return Err(format!("You don't have {}.", trade.request()).into());
+	}
+	if !trade
+		.offer()
+		.are_available_to_user(context.database, context.guild, lister)
+		.await?
+	{
+		return Err(format!(
+			"Something went wrong: {lister_name} does not have the listed emojis."
+		)
+		.into());
+	}
+
+	let content = format!("You are about to fill listing #{id} from {lister_name}.\nYou will **lose**: {}\nYou will **gain**: {}\nDo you want to proceed?", trade.request(), trade.offer());
+	let buttons = [
+		Button {
+			id: "yes",
+			label: "Yes",
+			style: ButtonStyle::Primary,
+		},
+		Button {
+			id: "no",
+			label: "No",
+			style: ButtonStyle::Secondary,
+		},
+	];
+	let button_press = context
+		.discord
+		.ask_with_buttons(
+			interaction,
+			content,
+			&buttons,
+			context.guild_config().trade_confirmation_timeout,
+		)
+		.await?;
+
+	let Some(button_press) = button_press else {
+		context
+			.discord
+			.follow_up(
+				interaction,
+				String::from("The trade confirmation has timed out."),
+				true,
+			)
+			.await?;
+		context.discord.delete_reply(interaction).await?;
+		return Ok(());
+	};
+	match button_press.custom_id.as_str() {
+		"yes" => {
+			if complete_listing(context.database, id, &trade).await? {
+				remove_invalidated_trade_offers(context.database, context.guild, &[lister, user])
+					.await?;
+				let filler_name = context.get_user_name(user).await;
+				let content = format!(
+					"{filler_name} filled listing #{id}, trading away {} to {lister_name} in exchange for {}.",
+					trade.request(),
+					trade.offer()
+				);
+				context
+					.discord
+					.reply_to_button(&button_press, content, false)
+					.await?;
+			} else {
+				let content = format!("Listing #{id} was filled or removed, or one of you no longer has the emojis, so the trade was cancelled.");
+				context
+					.discord
+					.reply_to_button(&button_press, content, true)
+					.await?;
+			}
+			context.discord.delete_reply(interaction).await?;
+		}
+		"no" => {
+			context
+				.discord
+				.update_button_message(&button_press, String::from("You have cancelled the trade."))
+				.await?;
+		}
+		_ => return Err(BotError::InvalidInteraction("unknown button")),
+	}
+	Ok(())
+}
+
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let subcommand = interaction
+		.data
+		.options
+		.first()
+		.ok_or(BotError::InvalidInteraction("subcommand missing"))?;
+	let CommandDataOptionValue::SubCommand(options) = &subcommand.value else {
+		return Err(BotError::InvalidInteraction("expected a subcommand"));
+	};
+	let user = interaction.user.id;
+
+	let mut ephemeral = false;
+	let message = match subcommand.name.as_str() {
+		"list" => try_list(context, options, user).await?,
+		"browse" => {
+			ephemeral = true;
+			browse(context, options).await?
+		}
+		"fill" => return try_fill(context, interaction, options, user).await,
+		"remove" => try_remove(context, options, user).await?,
+		_ => return Err(BotError::InvalidInteraction("unknown subcommand")),
+	};
+	interaction
+		.reply(context.discord, message, ephemeral)
+		.await?;
+	Ok(())
+}
+
+/// Offers and what to give are finished from the caller's emojis, and everything else from all emojis.
+async fn autocomplete(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+) -> Result<Vec<Choice>, BotError> {
+	let option = interaction
+		.data
+		.autocomplete()
+		.ok_or(BotError::InvalidInteraction("no option to autocomplete"))?;
+	match option.name {
+		"offer" => autocomplete::owned_emojis(context, interaction.user.id, option.value).await,
+		"give" => autocomplete::owned_emoji(context, interaction.user.id, option.value).await,
+		"emoji" => Ok(autocomplete::any_emoji(option.value)),
+		_ => Ok(Vec::new()),
+	}
+}
+
+fn register() -> CreateCommand {
+	CreateCommand::new("market")
+		.description(
+			"List emojis for anyone to trade for, or browse and fill the listings of others.",
+		)
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"list",
+				"List emojis for anyone to trade for.",
+			)
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::String,
+					"offer",
+					"The emojis you are giving. Repeat emojis for multiples.",
+				)
+				.required(true)
+				.set_autocomplete(true),
+			)
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::String,
+					"request",
+					"The emojis you want for them. Repeat emojis for multiples.",
+				)
+				.required(true),
+			)
+			.add_sub_option(CreateCommandOption::new(
+				CommandOptionType::Boolean,
+				"any_one",
+				"Whether any one of the requested emojis will do, rather than all of them.",
+			)),
+		)
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"browse",
+				"Show the newest listings.",
+			)
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::String,
+					"emoji",
+					"Only show listings that give or want this emoji.",
+				)
+				.set_autocomplete(true),
+			),
+		)
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"fill",
+				"Trade for a listing.",
+			)
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::Integer,
+					"listing",
+					"The number of the listing.",
+				)
+				.required(true),
+			)
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::String,
+					"give",
+					"Which of the requested emojis you give, if any one of them will do.",
+				)
+				.set_autocomplete(true),
+			),
+		)
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"remove",
+				"Remove one of your listings.",
+			)
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::Integer,
+					"listing",
+					"The number of the listing.",
+				)
+				.required(true),
+			),
+		)
+}
+
+pub struct MarketCommand;
+
+#[async_trait]
+impl SlashCommand for MarketCommand {
+	fn name(&self) -> &'static str {
+		"market"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+	async fn autocomplete(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<Vec<Choice>, BotError> {
+		autocomplete(context, interaction).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::{all::RoleId, json::json};
+	use sqlx::query_scalar;
+
+	use crate::{
+		discord::fake::{FakeDiscord, Sent},
+		emoji::EmojiMap,
+		queries::give_emoji,
+		testing::{self, run_subcommand, GUILD, TRADER},
+	};
+
+	use super::*;
+
+	#[tokio::test]
+	async fn listing_can_be_filled_with_any_one_of_its_request() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let trader = [RoleId::new(TRADER)];
+		let (alice, bob) = (UserId::new(1), UserId::new(2));
+		let discord = FakeDiscord::new()
+			.with_member(alice, "Alice", &trader)
+			.with_member(bob, "Bob", &trader);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		for (user, emoji) in [(alice, "🦊"), (alice, "🦊"), (bob, "🐻")] {
+			give_emoji(&database, GUILD, user, emoji_map.get(emoji).unwrap())
+				.await
+				.unwrap();
+		}
+		let both_sides = json!([
+			{ "name": "offer", "type": 3, "value": "🦊" },
+			{ "name": "request", "type": 3, "value": "🦊🦊" },
+		]);
+		assert!(
+			run_subcommand(context, &MarketCommand, alice, "list", both_sides)
+				.await
+				.contains("both sides")
+		);
+		let listing = json!([
+			{ "name": "offer", "type": 3, "value": "🦊🦊" },
+			{ "name": "request", "type": 3, "value": "🐺🐻" },
+			{ "name": "any_one", "type": 5, "value": true },
+		]);
+		assert_eq!(
+			run_subcommand(context, &MarketCommand, alice, "list", listing.clone()).await,
+			""
+		);
+		assert_eq!(
+			run_subcommand(context, &MarketCommand, alice, "list", listing).await,
+			""
+		);
+		assert_eq!(
+			run_subcommand(
+				context,
+				&MarketCommand,
+				bob,
+				"browse",
+				json!([{ "name": "emoji", "type": 3, "value": "🐺" }])
+			)
+			.await,
+			""
+		);
+		let fill = |give: &str| {
+			let mut options = vec![json!({ "name": "listing", "type": 4, "value": 1 })];
+			if !give.is_empty() {
+				options.push(json!({ "name": "give", "type": 3, "value": give }));
+			}
+			serenity::json::Value::from(options)
+		};
+		assert!(
+			run_subcommand(context, &MarketCommand, bob, "fill", fill(""))
+				.await
+				.contains("Pick which one")
+		);
+		assert!(
+			run_subcommand(context, &MarketCommand, bob, "fill", fill("🐺"))
+				.await
+				.contains("don't have")
+		);
+		discord.press_button(Some("yes"));
+		assert_eq!(
+			run_subcommand(context, &MarketCommand, bob, "fill", fill("🐻")).await,
+			""
+		);
+
+		let sent = discord.take_sent();
+		assert!(
+			matches!(
+				&sent[..],
+				[
+					Sent::Reply { .. },
+					Sent::Reply { .. },
+					Sent::Reply { content, ephemeral: true },
+					Sent::Buttons { .. },
+					Sent::ButtonReply { ephemeral: false, .. },
+					Sent::DeletedReply,
+				] if content.starts_with("#2: Alice gives 🦊x2 for any one of 🐻🐺")
+			),
+			"{sent:?}"
+		);
+		assert_eq!(testing::inventory(&database, alice).await, ["🐻"]);
+		assert_eq!(testing::inventory(&database, bob).await, ["🦊", "🦊"]);
+		// The second listing went with the foxes.
+		let listings: i64 = query_scalar("SELECT COUNT(*) FROM market_listings")
+			.fetch_one(&database)
+			.await
+			.unwrap();
+		assert_eq!(listings, 0);
+	}
+}
@@ -1,137 +1,443 @@
+mod auction;
+pub(crate) mod auction_house;
+mod circle_trade;
+pub(crate) mod expiry;
+mod listing;
+pub(crate) mod market;
 mod queries;
 pub(crate) mod recycling;
 pub(crate) mod trade;
 mod trade_offer;
 pub(crate) mod trading_roles;
+mod wildcard;
 
-use serenity::{
-	all::{ButtonStyle, CommandDataOption, CommandInteraction, GuildId, UserId},
-	builder::{
-		CreateActionRow, CreateButton, CreateInteractionResponse,
-		CreateInteractionResponseFollowup, CreateInteractionResponseMessage,
-	},
-	gateway::ShardMessenger,
-};
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use serenity::all::{ButtonStyle, CommandDataOption, CommandInteraction, GuildId, UserId};
 use sqlx::{Pool, Sqlite};
-use std::fmt::Write;
+use std::{
+	collections::{HashMap, HashSet},
+	fmt::Write,
+	time::Duration,
+};
 
 use crate::{
-	context::Context, emoji::EmojiMap, emojis_with_counts::EmojisWithCounts,
-	util::get_and_parse_emoji_option,
+	context::Context,
+	discord::{
+		Button, ButtonPress, Modal, SelectMenu, SelectOption, TextInput, MAX_SELECT_OPTIONS,
+	},
+	emoji::{Emoji, EmojiMap},
+	emoji_list::Category,
+	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
+	user_settings::{
+		notifications::{notify, Notice},
+		privacy::{get_privacy_settings, TradeOffersFrom},
+	},
+	util::{describe_duration, get_and_parse_emoji_option, parse_emoji_input},
 };
 
-use self::{queries::*, trade_offer::TradeOffer, trading_roles::has_trading_role};
+use self::{
+	circle_trade::CircleTrade,
+	queries::*,
+	trade_offer::TradeOffer,
+	trading_roles::has_trading_role,
+	wildcard::{Wildcard, WildcardKind},
+};
 
 pub(super) async fn try_offer_trade(
 	context: Context<'_>,
-	options: Vec<CommandDataOption>,
-	guild: GuildId,
+	options: &[CommandDataOption],
 	user: UserId,
 	target_user: UserId,
-) -> Result<String, String> {
+) -> Result<String, BotError> {
 	if user == target_user {
-		return Err(String::from("You can't trade yourself."));
+		return Err("You can't trade yourself.".into());
 	}
-	if does_trade_offer_exist(context.database, user, target_user).await {
-		return Err(String::from("You already have a trade offer to that user."));
+	if does_trade_offer_exist(context.database, context.guild, user, target_user).await? {
+		return Err("You already have a trade offer to that user.".into());
 	}
-	let offer = get_and_parse_emoji_option(context.emoji_map, options.get(1))?;
+	check_offer_limits(context, user, target_user, false).await?;
+	let option = |name: &str| options.iter().find(|option| option.name == name);
+	let offer = get_and_parse_emoji_option(context.emoji_map, option("offer"))?;
 	if offer.is_empty() {
-		return Err(String::from("Offer is empty."));
+		return Err("Offer is empty.".into());
 	}
-	let request = get_and_parse_emoji_option(context.emoji_map, options.get(2))?;
-	if request.is_empty() {
-		return Err(String::from("Request is empty."));
+	let request = match option("request") {
+		Some(request) => get_and_parse_emoji_option(context.emoji_map, Some(request))?,
+		None => Vec::new(),
+	};
+	let wildcard = parse_wildcard(context, options)?;
+	if request.is_empty() && wildcard.is_none() {
+		return Err("Request is empty.".into());
 	}
 	let offer = EmojisWithCounts::from_flat(&offer);
 	let request = EmojisWithCounts::from_flat(&request);
-	let trade_offer = TradeOffer::new(user, target_user, offer, request)?;
-	if !trade_offer
-		.offer()
-		.are_owned_by_user(context.database, user)
-		.await
-	{
-		return Err(String::from("You don't have those emojis to offer."));
+	let mut trade_offer = TradeOffer::new(context.guild, user, target_user, offer, request)?;
+	if let Some(wildcard) = wildcard {
+		trade_offer = trade_offer.with_wildcard(wildcard)?;
 	}
+	check_offered_emojis(context, user, trade_offer.offer()).await?;
 
-	let name = context.get_user_name(guild, target_user).await;
+	let name = context.get_user_name(target_user).await;
 	let output = format!(
 		"You are now offering {} in return for {}'s {}.",
 		trade_offer.offer(),
 		name,
-		trade_offer.request()
+		trade_offer.describe_request()
 	);
 
-	add_trade_offer(context.database, trade_offer).await;
+	let reserve = context.guild_config().reserve_offered_emojis;
+	if !add_trade_offer(context.database, trade_offer, reserve).await? {
+		return Err(
+			"Some of the emojis you offered were locked while you were offering them.".into(),
+		);
+	}
+	notify(
+		context.database,
+		context.discord,
+		context.guild,
+		target_user,
+		&[Notice::TradeOffer(user)],
+	)
+	.await?;
 
 	Ok(output)
 }
 
+/// Makes sure the user has the emojis to offer, and if some of them are locked in other trade offers or escrow, says so.
+async fn check_offered_emojis(
+	context: Context<'_>,
+	user: UserId,
+	offer: &EmojisWithCounts,
+) -> Result<(), BotError> {
+	if offer
+		.are_available_to_user(context.database, context.guild, user)
+		.await?
+	{
+		return Ok(());
+	}
+	let owned = EmojisWithCounts::from_database_for_user(
+		context.database,
+		context.emoji_map,
+		context.guild,
+		user,
+	)
+	.await?;
+	let locked =
+		EmojisWithCounts::locked_for_user(context.database, context.emoji_map, context.guild, user)
+			.await?;
+	for (emoji, count) in offer {
+		let owned = owned.count_of(*emoji);
+		let unlocked = owned - locked.count_of(*emoji);
+		if owned >= *count && unlocked < *count {
+			return Err(format!(
+				"You have {owned} {emoji}, but only {unlocked} of them {} not locked in other trade offers or escrow.",
+				if unlocked == 1 { "is" } else { "are" }
+			)
+			.into());
+		}
+	}
+	Err("You don't have those emojis to offer.".into())
+}
+
+/// The wildcard from whichever of the `any_of`, `category` and `duplicates` options is given, for `wildcard_count` emojis.
+fn parse_wildcard(
+	context: Context<'_>,
+	options: &[CommandDataOption],
+) -> Result<Option<Wildcard>, BotError> {
+	let option = |name: &str| options.iter().find(|option| option.name == name);
+	let mut kinds = Vec::new();
+	if let Some(any_of) = option("any_of") {
+		let emojis = get_and_parse_emoji_option(context.emoji_map, Some(any_of))?;
+		kinds.push(WildcardKind::AnyOf(emojis));
+	}
+	if let Some(category) = option("category").and_then(|option| option.value.as_str()) {
+		let category = Category::ALL
+			.into_iter()
+			.find(|other| other.name() == category)
+			.ok_or(BotError::InvalidInteraction("unknown category"))?;
+		kinds.push(WildcardKind::Category(category));
+	}
+	if option("duplicates").and_then(|option| option.value.as_bool()) == Some(true) {
+		kinds.push(WildcardKind::Duplicates);
+	}
+	let count = option("wildcard_count")
+		.and_then(|option| option.value.as_i64())
+		.unwrap_or(1);
+	let count = u32::try_from(count)
+		.ok()
+		.filter(|count| *count > 0)
+		.ok_or("The wildcard has to be for at least one emoji.")?;
+	match kinds.len() {
+		0 => Ok(None),
+		1 => Ok(kinds.pop().map(|kind| Wildcard::new(kind, count))),
+		_ => Err("Pick only one of `any_of`, `category` and `duplicates`.".into()),
+	}
+}
+
+/// Checks who the target user takes offers from, and how many and how often the user may make them.
+///
+/// A counter-offer answers an offer from the target user, so it isn't held back by the cooldown after rejections.
+async fn check_offer_limits(
+	context: Context<'_>,
+	user: UserId,
+	target_user: UserId,
+	countering: bool,
+) -> Result<(), BotError> {
+	if is_blocked(context.database, target_user, user).await? {
+		return Err("That user doesn't take trade offers from you.".into());
+	}
+	match get_privacy_settings(context.database, target_user)
+		.await?
+		.trade_offers_from
+	{
+		TradeOffersFrom::Everyone => (),
+		TradeOffersFrom::TradingRole if has_trading_role(context, user).await => (),
+		TradeOffersFrom::TradingRole => {
+			return Err("That user only takes trade offers from users with a trading role.".into())
+		}
+		TradeOffersFrom::Nobody => return Err("That user doesn't take trade offers.".into()),
+	}
+	let guild_config = context.guild_config();
+	let last_rejection = if countering {
+		None
+	} else {
+		get_last_rejection(context.database, context.guild, user, target_user).await?
+	};
+	if let Some(rejected) = last_rejection {
+		let cooldown = TimeDelta::from_std(guild_config.rejection_cooldown).unwrap_or_default();
+		let left = rejected.and_utc() + cooldown - Utc::now();
+		if left > TimeDelta::zero() {
+			return Err(format!(
+				"That user rejected your last trade offer. You can offer them a new trade in {}.",
+				describe_duration(left)
+			)
+			.into());
+		}
+	}
+	let max = guild_config.max_outgoing_offers;
+	if count_outgoing_trade_offers(context.database, context.guild, user).await? >= max {
+		return Err(format!(
+			"You already have {max} outgoing trade offers, which is the most you can have. Withdraw one to make a new one."
+		)
+		.into());
+	}
+	Ok(())
+}
+
+/// Replaces the offer from the offering user with one back to them, where either side left out of the options stays as it was.
+pub(super) async fn try_counter_offer(
+	context: Context<'_>,
+	options: &[CommandDataOption],
+	user: UserId,
+	offering_user: UserId,
+) -> Result<String, BotError> {
+	let side = |name: &str| {
+		options
+			.iter()
+			.find(|option| option.name == name)
+			.map(|option| {
+				get_and_parse_emoji_option(context.emoji_map, Some(option))
+					.map(|emojis| EmojisWithCounts::from_flat(&emojis))
+			})
+			.transpose()
+	};
+	counter_offer(
+		context,
+		user,
+		offering_user,
+		side("offer")?,
+		side("request")?,
+	)
+	.await
+}
+
+/// Replaces the offer from the offering user with one back to them, where either side that is None stays as it was.
+async fn counter_offer(
+	context: Context<'_>,
+	user: UserId,
+	offering_user: UserId,
+	offer: Option<EmojisWithCounts>,
+	request: Option<EmojisWithCounts>,
+) -> Result<String, BotError> {
+	let name = context.get_user_name(offering_user).await;
+	let Some(original) = get_trade_offer(
+		context.database,
+		context.emoji_map,
+		context.guild,
+		offering_user,
+		user,
+	)
+	.await?
+	else {
+		return Err(format!("You have no trade offer from {name}.").into());
+	};
+	if does_trade_offer_exist(context.database, context.guild, user, offering_user).await? {
+		return Err(format!(
+			"You already have a trade offer to {name}. Withdraw it to counter theirs."
+		)
+		.into());
+	}
+	check_offer_limits(context, user, offering_user, true).await?;
+	if let (Some(wildcard), None) = (original.wildcard(), &offer) {
+		return Err(format!(
+			"{name} asks for {wildcard}, so say what you offer instead with the `offer` option."
+		)
+		.into());
+	}
+	let offer = offer.unwrap_or_else(|| original.request().clone());
+	let request = request.unwrap_or_else(|| original.offer().clone());
+	let counter_offer = TradeOffer::new(context.guild, user, offering_user, offer, request)?;
+	if original.wildcard().is_none() && counter_offer == original.reversed() {
+		return Err(format!(
+			"That is just {name}'s offer turned around. Use `/trade accept` to take it as it is."
+		)
+		.into());
+	}
+	check_offered_emojis(context, user, counter_offer.offer()).await?;
+
+	if !replace_with_counter_offer(
+		context.database,
+		context.emoji_map,
+		&original,
+		&counter_offer,
+		context.guild_config().reserve_offered_emojis,
+	)
+	.await?
+	{
+		return Err(format!(
+			"The offer from {name} was changed or withdrawn while you were countering it, or your emojis were locked in the meantime."
+		)
+		.into());
+	}
+	notify(
+		context.database,
+		context.discord,
+		context.guild,
+		offering_user,
+		&[Notice::TradeOffer(user)],
+	)
+	.await?;
+
+	Ok(format!(
+		"You countered {name}'s offer. You are now offering {} in return for their {}.",
+		counter_offer.offer(),
+		counter_offer.request()
+	))
+}
+
 pub(super) async fn try_cancel_offer(
 	context: Context<'_>,
-	guild: GuildId,
 	user: UserId,
 	target_user: UserId,
-) -> Result<String, String> {
-	let name = context.get_user_name(guild, target_user).await;
-	if !does_trade_offer_exist(context.database, user, target_user).await {
-		return Err(format!("You have no trade offer to {}.", name));
+) -> Result<String, BotError> {
+	let name = context.get_user_name(target_user).await;
+	if !does_trade_offer_exist(context.database, context.guild, user, target_user).await? {
+		return Err(format!("You have no trade offer to {}.", name).into());
 	}
 
-	remove_trade_offer(context.database, user, target_user).await;
+	remove_trade_offer(context.database, context.guild, user, target_user).await?;
 
 	Ok(format!("Trade offer to {} rescinded.", name))
 }
 
 pub(super) async fn try_reject_offer(
 	context: Context<'_>,
-	guild: GuildId,
 	user: UserId,
 	other_user: UserId,
-) -> Result<String, String> {
-	let name = context.get_user_name(guild, other_user).await;
-	if !does_trade_offer_exist(context.database, other_user, user).await {
-		return Err(format!("You have no trade offer from {}.", name));
+) -> Result<String, BotError> {
+	let name = context.get_user_name(other_user).await;
+	if !does_trade_offer_exist(context.database, context.guild, other_user, user).await? {
+		return Err(format!("You have no trade offer from {}.", name).into());
 	}
 
-	remove_trade_offer(context.database, other_user, user).await;
+	remove_trade_offer(context.database, context.guild, other_user, user).await?;
+	record_rejection(context.database, context.guild, other_user, user).await?;
 
 	Ok(format!("Trade offer from {} rejected.", name))
 }
 
-pub(super) async fn view_offers(
+pub(super) async fn try_block_user(
 	context: Context<'_>,
-	guild: GuildId,
 	user: UserId,
-) -> Result<String, String> {
-	let outgoing = get_outgoing_trade_offers(context.database, context.emoji_map, user).await;
-	let incoming = get_incoming_trade_offers(context.database, context.emoji_map, user).await;
+	blocked_user: UserId,
+) -> Result<String, BotError> {
+	if user == blocked_user {
+		return Err("You can't block yourself.".into());
+	}
+	block_user(context.database, user, blocked_user).await?;
+	let name = context.get_user_name(blocked_user).await;
+	Ok(format!(
+		"{name} can no longer offer you trades, and their offers to you were removed."
+	))
+}
+
+pub(super) async fn try_unblock_user(
+	context: Context<'_>,
+	user: UserId,
+	blocked_user: UserId,
+) -> Result<String, BotError> {
+	let name = context.get_user_name(blocked_user).await;
+	if !unblock_user(context.database, user, blocked_user).await? {
+		return Err(format!("You haven't blocked {name}.").into());
+	}
+	Ok(format!("{name} can offer you trades again."))
+}
+
+/// Like "expires in 3 days", for an offer made at that time, in UTC.
+fn describe_expiry(context: Context<'_>, made: NaiveDateTime) -> String {
+	let lifetime = TimeDelta::from_std(context.guild_config().offer_lifetime).unwrap_or_default();
+	let left = made.and_utc() + lifetime - Utc::now();
+	if left > TimeDelta::zero() {
+		format!("expires in {}", describe_duration(left))
+	} else {
+		String::from("expires any moment now")
+	}
+}
+
+pub(super) async fn view_offers(context: Context<'_>, user: UserId) -> Result<String, BotError> {
+	let outgoing =
+		get_outgoing_trade_offers(context.database, context.emoji_map, context.guild, user).await?;
+	let incoming =
+		get_incoming_trade_offers(context.database, context.emoji_map, context.guild, user).await?;
 
 	let mut output = String::new();
 	if !outgoing.is_empty() {
 		output.push_str("Outgoing:\n");
-		for trade in outgoing {
-			let name = context.get_user_name(guild, trade.target_user()).await;
+		for (trade, made, countering) in outgoing {
+			let name = context.get_user_name(trade.target_user()).await;
 			output
 				.write_fmt(format_args!(
-					"You are offering {} for {}'s {}.\n",
+					"You are {} {} for {}'s {} ({}).\n",
+					if countering {
+						"countering with"
+					} else {
+						"offering"
+					},
 					trade.offer(),
 					name,
-					trade.request()
+					trade.describe_request(),
+					describe_expiry(context, made)
 				))
 				.unwrap();
 		}
 	}
 	if !incoming.is_empty() {
 		output.push_str("Incoming:\n");
-		for trade in incoming {
-			let name = context.get_user_name(guild, trade.offering_user()).await;
+		for (trade, made, countering) in incoming {
+			let name = context.get_user_name(trade.offering_user()).await;
 			output
 				.write_fmt(format_args!(
-					"{} is offering {} for your {}.\n",
+					"{} is {} {} for your {} ({}).\n",
 					name,
+					if countering {
+						"countering with"
+					} else {
+						"offering"
+					},
 					trade.offer(),
-					trade.request()
+					trade.describe_request(),
+					describe_expiry(context, made)
 				))
 				.unwrap();
 		}
@@ -139,158 +445,496 @@ pub(super) async fn view_offers(
 	if output.is_empty() {
 		output.push_str("You have no outgoing or incoming trade offers.");
 	}
+	let blocked = get_blocked_users(context.database, user).await?;
+	if !blocked.is_empty() {
+		let mut names = Vec::new();
+		for blocked_user in blocked {
+			names.push(context.get_user_name(blocked_user).await);
+		}
+		write!(output, "\nYou blocked {}.", names.join(", ")).unwrap();
+	}
 	Ok(output)
 }
 
 pub(super) async fn try_accept_offer(
 	context: Context<'_>,
-	shard_messenger: ShardMessenger,
 	interaction: &CommandInteraction,
-	guild: GuildId,
 	accepting_user: UserId,
 	offering_user: UserId,
-) -> Result<(), String> {
-	if !has_trading_role(context, guild, accepting_user).await {
-		return Err(String::from("You do not have a role that allows trading."));
+) -> Result<(), BotError> {
+	if !has_trading_role(context, accepting_user).await {
+		return Err("You do not have a role that allows trading.".into());
 	}
-	if !has_trading_role(context, guild, offering_user).await {
-		return Err(String::from(
-			"Offering user does not have a role that allows trading.",
-		));
+	if !has_trading_role(context, offering_user).await {
+		return Err("Offering user does not have a role that allows trading.".into());
 	}
 
-	let offerer_name = context.get_user_name(guild, offering_user).await;
+	let offerer_name = context.get_user_name(offering_user).await;
 	let trade = match validate_trade_offer(
 		context.database,
 		context.emoji_map,
+		context.guild,
 		offering_user,
 		accepting_user,
 	)
-	.await
+	.await?
 	{
-		TradeOfferValidation::NoTrade => Err(format!(
-			"You do not have a trade offer from {offerer_name}."
-		)),
+		TradeOfferValidation::NoTrade => {
+			return Err(format!("You do not have a trade offer from {offerer_name}.").into())
+		}
 		TradeOfferValidation::TargetLacksEmojis => {
-			Err(String::from("You do not have the requested emojis."))
+			return Err("You do not have the requested emojis.".into());
 		}
-		TradeOfferValidation::OffererLacksEmojis => Err(format!(
-			"Something went wrong: {offerer_name} does not have the offered emojis."
-		)),
-		TradeOfferValidation::Valid(trade) => Ok(trade),
-	}?;
+		TradeOfferValidation::OffererLacksEmojis => {
+			return Err(format!(
+				"Something went wrong: {offerer_name} does not have the offered emojis."
+			)
+			.into())
+		}
+		TradeOfferValidation::Valid(trade) => trade,
+	};
+
+	let timeout = context.guild_config().trade_confirmation_timeout;
+	let (chosen, choice) = match trade.wildcard() {
+		Some(wildcard) => {
+			let Some(picked) =
+				pick_wildcard_emojis(context, interaction, &trade, wildcard, timeout).await?
+			else {
+				context
+					.discord
+					.follow_up(
+						interaction,
+						String::from("The trade confirmation has timed out."),
+						true,
+					)
+					.await?;
+				context.discord.delete_reply(interaction).await?;
+				return Ok(());
+			};
+			picked
+		}
+		None => (Vec::new(), None),
+	};
+	let filled = trade.fill_wildcard(&chosen);
 
-	let s1 = if trade.request().emoji_count() != 1 {
+	let s1 = if filled.request().emoji_count() != 1 {
 		"s"
 	} else {
 		""
 	};
-	let s2 = if trade.offer().emoji_count() != 1 {
+	let s2 = if filled.offer().emoji_count() != 1 {
 		"s"
 	} else {
 		""
 	};
-	let content = format!("You are about to accept the trade offer from {offerer_name}.\nYou will **lose** the following emoji{s1}: {}\nYou will **gain** the following emoji{s2}: {}\nDo you want to proceed?", trade.request(), trade.offer());
-
-	let components = vec![CreateActionRow::Buttons(vec![
-		CreateButton::new("yes")
-			.label("Yes")
-			.style(ButtonStyle::Primary),
-		CreateButton::new("no")
-			.label("No")
-			.style(ButtonStyle::Secondary),
-	])];
-
-	let _ = interaction
-		.create_response(
-			&context.http,
-			CreateInteractionResponse::Message(
-				CreateInteractionResponseMessage::new()
-					.content(content)
-					.ephemeral(true)
-					.components(components),
-			),
-		)
-		.await;
+	let content = format!("You are about to accept the trade offer from {offerer_name}.\nYou will **lose** the following emoji{s1}: {}\nYou will **gain** the following emoji{s2}: {}\nDo you want to proceed?", filled.request(), filled.offer());
 
-	let message = interaction
-		.get_response(&context.http)
-		.await
-		.map_err(|_| String::from("Error retrieving interaction response."))?;
-	let button_press = message
-		.await_component_interaction(shard_messenger)
-		.timeout(std::time::Duration::from_secs(60))
-		.await;
+	let buttons = [
+		Button {
+			id: "yes",
+			label: "Yes",
+			style: ButtonStyle::Primary,
+		},
+		Button {
+			id: "no",
+			label: "No",
+			style: ButtonStyle::Secondary,
+		},
+		Button {
+			id: "counter",
+			label: "Counter",
+			style: ButtonStyle::Secondary,
+		},
+	];
+	let button_press = match choice {
+		Some(choice) => {
+			context
+				.discord
+				.update_buttons(&choice, content, &buttons, timeout)
+				.await?
+		}
+		None => {
+			context
+				.discord
+				.ask_with_buttons(interaction, content, &buttons, timeout)
+				.await?
+		}
+	};
 
 	if let Some(button_press) = button_press {
-		match button_press.data.custom_id.as_str() {
+		match button_press.custom_id.as_str() {
 			"yes" => {
-				let accepter_name = context.get_user_name(guild, accepting_user).await;
+				let accepter_name = context.get_user_name(accepting_user).await;
 				let result = try_confirm_trade(
 					context.database,
 					context.emoji_map,
 					trade,
+					&chosen,
 					offerer_name,
 					accepter_name,
 				)
 				.await;
 				match result {
 					Ok(content) => {
-						let _ = button_press
-							.create_response(
-								&context.http,
-								CreateInteractionResponse::Message(
-									CreateInteractionResponseMessage::new()
-										.content(content)
-										.ephemeral(false),
-								),
-							)
-							.await;
+						context
+							.discord
+							.reply_to_button(&button_press, content, false)
+							.await?;
 					}
-					Err(content) => {
-						let _ = button_press
-							.create_response(
-								&context.http,
-								CreateInteractionResponse::Message(
-									CreateInteractionResponseMessage::new()
-										.content(content)
-										.ephemeral(true),
-								),
-							)
-							.await;
+					Err(BotError::User(content)) => {
+						context
+							.discord
+							.reply_to_button(&button_press, content, true)
+							.await?;
 					}
+					Err(error) => return Err(error),
 				}
 
-				let _ = interaction.delete_response(&context.http).await;
+				context.discord.delete_reply(interaction).await?;
 			}
 			"no" => {
-				let _ = button_press
-					.create_response(
-						&context.http,
-						CreateInteractionResponse::UpdateMessage(
-							CreateInteractionResponseMessage::new()
-								.content("You have cancelled the trade.")
-								.components(vec![]),
-						),
+				context
+					.discord
+					.update_button_message(
+						&button_press,
+						String::from("You have cancelled the trade."),
 					)
-					.await;
+					.await?;
 			}
-			_ => panic!(),
+			"counter" => {
+				let spaced = |emojis: &EmojisWithCounts| {
+					emojis
+						.clone()
+						.flatten()
+						.iter()
+						.map(Emoji::as_str)
+						.collect::<Vec<_>>()
+						.join(" ")
+				};
+				let modal = Modal {
+					id: "counter",
+					title: String::from("Counter the trade offer"),
+					inputs: vec![
+						TextInput {
+							id: "offer",
+							label: "What you give",
+							// Whatever a wildcard asks for is left for them to fill in.
+							value: spaced(trade.request()),
+						},
+						TextInput {
+							id: "request",
+							label: "What you get",
+							value: spaced(trade.offer()),
+						},
+					],
+				};
+				let Some(submission) = context
+					.discord
+					.ask_with_modal(&button_press, &modal, timeout)
+					.await?
+				else {
+					context
+						.discord
+						.follow_up(
+							interaction,
+							String::from("The counter offer has timed out."),
+							true,
+						)
+						.await?;
+					context.discord.delete_reply(interaction).await?;
+					return Ok(());
+				};
+				let side = |id: &str| -> Result<Option<EmojisWithCounts>, BotError> {
+					let emojis = parse_emoji_input(context.emoji_map, submission.value(id))?;
+					if emojis.is_empty() {
+						return Err("You did not specify any emojis.".into());
+					}
+					Ok(Some(EmojisWithCounts::from_flat(&emojis)))
+				};
+				let result = match (side("offer"), side("request")) {
+					(Ok(offer), Ok(request)) => {
+						counter_offer(context, accepting_user, offering_user, offer, request).await
+					}
+					(Err(error), _) | (_, Err(error)) => Err(error),
+				};
+				match result {
+					Ok(content) => {
+						context
+							.discord
+							.reply_to_modal(&submission, content, false)
+							.await?;
+					}
+					Err(BotError::User(content)) => {
+						context
+							.discord
+							.reply_to_modal(&submission, content, true)
+							.await?;
+					}
+					Err(error) => return Err(error),
+				}
+
+				context.discord.delete_reply(interaction).await?;
+			}
+			_ => return Err(BotError::InvalidInteraction("unknown button")),
 		}
 	} else {
-		let _ = interaction
-			.create_followup(
-				&context.http,
-				CreateInteractionResponseFollowup::new()
-					.content("The trade confirmation has timed out.")
-					.ephemeral(true),
+		context
+			.discord
+			.follow_up(
+				interaction,
+				String::from("The trade confirmation has timed out."),
+				true,
 			)
-			.await;
-		let _ = interaction.delete_response(&context.http).await;
+			.await?;
+		context.discord.delete_reply(interaction).await?;
 	}
 	Ok(())
 }
 
+/// Has the accepting user choose, one at a time from a select menu, the emojis that fill the trade offer's wildcard.
+///
+/// Returns the chosen emojis with the last choice, which the confirmation replaces, or None if the user stopped choosing before the timeout.
+async fn pick_wildcard_emojis(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+	trade: &TradeOffer,
+	wildcard: &Wildcard,
+	timeout: Duration,
+) -> Result<Option<(Vec<Emoji>, Option<ButtonPress>)>, BotError> {
+	let owned = get_unlocked_emojis(
+		context.database,
+		context.emoji_map,
+		context.guild,
+		trade.target_user(),
+	)
+	.await?;
+	let mut chosen = Vec::new();
+	let mut choice: Option<ButtonPress> = None;
+	while chosen.len() < wildcard.count() as usize {
+		let candidates = wildcard.candidates(
+			&owned,
+			trade.offer(),
+			trade.fill_wildcard(&chosen).request(),
+		);
+		let menus = [SelectMenu {
+			id: "wildcard",
+			options: candidates
+				.iter()
+				.take(MAX_SELECT_OPTIONS)
+				.map(|(emoji, count)| SelectOption {
+					value: emoji.as_str(),
+					label: format!("{emoji} {} (×{count})", emoji.name()),
+					default: false,
+				})
+				.collect(),
+		}];
+		let content = format!(
+			"The offer asks for {wildcard}. Choose emoji {} of {}:",
+			chosen.len() + 1,
+			wildcard.count()
+		);
+		choice = match &choice {
+			Some(choice) => {
+				context
+					.discord
+					.update_select_menus(choice, content, &menus, timeout)
+					.await?
+			}
+			None => {
+				context
+					.discord
+					.ask_with_select_menus(interaction, content, &menus, timeout)
+					.await?
+			}
+		};
+		let Some(press) = &choice else {
+			return Ok(None);
+		};
+		let emoji = press
+			.values
+			.first()
+			.and_then(|value| context.emoji_map.get(value))
+			.filter(|emoji| candidates.iter().any(|(other, _)| other == emoji))
+			.ok_or(BotError::InvalidInteraction("unknown wildcard choice"))?;
+		chosen.push(emoji);
+	}
+	Ok(Some((chosen, choice)))
+}
+
+/// The users after the proposer, in order around the circle, by the names of their options. Each has a matching option for what they give.
+pub(super) const CIRCLE_POSITIONS: [&str; 4] = ["second", "third", "fourth", "fifth"];
+
+/// Proposes a trade around a circle of users, which goes through once all of them confirm it on a message everyone can see.
+pub(super) async fn try_circle_trade(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+	options: &[CommandDataOption],
+	user: UserId,
+) -> Result<(), BotError> {
+	let option = |name: &str| options.iter().find(|option| option.name == name);
+	let gift = |option| {
+		get_and_parse_emoji_option(context.emoji_map, Some(option))
+			.map(|emojis| EmojisWithCounts::from_flat(&emojis))
+	};
+	let mut gifts = vec![(
+		user,
+		gift(option("gives").ok_or(BotError::InvalidInteraction("gives option missing"))?)?,
+	)];
+	for position in CIRCLE_POSITIONS {
+		let participant = option(position).and_then(|option| option.value.as_user_id());
+		match (participant, option(&format!("{position}_gives"))) {
+			(Some(participant), Some(gives)) => gifts.push((participant, gift(gives)?)),
+			(None, None) => (),
+			_ => {
+				return Err(format!(
+					"Pick both the {position} user and what they give, or neither."
+				)
+				.into())
+			}
+		}
+	}
+	let trade = CircleTrade::new(context.guild, gifts)?;
+
+	let mut names = HashMap::new();
+	for participant in trade.participants() {
+		names.insert(participant, context.get_user_name(participant).await);
+	}
+	for participant in trade.participants() {
+		if !has_trading_role(context, participant).await {
+			return Err(format!(
+				"{} does not have a role that allows trading.",
+				names[&participant]
+			)
+			.into());
+		}
+	}
+	for (giver, _, emojis) in trade.gifts() {
+		if !emojis
+			.are_available_to_user(context.database, context.guild, giver)
+			.await?
+		{
+			return Err(format!("{} doesn't have {emojis}.", names[&giver]).into());
+		}
+	}
+
+	let buttons = [
+		Button {
+			id: "confirm",
+			label: "Confirm",
+			style: ButtonStyle::Primary,
+		},
+		Button {
+			id: "decline",
+			label: "Decline",
+			style: ButtonStyle::Secondary,
+		},
+	];
+	let describe = |confirmed: &HashSet<UserId>| {
+		let mut content = format!(
+			"{} proposes a trade around a circle. It goes through once everyone confirms it:\n",
+			names[&trade.proposer()]
+		);
+		for (giver, recipient, emojis) in trade.gifts() {
+			let mark = if confirmed.contains(&giver) {
+				"✅"
+			} else {
+				"⏳"
+			};
+			writeln!(
+				content,
+				"{mark} {} gives {emojis} to {}",
+				names[&giver], names[&recipient]
+			)
+			.unwrap();
+		}
+		content
+	};
+	let timeout = context.guild_config().trade_confirmation_timeout;
+	let mut confirmed = HashSet::new();
+	let mut press = context
+		.discord
+		.ask_everyone_with_buttons(interaction, describe(&confirmed), &buttons, timeout)
+		.await?;
+	let last_press = loop {
+		let Some(button_press) = press else {
+			context
+				.discord
+				.follow_up(
+					interaction,
+					String::from("The trade timed out before everyone confirmed it."),
+					false,
+				)
+				.await?;
+			context.discord.delete_reply(interaction).await?;
+			return Ok(());
+		};
+		// Presses from anyone outside the circle change nothing.
+		if names.contains_key(&button_press.user) {
+			match button_press.custom_id.as_str() {
+				"confirm" => {
+					confirmed.insert(button_press.user);
+				}
+				"decline" => {
+					context
+						.discord
+						.update_button_message(
+							&button_press,
+							format!("{} declined the trade.", names[&button_press.user]),
+						)
+						.await?;
+					return Ok(());
+				}
+				_ => return Err(BotError::InvalidInteraction("unknown button")),
+			}
+		}
+		if confirmed.len() == names.len() {
+			break button_press;
+		}
+		press = context
+			.discord
+			.update_buttons(&button_press, describe(&confirmed), &buttons, timeout)
+			.await?;
+	};
+
+	let content = match complete_circle_trade(context.database, &trade).await? {
+		Some(giver) => format!(
+			"{} no longer has what they give, so the trade was cancelled.",
+			names[&giver]
+		),
+		None => {
+			let participants: Vec<_> = trade.participants().collect();
+			remove_invalidated_trade_offers(context.database, context.guild, &participants).await?;
+			let mut content = String::from("The trade went through:\n");
+			for (giver, recipient, emojis) in trade.gifts() {
+				writeln!(
+					content,
+					"{} gave {emojis} to {}",
+					names[&giver], names[&recipient]
+				)
+				.unwrap();
+			}
+			content
+		}
+	};
+	context
+		.discord
+		.update_button_message(&last_press, content)
+		.await?;
+	Ok(())
+}
+
+/// What the user can give away when filling in a wildcard.
+async fn get_unlocked_emojis(
+	executor: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	guild: GuildId,
+	user: UserId,
+) -> Result<EmojisWithCounts, BotError> {
+	let owned = EmojisWithCounts::from_database_for_user(executor, emoji_map, guild, user).await?;
+	let locked = EmojisWithCounts::locked_for_user(executor, emoji_map, guild, user).await?;
+	Ok(owned.without(&locked))
+}
+
 enum TradeOfferValidation {
 	Valid(TradeOffer),
 	NoTrade,
@@ -301,63 +945,89 @@ enum TradeOfferValidation {
 async fn validate_trade_offer(
 	executor: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	offering_user: UserId,
 	target_user: UserId,
-) -> TradeOfferValidation {
-	let Some(trade) = get_trade_offer(executor, emoji_map, offering_user, target_user).await else {
-		return TradeOfferValidation::NoTrade;
+) -> Result<TradeOfferValidation, BotError> {
+	let Some(trade) =
+		get_trade_offer(executor, emoji_map, guild, offering_user, target_user).await?
+	else {
+		return Ok(TradeOfferValidation::NoTrade);
 	};
 	if !trade
 		.request()
-		.are_owned_by_user(executor, target_user)
-		.await
+		.are_available_to_user(executor, guild, target_user)
+		.await?
 	{
-		return TradeOfferValidation::TargetLacksEmojis;
+		return Ok(TradeOfferValidation::TargetLacksEmojis);
 	}
-	if !trade
-		.offer()
-		.are_owned_by_user(executor, offering_user)
-		.await
-	{
-		return TradeOfferValidation::OffererLacksEmojis;
+	if let Some(wildcard) = trade.wildcard() {
+		let owned = get_unlocked_emojis(executor, emoji_map, guild, target_user).await?;
+		if wildcard
+			.candidates(&owned, trade.offer(), trade.request())
+			.emoji_count()
+			< wildcard.count()
+		{
+			return Ok(TradeOfferValidation::TargetLacksEmojis);
+		}
 	}
-	TradeOfferValidation::Valid(trade)
+	if !are_offered_emojis_available(executor, guild, offering_user, target_user).await? {
+		return Ok(TradeOfferValidation::OffererLacksEmojis);
+	}
+	Ok(TradeOfferValidation::Valid(trade))
 }
 
 async fn try_confirm_trade(
 	executor: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
 	trade_offer: TradeOffer,
+	chosen: &[Emoji],
 	offerer_name: String,
 	accepter_name: String,
-) -> Result<String, String> {
+) -> Result<String, BotError> {
 	let trade = match validate_trade_offer(
 		executor,
 		emoji_map,
+		trade_offer.guild(),
 		trade_offer.offering_user(),
 		trade_offer.target_user(),
 	)
-	.await
+	.await?
 	{
-		TradeOfferValidation::NoTrade => Err(format!(
-			"The trade offer from {offerer_name} is no longer there."
-		)),
+		TradeOfferValidation::NoTrade => {
+			return Err(format!("The trade offer from {offerer_name} is no longer there.").into())
+		}
 		TradeOfferValidation::TargetLacksEmojis => {
-			Err(String::from("You no longer have the requested emojis."))
+			return Err("You no longer have the requested emojis.".into());
 		}
 		TradeOfferValidation::OffererLacksEmojis => {
-			Err(format!("{offerer_name} no longer has the offered emojis."))
+			return Err(format!("{offerer_name} no longer has the offered emojis.").into());
 		}
-		TradeOfferValidation::Valid(trade) => Ok(trade),
-	}?;
+		TradeOfferValidation::Valid(trade) => trade,
+	};
 	if trade_offer != trade {
 		return Err(format!(
 			"The offer from {offerer_name} was changed while you were accepting it, so the trade was cancelled."
-		));
+		).into());
+	}
+
+	let trade = trade.fill_wildcard(chosen);
+	if trade_offer.wildcard().is_some()
+		&& !trade
+			.request()
+			.are_available_to_user(executor, trade.guild(), trade.target_user())
+			.await?
+	{
+		return Err("You no longer have the emojis you chose.".into());
 	}
 
-	complete_trade(executor, &trade_offer).await;
-	remove_invalidated_trade_offers(executor, &trade_offer).await;
+	complete_trade(executor, &trade).await?;
+	remove_invalidated_trade_offers(
+		executor,
+		trade.guild(),
+		&[trade.offering_user(), trade.target_user()],
+	)
+	.await?;
 
 	let output = format!(
 		"{accepter_name} successfully traded away {} to {offerer_name} in exchange for {}.",
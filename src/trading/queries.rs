@@ -1,34 +1,68 @@
-use serenity::model::prelude::UserId;
-use sqlx::{query, Executor, Pool, Sqlite, Transaction};
+use chrono::NaiveDateTime;
+use serenity::model::prelude::{GuildId, UserId};
+use sqlx::{query, query_scalar, Executor, Pool, Sqlite, Transaction};
 
 use crate::{
 	emoji::{Emoji, EmojiMap},
+	emoji_list::Category,
+	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
 	inventory::queries::remove_empty_groups,
 };
 
-use super::trade_offer::TradeOffer;
+use super::{
+	auction::{Auction, Bid},
+	circle_trade::CircleTrade,
+	listing::Listing,
+	trade_offer::TradeOffer,
+	wildcard::{Wildcard, WildcardKind},
+};
+
+/// With `reserve`, the offered emojis are locked until the offer is gone.
+///
+/// Returns false, and changes nothing, if they couldn't all be locked.
+pub(super) async fn add_trade_offer(
+	executor: &Pool<Sqlite>,
+	trade_offer: TradeOffer,
+	reserve: bool,
+) -> Result<bool, BotError> {
+	let mut transaction = executor.begin().await?;
+	if insert_trade_offer(&mut transaction, &trade_offer, None, reserve)
+		.await?
+		.is_none()
+	{
+		return Ok(false);
+	}
+	transaction.commit().await?;
+	Ok(true)
+}
 
-pub(super) async fn add_trade_offer(executor: &Pool<Sqlite>, trade_offer: TradeOffer) {
+/// Returns the ID of the new offer, or None if `reserve` was given and the offered emojis couldn't all be locked, in which case the transaction should be rolled back.
+async fn insert_trade_offer(
+	transaction: &mut Transaction<'_, Sqlite>,
+	trade_offer: &TradeOffer,
+	counter_to: Option<i64>,
+	reserve: bool,
+) -> Result<Option<i64>, BotError> {
+	let guild_id = trade_offer.guild().get() as i64;
 	let user_id = trade_offer.offering_user().get() as i64;
 	let target_user_id = trade_offer.target_user().get() as i64;
-	let emojis = trade_offer.to_database_format();
-
-	let mut transaction = executor.begin().await.unwrap();
 	let trade_id = query!(
 		"
 		INSERT INTO
-			trade_offers (user, target_user)
+			trade_offers (guild, user, target_user, counter_to)
 		VALUES
-			(?, ?)
+			(?, ?, ?, ?)
 		",
+		guild_id,
 		user_id,
-		target_user_id
+		target_user_id,
+		counter_to
 	)
-	.execute(&mut *transaction)
-	.await
-	.unwrap()
+	.execute(&mut **transaction)
+	.await?
 	.last_insert_rowid();
-	for (emoji, count) in emojis {
+	for (emoji, count) in trade_offer.to_database_format() {
 		let emoji = emoji.as_str();
 		query!(
 			"
@@ -41,17 +75,160 @@ pub(super) async fn add_trade_offer(executor: &Pool<Sqlite>, trade_offer: TradeO
 			emoji,
 			count
 		)
-		.execute(&mut *transaction)
-		.await
-		.unwrap();
+		.execute(&mut **transaction)
+		.await?;
+	}
+	if let Some(wildcard) = trade_offer.wildcard() {
+		insert_trade_wildcard(transaction, trade_id, wildcard).await?;
+	}
+	if reserve {
+		let lock = query!(
+			"
+			INSERT INTO
+				emoji_locks (trade)
+			VALUES
+				(?)
+			",
+			trade_id
+		)
+		.execute(&mut **transaction)
+		.await?
+		.last_insert_rowid();
+		if !lock_emojis(
+			transaction,
+			trade_offer.guild(),
+			trade_offer.offering_user(),
+			lock,
+			trade_offer.offer(),
+		)
+		.await?
+		{
+			return Ok(None);
+		}
+	}
+	Ok(Some(trade_id))
+}
+
+async fn insert_trade_wildcard(
+	transaction: &mut Transaction<'_, Sqlite>,
+	trade: i64,
+	wildcard: &Wildcard,
+) -> Result<(), BotError> {
+	let kind = wildcard.key();
+	let category = match wildcard.kind() {
+		WildcardKind::Category(category) => Some(category.name()),
+		_ => None,
+	};
+	let count = wildcard.count();
+	query!(
+		"
+		INSERT INTO
+			trade_offer_wildcards (trade, kind, category, count)
+		VALUES
+			(?, ?, ?, ?)
+		",
+		trade,
+		kind,
+		category,
+		count
+	)
+	.execute(&mut **transaction)
+	.await?;
+	if let WildcardKind::AnyOf(emojis) = wildcard.kind() {
+		for emoji in emojis {
+			let emoji = emoji.as_str();
+			query!(
+				"
+				INSERT INTO
+					trade_offer_wildcard_emojis (trade, emoji)
+				VALUES
+					(?, ?)
+				",
+				trade,
+				emoji
+			)
+			.execute(&mut **transaction)
+			.await?;
+		}
+	}
+	Ok(())
+}
+
+/// Swaps the original offer for the counter-offer, which keeps a link to it. With `reserve`, the counter-offer locks its emojis like `add_trade_offer`.
+///
+/// Returns false, and changes nothing, if the original offer is gone or no longer what it was, or the counter-offer's emojis couldn't all be locked.
+pub(super) async fn replace_with_counter_offer(
+	executor: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	original: &TradeOffer,
+	counter_offer: &TradeOffer,
+	reserve: bool,
+) -> Result<bool, BotError> {
+	let guild_id = original.guild().get() as i64;
+	let user_id = original.offering_user().get() as i64;
+	let target_user_id = original.target_user().get() as i64;
+	let mut transaction = executor.begin().await?;
+	let Some(original_id) = query_scalar!(
+		"
+		SELECT
+			id
+		FROM
+			trade_offers
+		WHERE
+			guild = ? AND user = ? AND target_user = ?
+		",
+		guild_id,
+		user_id,
+		target_user_id
+	)
+	.fetch_optional(&mut *transaction)
+	.await?
+	else {
+		return Ok(false);
+	};
+	let emojis = get_trade_emojis(&mut transaction, emoji_map, original_id).await?;
+	let wildcard = get_trade_wildcard(&mut transaction, emoji_map, original_id).await?;
+	let current = TradeOffer::from_database(
+		original.guild(),
+		original.offering_user(),
+		original.target_user(),
+		emojis,
+		wildcard,
+	);
+	if &current != original {
+		return Ok(false);
 	}
-	transaction.commit().await.unwrap();
+	if insert_trade_offer(&mut transaction, counter_offer, Some(original_id), reserve)
+		.await?
+		.is_none()
+	{
+		return Ok(false);
+	}
+	query!(
+		"
+		DELETE FROM
+			trade_offers
+		WHERE
+			id = ?
+		",
+		original_id
+	)
+	.execute(&mut *transaction)
+	.await?;
+	transaction.commit().await?;
+	Ok(true)
 }
 
-pub(super) async fn remove_trade_offer<'c, E>(executor: E, user: UserId, target_user: UserId)
+pub(super) async fn remove_trade_offer<'c, E>(
+	executor: E,
+	guild: GuildId,
+	user: UserId,
+	target_user: UserId,
+) -> Result<(), BotError>
 where
 	E: Executor<'c, Database = Sqlite>,
 {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let target_user_id = target_user.get() as i64;
 	query!(
@@ -59,21 +236,22 @@ where
 		DELETE FROM
 			trade_offers
 		WHERE
-			user = ? AND target_user = ?
+			guild = ? AND user = ? AND target_user = ?
 		",
+		guild_id,
 		user_id,
 		target_user_id
 	)
 	.execute(executor)
-	.await
-	.unwrap();
+	.await?;
+	Ok(())
 }
 
 pub(super) async fn get_trade_emojis(
 	executor: &mut Transaction<'_, Sqlite>,
 	emoji_map: &EmojiMap,
 	trade: i64,
-) -> Vec<(Emoji, i64)> {
+) -> Result<Vec<(Emoji, i64)>, BotError> {
 	query!(
 		"
 		SELECT
@@ -86,90 +264,163 @@ pub(super) async fn get_trade_emojis(
 		trade
 	)
 	.fetch_all(&mut **executor)
-	.await
-	.unwrap()
+	.await?
 	.into_iter()
-	.map(|record| {
-		let emoji = emoji_map
-			.get(record.emoji.as_str())
-			.expect("Could not find emoji from database in emoji map.");
-		(emoji, record.count)
-	})
-	.collect::<Vec<_>>()
+	.map(|record| Ok((emoji_map.get_from_database(&record.emoji)?, record.count)))
+	.collect()
+}
+
+async fn get_trade_wildcard(
+	executor: &mut Transaction<'_, Sqlite>,
+	emoji_map: &EmojiMap,
+	trade: i64,
+) -> Result<Option<Wildcard>, BotError> {
+	let Some(record) = query!(
+		"
+		SELECT
+			kind, category, count
+		FROM
+			trade_offer_wildcards
+		WHERE
+			trade = ?
+		",
+		trade
+	)
+	.fetch_optional(&mut **executor)
+	.await?
+	else {
+		return Ok(None);
+	};
+	let kind = match (record.kind.as_str(), record.category.as_deref()) {
+		("any_of", _) => {
+			let emojis = query_scalar!(
+				"
+				SELECT
+					emoji
+				FROM
+					trade_offer_wildcard_emojis
+				WHERE
+					trade = ?
+				",
+				trade
+			)
+			.fetch_all(&mut **executor)
+			.await?
+			.into_iter()
+			.map(|emoji| emoji_map.get_from_database(&emoji))
+			.collect::<Result<_, _>>()?;
+			WildcardKind::AnyOf(emojis)
+		}
+		("category", Some(category)) => WildcardKind::Category(
+			Category::ALL
+				.into_iter()
+				.find(|other| other.name() == category)
+				.ok_or_else(|| {
+					sqlx::Error::Protocol(format!("unknown wildcard category {category}"))
+				})?,
+		),
+		("duplicates", _) => WildcardKind::Duplicates,
+		(kind, _) => {
+			return Err(sqlx::Error::Protocol(format!("unknown wildcard kind {kind}")).into())
+		}
+	};
+	Ok(Some(Wildcard::new(kind, record.count as u32)))
 }
 
+/// Each with when it was made, in UTC, and whether it counters an earlier offer.
 pub(super) async fn get_outgoing_trade_offers(
 	executor: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	user: UserId,
-) -> Vec<TradeOffer> {
+) -> Result<Vec<(TradeOffer, NaiveDateTime, bool)>, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
-	let mut transaction = executor.begin().await.unwrap();
+	let mut transaction = executor.begin().await?;
 	let offers = query!(
 		"
 		SELECT
-			id, target_user
+			id, target_user, time, counter_to
 		FROM
 			trade_offers
 		WHERE
-			user = ?
+			guild = ? AND user = ?
 		",
+		guild_id,
 		user_id
 	)
 	.fetch_all(&mut *transaction)
-	.await
-	.unwrap();
+	.await?;
 	let mut full_offers = Vec::new();
 	for record in offers {
-		let emojis = get_trade_emojis(&mut transaction, emoji_map, record.id).await;
-		let offer = TradeOffer::from_database(user, UserId::new(record.target_user as u64), emojis);
-		full_offers.push(offer);
+		let emojis = get_trade_emojis(&mut transaction, emoji_map, record.id).await?;
+		let wildcard = get_trade_wildcard(&mut transaction, emoji_map, record.id).await?;
+		let offer = TradeOffer::from_database(
+			guild,
+			user,
+			UserId::new(record.target_user as u64),
+			emojis,
+			wildcard,
+		);
+		full_offers.push((offer, record.time, record.counter_to.is_some()));
 	}
-	transaction.commit().await.unwrap();
-	full_offers
+	transaction.commit().await?;
+	Ok(full_offers)
 }
 
+/// Each with when it was made, in UTC, and whether it counters an earlier offer.
 pub(super) async fn get_incoming_trade_offers(
 	executor: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	user: UserId,
-) -> Vec<TradeOffer> {
+) -> Result<Vec<(TradeOffer, NaiveDateTime, bool)>, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
-	let mut transaction = executor.begin().await.unwrap();
+	let mut transaction = executor.begin().await?;
 	let offers = query!(
 		"
 		SELECT
-			id, user
+			id, user, time, counter_to
 		FROM
 			trade_offers
 		WHERE
-			target_user = ?
+			guild = ? AND target_user = ?
 		",
+		guild_id,
 		user_id
 	)
 	.fetch_all(&mut *transaction)
-	.await
-	.unwrap();
+	.await?;
 	let mut full_offers = Vec::new();
 	for record in offers {
-		let emojis = get_trade_emojis(&mut transaction, emoji_map, record.id).await;
-		let offer = TradeOffer::from_database(UserId::new(record.user as u64), user, emojis);
-		full_offers.push(offer);
+		let emojis = get_trade_emojis(&mut transaction, emoji_map, record.id).await?;
+		let wildcard = get_trade_wildcard(&mut transaction, emoji_map, record.id).await?;
+		let offer = TradeOffer::from_database(
+			guild,
+			UserId::new(record.user as u64),
+			user,
+			emojis,
+			wildcard,
+		);
+		full_offers.push((offer, record.time, record.counter_to.is_some()));
 	}
-	transaction.commit().await.unwrap();
-	full_offers
+	transaction.commit().await?;
+	Ok(full_offers)
 }
 
 // This one could easily be done in one query, but it would be more of a hassle with the other `get_trade_emojis` callers, and I think it's good to use that same function everywhere, even if it may be slightly less optimized.
 pub(super) async fn get_trade_offer(
 	executor: &Pool<Sqlite>,
 	emoji_map: &EmojiMap,
+	guild: GuildId,
 	offering_user: UserId,
 	target_user: UserId,
-) -> Option<TradeOffer> {
+) -> Result<Option<TradeOffer>, BotError> {
+	let guild_id = guild.get() as i64;
 	let offering_user_id = offering_user.get() as i64;
 	let target_user_id = target_user.get() as i64;
-	let mut transaction = executor.begin().await.unwrap();
+	let mut transaction = executor.begin().await?;
 	let offer = query!(
 		"
 		SELECT
@@ -177,103 +428,206 @@ pub(super) async fn get_trade_offer(
 		FROM
 			trade_offers
 		WHERE
-			user = ? AND target_user = ?
+			guild = ? AND user = ? AND target_user = ?
 		",
+		guild_id,
 		offering_user_id,
 		target_user_id
 	)
 	.fetch_optional(&mut *transaction)
-	.await
-	.unwrap()?;
-	let emojis = get_trade_emojis(&mut transaction, emoji_map, offer.id).await;
-	transaction.commit().await.unwrap();
-	Some(TradeOffer::from_database(
+	.await?;
+	let Some(offer) = offer else {
+		return Ok(None);
+	};
+	let emojis = get_trade_emojis(&mut transaction, emoji_map, offer.id).await?;
+	let wildcard = get_trade_wildcard(&mut transaction, emoji_map, offer.id).await?;
+	transaction.commit().await?;
+	Ok(Some(TradeOffer::from_database(
+		guild,
 		offering_user,
 		target_user,
 		emojis,
-	))
+		wildcard,
+	)))
 }
 
 pub(super) async fn does_trade_offer_exist(
 	executor: &Pool<Sqlite>,
+	guild: GuildId,
 	user: UserId,
 	target_user: UserId,
-) -> bool {
+) -> Result<bool, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
 	let target_user_id = target_user.get() as i64;
-	query!(
+	let count = query!(
 		"
 		SELECT
 			COUNT() as count
 		FROM
 			trade_offers
 		WHERE
-			user = ? AND target_user = ?
+			guild = ? AND user = ? AND target_user = ?
 		",
+		guild_id,
 		user_id,
 		target_user_id
 	)
 	.fetch_one(executor)
-	.await
-	.unwrap()
-	.count != 0
+	.await?
+	.count;
+	Ok(count != 0)
 }
 
-pub(super) async fn complete_trade(executor: &Pool<Sqlite>, trade_offer: &TradeOffer) {
-	let mut transaction = executor.begin().await.unwrap();
+/// Whether the offering user still has the emojis for their side of the offer, counting the ones the offer reserved.
+pub(super) async fn are_offered_emojis_available(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	offering_user: UserId,
+	target_user: UserId,
+) -> Result<bool, BotError> {
+	let guild_id = guild.get() as i64;
+	let offering_user_id = offering_user.get() as i64;
+	let target_user_id = target_user.get() as i64;
+	// Offered emojis have negative counts.
+	let lacking = query_scalar!(
+		"
+		SELECT
+			COUNT(*)
+		FROM
+			trade_offers
+		JOIN
+			trade_offer_contents AS contents ON contents.trade = trade_offers.id
+		WHERE
+			trade_offers.guild = ?1 AND trade_offers.user = ?2 AND trade_offers.target_user = ?3 AND -contents.count > (
+				SELECT COUNT(*)
+				FROM emoji_inventory
+				WHERE guild = ?1 AND user = ?2 AND emoji = contents.emoji AND (lock IS NULL OR lock IN (
+					SELECT id
+					FROM emoji_locks
+					WHERE trade = trade_offers.id
+				))
+			)
+		",
+		guild_id,
+		offering_user_id,
+		target_user_id
+	)
+	.fetch_one(executor)
+	.await?;
+	Ok(lacking == 0)
+}
 
-	log_trade(&mut transaction, trade_offer).await;
+pub(super) async fn complete_trade(
+	executor: &Pool<Sqlite>,
+	trade_offer: &TradeOffer,
+) -> Result<(), BotError> {
+	let mut transaction = executor.begin().await?;
 
 	remove_trade_offer(
 		&mut *transaction,
+		trade_offer.guild(),
 		trade_offer.offering_user(),
 		trade_offer.target_user(),
 	)
-	.await;
+	.await?;
+	exchange(&mut transaction, trade_offer).await?;
+
+	transaction.commit().await?;
+	Ok(())
+}
+
+/// Logs the trade and moves both sides of it.
+async fn exchange(
+	transaction: &mut Transaction<'_, Sqlite>,
+	trade_offer: &TradeOffer,
+) -> Result<(), BotError> {
+	log_trade(transaction, trade_offer).await?;
 
 	for (emoji, count) in trade_offer.offer() {
 		transfer_emoji(
-			&mut transaction,
+			transaction,
+			trade_offer.guild(),
 			*emoji,
 			*count,
 			trade_offer.offering_user(),
 			trade_offer.target_user(),
 		)
-		.await;
+		.await?;
 	}
 	for (emoji, count) in trade_offer.request() {
 		transfer_emoji(
-			&mut transaction,
+			transaction,
+			trade_offer.guild(),
 			*emoji,
 			*count,
 			trade_offer.target_user(),
 			trade_offer.offering_user(),
 		)
-		.await;
+		.await?;
 	}
 
-	remove_empty_groups(&mut transaction, trade_offer.offering_user()).await;
-	remove_empty_groups(&mut transaction, trade_offer.target_user()).await;
+	remove_empty_groups(
+		transaction,
+		trade_offer.guild(),
+		trade_offer.offering_user(),
+	)
+	.await?;
+	remove_empty_groups(transaction, trade_offer.guild(), trade_offer.target_user()).await?;
+	Ok(())
+}
 
-	transaction.commit().await.unwrap();
+/// Like `EmojisWithCounts::are_available_to_user`, but within a transaction that is about to move them.
+async fn has_emojis(
+	transaction: &mut Transaction<'_, Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	emojis: &EmojisWithCounts,
+) -> Result<bool, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	for (emoji, count) in emojis {
+		let emoji = emoji.as_str();
+		let owned = query!(
+			"
+			SELECT COUNT(*) AS count
+			FROM emoji_inventory
+			WHERE guild = ? AND user = ? AND emoji = ? AND lock IS NULL
+			",
+			guild_id,
+			user_id,
+			emoji
+		)
+		.fetch_one(&mut **transaction)
+		.await?
+		.count;
+		if owned < *count as i64 {
+			return Ok(false);
+		}
+	}
+	Ok(true)
 }
 
-pub(super) async fn log_trade(executor: &mut Transaction<'_, Sqlite>, trade_offer: &TradeOffer) {
+pub(super) async fn log_trade(
+	executor: &mut Transaction<'_, Sqlite>,
+	trade_offer: &TradeOffer,
+) -> Result<(), BotError> {
+	let guild_id = trade_offer.guild().get() as i64;
 	let offering_user_id = trade_offer.offering_user().get() as i64;
 	let target_user = trade_offer.target_user().get() as i64;
 	let id = query!(
 		"
 		INSERT INTO
-			trade_log (initiating_user, recipient_user)
+			trade_log (guild, initiating_user, recipient_user)
 		VALUES
-			(?, ?)
+			(?, ?, ?)
 		",
+		guild_id,
 		offering_user_id,
 		target_user,
 	)
 	.execute(&mut **executor)
-	.await
-	.unwrap()
+	.await?
 	.last_insert_rowid();
 	for (emoji, count) in trade_offer.to_database_format() {
 		let emoji = emoji.as_str();
@@ -289,18 +643,132 @@ pub(super) async fn log_trade(executor: &mut Transaction<'_, Sqlite>, trade_offe
 			count
 		)
 		.execute(&mut **executor)
-		.await
-		.unwrap();
+		.await?;
+	}
+	Ok(())
+}
+
+/// Moves every gift around the circle and logs the trade, all at once.
+///
+/// If someone no longer has what they give, nothing changes and they are returned.
+pub(super) async fn complete_circle_trade(
+	executor: &Pool<Sqlite>,
+	trade: &CircleTrade,
+) -> Result<Option<UserId>, BotError> {
+	let mut transaction = executor.begin().await?;
+
+	for (giver, _, emojis) in trade.gifts() {
+		if !has_emojis(&mut transaction, trade.guild(), giver, emojis).await? {
+			return Ok(Some(giver));
+		}
+	}
+
+	log_circle_trade(&mut transaction, trade).await?;
+	for (giver, recipient, emojis) in trade.gifts() {
+		for (emoji, count) in emojis {
+			transfer_emoji(
+				&mut transaction,
+				trade.guild(),
+				*emoji,
+				*count,
+				giver,
+				recipient,
+			)
+			.await?;
+		}
+	}
+	for user in trade.participants() {
+		remove_empty_groups(&mut transaction, trade.guild(), user).await?;
+	}
+
+	transaction.commit().await?;
+	Ok(None)
+}
+
+/// The contents are from the proposing user's side, like for a trade between two users, and every gift goes in the participants table.
+async fn log_circle_trade(
+	executor: &mut Transaction<'_, Sqlite>,
+	trade: &CircleTrade,
+) -> Result<(), BotError> {
+	let guild_id = trade.guild().get() as i64;
+	let proposer = trade.proposer();
+	let (_, first_recipient, given) = trade
+		.gifts()
+		.next()
+		.ok_or(BotError::InvalidInteraction("empty circle trade"))?;
+	let proposer_id = proposer.get() as i64;
+	let first_recipient_id = first_recipient.get() as i64;
+	let id = query!(
+		"
+		INSERT INTO
+			trade_log (guild, initiating_user, recipient_user)
+		VALUES
+			(?, ?, ?)
+		",
+		guild_id,
+		proposer_id,
+		first_recipient_id,
+	)
+	.execute(&mut **executor)
+	.await?
+	.last_insert_rowid();
+	let gotten = trade
+		.gotten_by(proposer)
+		.iter()
+		.map(|(emoji, count)| (*emoji, *count as i64));
+	let given = given
+		.iter()
+		.map(|(emoji, count)| (*emoji, -(*count as i64)));
+	for (emoji, count) in gotten.chain(given) {
+		let emoji = emoji.as_str();
+		query!(
+			"
+			INSERT INTO
+				trade_log_contents (trade, emoji, count)
+			VALUES
+				(?, ?, ?)
+			",
+			id,
+			emoji,
+			count
+		)
+		.execute(&mut **executor)
+		.await?;
+	}
+	for (giver, recipient, emojis) in trade.gifts() {
+		let giver_id = giver.get() as i64;
+		let recipient_id = recipient.get() as i64;
+		for (emoji, count) in emojis {
+			let emoji = emoji.as_str();
+			query!(
+				"
+				INSERT INTO
+					trade_log_participants (trade, user, recipient_user, emoji, count)
+				VALUES
+					(?, ?, ?, ?, ?)
+				",
+				id,
+				giver_id,
+				recipient_id,
+				emoji,
+				count
+			)
+			.execute(&mut **executor)
+			.await?;
+		}
 	}
+	Ok(())
 }
 
 async fn transfer_emoji(
 	transaction: &mut Transaction<'_, Sqlite>,
+	guild: GuildId,
 	emoji: Emoji,
 	count: u32,
 	from: UserId,
 	to: UserId,
-) {
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
 	let emoji = emoji.as_str();
 	let from_id = from.get() as i64;
 	let to_id = to.get() as i64;
@@ -308,42 +776,44 @@ async fn transfer_emoji(
 		"
 		UPDATE emoji_inventory
 		SET user = ?, group_id = NULL
-		WHERE user = ? AND rowid IN (
+		WHERE guild = ? AND user = ? AND rowid IN (
 			SELECT emoji_inventory.rowid
 			FROM emoji_inventory
 			LEFT JOIN emoji_inventory_groups
 			ON emoji_inventory.group_id = emoji_inventory_groups.id
-			WHERE emoji_inventory.user = ? AND emoji_inventory.emoji = ?
+			WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ? AND emoji_inventory.emoji = ? AND lock IS NULL
 			ORDER BY sort_order DESC
 			LIMIT ?
 		)
 		",
 		to_id,
+		guild_id,
 		from_id,
+		guild_id,
 		from_id,
 		emoji,
 		count,
 	)
 	.execute(&mut **transaction)
-	.await
-	.unwrap();
+	.await?;
+	Ok(())
 }
 
-/// Removes trade offers where the offering user no longer has the emojis to complete their end of the trade.
+/// Removes trade offers and market listings where the offering user no longer has the emojis to complete their end of the trade.
 ///
-/// To be run after a trade completes.
+/// To be run after a trade completes, for the users who took part in it.
 ///
 // This could probably all be a single query but I don't know how to write it.
 pub(super) async fn remove_invalidated_trade_offers(
 	executor: &Pool<Sqlite>,
-	trade_offer: &TradeOffer,
-) {
-	let user_one = trade_offer.offering_user().get() as i64;
-	let user_two = trade_offer.target_user().get() as i64;
+	guild: GuildId,
+	users: &[UserId],
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
 
-	let mut transaction = executor.begin().await.unwrap();
+	let mut transaction = executor.begin().await?;
 
-	for user in [user_one, user_two] {
+	for user in users.iter().map(|user| user.get() as i64) {
 		// User ID 0 stands for no user, or the game itself, or something. It doesn't need to be processed.
 		if user == 0 {
 			continue;
@@ -352,13 +822,13 @@ pub(super) async fn remove_invalidated_trade_offers(
 			"
 			SELECT id
 			FROM trade_offers
-			WHERE user = ?
+			WHERE guild = ? AND user = ?
 		",
+			guild_id,
 			user
 		)
 		.fetch_all(&mut *transaction)
-		.await
-		.unwrap();
+		.await?;
 		for trade_offer in trade_offers {
 			let trade_id = trade_offer.id;
 			let emojis = query!(
@@ -370,24 +840,30 @@ pub(super) async fn remove_invalidated_trade_offers(
 				trade_id
 			)
 			.fetch_all(&mut *transaction)
-			.await
-			.unwrap();
+			.await?;
 			for emoji_record in emojis {
 				let emoji = emoji_record.emoji;
+				// What the offer reserved itself still backs it.
 				let count = query!(
 					"
 					SELECT COUNT(*) as count
 					FROM emoji_inventory
-					WHERE user = ? AND emoji = ?
+					WHERE guild = ? AND user = ? AND emoji = ? AND (lock IS NULL OR lock IN (
+						SELECT id
+						FROM emoji_locks
+						WHERE trade = ?
+					))
 					",
+					guild_id,
 					user,
-					emoji
+					emoji,
+					trade_id
 				)
 				.fetch_one(&mut *transaction)
-				.await
-				.unwrap()
+				.await?
 				.count;
-				if count < emoji_record.count {
+				// Offered emojis have negative counts.
+				if count < -emoji_record.count {
 					query!(
 						"
 						DELETE FROM trade_offers
@@ -396,12 +872,897 @@ pub(super) async fn remove_invalidated_trade_offers(
 						trade_id
 					)
 					.execute(&mut *transaction)
-					.await
-					.unwrap();
+					.await?;
 				}
 			}
 		}
+		query!(
+			"
+			DELETE FROM market_listings
+			WHERE guild = ?1 AND user = ?2 AND id IN (
+				SELECT listing
+				FROM market_listing_contents AS contents
+				WHERE -contents.count > (
+					SELECT COUNT(*)
+					FROM emoji_inventory
+					WHERE guild = ?1 AND user = ?2 AND emoji = contents.emoji AND lock IS NULL
+				)
+			)
+			",
+			guild_id,
+			user
+		)
+		.execute(&mut *transaction)
+		.await?;
 	}
 
-	transaction.commit().await.unwrap();
+	transaction.commit().await?;
+	Ok(())
+}
+
+pub(super) async fn count_outgoing_trade_offers(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+) -> Result<usize, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let count = query!(
+		"
+		SELECT
+			COUNT() as count
+		FROM
+			trade_offers
+		WHERE
+			guild = ? AND user = ?
+		",
+		guild_id,
+		user_id
+	)
+	.fetch_one(executor)
+	.await?
+	.count;
+	Ok(count as usize)
+}
+
+/// Also removes any trade offers from the blocked user to the user, in every guild.
+pub(super) async fn block_user(
+	executor: &Pool<Sqlite>,
+	user: UserId,
+	blocked_user: UserId,
+) -> Result<(), BotError> {
+	let user_id = user.get() as i64;
+	let blocked_user_id = blocked_user.get() as i64;
+	let mut transaction = executor.begin().await?;
+	query!(
+		"
+		INSERT OR IGNORE INTO
+			blocked_users (user, blocked_user)
+		VALUES
+			(?, ?)
+		",
+		user_id,
+		blocked_user_id
+	)
+	.execute(&mut *transaction)
+	.await?;
+	query!(
+		"
+		DELETE FROM
+			trade_offers
+		WHERE
+			user = ? AND target_user = ?
+		",
+		blocked_user_id,
+		user_id
+	)
+	.execute(&mut *transaction)
+	.await?;
+	transaction.commit().await?;
+	Ok(())
+}
+
+/// Returns false if the user wasn't blocked.
+pub(super) async fn unblock_user(
+	executor: &Pool<Sqlite>,
+	user: UserId,
+	blocked_user: UserId,
+) -> Result<bool, BotError> {
+	let user_id = user.get() as i64;
+	let blocked_user_id = blocked_user.get() as i64;
+	let result = query!(
+		"
+		DELETE FROM
+			blocked_users
+		WHERE
+			user = ? AND blocked_user = ?
+		",
+		user_id,
+		blocked_user_id
+	)
+	.execute(executor)
+	.await?;
+	Ok(result.rows_affected() > 0)
+}
+
+pub(super) async fn is_blocked(
+	executor: &Pool<Sqlite>,
+	user: UserId,
+	blocked_user: UserId,
+) -> Result<bool, BotError> {
+	let user_id = user.get() as i64;
+	let blocked_user_id = blocked_user.get() as i64;
+	let count = query!(
+		"
+		SELECT
+			COUNT() as count
+		FROM
+			blocked_users
+		WHERE
+			user = ? AND blocked_user = ?
+		",
+		user_id,
+		blocked_user_id
+	)
+	.fetch_one(executor)
+	.await?
+	.count;
+	Ok(count != 0)
+}
+
+pub(super) async fn get_blocked_users(
+	executor: &Pool<Sqlite>,
+	user: UserId,
+) -> Result<Vec<UserId>, BotError> {
+	let user_id = user.get() as i64;
+	Ok(query!(
+		"
+		SELECT
+			blocked_user
+		FROM
+			blocked_users
+		WHERE
+			user = ?
+		",
+		user_id
+	)
+	.fetch_all(executor)
+	.await?
+	.into_iter()
+	.map(|record| UserId::new(record.blocked_user as u64))
+	.collect())
+}
+
+/// Remembers when the target user last rejected an offer from the user.
+pub(super) async fn record_rejection(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	target_user: UserId,
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let target_user_id = target_user.get() as i64;
+	query!(
+		"
+		INSERT OR REPLACE INTO
+			trade_rejections (guild, user, target_user)
+		VALUES
+			(?, ?, ?)
+		",
+		guild_id,
+		user_id,
+		target_user_id
+	)
+	.execute(executor)
+	.await?;
+	Ok(())
+}
+
+/// When the target user last rejected an offer from the user, in UTC.
+pub(super) async fn get_last_rejection(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	target_user: UserId,
+) -> Result<Option<NaiveDateTime>, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let target_user_id = target_user.get() as i64;
+	Ok(query!(
+		"
+		SELECT
+			time
+		FROM
+			trade_rejections
+		WHERE
+			guild = ? AND user = ? AND target_user = ?
+		",
+		guild_id,
+		user_id,
+		target_user_id
+	)
+	.fetch_optional(executor)
+	.await?
+	.map(|record| record.time))
+}
+
+pub(super) async fn get_guilds_with_trade_offers(
+	executor: &Pool<Sqlite>,
+) -> Result<Vec<GuildId>, BotError> {
+	Ok(query!(
+		"
+		SELECT DISTINCT
+			guild
+		FROM
+			trade_offers
+		"
+	)
+	.fetch_all(executor)
+	.await?
+	.into_iter()
+	.map(|record| GuildId::new(record.guild as u64))
+	.collect())
+}
+
+/// Removes the guild's trade offers made before the cutoff, in UTC, and returns who made each of them to whom.
+pub(super) async fn remove_expired_trade_offers(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	cutoff: NaiveDateTime,
+) -> Result<Vec<(UserId, UserId)>, BotError> {
+	let guild_id = guild.get() as i64;
+	Ok(query!(
+		"
+		DELETE FROM
+			trade_offers
+		WHERE
+			guild = ? AND time < ?
+		RETURNING
+			user, target_user
+		",
+		guild_id,
+		cutoff
+	)
+	.fetch_all(executor)
+	.await?
+	.into_iter()
+	.map(|record| {
+		(
+			UserId::new(record.user as u64),
+			UserId::new(record.target_user as u64),
+		)
+	})
+	.collect())
+}
+
+/// Returns the ID of the new listing.
+pub(super) async fn add_listing(
+	executor: &Pool<Sqlite>,
+	listing: &Listing,
+) -> Result<i64, BotError> {
+	let guild_id = listing.guild().get() as i64;
+	let user_id = listing.user().get() as i64;
+	let any_one = listing.any_one();
+	let mut transaction = executor.begin().await?;
+	let listing_id = query!(
+		"
+		INSERT INTO
+			market_listings (guild, user, any_one)
+		VALUES
+			(?, ?, ?)
+		",
+		guild_id,
+		user_id,
+		any_one
+	)
+	.execute(&mut *transaction)
+	.await?
+	.last_insert_rowid();
+	for (emoji, count) in listing.to_database_format() {
+		let emoji = emoji.as_str();
+		query!(
+			"
+			INSERT INTO
+				market_listing_contents (listing, emoji, count)
+			VALUES
+				(?, ?, ?)
+			",
+			listing_id,
+			emoji,
+			count
+		)
+		.execute(&mut *transaction)
+		.await?;
+	}
+	transaction.commit().await?;
+	Ok(listing_id)
+}
+
+async fn get_listing_emojis(
+	executor: &mut Transaction<'_, Sqlite>,
+	emoji_map: &EmojiMap,
+	listing: i64,
+) -> Result<Vec<(Emoji, i64)>, BotError> {
+	query!(
+		"
+		SELECT
+			emoji, count
+		FROM
+			market_listing_contents
+		WHERE
+			listing = ?
+		",
+		listing
+	)
+	.fetch_all(&mut **executor)
+	.await?
+	.into_iter()
+	.map(|record| Ok((emoji_map.get_from_database(&record.emoji)?, record.count)))
+	.collect()
+}
+
+pub(super) async fn get_listing(
+	executor: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	guild: GuildId,
+	listing: i64,
+) -> Result<Option<Listing>, BotError> {
+	let guild_id = guild.get() as i64;
+	let mut transaction = executor.begin().await?;
+	let Some(record) = query!(
+		"
+		SELECT
+			user, any_one
+		FROM
+			market_listings
+		WHERE
+			guild = ? AND id = ?
+		",
+		guild_id,
+		listing
+	)
+	.fetch_optional(&mut *transaction)
+	.await?
+	else {
+		return Ok(None);
+	};
+	let emojis = get_listing_emojis(&mut transaction, emoji_map, listing).await?;
+	transaction.commit().await?;
+	Ok(Some(Listing::from_database(
+		guild,
+		UserId::new(record.user as u64),
+		record.any_one != 0,
+		emojis,
+	)))
+}
+
+/// The newest listings first, each with its ID. With an emoji, only listings that give or ask for it.
+pub(super) async fn get_listings(
+	executor: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	guild: GuildId,
+	emoji: Option<Emoji>,
+	limit: i64,
+) -> Result<Vec<(i64, Listing)>, BotError> {
+	let guild_id = guild.get() as i64;
+	let emoji = emoji.map(|emoji| emoji.as_str());
+	let mut transaction = executor.begin().await?;
+	let records = query!(
+		"
+		SELECT
+			id, user, any_one
+		FROM
+			market_listings
+		WHERE
+			guild = ?1 AND (?2 IS NULL OR id IN (
+				SELECT listing
+				FROM market_listing_contents
+				WHERE emoji = ?2
+			))
+		ORDER BY
+			id DESC
+		LIMIT ?3
+		",
+		guild_id,
+		emoji,
+		limit
+	)
+	.fetch_all(&mut *transaction)
+	.await?;
+	let mut listings = Vec::new();
+	for record in records {
+		let emojis = get_listing_emojis(&mut transaction, emoji_map, record.id).await?;
+		let listing = Listing::from_database(
+			guild,
+			UserId::new(record.user as u64),
+			record.any_one != 0,
+			emojis,
+		);
+		listings.push((record.id, listing));
+	}
+	transaction.commit().await?;
+	Ok(listings)
+}
+
+/// Returns false if the user has no such listing.
+pub(super) async fn remove_listing(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	listing: i64,
+) -> Result<bool, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let result = query!(
+		"
+		DELETE FROM
+			market_listings
+		WHERE
+			guild = ? AND user = ? AND id = ?
+		",
+		guild_id,
+		user_id,
+		listing
+	)
+	.execute(executor)
+	.await?;
+	Ok(result.rows_affected() > 0)
+}
+
+/// Removes the listing and makes the trade that fills it, all at once.
+///
+/// Returns false, and changes nothing, if the listing is gone or either user no longer has their side of the trade.
+pub(super) async fn complete_listing(
+	executor: &Pool<Sqlite>,
+	listing: i64,
+	trade: &TradeOffer,
+) -> Result<bool, BotError> {
+	let mut transaction = executor.begin().await?;
+	let removed = query!(
+		"
+		DELETE FROM
+			market_listings
+		WHERE
+			id = ?
+		",
+		listing
+	)
+	.execute(&mut *transaction)
+	.await?
+	.rows_affected();
+	if removed == 0
+		|| !has_emojis(
+			&mut transaction,
+			trade.guild(),
+			trade.offering_user(),
+			trade.offer(),
+		)
+		.await?
+		|| !has_emojis(
+			&mut transaction,
+			trade.guild(),
+			trade.target_user(),
+			trade.request(),
+		)
+		.await?
+	{
+		return Ok(false);
+	}
+	exchange(&mut transaction, trade).await?;
+	transaction.commit().await?;
+	Ok(true)
+}
+
+/// Locks as many of the user's unlocked emojis as the lock holds back from anything else.
+///
+/// Returns false if the user doesn't have enough of them unlocked. The transaction should then be rolled back, since some may have been locked.
+async fn lock_emojis(
+	transaction: &mut Transaction<'_, Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	lock: i64,
+	emojis: &EmojisWithCounts,
+) -> Result<bool, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	for (emoji, count) in emojis {
+		let emoji = emoji.as_str();
+		let locked = query!(
+			"
+			UPDATE emoji_inventory
+			SET lock = ?
+			WHERE rowid IN (
+				SELECT emoji_inventory.rowid
+				FROM emoji_inventory
+				LEFT JOIN emoji_inventory_groups
+				ON emoji_inventory.group_id = emoji_inventory_groups.id
+				WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ? AND emoji_inventory.emoji = ? AND lock IS NULL
+				ORDER BY sort_order DESC
+				LIMIT ?
+			)
+			",
+			lock,
+			guild_id,
+			user_id,
+			emoji,
+			count
+		)
+		.execute(&mut **transaction)
+		.await?
+		.rows_affected();
+		if locked < *count as u64 {
+			return Ok(false);
+		}
+	}
+	Ok(true)
+}
+
+/// What is held back by a lock.
+async fn get_locked_emojis(
+	transaction: &mut Transaction<'_, Sqlite>,
+	emoji_map: &EmojiMap,
+	lock: i64,
+) -> Result<EmojisWithCounts, BotError> {
+	let emojis = query!(
+		"
+		SELECT
+			emoji, COUNT(*) AS count
+		FROM
+			emoji_inventory
+		WHERE
+			lock = ?
+		GROUP BY
+			emoji
+		",
+		lock
+	)
+	.fetch_all(&mut **transaction)
+	.await?
+	.into_iter()
+	.map(|record| {
+		Ok((
+			emoji_map.get_from_database(&record.emoji)?,
+			record.count as u32,
+		))
+	})
+	.collect::<Result<Vec<_>, BotError>>()?;
+	Ok(EmojisWithCounts::from_iter(emojis))
+}
+
+/// Puts the emojis up for auction until it ends, and locks them. Returns the ID of the new auction, or None if the user doesn't have the emojis unlocked.
+pub(super) async fn add_auction(
+	executor: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	lot: &EmojisWithCounts,
+	ends: NaiveDateTime,
+) -> Result<Option<i64>, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let mut transaction = executor.begin().await?;
+	let auction = query!(
+		"
+		INSERT INTO
+			auctions (guild, user, ends)
+		VALUES
+			(?, ?, ?)
+		",
+		guild_id,
+		user_id,
+		ends
+	)
+	.execute(&mut *transaction)
+	.await?
+	.last_insert_rowid();
+	let lock = query!(
+		"
+		INSERT INTO
+			emoji_locks (auction)
+		VALUES
+			(?)
+		",
+		auction
+	)
+	.execute(&mut *transaction)
+	.await?
+	.last_insert_rowid();
+	if !lock_emojis(&mut transaction, guild, user, lock, lot).await? {
+		return Ok(None);
+	}
+	transaction.commit().await?;
+	Ok(Some(auction))
+}
+
+/// Replaces the user's bid on the auction, if they had one, and locks the emojis in the new one.
+///
+/// Returns false, and changes nothing, if the user doesn't have the emojis unlocked once their old bid is released.
+pub(super) async fn place_bid(
+	executor: &Pool<Sqlite>,
+	auction: &Auction,
+	user: UserId,
+	emojis: &EmojisWithCounts,
+) -> Result<bool, BotError> {
+	let auction_id = auction.id();
+	let user_id = user.get() as i64;
+	let mut transaction = executor.begin().await?;
+	query!(
+		"
+		DELETE FROM
+			auction_bids
+		WHERE
+			auction = ? AND user = ?
+		",
+		auction_id,
+		user_id
+	)
+	.execute(&mut *transaction)
+	.await?;
+	let bid = query!(
+		"
+		INSERT INTO
+			auction_bids (auction, user)
+		VALUES
+			(?, ?)
+		",
+		auction_id,
+		user_id
+	)
+	.execute(&mut *transaction)
+	.await?
+	.last_insert_rowid();
+	let lock = query!(
+		"
+		INSERT INTO
+			emoji_locks (bid)
+		VALUES
+			(?)
+		",
+		bid
+	)
+	.execute(&mut *transaction)
+	.await?
+	.last_insert_rowid();
+	if !lock_emojis(&mut transaction, auction.guild(), user, lock, emojis).await? {
+		return Ok(false);
+	}
+	transaction.commit().await?;
+	Ok(true)
+}
+
+/// Takes back the user's bid and releases its emojis. Returns false if they had no bid on the auction.
+pub(super) async fn withdraw_bid(
+	executor: &Pool<Sqlite>,
+	auction: i64,
+	user: UserId,
+) -> Result<bool, BotError> {
+	let user_id = user.get() as i64;
+	let result = query!(
+		"
+		DELETE FROM
+			auction_bids
+		WHERE
+			auction = ? AND user = ?
+		",
+		auction,
+		user_id
+	)
+	.execute(executor)
+	.await?;
+	Ok(result.rows_affected() > 0)
+}
+
+async fn load_auction(
+	transaction: &mut Transaction<'_, Sqlite>,
+	emoji_map: &EmojiMap,
+	guild: GuildId,
+	auction: i64,
+) -> Result<Option<Auction>, BotError> {
+	let guild_id = guild.get() as i64;
+	let Some(record) = query!(
+		"
+		SELECT
+			auctions.user, ends, emoji_locks.id AS lock
+		FROM
+			auctions
+		JOIN
+			emoji_locks ON emoji_locks.auction = auctions.id
+		WHERE
+			guild = ? AND auctions.id = ?
+		",
+		guild_id,
+		auction
+	)
+	.fetch_optional(&mut **transaction)
+	.await?
+	else {
+		return Ok(None);
+	};
+	let lot = get_locked_emojis(transaction, emoji_map, record.lock).await?;
+	let bid_records = query!(
+		"
+		SELECT
+			auction_bids.user, emoji_locks.id AS lock
+		FROM
+			auction_bids
+		JOIN
+			emoji_locks ON emoji_locks.bid = auction_bids.id
+		WHERE
+			auction_bids.auction = ?
+		ORDER BY
+			auction_bids.id
+		",
+		auction
+	)
+	.fetch_all(&mut **transaction)
+	.await?;
+	let mut bids = Vec::new();
+	for bid in bid_records {
+		bids.push(Bid {
+			user: UserId::new(bid.user as u64),
+			emojis: get_locked_emojis(transaction, emoji_map, bid.lock).await?,
+		});
+	}
+	Ok(Some(Auction::from_database(
+		auction,
+		guild,
+		UserId::new(record.user as u64),
+		lot,
+		record.ends,
+		bids,
+	)))
+}
+
+pub(super) async fn get_auction(
+	executor: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	guild: GuildId,
+	auction: i64,
+) -> Result<Option<Auction>, BotError> {
+	let mut transaction = executor.begin().await?;
+	let auction = load_auction(&mut transaction, emoji_map, guild, auction).await?;
+	transaction.commit().await?;
+	Ok(auction)
+}
+
+/// The guild's auctions that are still running, the ones closing soonest first.
+pub(super) async fn get_auctions(
+	executor: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	guild: GuildId,
+	now: NaiveDateTime,
+) -> Result<Vec<Auction>, BotError> {
+	let guild_id = guild.get() as i64;
+	let mut transaction = executor.begin().await?;
+	let ids = query_scalar!(
+		"
+		SELECT
+			id
+		FROM
+			auctions
+		WHERE
+			guild = ? AND ends > ?
+		ORDER BY
+			ends, id
+		",
+		guild_id,
+		now
+	)
+	.fetch_all(&mut *transaction)
+	.await?;
+	let mut auctions = Vec::new();
+	for id in ids {
+		if let Some(auction) = load_auction(&mut transaction, emoji_map, guild, id).await? {
+			auctions.push(auction);
+		}
+	}
+	transaction.commit().await?;
+	Ok(auctions)
+}
+
+/// Auctions in every guild that should have closed by now.
+pub(super) async fn get_ended_auctions(
+	executor: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	now: NaiveDateTime,
+) -> Result<Vec<Auction>, BotError> {
+	let mut transaction = executor.begin().await?;
+	let records = query!(
+		"
+		SELECT
+			id, guild
+		FROM
+			auctions
+		WHERE
+			ends <= ?
+		",
+		now
+	)
+	.fetch_all(&mut *transaction)
+	.await?;
+	let mut auctions = Vec::new();
+	for record in records {
+		let guild = GuildId::new(record.guild as u64);
+		if let Some(auction) = load_auction(&mut transaction, emoji_map, guild, record.id).await? {
+			auctions.push(auction);
+		}
+	}
+	transaction.commit().await?;
+	Ok(auctions)
+}
+
+/// Ends the auction and releases everything it held in escrow, then sells the lot for the winning bid, if there is one, all at once.
+///
+/// Returns false, and changes nothing, if the auction was already closed, or the winning bid was withdrawn or changed since the auction was loaded.
+pub(super) async fn close_auction(
+	executor: &Pool<Sqlite>,
+	emoji_map: &EmojiMap,
+	auction: &Auction,
+	winning_bid: Option<&Bid>,
+) -> Result<bool, BotError> {
+	let auction_id = auction.id();
+	let mut transaction = executor.begin().await?;
+	if let Some(bid) = winning_bid {
+		let user_id = bid.user.get() as i64;
+		let Some(lock) = query_scalar!(
+			"
+			SELECT
+				emoji_locks.id
+			FROM
+				auction_bids
+			JOIN
+				emoji_locks ON emoji_locks.bid = auction_bids.id
+			WHERE
+				auction_bids.auction = ? AND auction_bids.user = ?
+			",
+			auction_id,
+			user_id
+		)
+		.fetch_optional(&mut *transaction)
+		.await?
+		else {
+			return Ok(false);
+		};
+		if get_locked_emojis(&mut transaction, emoji_map, lock).await? != bid.emojis {
+			return Ok(false);
+		}
+	}
+	let removed = query!(
+		"
+		DELETE FROM
+			auctions
+		WHERE
+			id = ?
+		",
+		auction_id
+	)
+	.execute(&mut *transaction)
+	.await?
+	.rows_affected();
+	if removed == 0 {
+		return Ok(false);
+	}
+	if let Some(bid) = winning_bid {
+		let trade = auction.trade_with(bid)?;
+		// Deleting the auction released the lot and the bid, so both should be there to move.
+		if !has_emojis(
+			&mut transaction,
+			trade.guild(),
+			trade.offering_user(),
+			trade.offer(),
+		)
+		.await? || !has_emojis(
+			&mut transaction,
+			trade.guild(),
+			trade.target_user(),
+			trade.request(),
+		)
+		.await?
+		{
+			return Ok(false);
+		}
+		exchange(&mut transaction, &trade).await?;
+	}
+	transaction.commit().await?;
+	Ok(true)
 }
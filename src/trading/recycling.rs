@@ -1,128 +1,190 @@
 use serenity::{
-	all::{CommandInteraction, CommandOptionType, UserId},
+	all::{CommandInteraction, CommandOptionType, GuildId, UserId},
+	async_trait,
 	builder::{CreateCommand, CreateCommandOption},
 };
 use sqlx::{query, Pool, Sqlite};
 
 use crate::{
+	autocomplete,
+	commands::SlashCommand,
 	context::Context,
+	discord::Choice,
 	emoji::Emoji,
 	emojis_with_counts::EmojisWithCounts,
+	error::BotError,
 	inventory::queries::remove_empty_groups,
+	pity::{drop_table_for, record_drop, PityTable},
 	queries::give_emoji,
 	trading::{queries::log_trade, trade_offer::TradeOffer},
-	user_settings::private::is_private,
+	user_settings::privacy::get_privacy_settings,
 	util::{parse_emoji_input, ReplyShortcuts},
 };
 
 use super::queries::remove_invalidated_trade_offers;
 
-async fn recycle(database: &Pool<Sqlite>, user: UserId, emojis: EmojisWithCounts) -> Emoji {
+async fn recycle(
+	database: &Pool<Sqlite>,
+	drop_table: &PityTable<'_>,
+	guild: GuildId,
+	user: UserId,
+	emojis: EmojisWithCounts,
+) -> Result<Emoji, BotError> {
+	let guild_id = guild.get() as i64;
 	let user_id = user.get() as i64;
-	let trade_offer = TradeOffer::new_recycling(user, emojis);
+	let trade_offer = TradeOffer::new_recycling(guild, user, emojis, drop_table);
 	let random_emoji = trade_offer.recycling_emoji();
 
-	let mut transaction = database.begin().await.unwrap();
+	let mut transaction = database.begin().await?;
 
-	log_trade(&mut transaction, &trade_offer).await;
+	log_trade(&mut transaction, &trade_offer).await?;
 
 	for (emoji, count) in trade_offer.offer() {
 		let emoji_str = emoji.as_str();
 		let rows_affected = query!(
 			"
 			DELETE FROM emoji_inventory
-			WHERE user = ? AND emoji = ? AND rowid IN (
+			WHERE guild = ? AND user = ? AND emoji = ? AND rowid IN (
 				SELECT emoji_inventory.rowid
 				FROM emoji_inventory
 				LEFT JOIN emoji_inventory_groups
 				ON emoji_inventory.group_id = emoji_inventory_groups.id
-				WHERE emoji_inventory.user = ? AND emoji_inventory.emoji = ?
+				WHERE emoji_inventory.guild = ? AND emoji_inventory.user = ? AND emoji_inventory.emoji = ? AND lock IS NULL
 				ORDER BY sort_order DESC
 				LIMIT ?
 			)
 			",
+			guild_id,
 			user_id,
 			emoji_str,
+			guild_id,
 			user_id,
 			emoji_str,
 			count
 		)
 		.execute(&mut *transaction)
-		.await
-		.unwrap()
+		.await?
 		.rows_affected();
 
+		// The transaction is rolled back when it is dropped.
 		if rows_affected != *count as u64 {
-			transaction.rollback().await.unwrap();
-			panic!("Wrong number of rows affected on a recycle: {rows_affected} != {count}.");
+			return Err(BotError::Database(sqlx::Error::Protocol(format!(
+				"Wrong number of rows affected on a recycle: {rows_affected} != {count}."
+			))));
 		}
 	}
 
-	give_emoji(&mut *transaction, user, random_emoji).await;
+	give_emoji(&mut *transaction, guild, user, random_emoji).await?;
+	record_drop(
+		&mut *transaction,
+		guild,
+		user,
+		drop_table.owns(random_emoji),
+	)
+	.await?;
 
-	remove_empty_groups(&mut transaction, user).await;
+	remove_empty_groups(&mut transaction, guild, user).await?;
 
-	transaction.commit().await.unwrap();
+	transaction.commit().await?;
 
-	remove_invalidated_trade_offers(database, &trade_offer).await;
+	remove_invalidated_trade_offers(database, guild, &[user]).await?;
 
-	random_emoji
+	Ok(random_emoji)
 }
 
-pub async fn execute(context: Context<'_>, interaction: CommandInteraction) {
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
 	let input = interaction
 		.data
 		.options
 		.first()
 		.and_then(|option| option.value.as_str())
-		.unwrap();
-	let emojis = match parse_emoji_input(context.emoji_map, input) {
-		Ok(emojis) => emojis,
-		Err(message) => {
-			let _ = interaction.ephemeral_reply(context.http, message).await;
-			return;
-		}
-	};
-	if emojis.len() != 3 {
-		let _ = interaction
-			.ephemeral_reply(context.http, "You must specify exactly 3 emojis.")
-			.await;
-		return;
+		.ok_or(BotError::InvalidInteraction("emojis option missing"))?;
+	let emojis = parse_emoji_input(context.emoji_map, input)?;
+	let recycle_count = context.guild_config().recycle_count;
+	if emojis.len() != recycle_count {
+		return Err(format!("You must specify exactly {recycle_count} emojis.").into());
 	}
 	let emojis = EmojisWithCounts::from_flat(&emojis);
 	if !emojis
-		.are_owned_by_user(context.database, interaction.user.id)
-		.await
+		.are_available_to_user(context.database, context.guild, interaction.user.id)
+		.await?
 	{
-		let _ = interaction
-			.ephemeral_reply(context.http, "You don't own all specified emojis.")
-			.await;
-		return;
+		return Err("You don't own all specified emojis, or some of them are locked in trade offers or escrow.".into());
 	}
 
-	let emoji = recycle(context.database, interaction.user.id, emojis.clone()).await;
-
-	if is_private(context.database, interaction.user.id).await {
-		let message = format!("You recycled {emojis} and got {emoji}.");
-		let _ = interaction.ephemeral_reply(context.http, message).await;
-	} else {
-		let name = context
-			.get_user_name(interaction.guild_id.unwrap(), interaction.user.id)
-			.await;
+	let drop_table = drop_table_for(
+		context.database,
+		context.guild_config(),
+		context.guild,
+		interaction.user.id,
+	)
+	.await?;
+	let emoji = recycle(
+		context.database,
+		&drop_table,
+		context.guild,
+		interaction.user.id,
+		emojis.clone(),
+	)
+	.await?;
+
+	if get_privacy_settings(context.database, interaction.user.id)
+		.await?
+		.public_recycling
+	{
+		let name = context.get_user_name(interaction.user.id).await;
 		let message = format!("{name} recycled {emojis} and got {emoji}.");
-		let _ = interaction.public_reply(context.http, message).await;
+		interaction.public_reply(context.discord, message).await?;
+	} else {
+		let message = format!("You recycled {emojis} and got {emoji}.");
+		interaction
+			.ephemeral_reply(context.discord, message)
+			.await?;
 	}
+	Ok(())
 }
 
-pub fn register() -> CreateCommand {
+fn register() -> CreateCommand {
 	CreateCommand::new("recycle")
-		.description("Recycle 3 emojis for a new one.")
+		.description("Recycle emojis for a new one.")
 		.add_option(
 			CreateCommandOption::new(
 				CommandOptionType::String,
 				"emojis",
-				"The 3 emojis to recycle.",
+				"The emojis to recycle.",
 			)
-			.required(true),
+			.required(true)
+			.set_autocomplete(true),
 		)
 }
+
+pub struct RecycleCommand;
+
+#[async_trait]
+impl SlashCommand for RecycleCommand {
+	fn name(&self) -> &'static str {
+		"recycle"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+	async fn autocomplete(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<Vec<Choice>, BotError> {
+		let input = interaction
+			.data
+			.autocomplete()
+			.ok_or(BotError::InvalidInteraction("no option to autocomplete"))?
+			.value;
+		autocomplete::owned_emojis(context, interaction.user.id, input).await
+	}
+}
@@ -1,73 +1,124 @@
 use serenity::{
 	all::{CommandDataOptionValue, CommandInteraction, CommandOptionType},
+	async_trait,
 	builder::{CreateCommand, CreateCommandOption},
-	gateway::ShardMessenger,
 };
 
-use crate::{context::Context, util::ReplyShortcuts};
+use crate::{
+	autocomplete, commands::SlashCommand, context::Context, discord::Choice, emoji_list::Category,
+	error::BotError, util::ReplyShortcuts,
+};
 
-use super::{try_accept_offer, try_cancel_offer, try_offer_trade, try_reject_offer, view_offers};
+use super::{
+	try_accept_offer, try_block_user, try_cancel_offer, try_circle_trade, try_counter_offer,
+	try_offer_trade, try_reject_offer, try_unblock_user, view_offers, CIRCLE_POSITIONS,
+};
 
-pub async fn execute(
-	context: Context<'_>,
-	shard_messenger: ShardMessenger,
-	mut interaction: CommandInteraction,
-) {
-	let subcommand = interaction.data.options.pop().unwrap();
-	let CommandDataOptionValue::SubCommand(options) = subcommand.value else {
-		panic!()
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let subcommand = interaction
+		.data
+		.options
+		.first()
+		.ok_or(BotError::InvalidInteraction("subcommand missing"))?;
+	let CommandDataOptionValue::SubCommand(options) = &subcommand.value else {
+		return Err(BotError::InvalidInteraction("expected a subcommand"));
+	};
+	let argument_user = || {
+		options
+			.first()
+			.and_then(|option| option.value.as_user_id())
+			.ok_or(BotError::InvalidInteraction("user option missing"))
 	};
-	let argument_user = options.get(0).and_then(|option| option.value.as_user_id());
 	let user = interaction.user.id;
-	let guild = interaction.guild_id.unwrap();
 
 	let mut ephemeral = false;
-	let result = match subcommand.name.as_str() {
-		"offer" => try_offer_trade(context, options, guild, user, argument_user.unwrap()).await,
-		"withdraw" => try_cancel_offer(context, guild, user, argument_user.unwrap()).await,
+	let message = match subcommand.name.as_str() {
+		"offer" => try_offer_trade(context, options, user, argument_user()?).await?,
+		"counter" => try_counter_offer(context, options, user, argument_user()?).await?,
+		"withdraw" => try_cancel_offer(context, user, argument_user()?).await?,
 		"accept" => {
-			let result = try_accept_offer(
-				context,
-				shard_messenger,
-				&interaction,
-				guild,
-				user,
-				argument_user.unwrap(),
-			)
-			.await;
-			if let Err(result) = result {
-				Err(result)
-			} else {
-				return;
-			}
+			return try_accept_offer(context, interaction, user, argument_user()?).await;
+		}
+		"circle" => {
+			return try_circle_trade(context, interaction, options, user).await;
+		}
+		"reject" => try_reject_offer(context, user, argument_user()?).await?,
+		"block" => {
+			ephemeral = true;
+			try_block_user(context, user, argument_user()?).await?
+		}
+		"unblock" => {
+			ephemeral = true;
+			try_unblock_user(context, user, argument_user()?).await?
 		}
-		"reject" => try_reject_offer(context, guild, user, argument_user.unwrap()).await,
 		"view" => {
 			ephemeral = true;
-			view_offers(context, guild, user).await
+			view_offers(context, user).await?
 		}
-		_ => panic!("Received an invalid interaction subcommand."),
+		_ => return Err(BotError::InvalidInteraction("unknown subcommand")),
 	};
-	let _ = match result {
-		Ok(message) => interaction.reply(context.http, message, ephemeral).await,
-		Err(error) => interaction.ephemeral_reply(context.http, error).await,
+	interaction
+		.reply(context.discord, message, ephemeral)
+		.await?;
+	Ok(())
+}
+
+/// Offers are finished from the caller's emojis, requests and wildcards from the emojis of the user the offer is to, and gifts in a circle from the emojis of whoever gives them.
+async fn autocomplete(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+) -> Result<Vec<Choice>, BotError> {
+	let option = interaction
+		.data
+		.autocomplete()
+		.ok_or(BotError::InvalidInteraction("no option to autocomplete"))?;
+	let Some(CommandDataOptionValue::SubCommand(options)) =
+		interaction.data.options.first().map(|option| &option.value)
+	else {
+		return Err(BotError::InvalidInteraction("expected a subcommand"));
 	};
+	match option.name {
+		"offer" | "gives" => {
+			autocomplete::owned_emojis(context, interaction.user.id, option.value).await
+		}
+		"request" | "any_of" => {
+			// Until the user is picked, there is nothing to suggest.
+			let Some(target) = options.iter().find_map(|option| option.value.as_user_id()) else {
+				return Ok(Vec::new());
+			};
+			autocomplete::owned_emojis(context, target, option.value).await
+		}
+		name => {
+			let Some(position) = name.strip_suffix("_gives") else {
+				return Ok(Vec::new());
+			};
+			let Some(giver) = options
+				.iter()
+				.find(|option| option.name == position)
+				.and_then(|option| option.value.as_user_id())
+			else {
+				return Ok(Vec::new());
+			};
+			autocomplete::owned_emojis(context, giver, option.value).await
+		}
+	}
 }
 
-pub fn register() -> CreateCommand {
+fn register() -> CreateCommand {
 	CreateCommand::new("trade")
-		.description("Make, withdraw, accept or reject a trade offer, or view trade offers.")
+		.description("Make, answer or view trade offers, trade around a circle, or block users.")
+		.add_option(register_offer())
 		.add_option(
 			CreateCommandOption::new(
 				CommandOptionType::SubCommand,
-				"offer",
-				"Offer a trade to a user.",
+				"counter",
+				"Answer a trade offer from a user with one of your own, replacing theirs.",
 			)
 			.add_sub_option(
 				CreateCommandOption::new(
 					CommandOptionType::User,
 					"user",
-					"Whom the trade offer is to.",
+					"Whose trade offer to you to counter.",
 				)
 				.required(true),
 			)
@@ -75,17 +126,17 @@ pub fn register() -> CreateCommand {
 				CreateCommandOption::new(
 					CommandOptionType::String,
 					"offer",
-					"The emojis you are offering in this trade. Repeat emojis for multiples.",
+					"The emojis you are offering instead. Leave out to keep what they asked for.",
 				)
-				.required(true),
+				.set_autocomplete(true),
 			)
 			.add_sub_option(
 				CreateCommandOption::new(
 					CommandOptionType::String,
 					"request",
-					"The emojis requested in this trade. Repeat emojis for multiples.",
+					"The emojis you want instead. Leave out to keep what they offered.",
 				)
-				.required(true),
+				.set_autocomplete(true),
 			),
 		)
 		.add_option(
@@ -133,9 +184,636 @@ pub fn register() -> CreateCommand {
 				.required(true),
 			),
 		)
+		.add_option(register_circle())
 		.add_option(CreateCommandOption::new(
 			CommandOptionType::SubCommand,
 			"view",
 			"View incoming and outgoing trade offers.",
 		))
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"block",
+				"Stop a user from offering you trades.",
+			)
+			.add_sub_option(
+				CreateCommandOption::new(CommandOptionType::User, "user", "Whom to block.")
+					.required(true),
+			),
+		)
+		.add_option(
+			CreateCommandOption::new(
+				CommandOptionType::SubCommand,
+				"unblock",
+				"Let a blocked user offer you trades again.",
+			)
+			.add_sub_option(
+				CreateCommandOption::new(CommandOptionType::User, "user", "Whom to unblock.")
+					.required(true),
+			),
+		)
+}
+
+/// Besides or instead of exact emojis, the request can have a wildcard of one kind, which the user the offer is to fills in when they accept it.
+fn register_offer() -> CreateCommandOption {
+	let mut category = CreateCommandOption::new(
+		CommandOptionType::String,
+		"category",
+		"Also ask for any emojis from this category.",
+	);
+	for option in Category::ALL {
+		category = category.add_string_choice(option.name(), option.name());
+	}
+	CreateCommandOption::new(
+		CommandOptionType::SubCommand,
+		"offer",
+		"Offer a trade to a user.",
+	)
+	.add_sub_option(
+		CreateCommandOption::new(
+			CommandOptionType::User,
+			"user",
+			"Whom the trade offer is to.",
+		)
+		.required(true),
+	)
+	.add_sub_option(
+		CreateCommandOption::new(
+			CommandOptionType::String,
+			"offer",
+			"The emojis you are offering in this trade. Repeat emojis for multiples.",
+		)
+		.required(true)
+		.set_autocomplete(true),
+	)
+	.add_sub_option(
+		CreateCommandOption::new(
+			CommandOptionType::String,
+			"request",
+			"The emojis requested in this trade. Repeat emojis for multiples.",
+		)
+		.set_autocomplete(true),
+	)
+	.add_sub_option(
+		CreateCommandOption::new(
+			CommandOptionType::String,
+			"any_of",
+			"Also ask for any of these emojis, letting the user choose which.",
+		)
+		.set_autocomplete(true),
+	)
+	.add_sub_option(category)
+	.add_sub_option(CreateCommandOption::new(
+		CommandOptionType::Boolean,
+		"duplicates",
+		"Also ask for any emojis the user has more than one of.",
+	))
+	.add_sub_option(
+		CreateCommandOption::new(
+			CommandOptionType::Integer,
+			"wildcard_count",
+			"How many emojis the user chooses for `any_of`, `category` or `duplicates`. One if left out.",
+		)
+		.min_int_value(1),
+	)
+}
+
+/// The first two users after the caller are required, since a circle takes at least three.
+fn register_circle() -> CreateCommandOption {
+	let mut circle = CreateCommandOption::new(
+		CommandOptionType::SubCommand,
+		"circle",
+		"Propose a trade where everyone gives the next user emojis, and the last user gives you emojis.",
+	)
+	.add_sub_option(
+		CreateCommandOption::new(
+			CommandOptionType::String,
+			"gives",
+			"The emojis you give the second user. Repeat emojis for multiples.",
+		)
+		.required(true)
+		.set_autocomplete(true),
+	);
+	for (index, position) in CIRCLE_POSITIONS.into_iter().enumerate() {
+		let required = index < 2;
+		circle = circle
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::User,
+					position,
+					format!("The {position} user in the circle."),
+				)
+				.required(required),
+			)
+			.add_sub_option(
+				CreateCommandOption::new(
+					CommandOptionType::String,
+					format!("{position}_gives"),
+					format!("The emojis the {position} user gives the next user, or you if they are last."),
+				)
+				.required(required)
+				.set_autocomplete(true),
+			);
+	}
+	circle
+}
+
+pub struct TradeCommand;
+
+#[async_trait]
+impl SlashCommand for TradeCommand {
+	fn name(&self) -> &'static str {
+		"trade"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+	async fn autocomplete(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<Vec<Choice>, BotError> {
+		autocomplete(context, interaction).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::{
+		all::{RoleId, UserId},
+		json::json,
+	};
+	use sqlx::query_scalar;
+
+	use crate::{
+		config::Config,
+		discord::fake::{FakeDiscord, Sent},
+		emoji::EmojiMap,
+		queries::give_emoji,
+		testing::{self, GUILD, TRADER},
+	};
+
+	use super::*;
+
+	/// Runs the `/trade` subcommand on the target user, with the emoji options given by name.
+	async fn trade(
+		context: Context<'_>,
+		user: UserId,
+		subcommand: &str,
+		target: UserId,
+		emojis: &[(&str, &str)],
+	) -> String {
+		let mut options = vec![json!({ "name": "user", "type": 6, "value": target.to_string() })];
+		for (name, value) in emojis {
+			options.push(json!({ "name": name, "type": 3, "value": value }));
+		}
+		testing::run_subcommand(context, &TradeCommand, user, subcommand, options.into()).await
+	}
+
+	#[tokio::test]
+	async fn offered_trade_can_be_accepted() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let trader = [RoleId::new(TRADER)];
+		let (alice, bob) = (UserId::new(1), UserId::new(2));
+		let discord = FakeDiscord::new()
+			.with_member(alice, "Alice", &trader)
+			.with_member(bob, "Bob", &trader);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		give_emoji(&database, GUILD, alice, emoji_map.get("🙂").unwrap())
+			.await
+			.unwrap();
+		give_emoji(&database, GUILD, bob, emoji_map.get("🙃").unwrap())
+			.await
+			.unwrap();
+
+		let offer = testing::command_interaction(
+			alice,
+			"trade",
+			json!([{
+				"name": "offer",
+				"type": 1,
+				"options": [
+					{ "name": "user", "type": 6, "value": bob.to_string() },
+					{ "name": "offer", "type": 3, "value": "🙂" },
+					{ "name": "request", "type": 3, "value": "🙃" },
+				],
+			}]),
+		);
+		execute(context, &offer).await.unwrap();
+		discord.press_button(Some("yes"));
+		let accept = testing::command_interaction(
+			bob,
+			"trade",
+			json!([{
+				"name": "accept",
+				"type": 1,
+				"options": [{ "name": "user", "type": 6, "value": alice.to_string() }],
+			}]),
+		);
+		execute(context, &accept).await.unwrap();
+
+		let sent = discord.take_sent();
+		assert_eq!(sent.len(), 4, "{sent:?}");
+		assert!(matches!(
+			&sent[0],
+			Sent::Reply {
+				ephemeral: false,
+				..
+			}
+		));
+		assert!(
+			matches!(&sent[1], Sent::Buttons { buttons, .. } if buttons == &["yes", "no", "counter"])
+		);
+		assert!(matches!(
+			&sent[2],
+			Sent::ButtonReply {
+				ephemeral: false,
+				..
+			}
+		));
+		assert_eq!(sent[3], Sent::DeletedReply);
+
+		assert_eq!(testing::inventory(&database, alice).await, ["🙃"]);
+		assert_eq!(testing::inventory(&database, bob).await, ["🙂"]);
+		let trades: Vec<(i64, i64)> =
+			sqlx::query_as("SELECT initiating_user, recipient_user FROM trade_log")
+				.fetch_all(&database)
+				.await
+				.unwrap();
+		assert_eq!(trades, [(1, 2)]);
+		let offers: i64 = query_scalar("SELECT COUNT(*) FROM trade_offers")
+			.fetch_one(&database)
+			.await
+			.unwrap();
+		assert_eq!(offers, 0);
+	}
+
+	#[tokio::test]
+	async fn counter_button_replaces_the_offer() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let trader = [RoleId::new(TRADER)];
+		let (alice, bob) = (UserId::new(1), UserId::new(2));
+		let discord = FakeDiscord::new()
+			.with_member(alice, "Alice", &trader)
+			.with_member(bob, "Bob", &trader);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		for (user, emoji) in [(alice, "🙂"), (bob, "🙃"), (bob, "😀")] {
+			give_emoji(&database, GUILD, user, emoji_map.get(emoji).unwrap())
+				.await
+				.unwrap();
+		}
+
+		let offer = [("offer", "🙂"), ("request", "🙃")];
+		assert_eq!(trade(context, alice, "offer", bob, &offer).await, "");
+		discord.press_button(Some("counter"));
+		discord.submit_modal(Some(&[("offer", "😀"), ("request", "🙂")]));
+		assert_eq!(trade(context, bob, "accept", alice, &[]).await, "");
+
+		let sent = discord.take_sent();
+		assert!(
+			matches!(
+				&sent[..],
+				[
+					Sent::Reply { .. },
+					Sent::Buttons { .. },
+					Sent::Modal { inputs, .. },
+					Sent::ModalReply { content, ephemeral: false },
+					Sent::DeletedReply,
+				] if inputs == &[("offer", String::from("🙃")), ("request", String::from("🙂"))]
+					&& content.starts_with("You countered Alice's offer")
+			),
+			"{sent:?}"
+		);
+		let offers: Vec<(i64, i64, Option<i64>)> =
+			sqlx::query_as("SELECT user, target_user, counter_to FROM trade_offers")
+				.fetch_all(&database)
+				.await
+				.unwrap();
+		assert!(matches!(offers[..], [(2, 1, Some(_))]), "{offers:?}");
+		let contents: Vec<(String, i64)> =
+			sqlx::query_as("SELECT emoji, count FROM trade_offer_contents ORDER BY count")
+				.fetch_all(&database)
+				.await
+				.unwrap();
+		assert_eq!(
+			contents,
+			[(String::from("😀"), -1), (String::from("🙂"), 1)]
+		);
+	}
+
+	#[tokio::test]
+	async fn requests_are_completed_from_the_other_users_emojis() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let discord = FakeDiscord::new();
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		let (alice, bob) = (UserId::new(1), UserId::new(2));
+		give_emoji(&database, GUILD, alice, emoji_map.get("🙂").unwrap())
+			.await
+			.unwrap();
+		for _ in 0..2 {
+			give_emoji(&database, GUILD, bob, emoji_map.get("🙃").unwrap())
+				.await
+				.unwrap();
+		}
+
+		let typing = testing::command_interaction(
+			alice,
+			"trade",
+			json!([{
+				"name": "offer",
+				"type": 1,
+				"options": [
+					{ "name": "user", "type": 6, "value": bob.to_string() },
+					{ "name": "offer", "type": 3, "value": "🙂" },
+					{ "name": "request", "type": 3, "value": "face", "focused": true },
+				],
+			}]),
+		);
+		let choices = TradeCommand.autocomplete(context, &typing).await.unwrap();
+		assert_eq!(
+			choices,
+			[Choice {
+				name: String::from("🙃 upside-down face ×2"),
+				value: String::from("🙃"),
+			}]
+		);
+	}
+
+	#[tokio::test]
+	async fn offers_are_limited() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = Config::parse(
+			&format!("discord_token = \"test\"\n[guilds.{GUILD}]\nmax_outgoing_offers = 1"),
+			None,
+		)
+		.unwrap();
+		let discord = FakeDiscord::new();
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		let (alice, bob, carol) = (UserId::new(1), UserId::new(2), UserId::new(3));
+		give_emoji(&database, GUILD, alice, emoji_map.get("🙂").unwrap())
+			.await
+			.unwrap();
+		let offer = [("offer", "🙂"), ("request", "🙃")];
+
+		assert_eq!(trade(context, alice, "offer", bob, &offer).await, "");
+		assert!(trade(context, alice, "offer", carol, &offer)
+			.await
+			.contains("most you can have"));
+		assert_eq!(trade(context, bob, "reject", alice, &[]).await, "");
+		assert!(trade(context, alice, "offer", bob, &offer)
+			.await
+			.contains("in 1 hour"));
+		assert_eq!(trade(context, alice, "offer", carol, &offer).await, "");
+		assert_eq!(trade(context, carol, "block", alice, &[]).await, "");
+		assert!(trade(context, alice, "offer", carol, &offer)
+			.await
+			.contains("from you"));
+		let offers: i64 = query_scalar("SELECT COUNT(*) FROM trade_offers")
+			.fetch_one(&database)
+			.await
+			.unwrap();
+		assert_eq!(offers, 0);
+	}
+
+	#[tokio::test]
+	async fn reserved_emojis_cannot_be_offered_twice() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = Config::parse(
+			&format!("discord_token = \"test\"\n[guilds.{GUILD}]\nreserve_offered_emojis = true"),
+			None,
+		)
+		.unwrap();
+		let discord = FakeDiscord::new();
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		let (alice, bob, carol) = (UserId::new(1), UserId::new(2), UserId::new(3));
+		give_emoji(&database, GUILD, alice, emoji_map.get("🙂").unwrap())
+			.await
+			.unwrap();
+		let offer = [("offer", "🙂"), ("request", "🙃")];
+		let locked = || {
+			query_scalar::<_, i64>("SELECT COUNT(*) FROM emoji_inventory WHERE lock IS NOT NULL")
+				.fetch_one(&database)
+		};
+
+		assert_eq!(trade(context, alice, "offer", bob, &offer).await, "");
+		assert_eq!(locked().await.unwrap(), 1);
+		assert_eq!(
+			trade(context, alice, "offer", carol, &offer).await,
+			"You have 1 🙂, but only 0 of them are not locked in other trade offers or escrow."
+		);
+		assert_eq!(trade(context, bob, "reject", alice, &[]).await, "");
+		assert_eq!(locked().await.unwrap(), 0);
+		assert_eq!(trade(context, alice, "offer", carol, &offer).await, "");
+		assert_eq!(locked().await.unwrap(), 1);
+	}
+
+	#[tokio::test]
+	async fn counter_offer_replaces_the_original() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let discord = FakeDiscord::new();
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		let (alice, bob) = (UserId::new(1), UserId::new(2));
+		give_emoji(&database, GUILD, alice, emoji_map.get("🙂").unwrap())
+			.await
+			.unwrap();
+		for emoji in ["🙃", "😀"] {
+			give_emoji(&database, GUILD, bob, emoji_map.get(emoji).unwrap())
+				.await
+				.unwrap();
+		}
+
+		assert!(trade(context, bob, "counter", alice, &[])
+			.await
+			.contains("no trade offer"));
+		let offer = [("offer", "🙂"), ("request", "🙃")];
+		assert_eq!(trade(context, alice, "offer", bob, &offer).await, "");
+		assert!(trade(context, bob, "counter", alice, &[])
+			.await
+			.contains("turned around"));
+		assert_eq!(
+			trade(context, bob, "counter", alice, &[("offer", "😀")]).await,
+			""
+		);
+
+		let offers: Vec<(i64, i64, Option<i64>)> =
+			sqlx::query_as("SELECT user, target_user, counter_to FROM trade_offers")
+				.fetch_all(&database)
+				.await
+				.unwrap();
+		assert!(matches!(offers[..], [(2, 1, Some(_))]), "{offers:?}");
+		let contents: Vec<(String, i64)> =
+			sqlx::query_as("SELECT emoji, count FROM trade_offer_contents ORDER BY count")
+				.fetch_all(&database)
+				.await
+				.unwrap();
+		assert_eq!(
+			contents,
+			[(String::from("😀"), -1), (String::from("🙂"), 1)]
+		);
+		assert!(view_offers(context, alice)
+			.await
+			.unwrap()
+			.contains("is countering with 😀 for your 🙂"));
+	}
+
+	#[tokio::test]
+	async fn circle_trade_goes_through_once_everyone_confirms() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let trader = [RoleId::new(TRADER)];
+		let (alice, bob, carol, dave) = (
+			UserId::new(1),
+			UserId::new(2),
+			UserId::new(3),
+			UserId::new(4),
+		);
+		let discord = FakeDiscord::new()
+			.with_member(alice, "Alice", &trader)
+			.with_member(bob, "Bob", &trader)
+			.with_member(carol, "Carol", &trader);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		for (user, emoji) in [(alice, "🙂"), (bob, "🙃"), (carol, "😀")] {
+			give_emoji(&database, GUILD, user, emoji_map.get(emoji).unwrap())
+				.await
+				.unwrap();
+		}
+
+		discord.press_button(Some("confirm"));
+		discord.press_button_as(dave, "decline");
+		discord.press_button_as(bob, "confirm");
+		discord.press_button_as(carol, "confirm");
+		let circle = testing::command_interaction(
+			alice,
+			"trade",
+			json!([{
+				"name": "circle",
+				"type": 1,
+				"options": [
+					{ "name": "gives", "type": 3, "value": "🙂" },
+					{ "name": "second", "type": 6, "value": bob.to_string() },
+					{ "name": "second_gives", "type": 3, "value": "🙃" },
+					{ "name": "third", "type": 6, "value": carol.to_string() },
+					{ "name": "third_gives", "type": 3, "value": "😀" },
+				],
+			}]),
+		);
+		execute(context, &circle).await.unwrap();
+
+		let sent = discord.take_sent();
+		assert!(
+			matches!(
+				&sent[..],
+				[
+					Sent::PublicButtons { .. },
+					Sent::ButtonsUpdate { .. },
+					Sent::ButtonsUpdate { .. },
+					Sent::ButtonsUpdate { content, .. },
+					Sent::ButtonUpdate { .. },
+				] if content.contains("✅ Bob gives 🙃 to Carol") && content.contains("⏳ Carol")
+			),
+			"{sent:?}"
+		);
+		assert_eq!(testing::inventory(&database, alice).await, ["😀"]);
+		assert_eq!(testing::inventory(&database, bob).await, ["🙂"]);
+		assert_eq!(testing::inventory(&database, carol).await, ["🙃"]);
+		let gifts: Vec<(i64, i64, String)> = sqlx::query_as(
+			"SELECT user, recipient_user, emoji FROM trade_log_participants ORDER BY user",
+		)
+		.fetch_all(&database)
+		.await
+		.unwrap();
+		assert_eq!(
+			gifts,
+			[
+				(1, 2, String::from("🙂")),
+				(2, 3, String::from("🙃")),
+				(3, 1, String::from("😀")),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn wildcard_is_filled_with_the_emoji_the_accepter_chooses() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let trader = [RoleId::new(TRADER)];
+		let (alice, bob) = (UserId::new(1), UserId::new(2));
+		let discord = FakeDiscord::new()
+			.with_member(alice, "Alice", &trader)
+			.with_member(bob, "Bob", &trader);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		give_emoji(&database, GUILD, alice, emoji_map.get("🙂").unwrap())
+			.await
+			.unwrap();
+		for emoji in ["🍎", "🍐", "🐶"] {
+			give_emoji(&database, GUILD, bob, emoji_map.get(emoji).unwrap())
+				.await
+				.unwrap();
+		}
+
+		let offer = testing::command_interaction(
+			alice,
+			"trade",
+			json!([{
+				"name": "offer",
+				"type": 1,
+				"options": [
+					{ "name": "user", "type": 6, "value": bob.to_string() },
+					{ "name": "offer", "type": 3, "value": "🙂" },
+					{ "name": "category", "type": 3, "value": "food" },
+				],
+			}]),
+		);
+		execute(context, &offer).await.unwrap();
+		discord.choose("wildcard", "🍐");
+		discord.press_button(Some("yes"));
+		let accept = testing::command_interaction(
+			bob,
+			"trade",
+			json!([{
+				"name": "accept",
+				"type": 1,
+				"options": [{ "name": "user", "type": 6, "value": alice.to_string() }],
+			}]),
+		);
+		execute(context, &accept).await.unwrap();
+
+		let sent = discord.take_sent();
+		assert_eq!(sent.len(), 5, "{sent:?}");
+		assert!(
+			matches!(&sent[0], Sent::Reply { content, .. } if content.contains("any 1 food emoji"))
+		);
+		assert!(matches!(&sent[1], Sent::SelectMenus { menus, .. } if menus == &["wildcard"]));
+		assert!(
+			matches!(&sent[2], Sent::ButtonsUpdate { content, .. } if content.contains("**lose** the following emoji: 🍐"))
+		);
+		assert!(matches!(&sent[3], Sent::ButtonReply { .. }));
+		assert_eq!(sent[4], Sent::DeletedReply);
+
+		assert_eq!(testing::inventory(&database, alice).await, ["🍐"]);
+		let mut bob_inventory = testing::inventory(&database, bob).await;
+		bob_inventory.sort();
+		let mut expected = vec!["🍎", "🐶", "🙂"];
+		expected.sort();
+		assert_eq!(bob_inventory, expected);
+	}
 }
@@ -1,41 +1,65 @@
-use itertools::Itertools;
-use serenity::model::prelude::UserId;
+use serenity::model::prelude::{GuildId, UserId};
 
-use crate::{emoji::Emoji, emojis_with_counts::EmojisWithCounts};
+use crate::{drop_table::DropTable, emoji::Emoji, emojis_with_counts::EmojisWithCounts};
 
-/// A trade offer from one user to another user with an offered list of emojis and a requested list of emojis, both kept sorted.
+use super::wildcard::{Wildcard, WildcardKind};
+
+/// A trade offer from one user to another user in a guild with an offered list of emojis and a requested list of emojis, both kept sorted.
+///
+/// The request can also have a wildcard, for the target user to fill in when they accept.
 ///
 /// This comes with methods for converting to and from the structure the database uses, and for outputting the emoji contents as text.
 #[derive(PartialEq, Eq)]
 pub(super) struct TradeOffer {
+	guild: GuildId,
 	offering_user: UserId,
 	target_user: UserId,
 	offer: EmojisWithCounts,
 	request: EmojisWithCounts,
+	wildcard: Option<Wildcard>,
 }
 
 impl TradeOffer {
 	/// Fails if the same emoji exists on both sides of the trade, but performs no other checks.
 	pub fn new(
+		guild: GuildId,
 		user: UserId,
 		target_user: UserId,
 		offer: EmojisWithCounts,
 		request: EmojisWithCounts,
 	) -> Result<Self, String> {
-		if offer.iter().any(|emoji| request.iter().contains(emoji)) {
+		if offer.iter().any(|(emoji, _)| request.count_of(*emoji) > 0) {
 			return Err(String::from("You put an emoji on both sides of the trade."));
 		}
 		Ok(Self {
+			guild,
 			offering_user: user,
 			target_user,
 			offer,
 			request,
+			wildcard: None,
 		})
 	}
+	/// Fails if the wildcard takes from emojis that are offered.
+	pub fn with_wildcard(mut self, wildcard: Wildcard) -> Result<Self, String> {
+		if let WildcardKind::AnyOf(emojis) = wildcard.kind() {
+			if self.offer.iter().any(|(emoji, _)| emojis.contains(emoji)) {
+				return Err(String::from("You put an emoji on both sides of the trade."));
+			}
+		}
+		self.wildcard = Some(wildcard);
+		Ok(self)
+	}
 	/// Unflattens the trade emoji information from the way the database has it.
 	///
 	/// Does no sanity checking as we trust the database.
-	pub fn from_database(user: UserId, target_user: UserId, contents: Vec<(Emoji, i64)>) -> Self {
+	pub fn from_database(
+		guild: GuildId,
+		user: UserId,
+		target_user: UserId,
+		contents: Vec<(Emoji, i64)>,
+		wildcard: Option<Wildcard>,
+	) -> Self {
 		let mut offer = Vec::new();
 		let mut request = Vec::new();
 		for (emoji, count) in contents {
@@ -48,30 +72,65 @@ impl TradeOffer {
 		let offer = EmojisWithCounts::new(offer);
 		let request = EmojisWithCounts::new(request);
 		Self {
+			guild,
 			offering_user: user,
 			target_user,
 			offer,
 			request,
+			wildcard,
 		}
 	}
-	pub fn new_recycling(user: UserId, offer: EmojisWithCounts) -> Self {
+	pub fn new_recycling(
+		guild: GuildId,
+		user: UserId,
+		offer: EmojisWithCounts,
+		drop_table: &dyn DropTable,
+	) -> Self {
 		let random_emoji = loop {
-			let random_emoji = Emoji::random();
+			let random_emoji = Emoji::random(drop_table);
 			if !offer.iter().any(|(emoji, _)| emoji == &random_emoji) {
 				break random_emoji;
 			}
 		};
 		Self {
+			guild,
 			offering_user: user,
 			target_user: UserId::new(0),
 			offer,
 			request: EmojisWithCounts::from_iter([(random_emoji, 1)]),
+			wildcard: None,
+		}
+	}
+	/// The same trade, offered the other way around. A wildcard can't be offered, so it is left out.
+	pub fn reversed(&self) -> Self {
+		Self {
+			guild: self.guild,
+			offering_user: self.target_user,
+			target_user: self.offering_user,
+			offer: self.request.clone(),
+			request: self.offer.clone(),
+			wildcard: None,
+		}
+	}
+	/// The trade with the wildcard filled in by the chosen emojis, which should be picked from its candidates.
+	pub fn fill_wildcard(&self, chosen: &[Emoji]) -> Self {
+		let request = self.request.clone().flatten();
+		Self {
+			guild: self.guild,
+			offering_user: self.offering_user,
+			target_user: self.target_user,
+			offer: self.offer.clone(),
+			request: EmojisWithCounts::from_flat(request.iter().chain(chosen)),
+			wildcard: None,
 		}
 	}
 	/// Gets the first emoji in the request, which should be the only emoji if this is a recycling request.
 	pub fn recycling_emoji(&self) -> Emoji {
 		self.request.iter().next().unwrap().0
 	}
+	pub fn guild(&self) -> GuildId {
+		self.guild
+	}
 	pub fn offering_user(&self) -> UserId {
 		self.offering_user
 	}
@@ -84,6 +143,17 @@ impl TradeOffer {
 	pub fn request(&self) -> &EmojisWithCounts {
 		&self.request
 	}
+	pub fn wildcard(&self) -> Option<&Wildcard> {
+		self.wildcard.as_ref()
+	}
+	/// The request, with its wildcard if there is one.
+	pub fn describe_request(&self) -> String {
+		match &self.wildcard {
+			Some(wildcard) if self.request.is_empty() => wildcard.to_string(),
+			Some(wildcard) => format!("{} and {wildcard}", self.request),
+			None => self.request.to_string(),
+		}
+	}
 	/// Generates a single list of emojis closer to the way the database stores it, with positive counts representing emojis the initiator will gain, and negative counts representing emojis the initiator will give away.
 	pub fn to_database_format(&self) -> Vec<(Emoji, i64)> {
 		self.request
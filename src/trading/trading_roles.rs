@@ -1,25 +1,12 @@
-use serenity::all::{GuildId, RoleId, UserId};
+use serenity::all::UserId;
 
 use crate::context::Context;
 
-pub(crate) fn get_trading_roles() -> Vec<RoleId> {
-	std::fs::read_to_string("./roles.txt")
-		.expect("Could not read roles file.")
-		.lines()
-		.map(|line| line.parse().map(RoleId::new))
-		.collect::<Result<_, _>>()
-		.expect("Could not parse roles file.")
-}
-
-pub(super) async fn has_trading_role(context: Context<'_>, guild: GuildId, user: UserId) -> bool {
-	guild
-		.member(context, user)
+pub(super) async fn has_trading_role(context: Context<'_>, user: UserId) -> bool {
+	let trading_roles = &context.guild_config().trading_roles;
+	context
+		.discord
+		.member_roles(context.guild, user)
 		.await
-		.map(|member| {
-			member
-				.roles
-				.iter()
-				.any(|role| context.trading_roles.contains(role))
-		})
-		.unwrap_or(false)
+		.is_some_and(|roles| roles.iter().any(|role| trading_roles.contains(role)))
 }
@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+use crate::{emoji::Emoji, emoji_list::Category, emojis_with_counts::EmojisWithCounts};
+
+/// What a wildcard takes emojis from.
+#[derive(PartialEq, Eq, Clone)]
+pub(super) enum WildcardKind {
+	/// Any of these emojis, kept sorted and without repeats.
+	AnyOf(Vec<Emoji>),
+	Category(Category),
+	/// Any emojis the target user has more than one of, as long as they keep one of each.
+	Duplicates,
+}
+
+/// A part of a trade offer's request that the target user fills in with emojis of their choosing when they accept it.
+#[derive(PartialEq, Eq, Clone)]
+pub(super) struct Wildcard {
+	kind: WildcardKind,
+	count: u32,
+}
+
+impl Wildcard {
+	pub fn new(kind: WildcardKind, count: u32) -> Self {
+		let kind = match kind {
+			WildcardKind::AnyOf(mut emojis) => {
+				emojis.sort_unstable();
+				emojis.dedup();
+				WildcardKind::AnyOf(emojis)
+			}
+			kind => kind,
+		};
+		Self { kind, count }
+	}
+	pub fn kind(&self) -> &WildcardKind {
+		&self.kind
+	}
+	/// How many emojis the target user picks.
+	pub fn count(&self) -> u32 {
+		self.count
+	}
+	/// How it's stored in the database.
+	pub fn key(&self) -> &'static str {
+		match self.kind {
+			WildcardKind::AnyOf(_) => "any_of",
+			WildcardKind::Category(_) => "category",
+			WildcardKind::Duplicates => "duplicates",
+		}
+	}
+	fn matches(&self, emoji: Emoji) -> bool {
+		match &self.kind {
+			WildcardKind::AnyOf(emojis) => emojis.contains(&emoji),
+			WildcardKind::Category(category) => emoji.category() == *category,
+			WildcardKind::Duplicates => true,
+		}
+	}
+	/// How many of each emoji the target user has left to pick from, out of what they own.
+	///
+	/// Emojis they get in the trade can't be picked, and what they already give in the rest of the request is held back.
+	pub fn candidates(
+		&self,
+		owned: &EmojisWithCounts,
+		offer: &EmojisWithCounts,
+		request: &EmojisWithCounts,
+	) -> EmojisWithCounts {
+		let kept = (self.kind == WildcardKind::Duplicates) as u32;
+		EmojisWithCounts::from_iter(owned.iter().filter_map(|(emoji, count)| {
+			if !self.matches(*emoji) || offer.count_of(*emoji) > 0 {
+				return None;
+			}
+			let left = count.saturating_sub(request.count_of(*emoji) + kept);
+			(left > 0).then_some((*emoji, left))
+		}))
+	}
+}
+
+impl Display for Wildcard {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let s = if self.count != 1 { "s" } else { "" };
+		match &self.kind {
+			WildcardKind::AnyOf(emojis) => {
+				if self.count == 1 {
+					f.write_str("one of ")?;
+				} else {
+					write!(f, "{} of ", self.count)?;
+				}
+				emojis
+					.iter()
+					.try_for_each(|emoji| f.write_str(emoji.as_str()))
+			}
+			WildcardKind::Category(category) => {
+				write!(f, "any {} {category} emoji{s}", self.count)
+			}
+			WildcardKind::Duplicates => write!(f, "any {} duplicate emoji{s}", self.count),
+		}
+	}
+}
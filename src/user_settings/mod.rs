@@ -1 +1,2 @@
-pub mod private;
+pub mod notifications;
+pub mod privacy;
@@ -0,0 +1,451 @@
+//! How users hear about drops and trade offers, and the digest of what they missed for those who don't hear right away.
+
+use std::fmt::Write;
+
+use serenity::{
+	all::{CommandInteraction, CommandOptionType, GuildId, UserId},
+	async_trait,
+	builder::{CreateCommand, CreateCommandOption},
+};
+use sqlx::{query, Pool, Sqlite};
+
+use crate::{
+	commands::SlashCommand, context::Context, discord::Discord, emoji::Emoji, error::BotError,
+	util::ReplyShortcuts,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notifications {
+	/// Reacting with the emojis to the message that earned them. Anything else isn't notified of.
+	Reaction,
+	/// A digest by direct message, right away.
+	DirectMessage,
+	/// A digest that only the user can see, after the next command they use.
+	NextCommand,
+	Off,
+}
+
+impl Notifications {
+	const ALL: [Self; 4] = [
+		Self::Reaction,
+		Self::DirectMessage,
+		Self::NextCommand,
+		Self::Off,
+	];
+
+	/// How it's stored in the database, and the value of its command choice.
+	fn key(self) -> &'static str {
+		match self {
+			Self::Reaction => "reaction",
+			Self::DirectMessage => "direct_message",
+			Self::NextCommand => "next_command",
+			Self::Off => "none",
+		}
+	}
+
+	fn description(self) -> &'static str {
+		match self {
+			Self::Reaction => "Reactions to your messages",
+			Self::DirectMessage => "Direct messages",
+			Self::NextCommand => "A message only you can see, after your next command",
+			Self::Off => "No notifications",
+		}
+	}
+
+	fn from_key(key: &str) -> Option<Self> {
+		Self::ALL
+			.into_iter()
+			.find(|notifications| notifications.key() == key)
+	}
+}
+
+/// Something a user gets told about.
+#[derive(Debug, Clone, Copy)]
+pub enum Notice {
+	/// An emoji they were given.
+	Emoji(Emoji),
+	/// A trade offer made to them, by this user.
+	TradeOffer(UserId),
+	/// Their trade offer to this user expired.
+	ExpiredOffer(UserId),
+	/// Their streak reached a milestone with a reward to pick.
+	StreakReward,
+}
+
+impl Notice {
+	/// How it's stored in the database: its kind, and the emoji or the other user.
+	fn to_database_format(self) -> (&'static str, Option<&'static str>, Option<i64>) {
+		match self {
+			Self::Emoji(emoji) => ("emoji", Some(emoji.as_str()), None),
+			Self::TradeOffer(user) => ("trade_offer", None, Some(user.get() as i64)),
+			Self::ExpiredOffer(user) => ("expired_offer", None, Some(user.get() as i64)),
+			Self::StreakReward => ("streak_reward", None, None),
+		}
+	}
+}
+
+/// Users who turned drop reactions off, but want to hear through them, hear after their next command instead.
+pub async fn get_notifications(
+	database: &Pool<Sqlite>,
+	user: UserId,
+) -> Result<Notifications, BotError> {
+	let user_id = user.get() as i64;
+	let Some(record) = query!(
+		"
+		SELECT drop_reactions, notifications
+		FROM user_settings
+		WHERE user = ?
+		",
+		user_id
+	)
+	.fetch_optional(database)
+	.await?
+	else {
+		return Ok(Notifications::Reaction);
+	};
+	let notifications =
+		Notifications::from_key(&record.notifications).unwrap_or(Notifications::Reaction);
+	if notifications == Notifications::Reaction && record.drop_reactions == 0 {
+		return Ok(Notifications::NextCommand);
+	}
+	Ok(notifications)
+}
+
+/// Turning notifications off also drops whatever was still waiting to be told.
+async fn set_notifications(
+	database: &Pool<Sqlite>,
+	user: UserId,
+	notifications: Notifications,
+) -> Result<(), BotError> {
+	let user_id = user.get() as i64;
+	let key = notifications.key();
+	let mut transaction = database.begin().await?;
+	query!(
+		"
+		INSERT INTO user_settings (user, notifications)
+		VALUES (?, ?)
+		ON CONFLICT (user)
+			DO UPDATE SET notifications = excluded.notifications
+		",
+		user_id,
+		key
+	)
+	.execute(&mut *transaction)
+	.await?;
+	if notifications == Notifications::Off {
+		query!(
+			"
+			DELETE FROM pending_notifications
+			WHERE user = ?
+			",
+			user_id
+		)
+		.execute(&mut *transaction)
+		.await?;
+	}
+	transaction.commit().await?;
+	Ok(())
+}
+
+/// Tells the user about the notices the way they like, except for reactions, which are up to the caller as only it has something to react to. Returns how the user likes to be told.
+///
+/// A direct message that can't be sent stays in the digest for the user's next command.
+pub async fn notify(
+	database: &Pool<Sqlite>,
+	discord: &dyn Discord,
+	guild: GuildId,
+	user: UserId,
+	notices: &[Notice],
+) -> Result<Notifications, BotError> {
+	let notifications = get_notifications(database, user).await?;
+	if matches!(notifications, Notifications::Reaction | Notifications::Off) {
+		return Ok(notifications);
+	}
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let mut transaction = database.begin().await?;
+	for notice in notices {
+		let (kind, emoji, other_user) = notice.to_database_format();
+		query!(
+			"
+			INSERT INTO pending_notifications (guild, user, kind, emoji, other_user)
+			VALUES (?, ?, ?, ?, ?)
+			",
+			guild_id,
+			user_id,
+			kind,
+			emoji,
+			other_user
+		)
+		.execute(&mut *transaction)
+		.await?;
+	}
+	transaction.commit().await?;
+
+	if notifications == Notifications::DirectMessage {
+		if let Some((last_id, digest)) = get_digest(database, discord, guild, user).await? {
+			match discord.direct_message(user, digest).await {
+				Ok(()) => clear_digest(database, guild, user, last_id).await?,
+				Err(error) => eprintln!("Could not send a direct message to user {user}: {error}"),
+			}
+		}
+	}
+	Ok(notifications)
+}
+
+/// Everything the user has yet to be told in the guild, and the ID of the last notice in it. None if there is nothing.
+async fn get_digest(
+	database: &Pool<Sqlite>,
+	discord: &dyn Discord,
+	guild: GuildId,
+	user: UserId,
+) -> Result<Option<(i64, String)>, BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	let notices = query!(
+		"
+		SELECT id, kind, emoji, other_user
+		FROM pending_notifications
+		WHERE guild = ? AND user = ?
+		ORDER BY id ASC
+		",
+		guild_id,
+		user_id
+	)
+	.fetch_all(database)
+	.await?;
+	let Some(last_id) = notices.last().map(|notice| notice.id) else {
+		return Ok(None);
+	};
+
+	let emojis: Vec<_> = notices
+		.iter()
+		.filter_map(|notice| notice.emoji.as_deref())
+		.collect();
+	let streak_reward = notices.iter().any(|notice| notice.kind == "streak_reward");
+	let mut offering_users = Vec::new();
+	let mut expired_offer_targets = Vec::new();
+	for notice in &notices {
+		let (Some(other_user), Some(names)) = (
+			notice.other_user,
+			match notice.kind.as_str() {
+				"trade_offer" => Some(&mut offering_users),
+				"expired_offer" => Some(&mut expired_offer_targets),
+				_ => None,
+			},
+		) else {
+			continue;
+		};
+		let name = discord
+			.user_name(guild, UserId::new(other_user as u64))
+			.await;
+		if !names.contains(&name) {
+			names.push(name);
+		}
+	}
+
+	let mut digest = String::from("Since your last notification:");
+	if !emojis.is_empty() {
+		let s = if emojis.len() == 1 { "" } else { "s" };
+		write!(
+			digest,
+			"\n- You got {} emoji{s}: {}",
+			emojis.len(),
+			emojis.concat()
+		)
+		.unwrap();
+	}
+	if !offering_users.is_empty() {
+		write!(
+			digest,
+			"\n- {} offered you a trade. See your offers with /trade view.",
+			offering_users.join(", ")
+		)
+		.unwrap();
+	}
+	if !expired_offer_targets.is_empty() {
+		let s = if expired_offer_targets.len() == 1 {
+			""
+		} else {
+			"s"
+		};
+		write!(
+			digest,
+			"\n- Your trade offer{s} to {} expired.",
+			expired_offer_targets.join(", ")
+		)
+		.unwrap();
+	}
+	if streak_reward {
+		digest.push_str("\n- Your streak reached a milestone. Pick your reward with /streak.");
+	}
+	Ok(Some((last_id, digest)))
+}
+
+/// Clears the notices up to and including the last one in a digest, leaving any that came in while it was sent.
+async fn clear_digest(
+	database: &Pool<Sqlite>,
+	guild: GuildId,
+	user: UserId,
+	last_id: i64,
+) -> Result<(), BotError> {
+	let guild_id = guild.get() as i64;
+	let user_id = user.get() as i64;
+	query!(
+		"
+		DELETE FROM pending_notifications
+		WHERE guild = ? AND user = ? AND id <= ?
+		",
+		guild_id,
+		user_id,
+		last_id
+	)
+	.execute(database)
+	.await?;
+	Ok(())
+}
+
+/// Follows up on a command with whatever its user has yet to be told.
+pub async fn send_pending_digest(
+	context: Context<'_>,
+	interaction: &CommandInteraction,
+) -> Result<(), BotError> {
+	let user = interaction.user.id;
+	if let Some((last_id, digest)) =
+		get_digest(context.database, context.discord, context.guild, user).await?
+	{
+		context.discord.follow_up(interaction, digest, true).await?;
+		clear_digest(context.database, context.guild, user, last_id).await?;
+	}
+	Ok(())
+}
+
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let user = interaction.user.id;
+	let chosen = interaction
+		.data
+		.options
+		.first()
+		.and_then(|option| option.value.as_str());
+	let content = match chosen {
+		Some(key) => {
+			let notifications = Notifications::from_key(key)
+				.ok_or(BotError::InvalidInteraction("unknown notification setting"))?;
+			set_notifications(context.database, user, notifications).await?;
+			format!(
+				"You'll now be notified of drops and trade offers through: {}.",
+				notifications.description().to_lowercase()
+			)
+		}
+		None => {
+			let notifications = get_notifications(context.database, user).await?;
+			format!(
+				"You are notified of drops and trade offers through: {}.",
+				notifications.description().to_lowercase()
+			)
+		}
+	};
+	interaction
+		.ephemeral_reply(context.discord, content)
+		.await?;
+	Ok(())
+}
+
+fn register() -> CreateCommand {
+	let mut option = CreateCommandOption::new(
+		CommandOptionType::String,
+		"how",
+		"How to notify you. Leave out to see how you are notified now.",
+	);
+	for notifications in Notifications::ALL {
+		option = option.add_string_choice(notifications.description(), notifications.key());
+	}
+	CreateCommand::new("notifications")
+		.description("Choose how you are notified of drops and trade offers.")
+		.add_option(option)
+}
+
+pub struct NotificationsCommand;
+
+#[async_trait]
+impl SlashCommand for NotificationsCommand {
+	fn name(&self) -> &'static str {
+		"notifications"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::json::json;
+
+	use crate::{
+		discord::fake::{FakeDiscord, Sent},
+		emoji::EmojiMap,
+		testing::{self, GUILD},
+	};
+
+	use super::*;
+
+	#[tokio::test]
+	async fn digests_go_out_the_way_users_like() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let discord = FakeDiscord::new().with_member(UserId::new(2), "Trader", &[]);
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		let user = UserId::new(1);
+		let pumpkin = Notice::Emoji(emoji_map.get("🎃").unwrap());
+
+		assert_eq!(
+			notify(&database, &discord, GUILD, user, &[pumpkin])
+				.await
+				.unwrap(),
+			Notifications::Reaction
+		);
+
+		set_notifications(&database, user, Notifications::DirectMessage)
+			.await
+			.unwrap();
+		notify(&database, &discord, GUILD, user, &[pumpkin, pumpkin])
+			.await
+			.unwrap();
+		let sent = discord.take_sent();
+		assert!(
+			matches!(&sent[..], [Sent::DirectMessage { content, .. }] if content.contains("2 emojis: 🎃🎃")),
+			"{sent:?}"
+		);
+
+		let interaction = testing::command_interaction(
+			user,
+			"notifications",
+			json!([{"name": "how", "type": 3, "value": "next_command"}]),
+		);
+		execute(context, &interaction).await.unwrap();
+		notify(
+			&database,
+			&discord,
+			GUILD,
+			user,
+			&[Notice::TradeOffer(UserId::new(2)), Notice::StreakReward],
+		)
+		.await
+		.unwrap();
+		send_pending_digest(context, &interaction).await.unwrap();
+		send_pending_digest(context, &interaction).await.unwrap();
+		let sent = discord.take_sent();
+		assert!(
+			matches!(&sent[..], [Sent::Reply { .. }, Sent::FollowUp { content, ephemeral: true }] if content.contains("Trader offered you a trade") && content.contains("/streak")),
+			"{sent:?}"
+		);
+	}
+}
@@ -0,0 +1,326 @@
+//! What users let others see of them and send them, and `/settings` to change it.
+
+use serenity::{
+	all::{CommandInteraction, UserId},
+	async_trait,
+	builder::CreateCommand,
+};
+use sqlx::{query, Pool, Sqlite};
+
+use crate::{
+	commands::SlashCommand,
+	context::Context,
+	discord::{SelectMenu, SelectOption},
+	error::BotError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrivacySettings {
+	/// Whether others can view the user's inventory.
+	pub show_inventory: bool,
+	/// Whether the user is listed by `/who has`.
+	pub listed_as_owner: bool,
+	/// Whether recycling is announced to everyone, rather than only to the user.
+	pub public_recycling: bool,
+	/// Whether drops show as reactions to the message that earned them.
+	pub drop_reactions: bool,
+	pub trade_offers_from: TradeOffersFrom,
+}
+
+impl Default for PrivacySettings {
+	fn default() -> Self {
+		Self {
+			show_inventory: true,
+			listed_as_owner: true,
+			public_recycling: true,
+			drop_reactions: true,
+			trade_offers_from: TradeOffersFrom::Everyone,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeOffersFrom {
+	Everyone,
+	/// Users with one of the guild's trading roles.
+	TradingRole,
+	Nobody,
+}
+
+impl TradeOffersFrom {
+	const ALL: [Self; 3] = [Self::Everyone, Self::TradingRole, Self::Nobody];
+
+	/// How it's stored in the database, and the value of its menu option.
+	fn key(self) -> &'static str {
+		match self {
+			Self::Everyone => "everyone",
+			Self::TradingRole => "trading_role",
+			Self::Nobody => "nobody",
+		}
+	}
+
+	fn description(self) -> &'static str {
+		match self {
+			Self::Everyone => "Anyone can offer you trades",
+			Self::TradingRole => "Only users with a trading role can offer you trades",
+			Self::Nobody => "Nobody can offer you trades",
+		}
+	}
+
+	fn from_key(key: &str) -> Option<Self> {
+		Self::ALL.into_iter().find(|from| from.key() == key)
+	}
+}
+
+pub async fn get_privacy_settings(
+	database: &Pool<Sqlite>,
+	user: UserId,
+) -> Result<PrivacySettings, BotError> {
+	let user_id = user.get() as i64;
+	let record = query!(
+		"
+		SELECT show_inventory, listed_as_owner, public_recycling, drop_reactions, trade_offers_from
+		FROM user_settings
+		WHERE user = ?
+		",
+		user_id
+	)
+	.fetch_optional(database)
+	.await?;
+	Ok(
+		record.map_or_else(PrivacySettings::default, |record| PrivacySettings {
+			show_inventory: record.show_inventory != 0,
+			listed_as_owner: record.listed_as_owner != 0,
+			public_recycling: record.public_recycling != 0,
+			drop_reactions: record.drop_reactions != 0,
+			trade_offers_from: TradeOffersFrom::from_key(&record.trade_offers_from)
+				.unwrap_or(TradeOffersFrom::Everyone),
+		}),
+	)
+}
+
+async fn set_privacy_settings(
+	database: &Pool<Sqlite>,
+	user: UserId,
+	settings: PrivacySettings,
+) -> Result<(), BotError> {
+	let user_id = user.get() as i64;
+	let trade_offers_from = settings.trade_offers_from.key();
+	query!(
+		"
+		INSERT INTO user_settings (user, show_inventory, listed_as_owner, public_recycling, drop_reactions, trade_offers_from)
+		VALUES (?, ?, ?, ?, ?, ?)
+		ON CONFLICT (user)
+			DO UPDATE SET
+				show_inventory = excluded.show_inventory,
+				listed_as_owner = excluded.listed_as_owner,
+				public_recycling = excluded.public_recycling,
+				drop_reactions = excluded.drop_reactions,
+				trade_offers_from = excluded.trade_offers_from
+		",
+		user_id,
+		settings.show_inventory,
+		settings.listed_as_owner,
+		settings.public_recycling,
+		settings.drop_reactions,
+		trade_offers_from
+	)
+	.execute(database)
+	.await?;
+	Ok(())
+}
+
+/// A menu to turn a setting on or off with.
+fn toggle_menu(
+	id: &'static str,
+	on: bool,
+	on_label: &'static str,
+	off_label: &'static str,
+) -> SelectMenu {
+	SelectMenu {
+		id,
+		options: vec![
+			SelectOption {
+				value: "on",
+				label: String::from(on_label),
+				default: on,
+			},
+			SelectOption {
+				value: "off",
+				label: String::from(off_label),
+				default: !on,
+			},
+		],
+	}
+}
+
+/// One menu per setting, with the current value chosen.
+fn menus(settings: &PrivacySettings) -> Vec<SelectMenu> {
+	vec![
+		toggle_menu(
+			"show_inventory",
+			settings.show_inventory,
+			"Others can view your inventory",
+			"Your inventory is hidden from others",
+		),
+		toggle_menu(
+			"listed_as_owner",
+			settings.listed_as_owner,
+			"You are listed by /who has",
+			"You are not listed by /who has",
+		),
+		toggle_menu(
+			"public_recycling",
+			settings.public_recycling,
+			"Your recycling is announced to everyone",
+			"Only you see your recycling",
+		),
+		toggle_menu(
+			"drop_reactions",
+			settings.drop_reactions,
+			"Your drops show as reactions to your messages",
+			"Your drops don't show as reactions",
+		),
+		SelectMenu {
+			id: "trade_offers_from",
+			options: TradeOffersFrom::ALL
+				.into_iter()
+				.map(|from| SelectOption {
+					value: from.key(),
+					label: String::from(from.description()),
+					default: from == settings.trade_offers_from,
+				})
+				.collect(),
+		},
+	]
+}
+
+/// Changes the setting the menu is for to the chosen value.
+fn apply_choice(settings: &mut PrivacySettings, menu: &str, value: &str) -> Result<(), BotError> {
+	let on = value == "on";
+	match menu {
+		"show_inventory" => settings.show_inventory = on,
+		"listed_as_owner" => settings.listed_as_owner = on,
+		"public_recycling" => settings.public_recycling = on,
+		"drop_reactions" => settings.drop_reactions = on,
+		"trade_offers_from" => {
+			settings.trade_offers_from = TradeOffersFrom::from_key(value)
+				.ok_or(BotError::InvalidInteraction("unknown trade offer setting"))?
+		}
+		_ => return Err(BotError::InvalidInteraction("unknown settings menu")),
+	}
+	Ok(())
+}
+
+async fn execute(context: Context<'_>, interaction: &CommandInteraction) -> Result<(), BotError> {
+	let user = interaction.user.id;
+	let timeout = context.guild_config().trade_confirmation_timeout;
+	let mut settings = get_privacy_settings(context.database, user).await?;
+	let mut press = context
+		.discord
+		.ask_with_select_menus(
+			interaction,
+			String::from("Your privacy settings:"),
+			&menus(&settings),
+			timeout,
+		)
+		.await?;
+	while let Some(choice) = press {
+		let value = choice
+			.values
+			.first()
+			.ok_or(BotError::InvalidInteraction("nothing chosen"))?;
+		apply_choice(&mut settings, &choice.custom_id, value)?;
+		set_privacy_settings(context.database, user, settings).await?;
+		press = context
+			.discord
+			.update_select_menus(
+				&choice,
+				String::from("Your privacy settings are saved."),
+				&menus(&settings),
+				timeout,
+			)
+			.await?;
+	}
+	context.discord.delete_reply(interaction).await?;
+	Ok(())
+}
+
+fn register() -> CreateCommand {
+	CreateCommand::new("settings")
+		.description("Choose what others can see of you, and who can offer you trades.")
+}
+
+pub struct SettingsCommand;
+
+#[async_trait]
+impl SlashCommand for SettingsCommand {
+	fn name(&self) -> &'static str {
+		"settings"
+	}
+	fn register(&self) -> CreateCommand {
+		register()
+	}
+	async fn execute(
+		&self,
+		context: Context<'_>,
+		interaction: &CommandInteraction,
+	) -> Result<(), BotError> {
+		execute(context, interaction).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::json::json;
+
+	use crate::{
+		discord::fake::{FakeDiscord, Sent},
+		emoji::EmojiMap,
+		testing::{self, GUILD},
+	};
+
+	use super::*;
+
+	#[tokio::test]
+	async fn each_choice_is_saved() {
+		let database = testing::database().await;
+		let emoji_map = EmojiMap::without_images();
+		let config = testing::config();
+		let discord = FakeDiscord::new();
+		let context = Context::new(GUILD, &database, &emoji_map, &config, &discord);
+		let user = UserId::new(1);
+
+		discord.choose("listed_as_owner", "off");
+		discord.choose("trade_offers_from", "trading_role");
+		discord.press_button(None);
+		execute(
+			context,
+			&testing::command_interaction(user, "settings", json!([])),
+		)
+		.await
+		.unwrap();
+
+		let sent = discord.take_sent();
+		assert!(
+			matches!(
+				&sent[..],
+				[
+					Sent::SelectMenus { menus, .. },
+					Sent::SelectMenusUpdate { .. },
+					Sent::SelectMenusUpdate { .. },
+					Sent::DeletedReply
+				] if menus.len() == 5
+			),
+			"{sent:?}"
+		);
+		assert_eq!(
+			get_privacy_settings(&database, user).await.unwrap(),
+			PrivacySettings {
+				listed_as_owner: false,
+				trade_offers_from: TradeOffersFrom::TradingRole,
+				..PrivacySettings::default()
+			}
+		);
+	}
+}
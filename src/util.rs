@@ -1,149 +1,242 @@
-use std::{borrow::Cow, ops::Range, str::FromStr, sync::Arc};
+use std::{ops::Range, str::FromStr};
 
+use chrono::TimeDelta;
 use serenity::{
 	all::{CommandDataOption, CommandInteraction},
 	async_trait,
-	builder::{
-		CreateAttachment, CreateInteractionResponse, CreateInteractionResponseFollowup,
-		CreateInteractionResponseMessage,
-	},
-	http::Http,
-	Result as SerenityResult,
 };
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
+	discord::Discord,
 	emoji::{Emoji, EmojiMap},
+	error::BotError,
 	images::generate::EmojiToRender,
 	special_characters::ZWNJ,
 };
 
 #[async_trait]
 pub trait ReplyShortcuts {
-	async fn reply<S>(&self, http: &Arc<Http>, content: S, ephemeral: bool) -> SerenityResult<()>
+	async fn reply<S>(
+		&self,
+		discord: &dyn Discord,
+		content: S,
+		ephemeral: bool,
+	) -> Result<(), BotError>
 	where
 		S: Into<String> + Send;
-	async fn ephemeral_reply<S>(&self, http: &Arc<Http>, content: S) -> SerenityResult<()>
+	async fn ephemeral_reply<S>(&self, discord: &dyn Discord, content: S) -> Result<(), BotError>
 	where
 		S: Into<String> + std::marker::Send;
-	async fn public_reply<S>(&self, http: &Arc<Http>, content: S) -> SerenityResult<()>
+	async fn public_reply<S>(&self, discord: &dyn Discord, content: S) -> Result<(), BotError>
 	where
 		S: Into<String> + std::marker::Send;
 	async fn reply_image(
 		&self,
-		http: &Arc<Http>,
+		discord: &dyn Discord,
 		image: &[u8],
 		file_name: &str,
 		ephemeral: bool,
-	) -> SerenityResult<()>;
+	) -> Result<(), BotError>;
 	async fn public_reply_image(
 		&self,
-		http: &Arc<Http>,
+		discord: &dyn Discord,
 		image: &[u8],
 		file_name: &str,
-	) -> SerenityResult<()>;
+	) -> Result<(), BotError>;
+	#[allow(dead_code)]
 	async fn ephemeral_reply_image(
 		&self,
-		http: &Arc<Http>,
+		discord: &dyn Discord,
 		image: &[u8],
 		file_name: &str,
-	) -> SerenityResult<()>;
+	) -> Result<(), BotError>;
 	async fn follow_up_image(
 		&self,
-		http: &Arc<Http>,
+		discord: &dyn Discord,
 		image: &[u8],
 		file_name: &str,
-	) -> SerenityResult<serenity::all::Message>;
+	) -> Result<(), BotError>;
 }
 
 #[async_trait]
 impl ReplyShortcuts for CommandInteraction {
-	async fn reply<S>(&self, http: &Arc<Http>, content: S, ephemeral: bool) -> SerenityResult<()>
+	async fn reply<S>(
+		&self,
+		discord: &dyn Discord,
+		content: S,
+		ephemeral: bool,
+	) -> Result<(), BotError>
 	where
 		S: Into<String> + Send,
 	{
-		self.create_response(
-			http,
-			CreateInteractionResponse::Message(
-				CreateInteractionResponseMessage::new()
-					.content(content)
-					.ephemeral(ephemeral),
-			),
-		)
-		.await
+		discord.reply(self, content.into(), ephemeral).await
 	}
-	async fn ephemeral_reply<S>(&self, http: &Arc<Http>, content: S) -> SerenityResult<()>
+	async fn ephemeral_reply<S>(&self, discord: &dyn Discord, content: S) -> Result<(), BotError>
 	where
 		S: Into<String> + Send,
 	{
-		self.reply(http, content, true).await
+		self.reply(discord, content, true).await
 	}
-	async fn public_reply<S>(&self, http: &Arc<Http>, content: S) -> SerenityResult<()>
+	async fn public_reply<S>(&self, discord: &dyn Discord, content: S) -> Result<(), BotError>
 	where
 		S: Into<String> + Send,
 	{
-		self.reply(http, content, false).await
+		self.reply(discord, content, false).await
 	}
 	async fn reply_image(
 		&self,
-		http: &Arc<Http>,
+		discord: &dyn Discord,
 		image: &[u8],
 		file_name: &str,
 		ephemeral: bool,
-	) -> SerenityResult<()> {
-		self.create_response(
-			http,
-			CreateInteractionResponse::Message(
-				CreateInteractionResponseMessage::new()
-					.add_file(CreateAttachment::bytes(image, file_name))
-					.ephemeral(ephemeral),
-			),
-		)
-		.await
+	) -> Result<(), BotError> {
+		discord.reply_image(self, image, file_name, ephemeral).await
 	}
 	async fn public_reply_image(
 		&self,
-		http: &Arc<Http>,
+		discord: &dyn Discord,
 		image: &[u8],
 		file_name: &str,
-	) -> SerenityResult<()> {
-		self.reply_image(http, image, file_name, false).await
+	) -> Result<(), BotError> {
+		self.reply_image(discord, image, file_name, false).await
 	}
 	async fn ephemeral_reply_image(
 		&self,
-		http: &Arc<Http>,
+		discord: &dyn Discord,
 		image: &[u8],
 		file_name: &str,
-	) -> SerenityResult<()> {
-		self.reply_image(http, image, file_name, true).await
+	) -> Result<(), BotError> {
+		self.reply_image(discord, image, file_name, true).await
 	}
 	async fn follow_up_image(
 		&self,
-		http: &Arc<Http>,
+		discord: &dyn Discord,
 		image: &[u8],
 		file_name: &str,
-	) -> SerenityResult<serenity::all::Message> {
-		self.create_followup(
-			http,
-			CreateInteractionResponseFollowup::new()
-				.add_file(CreateAttachment::bytes(image, file_name)),
-		)
-		.await
+	) -> Result<(), BotError> {
+		discord.follow_up_image(self, image, file_name).await
 	}
 }
 
-pub fn parse_emoji_input(emoji_map: &EmojiMap, input: &str) -> Result<Vec<Emoji>, String> {
-	input
-		.graphemes(true)
-		.filter_map(|mut grapheme| {
-			if grapheme == " " || grapheme == ZWNJ {
-				return None;
-			}
-			grapheme = grapheme.trim_end_matches(ZWNJ);
-			let emoji = emoji_map.get(grapheme).ok_or_else(|| {
-				format!("Could not recognize \"{grapheme}\" as an emoji in my list.")
+/// A piece of emoji input: an emoji, however it was written, or a grapheme that is not one.
+struct InputToken<'i> {
+	/// Where the text starts in the input.
+	index: usize,
+	text: &'i str,
+	emoji: Option<Emoji>,
+}
+
+/// Splits emoji input into tokens. Besides the emojis themselves, `:shortcodes:` and quoted names are recognized.
+fn tokenize_emoji_input<'i>(
+	emoji_map: &EmojiMap,
+	input: &'i str,
+) -> Result<Vec<InputToken<'i>>, String> {
+	let mut tokens = Vec::new();
+	let mut skip_until = 0;
+	for (index, grapheme) in input.grapheme_indices(true) {
+		if index < skip_until {
+			continue;
+		}
+		let rest = &input[index..];
+		if let Some(length) = shortcode_length(rest) {
+			let text = &rest[..length];
+			let emoji = emoji_map.get_by_shortcode(text).ok_or_else(|| {
+				let suggestions: Vec<_> = emoji_map
+					.similar_shortcodes(text)
+					.into_iter()
+					.map(|shortcode| format!(":{shortcode}:"))
+					.collect();
+				format!(
+					"Could not recognize {text} as a shortcode in my list.{}",
+					did_you_mean(&suggestions)
+				)
+			})?;
+			tokens.push(InputToken {
+				index,
+				text,
+				emoji: Some(emoji),
+			});
+			skip_until = index + length;
+		} else if let Some(closing_quote) = closing_quote(grapheme) {
+			let name_start = index + grapheme.len();
+			let name_length = input[name_start..]
+				.find(closing_quote)
+				.ok_or("An emoji name is missing its closing quote.")?;
+			let name = &input[name_start..name_start + name_length];
+			let emoji = emoji_map.get_by_name(name).ok_or_else(|| {
+				let suggestions: Vec<_> = emoji_map
+					.similar_names(name)
+					.into_iter()
+					.map(|name| format!("\"{name}\""))
+					.collect();
+				format!(
+					"Could not find an emoji named \"{}\".{}",
+					name.trim(),
+					did_you_mean(&suggestions)
+				)
+			})?;
+			skip_until = name_start + name_length + closing_quote.len_utf8();
+			tokens.push(InputToken {
+				index,
+				text: &input[index..skip_until],
+				emoji: Some(emoji),
 			});
-			Some(emoji)
+		} else {
+			let text = grapheme.trim_end_matches(ZWNJ);
+			tokens.push(InputToken {
+				index,
+				text,
+				emoji: emoji_map.get(text),
+			});
+		}
+	}
+	Ok(tokens)
+}
+
+/// The length of the `:shortcode:` at the start of the input, if there is one.
+fn shortcode_length(input: &str) -> Option<usize> {
+	let inner = input.strip_prefix(':')?;
+	let end = inner.find(':')?;
+	let valid = end > 0
+		&& inner[..end]
+			.chars()
+			.all(|char| char.is_ascii_alphanumeric() || matches!(char, '_' | '-' | '+'));
+	valid.then_some(end + 2)
+}
+
+fn closing_quote(grapheme: &str) -> Option<char> {
+	match grapheme {
+		"\"" => Some('"'),
+		"“" => Some('”'),
+		_ => None,
+	}
+}
+
+fn did_you_mean(suggestions: &[String]) -> String {
+	if suggestions.is_empty() {
+		String::new()
+	} else {
+		format!(" Did you mean {}?", suggestions.join(", "))
+	}
+}
+
+/// Spaces and zero-width non-joiners can go between emojis.
+fn is_separator(text: &str) -> bool {
+	text.is_empty() || text == " "
+}
+
+pub fn parse_emoji_input(emoji_map: &EmojiMap, input: &str) -> Result<Vec<Emoji>, String> {
+	tokenize_emoji_input(emoji_map, input)?
+		.into_iter()
+		.filter(|token| !is_separator(token.text))
+		.map(|token| {
+			token.emoji.ok_or_else(|| {
+				format!(
+					"Could not recognize \"{}\" as an emoji in my list.",
+					token.text
+				)
+			})
 		})
 		.collect()
 }
@@ -158,12 +251,11 @@ pub fn parse_emoji_input_with_modifiers<'l>(
 	let mut last_emoji = None;
 	let mut size_modifier: Option<Range<usize>> = None;
 	let mut multiplier: Option<Range<usize>> = None;
-	for (index, mut grapheme) in input.grapheme_indices(true) {
-		if grapheme == " " || grapheme == ZWNJ {
+	for token in tokenize_emoji_input(emoji_map, input)? {
+		if is_separator(token.text) {
 			continue;
 		}
-		grapheme = grapheme.trim_end_matches(ZWNJ);
-		if let Some(emoji) = emoji_map.get_with_image(grapheme) {
+		if let Some(emoji) = token.emoji.map(|emoji| emoji_map.get_image(emoji)) {
 			if let Some(emoji) = last_emoji.take() {
 				let size: f32 = consume_number_input(input, &mut size_modifier, DEFAULT_EMOJI_SIZE)
 					.map_err(|err| format!("Error parsing {} as size modifier", err))?;
@@ -176,6 +268,7 @@ pub fn parse_emoji_input_with_modifiers<'l>(
 			}
 			last_emoji = Some(emoji);
 		} else {
+			let (index, grapheme) = (token.index, token.text);
 			let end_index = index + grapheme.len();
 			if let Some(ref mut multiplier) = &mut multiplier.as_mut() {
 				multiplier.end = end_index;
@@ -218,14 +311,22 @@ where
 		.unwrap_or(default))
 }
 
-/// Gets the emojis from a specified option index and ensures there is at least one emoji, otherwise returns a user-friendly error string.
+/// The option with the name, if it was given.
+pub fn find_option<'l>(
+	options: &'l [CommandDataOption],
+	name: &str,
+) -> Option<&'l CommandDataOption> {
+	options.iter().find(|option| option.name == name)
+}
+
+/// Gets the emojis from a specified option index and ensures there is at least one emoji, otherwise returns an error with a user-friendly message.
 pub fn get_and_parse_emoji_option(
 	emoji_map: &EmojiMap,
 	option: Option<&CommandDataOption>,
-) -> Result<Vec<Emoji>, Cow<'static, str>> {
+) -> Result<Vec<Emoji>, BotError> {
 	let input = option
 		.and_then(|option| option.value.as_str())
-		.ok_or("Emojis argument not supplied.")?;
+		.ok_or(BotError::InvalidInteraction("emojis option missing"))?;
 
 	let emojis = parse_emoji_input(emoji_map, input)?;
 
@@ -235,6 +336,21 @@ pub fn get_and_parse_emoji_option(
 	Ok(emojis)
 }
 
+/// Like "3 hours", in the largest unit that fits and rounded up, so waiting that long is always enough.
+pub fn describe_duration(duration: TimeDelta) -> String {
+	let minutes = (duration.num_seconds().max(1) + 59) / 60;
+	let hours = (minutes + 59) / 60;
+	let (count, unit) = if minutes < 60 {
+		(minutes, "minute")
+	} else if hours < 24 {
+		(hours, "hour")
+	} else {
+		((hours + 23) / 24, "day")
+	};
+	let s = if count == 1 { "" } else { "s" };
+	format!("{count} {unit}{s}")
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -258,4 +374,22 @@ mod tests {
 			assert_eq!(input.trim_end_matches(ZWNJ), expected);
 		}
 	}
+
+	#[test]
+	fn shortcodes_and_names() {
+		let emoji_map = EmojiMap::without_images();
+		let parsed = parse_emoji_input(
+			&emoji_map,
+			"🙂:thumbsup: \"red heart\"\u{200C}“Japanese here button”:+1:",
+		);
+		let parsed: Vec<_> = parsed.unwrap().iter().map(Emoji::as_str).collect();
+		assert_eq!(parsed, ["🙂", "👍", "❤️", "🈁", "👍"]);
+
+		let error = parse_emoji_input(&emoji_map, ":thumbsupp:").unwrap_err();
+		assert!(error.contains("Did you mean :thumbsup:"), "{error}");
+		let error = parse_emoji_input(&emoji_map, "\"thumb up\"").unwrap_err();
+		assert!(error.contains("Did you mean \"thumbs up\""), "{error}");
+		assert!(parse_emoji_input(&emoji_map, "\"red heart").is_err());
+		assert!(parse_emoji_input(&emoji_map, "a:b").is_err());
+	}
 }